@@ -1,7 +1,9 @@
 use crate::benchmark::BenchmarkFixture;
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+};
 use concrete_csprng::seeders::UnixSeeder;
 use criterion::Criterion;
 
@@ -51,11 +53,15 @@ bench! {
     ((),CleartextVectorDiscardingRetrievalFixture, (CleartextVector)),
     ((),CleartextVectorRetrievalFixture, (CleartextVector)),
     ((BinaryKeyDistribution), GlweCiphertextDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    ((TernaryKeyDistribution), GlweCiphertextDecryptionFixture, (PlaintextVector, GlweTernarySecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), GlweCiphertextDiscardingKeyswitchFixture, (GlweKeyswitchKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingMultiplicationFixture, (GlweCiphertext, GlweRelinearizationKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingTrivialEncryptionFixture, (PlaintextVector, GlweCiphertextMutView)),
     ((BinaryKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    ((TernaryKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweTernarySecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextZeroEncryptionFixture, (GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
@@ -73,6 +79,24 @@ bench! {
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertext, Vec)),
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextView, Slice)),
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextMutView, MutSlice)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingOppositeFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingOppositeFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingMonomialMultiplicationFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingMonomialMultiplicationFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorDiscardingAdditionFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorFusingAdditionFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorDiscardingSubtractionFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorFusingSubtractionFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorDiscardingOppositeFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorFusingOppositeFixture, (GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorDiscardingAdditionFixture, (GlweCiphertext, PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorFusingAdditionFixture, (GlweCiphertext, PlaintextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorDiscardingMultiplicationFixture, (GlweCiphertext, PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorFusingMultiplicationFixture, (GlweCiphertext, PlaintextVector)),
     ((BinaryKeyDistribution), GlweSecretKeyGenerationFixture, (GlweSecretKey)),
     ((BinaryKeyDistribution), GlweSeededCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertext)),
     ((BinaryKeyDistribution), GlweSeededCiphertextToGlweCiphertextTransformationFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertext, GlweCiphertext)),
@@ -93,11 +117,13 @@ bench! {
     ((BinaryKeyDistribution, BinaryKeyDistribution),
         LweBootstrapKeyConsumingRetrievalFixture, (LweBootstrapKeyMutView, MutSlice)),
     ((BinaryKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    ((TernaryKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, LweTernarySecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextTrivialDecryptionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    ((TernaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweTernarySecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertextView)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertextMutView)),
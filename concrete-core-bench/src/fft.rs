@@ -1,7 +1,9 @@
 use crate::benchmark::BenchmarkFixture;
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+};
 use criterion::Criterion;
 
 use paste::paste;
@@ -36,8 +38,13 @@ macro_rules! bench {
 
 #[cfg(feature = "backend_fft")]
 bench! {
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBlindRotationFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture2, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingManyLutBootstrapFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingMultiBitBootstrapFixture, (FftFourierLweMultiBitBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((TernaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingTernaryBootstrapFixture, (FftFourierLweTernaryBootstrapKey, FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingMultiplicationFixture, (GlweCiphertext, FftFourierGlweRelinearizationKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FftFourierGgswCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext,
         GlweCiphertext, FftFourierGgswCiphertext)),
@@ -45,7 +52,10 @@ bench! {
         (FftFourierLweBootstrapKey, LweKeyswitchKey, LweCiphertext, LweCiphertextVector)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingFixture,
         (FftFourierLweBootstrapKey, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
-        PlaintextVector, LweCiphertextVectorView, LweCiphertextVectorMutView))
+        PlaintextVector, LweCiphertextVectorView, LweCiphertextVectorMutView)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingWopPbsFixture,
+        (FftFourierLweBootstrapKey, LweKeyswitchKey, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+        PlaintextVector, LweCiphertextVector, LweCiphertextVector))
 }
 
 #[cfg(feature = "backend_fft_parallel")]
@@ -13,6 +13,9 @@ mod default;
 #[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
 mod fft;
 
+#[cfg(feature = "backend_ntt")]
+mod ntt;
+
 #[cfg(feature = "backend_cuda")]
 mod cuda;
 
@@ -27,6 +30,8 @@ fn main() {
     fft::bench();
     #[cfg(feature = "backend_fft_parallel")]
     fft::bench_parallel();
+    #[cfg(feature = "backend_ntt")]
+    ntt::bench();
     #[cfg(feature = "backend_cuda")]
     cuda::bench();
     #[cfg(feature = "backend_cuda")]
@@ -0,0 +1,144 @@
+use crate::fixture::{serialization_parameters, Fixture};
+use crate::generation::synthesizing::{
+    SerializableEntityParameters, SynthesizesSerializableEntity,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_core::prelude::{
+    AbstractEntity, EntityDeserializationEngine, EntitySerializationEngine,
+    EntityStreamDeserializationEngine, EntityStreamSerializationEngine,
+};
+
+/// A fixture for the types implementing the `EntityDeserializationEngine` and
+/// `EntityStreamDeserializationEngine` traits, which checks that corrupted or truncated serialized
+/// entities are rejected, both in memory and from a reader.
+///
+/// The serialized entity is produced by the `EntitySerializationEngine` and
+/// `EntityStreamSerializationEngine` implementations of the same engine.
+pub struct EntityDeserializationFixture;
+
+/// The alteration applied to a serialized entity before it gets deserialized.
+#[derive(Debug, Clone, Copy)]
+pub enum SerializedEntityAlteration {
+    /// The bit at the given position (modulo the number of bits) is flipped.
+    BitFlip(u64),
+    /// The serialized entity is truncated to the given length (modulo its length).
+    Truncation(u64),
+}
+
+impl SerializedEntityAlteration {
+    fn apply(&self, serialized: &mut Vec<u8>) {
+        match *self {
+            SerializedEntityAlteration::BitFlip(position) => {
+                let position = (position % (serialized.len() as u64 * 8)) as usize;
+                serialized[position / 8] ^= 1 << (position % 8);
+            }
+            SerializedEntityAlteration::Truncation(length) => {
+                serialized.truncate((length % serialized.len() as u64) as usize);
+            }
+        }
+    }
+}
+
+impl<Precision, Engine, Entity, DeserializedEntity>
+    Fixture<Precision, (), Engine, (Entity, DeserializedEntity)> for EntityDeserializationFixture
+where
+    Precision: IntegerPrecision,
+    Entity: AbstractEntity,
+    DeserializedEntity: AbstractEntity,
+    Engine: EntitySerializationEngine<Entity, Vec<u8>>
+        + for<'a> EntityDeserializationEngine<&'a [u8], DeserializedEntity>
+        + EntityStreamSerializationEngine<Entity, Vec<u8>>
+        + for<'a> EntityStreamDeserializationEngine<&'a [u8], DeserializedEntity>,
+    Maker: SynthesizesSerializableEntity<Precision, Entity>,
+{
+    type Parameters = SerializableEntityParameters;
+    type RepetitionPrototypes =
+        (<Maker as SynthesizesSerializableEntity<Precision, Entity>>::SerializableEntityProto,);
+    type SamplePrototypes = (SerializedEntityAlteration,);
+    type PreExecutionContext = (Entity, SerializedEntityAlteration);
+    type PostExecutionContext = (Entity, bool);
+    type Criteria = ();
+    type Outcome = bool;
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(serialization_parameters().into_iter())
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_entity = maker.new_serializable_entity(parameters);
+        (proto_entity,)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let alteration = if u64::uniform_between(0..2) == 0 {
+            SerializedEntityAlteration::BitFlip(u64::uniform())
+        } else {
+            SerializedEntityAlteration::Truncation(u64::uniform())
+        };
+        (alteration,)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_entity,) = repetition_proto;
+        let (alteration,) = sample_proto;
+        let synth_entity: Entity = maker.synthesize_serializable_entity(proto_entity);
+        (synth_entity, *alteration)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (entity, alteration) = context;
+        let mut serialized = unsafe { engine.serialize_unchecked(&entity) };
+        alteration.apply(&mut serialized);
+        let rejected = engine.deserialize(serialized.as_slice()).is_err();
+        let mut streamed = Vec::new();
+        unsafe { engine.serialize_into_unchecked(&entity, &mut streamed) };
+        alteration.apply(&mut streamed);
+        let stream_rejected = engine.deserialize_from(&mut streamed.as_slice()).is_err();
+        (entity, rejected && stream_rejected)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (entity, rejected) = context;
+        let (proto_entity,) = repetition_proto;
+        maker.unsynthesize_serializable_entity(proto_entity, entity);
+        rejected
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        outputs.iter().all(|rejected| *rejected)
+    }
+}
@@ -0,0 +1,166 @@
+use crate::fixture::Fixture;
+use crate::generation::synthesizing::{
+    SerializableEntityParameters, SynthesizesSerializableEntity,
+};
+use crate::generation::{IntegerPrecision, Maker};
+use concrete_core::prelude::{
+    AbstractEntity, DecompositionBaseLog, DecompositionLevelCount, EntityDeserializationEngine,
+    EntitySerializationEngine, EntityStreamDeserializationEngine, EntityStreamSerializationEngine,
+    GlweDimension, LweDimension, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `EntitySerializationEngine` and
+/// `EntityStreamSerializationEngine` traits, along with the `EntityDeserializationEngine` and
+/// `EntityStreamDeserializationEngine` traits for the entity the serialized entity deserializes
+/// to.
+///
+/// The entity goes through a serialization round trip, both in memory and through a stream, and
+/// each deserialized entity is checked to be structurally equal to the original one, to decrypt
+/// to the same values for ciphertexts and secret keys, or to give the same results when used for
+/// keys.
+pub struct EntitySerializationFixture;
+
+impl<Precision, Engine, Entity, DeserializedEntity>
+    Fixture<Precision, (), Engine, (Entity, DeserializedEntity)> for EntitySerializationFixture
+where
+    Precision: IntegerPrecision,
+    Entity: AbstractEntity,
+    DeserializedEntity: AbstractEntity,
+    Engine: EntitySerializationEngine<Entity, Vec<u8>>
+        + for<'a> EntityDeserializationEngine<&'a [u8], DeserializedEntity>
+        + EntityStreamSerializationEngine<Entity, Vec<u8>>
+        + for<'a> EntityStreamDeserializationEngine<&'a [u8], DeserializedEntity>,
+    Maker: SynthesizesSerializableEntity<Precision, Entity>
+        + SynthesizesSerializableEntity<
+            Precision,
+            DeserializedEntity,
+            SerializableEntityProto = <Maker as SynthesizesSerializableEntity<
+                Precision,
+                Entity,
+            >>::SerializableEntityProto,
+            SerializableEntityOutcome = <Maker as SynthesizesSerializableEntity<
+                Precision,
+                Entity,
+            >>::SerializableEntityOutcome,
+        >,
+{
+    type Parameters = SerializableEntityParameters;
+    type RepetitionPrototypes =
+        (<Maker as SynthesizesSerializableEntity<Precision, Entity>>::SerializableEntityProto,);
+    type SamplePrototypes = ();
+    type PreExecutionContext = (Entity,);
+    type PostExecutionContext = (Entity, DeserializedEntity, DeserializedEntity);
+    type Criteria = ();
+    type Outcome = (
+        <Maker as SynthesizesSerializableEntity<Precision, Entity>>::SerializableEntityOutcome,
+        <Maker as SynthesizesSerializableEntity<Precision, Entity>>::SerializableEntityOutcome,
+        <Maker as SynthesizesSerializableEntity<Precision, Entity>>::SerializableEntityOutcome,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(serialization_parameters().into_iter())
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_entity =
+            SynthesizesSerializableEntity::<Precision, Entity>::new_serializable_entity(
+                maker, parameters,
+            );
+        (proto_entity,)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_entity,) = repetition_proto;
+        let synth_entity: Entity = maker.synthesize_serializable_entity(proto_entity);
+        (synth_entity,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (entity,) = context;
+        let serialized = unsafe { engine.serialize_unchecked(&entity) };
+        let deserialized = unsafe { engine.deserialize_unchecked(serialized.as_slice()) };
+        let mut streamed = Vec::new();
+        unsafe { engine.serialize_into_unchecked(&entity, &mut streamed) };
+        let stream_deserialized =
+            unsafe { engine.deserialize_from_unchecked(&mut streamed.as_slice()) };
+        (entity, deserialized, stream_deserialized)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (entity, deserialized, stream_deserialized) = context;
+        let (proto_entity,) = repetition_proto;
+        (
+            maker.unsynthesize_serializable_entity(proto_entity, entity),
+            maker.unsynthesize_serializable_entity(proto_entity, deserialized),
+            maker.unsynthesize_serializable_entity(proto_entity, stream_deserialized),
+        )
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        outputs
+            .iter()
+            .all(|(original, round_tripped, stream_round_tripped)| {
+                original == round_tripped && original == stream_round_tripped
+            })
+    }
+}
+
+/// The parameters shared by the serialization and deserialization fixtures.
+pub(crate) fn serialization_parameters() -> Vec<SerializableEntityParameters> {
+    vec![
+        SerializableEntityParameters {
+            lwe_dimension: LweDimension(10),
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(256),
+            decomposition_level_count: DecompositionLevelCount(2),
+            decomposition_base_log: DecompositionBaseLog(4),
+            count: 3,
+            noise: Variance(0.00000001),
+        },
+        SerializableEntityParameters {
+            lwe_dimension: LweDimension(20),
+            glwe_dimension: GlweDimension(2),
+            polynomial_size: PolynomialSize(512),
+            decomposition_level_count: DecompositionLevelCount(3),
+            decomposition_base_log: DecompositionBaseLog(7),
+            count: 5,
+            noise: Variance(0.00000001),
+        },
+    ]
+}
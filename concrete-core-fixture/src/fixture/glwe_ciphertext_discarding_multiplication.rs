@@ -0,0 +1,428 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweRelinearizationKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesGlweRelinearizationKey,
+};
+use crate::generation::{
+    BinaryKeyDistribution, GaussianKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker,
+    TernaryKeyDistribution,
+};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::{Numeric, UnsignedInteger};
+use concrete_core::prelude::{
+    BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount, DeltaLog, DispersionParameter,
+    GaussianKeyKind, GlweCiphertextDiscardingMultiplicationEngine, GlweCiphertextEntity,
+    GlweDimension, GlweRelinearizationKeyEntity, PolynomialSize, TernaryKeyKind, Variance,
+};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingMultiplicationEngine` trait.
+pub struct GlweCiphertextDiscardingMultiplicationFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingMultiplicationParameters {
+    pub glwe_noise: Variance,
+    pub relinearization_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub message_bits: usize,
+    pub delta_log: DeltaLog,
+}
+
+// To be able to use different sets of parameters between different precision for the fixtures this
+// function needs to be const evaluable, the powf function to get the variance is not const, so we
+// compute the value manually and paste the result as the variance to use
+const fn get_parameters_for_raw_precision<T: IntegerPrecision>(
+) -> [GlweCiphertextDiscardingMultiplicationParameters; 2_usize] {
+    if T::Raw::BITS == 32 {
+        [
+            GlweCiphertextDiscardingMultiplicationParameters {
+                // Offline evaluation of 2.0f64.powf(-50.) is
+                // 0.0000000000000008881784197001252f64
+                glwe_noise: Variance(0.0000000000000008881784197001252f64),
+                relinearization_noise: Variance(0.0000000000000008881784197001252f64),
+                glwe_dimension: GlweDimension(1),
+                polynomial_size: PolynomialSize(512),
+                decomposition_base_log: DecompositionBaseLog(4),
+                decomposition_level_count: DecompositionLevelCount(6),
+                message_bits: 2,
+                delta_log: DeltaLog(28),
+            },
+            GlweCiphertextDiscardingMultiplicationParameters {
+                glwe_noise: Variance(0.0000000000000008881784197001252f64),
+                relinearization_noise: Variance(0.0000000000000008881784197001252f64),
+                glwe_dimension: GlweDimension(2),
+                polynomial_size: PolynomialSize(256),
+                decomposition_base_log: DecompositionBaseLog(4),
+                decomposition_level_count: DecompositionLevelCount(6),
+                message_bits: 2,
+                delta_log: DeltaLog(28),
+            },
+        ]
+    } else if T::Raw::BITS == 64 {
+        [
+            GlweCiphertextDiscardingMultiplicationParameters {
+                // Offline evaluation of 2.0f64.powf(-100.) is
+                // 0.0000000000000000000000000000007888609052210118f64
+                glwe_noise: Variance(0.0000000000000000000000000000007888609052210118f64),
+                relinearization_noise: Variance(
+                    0.0000000000000000000000000000007888609052210118f64,
+                ),
+                glwe_dimension: GlweDimension(1),
+                polynomial_size: PolynomialSize(512),
+                decomposition_base_log: DecompositionBaseLog(12),
+                decomposition_level_count: DecompositionLevelCount(4),
+                message_bits: 2,
+                delta_log: DeltaLog(60),
+            },
+            GlweCiphertextDiscardingMultiplicationParameters {
+                glwe_noise: Variance(0.0000000000000000000000000000007888609052210118f64),
+                relinearization_noise: Variance(
+                    0.0000000000000000000000000000007888609052210118f64,
+                ),
+                glwe_dimension: GlweDimension(2),
+                polynomial_size: PolynomialSize(256),
+                decomposition_base_log: DecompositionBaseLog(12),
+                decomposition_level_count: DecompositionLevelCount(4),
+                message_bits: 2,
+                delta_log: DeltaLog(60),
+            },
+        ]
+    } else {
+        unreachable!()
+    }
+}
+
+impl<Precision, KeyDistribution, Engine, InputCiphertext, RelinearizationKey, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (InputCiphertext, RelinearizationKey, OutputCiphertext),
+    > for GlweCiphertextDiscardingMultiplicationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextDiscardingMultiplicationEngine<
+        InputCiphertext,
+        RelinearizationKey,
+        OutputCiphertext,
+    >,
+    InputCiphertext: GlweCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>
+        + SynthesizesGlweRelinearizationKey<Precision, KeyDistribution, RelinearizationKey>,
+{
+    type Parameters = GlweCiphertextDiscardingMultiplicationParameters;
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweRelinearizationKey<Precision, KeyDistribution>>::GlweRelinearizationKeyProto,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        InputCiphertext,
+        InputCiphertext,
+        RelinearizationKey,
+        OutputCiphertext,
+    );
+    type PostExecutionContext = (
+        InputCiphertext,
+        InputCiphertext,
+        RelinearizationKey,
+        OutputCiphertext,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(IntoIterator::into_iter(get_parameters_for_raw_precision::<
+            Precision,
+        >()))
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_relinearization_key = maker.new_glwe_relinearization_key(
+            &proto_secret_key,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.relinearization_noise,
+        );
+        (proto_secret_key, proto_relinearization_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        // Both messages are polynomials with small random coefficients, bounded by the maximal
+        // messages used in the noise estimation of the product.
+        let raw_plaintext_vector_1: Vec<Precision::Raw> = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        )
+        .into_iter()
+        .map(|v| (v >> (Precision::Raw::BITS - parameters.message_bits)) << parameters.delta_log.0)
+        .collect();
+        let raw_message_2 = Precision::Raw::pick_vec(
+            &[
+                Precision::Raw::zero(),
+                Precision::Raw::one(),
+                Precision::Raw::power_of_two(1),
+            ],
+            parameters.polynomial_size.0,
+        );
+        let raw_plaintext_vector_2: Vec<Precision::Raw> = raw_message_2
+            .iter()
+            .map(|v| *v << parameters.delta_log.0)
+            .collect();
+        let proto_plaintext_vector_1 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_1);
+        let proto_plaintext_vector_2 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector_2);
+        let proto_ciphertext_1 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_1,
+            parameters.glwe_noise,
+        );
+        let proto_ciphertext_2 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector_2,
+            parameters.glwe_noise,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            raw_plaintext_vector_1,
+            raw_message_2,
+            proto_ciphertext_1,
+            proto_ciphertext_2,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_relinearization_key) = repetition_proto;
+        let (_, _, proto_ciphertext_1, proto_ciphertext_2, proto_output_ciphertext) = sample_proto;
+        let synth_ciphertext_1 = maker.synthesize_glwe_ciphertext(proto_ciphertext_1);
+        let synth_ciphertext_2 = maker.synthesize_glwe_ciphertext(proto_ciphertext_2);
+        let synth_relinearization_key =
+            maker.synthesize_glwe_relinearization_key(proto_relinearization_key);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_ciphertext_1,
+            synth_ciphertext_2,
+            synth_relinearization_key,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext_1, ciphertext_2, relinearization_key, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_mul_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &ciphertext_1,
+                &ciphertext_2,
+                &relinearization_key,
+                parameters.delta_log,
+            )
+        };
+        (
+            ciphertext_1,
+            ciphertext_2,
+            relinearization_key,
+            output_ciphertext,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext_1, ciphertext_2, relinearization_key, output_ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (raw_plaintext_vector_1, raw_message_2, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_ciphertext(ciphertext_1);
+        maker.destroy_glwe_ciphertext(ciphertext_2);
+        maker.destroy_glwe_relinearization_key(relinearization_key);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        // The first plaintext already carries the scaling factor, so its negacyclic product with
+        // the second message gives the expected plaintext.
+        let raw_expected_plaintext_vector =
+            negacyclic_wrapping_mul(raw_plaintext_vector_1, raw_message_2);
+        (
+            raw_expected_plaintext_vector,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let delta = 2_f64.powi(parameters.delta_log.0 as i32);
+        let output_variance = fix_estimate_multiplication_noise::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            Variance,
+            KeyDistribution,
+        >(
+            parameters.polynomial_size,
+            parameters.glwe_dimension,
+            parameters.glwe_noise,
+            parameters.glwe_noise,
+            delta,
+            delta,
+            2_f64.powi(parameters.message_bits as i32),
+            2.,
+            parameters.relinearization_noise,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+        );
+        (output_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means.into_iter().flatten().collect::<Vec<_>>();
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+// Computes the product of two polynomials modulo X^N + 1, with wrapping coefficients.
+fn negacyclic_wrapping_mul<T: UnsignedInteger>(lhs: &[T], rhs: &[T]) -> Vec<T> {
+    let poly_size = lhs.len();
+    let mut output = vec![T::ZERO; poly_size];
+    for (i, lhs_coef) in lhs.iter().enumerate() {
+        for (j, rhs_coef) in rhs.iter().enumerate() {
+            let product = lhs_coef.wrapping_mul(*rhs_coef);
+            if i + j < poly_size {
+                output[i + j] = output[i + j].wrapping_add(product);
+            } else {
+                output[i + j - poly_size] = output[i + j - poly_size].wrapping_sub(product);
+            }
+        }
+    }
+    output
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+#[allow(clippy::too_many_arguments)]
+fn fix_estimate_multiplication_noise<T, D1, D2, D3, K>(
+    poly_size: PolynomialSize,
+    mask_size: GlweDimension,
+    dispersion_glwe1: D1,
+    dispersion_glwe2: D2,
+    delta_1: f64,
+    delta_2: f64,
+    max_msg_1: f64,
+    max_msg_2: f64,
+    dispersion_rlk: D3,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D1: DispersionParameter,
+    D2: DispersionParameter,
+    D3: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<D1, D2, D3, BinaryKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else if k_type_id == TypeId::of::<TernaryKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<D1, D2, D3, TernaryKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else if k_type_id == TypeId::of::<GaussianKeyDistribution>() {
+        concrete_npe::estimate_multiplication_noise::<D1, D2, D3, GaussianKeyKind>(
+            poly_size,
+            mask_size,
+            dispersion_glwe1,
+            dispersion_glwe2,
+            delta_1,
+            delta_2,
+            max_msg_1,
+            max_msg_2,
+            dispersion_rlk,
+            base_log,
+            level,
+            T::BITS as u32,
+        )
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
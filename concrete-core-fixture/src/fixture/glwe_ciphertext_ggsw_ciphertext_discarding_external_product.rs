@@ -247,7 +247,7 @@ where
 // FIXME:
 // The current NPE does not use the key distribution markers of concrete-core. This function makes
 // the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
-fn fix_estimate_external_product_noise_with_binary_ggsw<T, D1, D2, K>(
+pub(crate) fn fix_estimate_external_product_noise_with_binary_ggsw<T, D1, D2, K>(
     poly_size: PolynomialSize,
     rlwe_mask_size: GlweDimension,
     var_glwe: D1,
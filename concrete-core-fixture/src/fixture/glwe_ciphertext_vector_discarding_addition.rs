@@ -0,0 +1,239 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertextVector, PrototypesGlweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::SynthesizesGlweCiphertextVector;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::{Numeric, UnsignedInteger};
+use concrete_core::prelude::{
+    DispersionParameter, GlweCiphertextCount, GlweCiphertextVectorDiscardingAdditionEngine,
+    GlweCiphertextVectorEntity, GlweDimension, LogStandardDev, PlaintextCount, PolynomialSize,
+    Variance,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextVectorDiscardingAdditionEngine` trait.
+pub struct GlweCiphertextVectorDiscardingAdditionFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextVectorDiscardingAdditionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub count: GlweCiphertextCount,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, InputCiphertextVector, OutputCiphertextVector>
+    Fixture<Precision, (KeyDistribution,), Engine, (InputCiphertextVector, OutputCiphertextVector)>
+    for GlweCiphertextVectorDiscardingAdditionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine:
+        GlweCiphertextVectorDiscardingAdditionEngine<InputCiphertextVector, OutputCiphertextVector>,
+    InputCiphertextVector: GlweCiphertextVectorEntity,
+    OutputCiphertextVector: GlweCiphertextVectorEntity,
+    Maker: SynthesizesGlweCiphertextVector<Precision, KeyDistribution, InputCiphertextVector>
+        + SynthesizesGlweCiphertextVector<Precision, KeyDistribution, OutputCiphertextVector>,
+{
+    type Parameters = GlweCiphertextVectorDiscardingAdditionParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertextVector<Precision, KeyDistribution>>::GlweCiphertextVectorProto,
+        <Maker as PrototypesGlweCiphertextVector<Precision, KeyDistribution>>::GlweCiphertextVectorProto,
+        <Maker as PrototypesGlweCiphertextVector<Precision, KeyDistribution>>::GlweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        InputCiphertextVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type PostExecutionContext = (
+        InputCiphertextVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextVectorDiscardingAdditionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    count: GlweCiphertextCount(10),
+                },
+                GlweCiphertextVectorDiscardingAdditionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-15.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    count: GlweCiphertextCount(10),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector1 =
+            Precision::Raw::uniform_vec(parameters.polynomial_size.0 * parameters.count.0);
+        let raw_plaintext_vector2 =
+            Precision::Raw::uniform_vec(parameters.polynomial_size.0 * parameters.count.0);
+        let proto_plaintext_vector1 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector1);
+        let proto_plaintext_vector2 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector2);
+        let proto_input_ciphertext_vector1 = maker
+            .encrypt_plaintext_vector_to_glwe_ciphertext_vector(
+                proto_secret_key,
+                &proto_plaintext_vector1,
+                parameters.noise,
+            );
+        let proto_input_ciphertext_vector2 = maker
+            .encrypt_plaintext_vector_to_glwe_ciphertext_vector(
+                proto_secret_key,
+                &proto_plaintext_vector2,
+                parameters.noise,
+            );
+        let proto_output_ciphertext_vector = maker
+            .trivially_encrypt_zeros_to_glwe_ciphertext_vector(
+                parameters.glwe_dimension.to_glwe_size(),
+                parameters.count,
+                PlaintextCount(parameters.polynomial_size.0 * parameters.count.0),
+            );
+        (
+            proto_plaintext_vector1,
+            proto_plaintext_vector2,
+            proto_input_ciphertext_vector1,
+            proto_input_ciphertext_vector2,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (
+            _,
+            _,
+            proto_input_ciphertext_vector1,
+            proto_input_ciphertext_vector2,
+            proto_output_ciphertext_vector,
+        ) = sample_proto;
+        let synth_input_ciphertext_vector1 =
+            maker.synthesize_glwe_ciphertext_vector(proto_input_ciphertext_vector1);
+        let synth_input_ciphertext_vector2 =
+            maker.synthesize_glwe_ciphertext_vector(proto_input_ciphertext_vector2);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_glwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            synth_input_ciphertext_vector1,
+            synth_input_ciphertext_vector2,
+            synth_output_ciphertext_vector,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext_vector1, input_ciphertext_vector2, mut output_ciphertext_vector) =
+            context;
+        unsafe {
+            engine.discard_add_glwe_ciphertext_vector_unchecked(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector1,
+                &input_ciphertext_vector2,
+            )
+        };
+        (
+            input_ciphertext_vector1,
+            input_ciphertext_vector2,
+            output_ciphertext_vector,
+        )
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_vector1, input_ciphertext_vector2, output_ciphertext_vector) =
+            context;
+        let (proto_plaintext_vector1, proto_plaintext_vector2, ..) = sample_proto;
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector1 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector1);
+        let raw_plaintext_vector2 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector2);
+        let predicted_output = raw_plaintext_vector1
+            .iter()
+            .zip(raw_plaintext_vector2.iter())
+            .map(|(&a, &b)| a.wrapping_add(b))
+            .collect();
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_glwe_ciphertext_vector(output_ciphertext_vector);
+        let proto_output_plaintext_vector = maker
+            .decrypt_glwe_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        maker.destroy_glwe_ciphertext_vector(input_ciphertext_vector1);
+        maker.destroy_glwe_ciphertext_vector(input_ciphertext_vector2);
+        (
+            predicted_output,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance = concrete_npe::estimate_addition_noise::<_, _>(
+            parameters.noise,
+            parameters.noise,
+            Precision::Raw::BITS as u32,
+        );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
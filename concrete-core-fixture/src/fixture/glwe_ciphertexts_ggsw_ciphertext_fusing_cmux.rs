@@ -259,7 +259,7 @@ where
 // FIXME:
 // The current NPE does not use the key distribution markers of concrete-core. This function makes
 // the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
-fn fix_estimate_cmux_noise_with_binary_ggsw<T, D1, D2, D3, K: KeyDistributionMarker>(
+pub(crate) fn fix_estimate_cmux_noise_with_binary_ggsw<T, D1, D2, D3, K: KeyDistributionMarker>(
     poly_size: PolynomialSize,
     glwe_mask_size: GlweDimension,
     var_output_glwe: D1,
@@ -0,0 +1,514 @@
+use crate::fixture::lwe_ciphertext_discarding_bootstrap_1::fix_estimate_pbs_noise;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweBootstrapKey,
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesLweBootstrapKey, SynthesizesLweCiphertext,
+};
+use crate::generation::{BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::{assert_noise_distribution, measure_noise_variance};
+use concrete_core::commons::numeric::{CastFrom, CastInto, Numeric, UnsignedInteger};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweCiphertextEntity,
+    GlweDimension, LogStandardDev, LweBootstrapKeyEntity, LweCiphertextDiscardingBootstrapEngine,
+    LweCiphertextEntity, LweDimension, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingBootstrapEngine` trait with
+/// a bootstrap key encrypting a ternary input key.
+///
+/// Every sample is also bootstrapped with a binary bootstrap key of the same parameters, which
+/// serves as a reference for the decryption and the noise. Both bootstraps are executed by the
+/// engine, so the benchmarks of this fixture time the pair.
+pub struct LweCiphertextDiscardingTernaryBootstrapFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingTernaryBootstrapParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub message_bits: usize,
+}
+
+impl LweCiphertextDiscardingTernaryBootstrapParameters {
+    // The messages cover the whole torus, without padding bit.
+    fn shift<Raw: Numeric>(&self) -> usize {
+        Raw::BITS - self.message_bits
+    }
+
+    // The lookup table encoded in the accumulator evaluates x -> x + 1 on the first half of the
+    // torus, and the negacyclic extension of the accumulator evaluates x -> -(x - half + 1) on
+    // the second half.
+    fn lut<Raw: RawUnsignedIntegers>(&self, message: usize) -> Raw {
+        let half = 1 << (self.message_bits - 1);
+        let value =
+            <Raw as CastFrom<f64>>::cast_from((message % half + 1) as f64) << self.shift::<Raw>();
+        if message < half {
+            value
+        } else {
+            value.wrapping_neg()
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        BootstrapKey,
+        BinaryBootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            BootstrapKey,
+            BinaryBootstrapKey,
+            Accumulator,
+            InputCiphertext,
+            OutputCiphertext,
+        ),
+    > for LweCiphertextDiscardingTernaryBootstrapFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingBootstrapEngine<
+            BootstrapKey,
+            Accumulator,
+            InputCiphertext,
+            OutputCiphertext,
+        > + LweCiphertextDiscardingBootstrapEngine<
+            BinaryBootstrapKey,
+            Accumulator,
+            InputCiphertext,
+            OutputCiphertext,
+        >,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+    Accumulator: GlweCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    BinaryBootstrapKey: LweBootstrapKeyEntity,
+    Maker: SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesLweBootstrapKey<
+            Precision,
+            BinaryKeyDistribution,
+            OutputKeyDistribution,
+            BinaryBootstrapKey,
+        > + SynthesizesGlweCiphertext<Precision, OutputKeyDistribution, Accumulator>
+        + SynthesizesLweCiphertext<Precision, InputKeyDistribution, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision, BinaryKeyDistribution, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision, OutputKeyDistribution, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextDiscardingTernaryBootstrapParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, BinaryKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            BinaryKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, BinaryKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, OutputKeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        BootstrapKey,
+        BinaryBootstrapKey,
+        Accumulator,
+        OutputCiphertext,
+        OutputCiphertext,
+        InputCiphertext,
+        InputCiphertext,
+    );
+    type PostExecutionContext = (
+        BootstrapKey,
+        BinaryBootstrapKey,
+        Accumulator,
+        OutputCiphertext,
+        OutputCiphertext,
+        InputCiphertext,
+        InputCiphertext,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextDiscardingTernaryBootstrapParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(1024),
+                    decomp_level_count: DecompositionLevelCount(2),
+                    decomp_base_log: DecompositionBaseLog(10),
+                    message_bits: 3,
+                },
+                LweCiphertextDiscardingTernaryBootstrapParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(2048),
+                    decomp_level_count: DecompositionLevelCount(3),
+                    decomp_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        // The messages of the first half of the torus are spread over the boxes of coefficients
+        // of the accumulator. Its first half box is negated and rotated to the end, so that the
+        // messages sit in the middle of their boxes.
+        let box_size = (2 * parameters.poly_size.0) >> parameters.message_bits;
+        let mut raw_plaintext_vector: Vec<Precision::Raw> = (0..parameters.poly_size.0)
+            .map(|i| parameters.lut(i / box_size))
+            .collect();
+        raw_plaintext_vector[..box_size / 2]
+            .iter_mut()
+            .for_each(|coef| *coef = coef.wrapping_neg());
+        raw_plaintext_vector.rotate_left(box_size / 2);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_accumulator = maker.trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            &proto_plaintext_vector,
+        );
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_binary_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            BinaryKeyDistribution,
+        >>::new_lwe_secret_key(
+            maker, parameters.lwe_dimension
+        );
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::new_lwe_bootstrap_key(
+            maker,
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.noise,
+        );
+        let proto_binary_bootstrap_key = <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            BinaryKeyDistribution,
+            OutputKeyDistribution,
+        >>::new_lwe_bootstrap_key(
+            maker,
+            &proto_binary_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.noise,
+        );
+        (
+            proto_accumulator,
+            proto_lwe_secret_key,
+            proto_binary_lwe_secret_key,
+            proto_glwe_secret_key,
+            proto_bootstrap_key,
+            proto_binary_bootstrap_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_lwe_secret_key, proto_binary_lwe_secret_key, ..) = repetition_proto;
+        let raw_plaintext = Precision::Raw::uniform_between(0..1 << parameters.message_bits)
+            << parameters.shift::<Precision::Raw>();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_binary_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            BinaryKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_binary_lwe_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            maker,
+            LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+        );
+        (
+            proto_plaintext,
+            proto_input_ciphertext,
+            proto_binary_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_accumulator, _, _, _, proto_bootstrap_key, proto_binary_bootstrap_key) =
+            repetition_proto;
+        let (_, proto_input_ciphertext, proto_binary_input_ciphertext, proto_output_ciphertext) =
+            sample_proto;
+        let synth_bootstrap_key =
+            <Maker as SynthesizesLweBootstrapKey<
+                Precision,
+                InputKeyDistribution,
+                OutputKeyDistribution,
+                BootstrapKey,
+            >>::synthesize_lwe_bootstrap_key(maker, proto_bootstrap_key);
+        let synth_binary_bootstrap_key =
+            <Maker as SynthesizesLweBootstrapKey<
+                Precision,
+                BinaryKeyDistribution,
+                OutputKeyDistribution,
+                BinaryBootstrapKey,
+            >>::synthesize_lwe_bootstrap_key(maker, proto_binary_bootstrap_key);
+        let synth_accumulator = maker.synthesize_glwe_ciphertext(proto_accumulator);
+        let synth_input_ciphertext = <Maker as SynthesizesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+            InputCiphertext,
+        >>::synthesize_lwe_ciphertext(
+            maker, proto_input_ciphertext
+        );
+        let synth_binary_input_ciphertext =
+            <Maker as SynthesizesLweCiphertext<
+                Precision,
+                BinaryKeyDistribution,
+                InputCiphertext,
+            >>::synthesize_lwe_ciphertext(maker, proto_binary_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        let synth_binary_output_ciphertext =
+            maker.synthesize_lwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_bootstrap_key,
+            synth_binary_bootstrap_key,
+            synth_accumulator,
+            synth_output_ciphertext,
+            synth_binary_output_ciphertext,
+            synth_input_ciphertext,
+            synth_binary_input_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            bootstrap_key,
+            binary_bootstrap_key,
+            accumulator,
+            mut output_ciphertext,
+            mut binary_output_ciphertext,
+            input_ciphertext,
+            binary_input_ciphertext,
+        ) = context;
+        unsafe {
+            engine.discard_bootstrap_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &accumulator,
+                &bootstrap_key,
+            );
+            engine.discard_bootstrap_lwe_ciphertext_unchecked(
+                &mut binary_output_ciphertext,
+                &binary_input_ciphertext,
+                &accumulator,
+                &binary_bootstrap_key,
+            );
+        };
+        (
+            bootstrap_key,
+            binary_bootstrap_key,
+            accumulator,
+            output_ciphertext,
+            binary_output_ciphertext,
+            input_ciphertext,
+            binary_input_ciphertext,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (
+            bootstrap_key,
+            binary_bootstrap_key,
+            accumulator,
+            output_ciphertext,
+            binary_output_ciphertext,
+            input_ciphertext,
+            binary_input_ciphertext,
+        ) = context;
+        let (_, _, _, proto_glwe_secret_key, ..) = repetition_proto;
+        let (proto_plaintext, ..) = sample_proto;
+        let proto_output_lwe_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(proto_glwe_secret_key);
+        let decrypt = |maker: &mut Maker, output_ciphertext: OutputCiphertext| {
+            let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(output_ciphertext);
+            let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+                Precision,
+                OutputKeyDistribution,
+            >>::decrypt_lwe_ciphertext_to_plaintext(
+                maker,
+                &proto_output_lwe_secret_key,
+                &proto_output_ciphertext,
+            );
+            maker.transform_plaintext_to_raw(&proto_output_plaintext)
+        };
+        let output = decrypt(maker, output_ciphertext);
+        let binary_output = decrypt(maker, binary_output_ciphertext);
+        <Maker as SynthesizesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+            InputCiphertext,
+        >>::destroy_lwe_ciphertext(maker, input_ciphertext);
+        <Maker as SynthesizesLweCiphertext<
+            Precision,
+            BinaryKeyDistribution,
+            InputCiphertext,
+        >>::destroy_lwe_ciphertext(maker, binary_input_ciphertext);
+        <Maker as SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        >>::destroy_lwe_bootstrap_key(maker, bootstrap_key);
+        <Maker as SynthesizesLweBootstrapKey<
+            Precision,
+            BinaryKeyDistribution,
+            OutputKeyDistribution,
+            BinaryBootstrapKey,
+        >>::destroy_lwe_bootstrap_key(maker, binary_bootstrap_key);
+        maker.destroy_glwe_ciphertext(accumulator);
+        let shift = parameters.shift::<Precision::Raw>();
+        let message: f64 = (maker.transform_plaintext_to_raw(proto_plaintext) >> shift).cast_into();
+        (parameters.lut(message as usize), output, binary_output)
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // Every coefficient of a ternary key costs two cmuxes, so the output noise is bounded by
+        // the one of a binary bootstrap over twice the lwe dimension.
+        let predicted_variance: Variance =
+            fix_estimate_pbs_noise::<Precision::Raw, Variance, OutputKeyDistribution>(
+                LweDimension(2 * parameters.lwe_dimension.0),
+                parameters.poly_size,
+                parameters.glwe_dimension,
+                parameters.decomp_base_log,
+                parameters.decomp_level_count,
+                parameters.noise,
+            );
+        (predicted_variance,)
+    }
+
+    fn verify(
+        parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let expected: Vec<_> = outputs.iter().map(|(expected, ..)| *expected).collect();
+        let actual: Vec<_> = outputs.iter().map(|(_, actual, _)| *actual).collect();
+        let binary: Vec<_> = outputs.iter().map(|(.., binary)| *binary).collect();
+
+        // Both bootstraps must decrypt to the expected message.
+        let rounding = Precision::Raw::ONE << (parameters.shift::<Precision::Raw>() - 1);
+        let decode = |raw: Precision::Raw| {
+            raw.wrapping_add(rounding) >> parameters.shift::<Precision::Raw>()
+        };
+        let decrypts = expected
+            .iter()
+            .zip(actual.iter().zip(binary.iter()))
+            .all(|(e, (a, b))| decode(*a) == decode(*e) && decode(*b) == decode(*e));
+
+        // Doubling the cmuxes at most doubles the variance of the binary bootstrap, which adds 0.5
+        // to the log2 of the standard deviation, on top of which the same 0.5 slack as in
+        // `assert_noise_distribution` is given.
+        let actual_std_log2 = measure_noise_variance(&actual, &expected)
+            .get_standard_dev()
+            .log2();
+        let binary_std_log2 = measure_noise_variance(&binary, &expected)
+            .get_standard_dev()
+            .log2();
+        if actual_std_log2 > binary_std_log2 + 1. {
+            println!(
+                "ternary std {:?}, binary std {:?}",
+                actual_std_log2, binary_std_log2
+            );
+        }
+
+        decrypts
+            && actual_std_log2 <= binary_std_log2 + 1.
+            && assert_noise_distribution(&actual, &expected, criteria.0)
+    }
+}
@@ -0,0 +1,542 @@
+use crate::fixture::lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing::fix_estimate_wop_pbs_noise;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweBootstrapKey, PrototypesLweCiphertextVector,
+    PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, PrototypesLweKeyswitchKey,
+    PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweBootstrapKey, SynthesizesLweCiphertextVector,
+    SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    SynthesizesLweKeyswitchKey, SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_delta_std_dev;
+use concrete_core::commons::math::decomposition::SignedDecomposer;
+use concrete_core::commons::numeric::Numeric;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, ExtractedBitsCount, GlweDimension,
+    LweBootstrapKeyEntity, LweCiphertextCount, LweCiphertextVectorDiscardingWopPbsEngine,
+    LweCiphertextVectorEntity, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LweDimension, LweKeyswitchKeyEntity, PlaintextVectorEntity, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `LweCiphertextVectorDiscardingWopPbsEngine` trait.
+pub struct LweCiphertextVectorDiscardingWopPbsFixture;
+
+#[derive(Debug, Clone)]
+pub struct LweCiphertextVectorDiscardingWopPbsParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomp_level_count_bsk: DecompositionLevelCount,
+    pub decomp_base_log_bsk: DecompositionBaseLog,
+    pub decomp_level_count_ksk: DecompositionLevelCount,
+    pub decomp_base_log_ksk: DecompositionBaseLog,
+    pub decomp_level_count_pfpksk: DecompositionLevelCount,
+    pub decomp_base_log_pfpksk: DecompositionBaseLog,
+    pub decomp_level_count_cbs: DecompositionLevelCount,
+    pub decomp_base_log_cbs: DecompositionBaseLog,
+    // The number of bits of each input block, the output blocks use the same layout
+    pub extracted_bits_counts: &'static [ExtractedBitsCount],
+}
+
+// To be able to use different sets of parameters between different precision for the fixtures this
+// function needs to be const evaluable, the powf function to get the variance is not const, so we
+// compute the value manually and paste the result as the variance to use
+const fn get_parameters_for_raw_precision<Precision: IntegerPrecision>(
+) -> [LweCiphertextVectorDiscardingWopPbsParameters; 1_usize] {
+    if Precision::Raw::BITS == 32 {
+        [LweCiphertextVectorDiscardingWopPbsParameters {
+            // Offline evaluation 2.0f64.powf(-120.) is
+            // 0.000000000000000000000000000000000000752316384526264
+            noise: Variance(0.000000000000000000000000000000000000752316384526264f64),
+            lwe_dimension: LweDimension(10),
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(512),
+            decomp_level_count_bsk: DecompositionLevelCount(7),
+            decomp_base_log_bsk: DecompositionBaseLog(4),
+            decomp_level_count_ksk: DecompositionLevelCount(9),
+            decomp_base_log_ksk: DecompositionBaseLog(1),
+            decomp_level_count_pfpksk: DecompositionLevelCount(7),
+            decomp_base_log_pfpksk: DecompositionBaseLog(4),
+            decomp_level_count_cbs: DecompositionLevelCount(7),
+            decomp_base_log_cbs: DecompositionBaseLog(4),
+            extracted_bits_counts: &[ExtractedBitsCount(4), ExtractedBitsCount(5)],
+        }]
+    } else if Precision::Raw::BITS == 64 {
+        [LweCiphertextVectorDiscardingWopPbsParameters {
+            // Offline evaluation 2.0f64.powf(-120.) is
+            // 0.000000000000000000000000000000000000752316384526264
+            noise: Variance(0.000000000000000000000000000000000000752316384526264f64),
+            lwe_dimension: LweDimension(10),
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(512),
+            decomp_level_count_bsk: DecompositionLevelCount(9),
+            decomp_base_log_bsk: DecompositionBaseLog(4),
+            decomp_level_count_ksk: DecompositionLevelCount(7),
+            decomp_base_log_ksk: DecompositionBaseLog(4),
+            decomp_level_count_pfpksk: DecompositionLevelCount(9),
+            decomp_base_log_pfpksk: DecompositionBaseLog(4),
+            decomp_level_count_cbs: DecompositionLevelCount(4),
+            decomp_base_log_cbs: DecompositionBaseLog(6),
+            extracted_bits_counts: &[ExtractedBitsCount(4), ExtractedBitsCount(5)],
+        }]
+    } else {
+        unreachable!()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        BigLweAndGlweKeyDistribution,
+        SmallLweKeyDistribution,
+        Engine,
+        BootstrapKey,
+        KeyswitchKey,
+        CBSPFPKSK,
+        LUTs,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >
+    Fixture<
+        Precision,
+        (BigLweAndGlweKeyDistribution, SmallLweKeyDistribution),
+        Engine,
+        (
+            BootstrapKey,
+            KeyswitchKey,
+            CBSPFPKSK,
+            LUTs,
+            InputCiphertextVector,
+            OutputCiphertextVector,
+        ),
+    > for LweCiphertextVectorDiscardingWopPbsFixture
+where
+    Precision: IntegerPrecision,
+    BigLweAndGlweKeyDistribution: KeyDistributionMarker,
+    SmallLweKeyDistribution: KeyDistributionMarker,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    CBSPFPKSK: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LUTs: PlaintextVectorEntity,
+    Engine: LweCiphertextVectorDiscardingWopPbsEngine<
+        InputCiphertextVector,
+        OutputCiphertextVector,
+        BootstrapKey,
+        KeyswitchKey,
+        LUTs,
+        CBSPFPKSK,
+    >,
+    Maker: PrototypesGlweSecretKey<Precision, BigLweAndGlweKeyDistribution>
+        + SynthesizesLweBootstrapKey<
+            Precision,
+            SmallLweKeyDistribution,
+            BigLweAndGlweKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesLweKeyswitchKey<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+            SmallLweKeyDistribution,
+            KeyswitchKey,
+        > + SynthesizesLweCiphertextVector<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+            InputCiphertextVector,
+        > + SynthesizesLweCiphertextVector<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+            OutputCiphertextVector,
+        > + SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+            BigLweAndGlweKeyDistribution,
+            CBSPFPKSK,
+        > + SynthesizesPlaintextVector<Precision, LUTs>,
+{
+    type Parameters = LweCiphertextVectorDiscardingWopPbsParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<
+            Precision,
+            BigLweAndGlweKeyDistribution
+        >>::LweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            SmallLweKeyDistribution,
+            BigLweAndGlweKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweKeyswitchKey<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+            SmallLweKeyDistribution,
+        >>::LweKeyswitchKeyProto,
+        <Maker as PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+            BigLweAndGlweKeyDistribution,
+        >>::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision, BigLweAndGlweKeyDistribution
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision, BigLweAndGlweKeyDistribution
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        OutputCiphertextVector,
+        InputCiphertextVector,
+        BootstrapKey,
+        KeyswitchKey,
+        LUTs,
+        CBSPFPKSK,
+    );
+    type PostExecutionContext = (
+        OutputCiphertextVector,
+        InputCiphertextVector,
+        BootstrapKey,
+        KeyswitchKey,
+        LUTs,
+        CBSPFPKSK,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(get_parameters_for_raw_precision::<Precision>().into_iter())
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_small_lwe_sk = <Maker as PrototypesLweSecretKey<
+            Precision,
+            SmallLweKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_sk = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.polynomial_size
+        );
+        let proto_big_lwe_sk = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+        >>::transform_glwe_secret_key_to_lwe_secret_key(
+            maker, &proto_glwe_sk
+        );
+
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_small_lwe_sk,
+            &proto_glwe_sk,
+            parameters.decomp_level_count_bsk,
+            parameters.decomp_base_log_bsk,
+            parameters.noise,
+        );
+
+        let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+            &proto_big_lwe_sk,
+            &proto_small_lwe_sk,
+            parameters.decomp_level_count_ksk,
+            parameters.decomp_base_log_ksk,
+            parameters.noise,
+        );
+
+        let proto_cbs_pfpksk =
+            <Maker as PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+                Precision,
+                BigLweAndGlweKeyDistribution,
+                BigLweAndGlweKeyDistribution,
+            >>::new_lwe_private_functional_packing_keyswitch_key(
+                maker,
+                &proto_big_lwe_sk,
+                &proto_glwe_sk,
+                parameters.decomp_base_log_pfpksk,
+                parameters.decomp_level_count_pfpksk,
+                parameters.noise,
+            );
+
+        // A random function is evaluated, each output block holding as many bits as the input
+        // block at the same position
+        let total_bits: usize = parameters
+            .extracted_bits_counts
+            .iter()
+            .map(|bits| bits.0)
+            .sum();
+        let mut raw_luts = Vec::with_capacity(parameters.extracted_bits_counts.len() << total_bits);
+        for bits in parameters.extracted_bits_counts.iter() {
+            raw_luts.extend(
+                Precision::Raw::uniform_between_vec(0..1 << bits.0, 1 << total_bits)
+                    .into_iter()
+                    .map(|value| value << (Precision::Raw::BITS - bits.0)),
+            );
+        }
+        let proto_lut_plaintext_vector = maker.transform_raw_vec_to_plaintext_vector(&raw_luts);
+
+        (
+            proto_big_lwe_sk,
+            proto_bootstrap_key,
+            proto_keyswitch_key,
+            proto_cbs_pfpksk,
+            proto_lut_plaintext_vector,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_big_lwe_sk, _, _, _, _) = repetition_proto;
+
+        let raw_input_blocks: Vec<Precision::Raw> = parameters
+            .extracted_bits_counts
+            .iter()
+            .map(|bits| Precision::Raw::uniform_between(0..1 << bits.0))
+            .collect();
+        let encoded_input_blocks: Vec<Precision::Raw> = raw_input_blocks
+            .iter()
+            .zip(parameters.extracted_bits_counts.iter())
+            .map(|(&block, bits)| block << (Precision::Raw::BITS - bits.0))
+            .collect();
+        let proto_input_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&encoded_input_blocks);
+
+        let proto_input_lwe_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_big_lwe_sk,
+            &proto_input_plaintext_vector,
+            parameters.noise,
+        );
+
+        let proto_output_lwe_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+        >>::trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            maker,
+            LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0),
+            LweCiphertextCount(parameters.extracted_bits_counts.len()),
+        );
+
+        (
+            raw_input_blocks,
+            proto_input_lwe_ciphertext_vector,
+            proto_output_lwe_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (
+            _,
+            proto_bootstrap_key,
+            proto_keyswitch_key,
+            proto_cbs_pfpksk,
+            proto_lut_plaintext_vector,
+        ) = repetition_proto;
+        let (_, proto_input_lwe_ciphertext_vector, proto_output_lwe_ciphertext_vector) =
+            sample_proto;
+
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_lwe_ciphertext_vector);
+        let synth_input_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_lwe_ciphertext_vector);
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_keyswitch_key = maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key);
+        let synth_luts = maker.synthesize_plaintext_vector(proto_lut_plaintext_vector);
+        let synth_cbs_pfpksk = maker
+            .synthesize_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                proto_cbs_pfpksk,
+            );
+
+        (
+            synth_output_ciphertext_vector,
+            synth_input_ciphertext_vector,
+            synth_bootstrap_key,
+            synth_keyswitch_key,
+            synth_luts,
+            synth_cbs_pfpksk,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            mut output_ciphertext_vector,
+            input_ciphertext_vector,
+            bootstrap_key,
+            keyswitch_key,
+            luts,
+            cbs_pfpksk,
+        ) = context;
+
+        unsafe {
+            engine.discard_wop_pbs_lwe_ciphertext_vector_unchecked(
+                &mut output_ciphertext_vector,
+                &input_ciphertext_vector,
+                &bootstrap_key,
+                &keyswitch_key,
+                &luts,
+                parameters.extracted_bits_counts,
+                parameters.decomp_level_count_cbs,
+                parameters.decomp_base_log_cbs,
+                &cbs_pfpksk,
+            )
+        };
+
+        (
+            output_ciphertext_vector,
+            input_ciphertext_vector,
+            bootstrap_key,
+            keyswitch_key,
+            luts,
+            cbs_pfpksk,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_big_lwe_sk, _, _, _, _) = repetition_proto;
+        let (raw_input_blocks, _, _) = sample_proto;
+        let (
+            output_ciphertext_vector,
+            input_ciphertext_vector,
+            bootstrap_key,
+            keyswitch_key,
+            luts,
+            cbs_pfpksk,
+        ) = context;
+
+        let proto_luts = maker.unsynthesize_plaintext_vector(luts);
+        let raw_luts = maker.transform_plaintext_vector_to_raw_vec(&proto_luts);
+        let small_lut_size = raw_luts.len() / parameters.extracted_bits_counts.len();
+
+        // The bits of the first block are the most significant bits of the look-up table index
+        let mut index: usize = 0;
+        for (&block, bits) in raw_input_blocks
+            .iter()
+            .zip(parameters.extracted_bits_counts.iter())
+        {
+            for bit_position in (0..bits.0).rev() {
+                index <<= 1;
+                // Complicated to write conversion for a Precsision::Raw value to usize, dumb
+                // workaround to still be able to write what we need
+                if (block >> bit_position) & Precision::Raw::one() == Precision::Raw::one() {
+                    index |= 1;
+                }
+            }
+        }
+
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(output_ciphertext_vector);
+        let proto_decrypted_output_plaintext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            BigLweAndGlweKeyDistribution,
+        >>::decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+            maker,
+            proto_big_lwe_sk,
+            &proto_output_ciphertext_vector,
+        );
+        let raw_decrypted_output_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(&proto_decrypted_output_plaintext_vector);
+
+        let vec_expected: Vec<Precision::Raw> = (0..parameters.extracted_bits_counts.len())
+            .map(|small_lut_idx| raw_luts[small_lut_idx * small_lut_size + index])
+            .collect();
+
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_keyswitch_key(keyswitch_key);
+        maker.destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(cbs_pfpksk);
+
+        (vec_expected, raw_decrypted_output_plaintext_vector)
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let total_bits: usize = parameters
+            .extracted_bits_counts
+            .iter()
+            .map(|bits| bits.0)
+            .sum();
+        (fix_estimate_wop_pbs_noise::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            BigLweAndGlweKeyDistribution,
+            SmallLweKeyDistribution,
+        >(
+            ExtractedBitsCount(total_bits),
+            parameters.lwe_dimension,
+            parameters.polynomial_size,
+            parameters.glwe_dimension,
+            parameters.decomp_base_log_cbs,
+            parameters.decomp_level_count_cbs,
+            parameters.noise,
+            parameters.noise,
+            Precision::Raw::BITS as u32,
+        ),)
+    }
+
+    fn verify(
+        parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let decomposers: Vec<_> = parameters
+            .extracted_bits_counts
+            .iter()
+            .map(|bits| {
+                SignedDecomposer::<Precision::Raw>::new(
+                    DecompositionBaseLog(bits.0),
+                    DecompositionLevelCount(1),
+                )
+            })
+            .collect();
+
+        // Each output block is decoded with the precision of its own look-up table
+        let recovered_lut_evals_are_correct = outputs.iter().all(|(expected, actual)| {
+            expected
+                .iter()
+                .zip(actual.iter())
+                .zip(decomposers.iter())
+                .all(|((&expected, &decrypted), decomposer)| {
+                    decomposer.closest_representable(decrypted) == expected
+                })
+        });
+
+        let (expected, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let expected: Vec<Precision::Raw> = expected.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        let noise_ok = assert_delta_std_dev(&expected, &actual, criteria.0);
+
+        recovered_lut_evals_are_correct && noise_ok
+    }
+}
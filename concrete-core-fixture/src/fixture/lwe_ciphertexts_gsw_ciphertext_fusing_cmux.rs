@@ -0,0 +1,227 @@
+use crate::fixture::glwe_ciphertexts_ggsw_ciphertext_fusing_cmux::fix_estimate_cmux_noise_with_binary_ggsw;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGswCiphertext, PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesGswCiphertext, SynthesizesLweCiphertext};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::UnsignedInteger;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
+    GswCiphertextEntity, LogStandardDev, LweCiphertextEntity,
+    LweCiphertextsGswCiphertextFusingCmuxEngine, LweDimension, PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `LweCiphertextsGswCiphertextFusingCmux` trait.
+pub struct LweCiphertextsGswCiphertextFusingCmuxFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextsGswCiphertextFusingCmuxParameters {
+    pub gsw_noise: Variance,
+    pub lwe_noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub gsw_encrypted_value: usize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+}
+
+impl<Precision, KeyDistribution, Engine, LweInput, LweOutput, GswInput>
+    Fixture<Precision, (KeyDistribution,), Engine, (LweInput, LweOutput, GswInput)>
+    for LweCiphertextsGswCiphertextFusingCmuxFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextsGswCiphertextFusingCmuxEngine<LweInput, LweOutput, GswInput>,
+    LweInput: LweCiphertextEntity,
+    LweOutput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity,
+    Maker: SynthesizesLweCiphertext<Precision, KeyDistribution, LweInput>
+        + SynthesizesLweCiphertext<Precision, KeyDistribution, LweOutput>
+        + SynthesizesGswCiphertext<Precision, KeyDistribution, GswInput>,
+{
+    type Parameters = LweCiphertextsGswCiphertextFusingCmuxParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGswCiphertext<Precision, KeyDistribution>>::GswCiphertextProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (LweInput, GswInput, LweOutput);
+    type PostExecutionContext = (LweInput, GswInput, LweOutput);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextsGswCiphertextFusingCmuxParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    gsw_encrypted_value: 0,
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+                LweCiphertextsGswCiphertextFusingCmuxParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    gsw_encrypted_value: 1,
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let raw_plaintext = match parameters.gsw_encrypted_value {
+            0 => Precision::Raw::zero(),
+            _ => Precision::Raw::one(),
+        };
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_gsw = maker.encrypt_plaintext_to_gsw_ciphertext(
+            &proto_secret_key,
+            &proto_plaintext,
+            parameters.gsw_noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        (proto_plaintext, proto_secret_key, proto_gsw)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_secret_key, _) = repetition_proto;
+        let raw_plaintext0 = Precision::Raw::uniform();
+        let raw_plaintext1 = Precision::Raw::uniform();
+        let proto_plaintext0 = maker.transform_raw_to_plaintext(&raw_plaintext0);
+        let proto_plaintext1 = maker.transform_raw_to_plaintext(&raw_plaintext1);
+        let proto_output_lwe_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext0,
+            parameters.lwe_noise,
+        );
+        let proto_lwe_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext1,
+            parameters.lwe_noise,
+        );
+        (
+            proto_plaintext0,
+            proto_output_lwe_ciphertext,
+            proto_plaintext1,
+            proto_lwe_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_gsw_ciphertext) = repetition_proto;
+        let (_, proto_output_lwe_ciphertext, _, proto_lwe_ciphertext) = sample_proto;
+        let synth_lwe_ciphertext = maker.synthesize_lwe_ciphertext(proto_lwe_ciphertext);
+        let synth_gsw_ciphertext = maker.synthesize_gsw_ciphertext(proto_gsw_ciphertext);
+        let synth_output_lwe_ciphertext =
+            maker.synthesize_lwe_ciphertext(proto_output_lwe_ciphertext);
+        (
+            synth_lwe_ciphertext,
+            synth_gsw_ciphertext,
+            synth_output_lwe_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (lwe_ciphertext, gsw_ciphertext, mut output_lwe_ciphertext) = context;
+        unsafe {
+            engine.fuse_cmux_lwe_ciphertexts_gsw_ciphertext_unchecked(
+                &mut output_lwe_ciphertext,
+                &lwe_ciphertext,
+                &gsw_ciphertext,
+            )
+        };
+        (lwe_ciphertext, gsw_ciphertext, output_lwe_ciphertext)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (lwe_ciphertext, gsw_ciphertext, output_lwe_ciphertext) = context;
+        let (proto_plaintext, proto_secret_key, _) = repetition_proto;
+        let (proto_input_plaintext0, _, proto_input_plaintext1, _) = sample_proto;
+        let proto_output_lwe_ciphertext = maker.unsynthesize_lwe_ciphertext(output_lwe_ciphertext);
+        maker.destroy_gsw_ciphertext(gsw_ciphertext);
+        maker.destroy_lwe_ciphertext(lwe_ciphertext);
+        let proto_output_plaintext = maker
+            .decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_lwe_ciphertext);
+        let raw_selector = maker.transform_plaintext_to_raw(proto_plaintext);
+        let raw_input_plaintext0 = maker.transform_plaintext_to_raw(proto_input_plaintext0);
+        let raw_input_plaintext1 = maker.transform_plaintext_to_raw(proto_input_plaintext1);
+        (
+            raw_input_plaintext0.wrapping_add(
+                raw_input_plaintext1.wrapping_sub(raw_input_plaintext0) * raw_selector,
+            ),
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // An LWE ciphertext of dimension n is a GLWE ciphertext of dimension n with polynomials of
+        // size one, so the GLWE estimate applies.
+        let output_variance = fix_estimate_cmux_noise_with_binary_ggsw::<
+            Precision::Raw,
+            Variance,
+            Variance,
+            Variance,
+            KeyDistribution,
+        >(
+            PolynomialSize(1),
+            GlweDimension(parameters.lwe_dimension.0),
+            parameters.lwe_noise,
+            parameters.lwe_noise,
+            parameters.gsw_noise,
+            parameters.decomposition_base_log,
+            parameters.decomposition_level_count,
+        );
+        (output_variance,)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
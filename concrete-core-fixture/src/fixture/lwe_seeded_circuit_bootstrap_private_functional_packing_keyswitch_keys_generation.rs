@@ -0,0 +1,227 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesGlweSecretKey, PrototypesLweSecretKey};
+use crate::generation::synthesizing::{
+    SynthesizesGlweSecretKey, SynthesizesLweSecretKey,
+    SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, GlweSecretKeyEntity, LweDimension, LweSecretKeyEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine, PolynomialSize,
+    Variance,
+};
+
+/// A fixture for the types implementing the
+/// `LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine` trait.
+///
+/// The keys can only be used in a circuit bootstrap, so the fixture checks the shape of the
+/// generated keys. The content of the expanded keys is checked against the unseeded generation in
+/// the `commons` module.
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters {
+    pub noise: Variance,
+    pub lwe_dimension_in: LweDimension,
+    pub glwe_dimension_out: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub level: DecompositionLevelCount,
+    pub base_log: DecompositionBaseLog,
+}
+
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSecretKey,
+        OutputSecretKey,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            InputSecretKey,
+            OutputSecretKey,
+            LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+        ),
+    > for LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine<
+        InputSecretKey,
+        OutputSecretKey,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    >,
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys:
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    Maker: SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+        > + SynthesizesLweSecretKey<Precision, InputKeyDistribution, InputSecretKey>
+        + SynthesizesGlweSecretKey<Precision, OutputKeyDistribution, OutputSecretKey>,
+{
+    type Parameters =
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+    );
+    type PreExecutionContext = (InputSecretKey, OutputSecretKey);
+    type PostExecutionContext = (
+        InputSecretKey,
+        OutputSecretKey,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    );
+    type Criteria = ();
+    type Outcome = (
+        LweDimension,
+        GlweDimension,
+        PolynomialSize,
+        DecompositionLevelCount,
+        DecompositionBaseLog,
+        FunctionalPackingKeyswitchKeyCount,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension_in: LweDimension(10),
+                    glwe_dimension_out: GlweDimension(1),
+                    polynomial_size: PolynomialSize(128),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                },
+                LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension_in: LweDimension(10),
+                    glwe_dimension_out: GlweDimension(2),
+                    polynomial_size: PolynomialSize(128),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_lwe_secret_key_in =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.lwe_dimension_in,
+            );
+        let proto_glwe_secret_key_out = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.glwe_dimension_out,
+            parameters.polynomial_size,
+        );
+        (proto_lwe_secret_key_in, proto_glwe_secret_key_out)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_lwe_secret_key_in, proto_glwe_secret_key_out) = sample_proto;
+        let synth_lwe_secret_key_in = maker.synthesize_lwe_secret_key(proto_lwe_secret_key_in);
+        let synth_glwe_secret_key_out = maker.synthesize_glwe_secret_key(proto_glwe_secret_key_out);
+        (synth_lwe_secret_key_in, synth_glwe_secret_key_out)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (lwe_sk_in, glwe_sk_out) = context;
+        let seeded_cbs_pfpksk = unsafe {
+            engine
+                .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                    &lwe_sk_in,
+                    &glwe_sk_out,
+                    parameters.base_log,
+                    parameters.level,
+                    parameters.noise,
+                )
+        };
+        (lwe_sk_in, glwe_sk_out, seeded_cbs_pfpksk)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (lwe_sk_in, glwe_sk_out, seeded_cbs_pfpksk) = context;
+        let outcome = (
+            seeded_cbs_pfpksk.input_lwe_dimension(),
+            seeded_cbs_pfpksk.output_glwe_dimension(),
+            seeded_cbs_pfpksk.output_polynomial_size(),
+            seeded_cbs_pfpksk.decomposition_level_count(),
+            seeded_cbs_pfpksk.decomposition_base_log(),
+            seeded_cbs_pfpksk.key_count(),
+        );
+        maker.destroy_lwe_secret_key(lwe_sk_in);
+        maker.destroy_glwe_secret_key(glwe_sk_out);
+        maker.destroy_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            seeded_cbs_pfpksk,
+        );
+        outcome
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        // One private functional packing keyswitch key is generated for each polynomial of the
+        // output glwe secret key, plus one for the constant term.
+        let expected = (
+            parameters.lwe_dimension_in,
+            parameters.glwe_dimension_out,
+            parameters.polynomial_size,
+            parameters.level,
+            parameters.base_log,
+            FunctionalPackingKeyswitchKeyCount(parameters.glwe_dimension_out.to_glwe_size().0),
+        );
+        outputs.iter().all(|outcome| *outcome == expected)
+    }
+}
@@ -0,0 +1,259 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    PrototypesLweSecretKey,
+    PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, LweDimension,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine,
+    PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the
+/// `LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine`
+/// trait.
+///
+/// The shape of the transformed keys is compared with the shape of keys generated without a seed
+/// for the same secret keys.
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationParameters
+{
+    pub noise: Variance,
+    pub lwe_dimension_in: LweDimension,
+    pub glwe_dimension_out: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub level: DecompositionLevelCount,
+    pub base_log: DecompositionBaseLog,
+}
+
+type CircuitBootstrapKeysShape = (
+    LweDimension,
+    GlweDimension,
+    PolynomialSize,
+    DecompositionLevelCount,
+    DecompositionBaseLog,
+    FunctionalPackingKeyswitchKeyCount,
+);
+
+fn circuit_bootstrap_keys_shape<Keys>(keys: &Keys) -> CircuitBootstrapKeysShape
+where
+    Keys: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    (
+        keys.input_lwe_dimension(),
+        keys.output_glwe_dimension(),
+        keys.output_polynomial_size(),
+        keys.decomposition_level_count(),
+        keys.decomposition_base_log(),
+        keys.key_count(),
+    )
+}
+
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSeededKeys,
+        OutputKeys,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (InputSeededKeys, OutputKeys),
+    >
+    for LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine<
+        InputSeededKeys,
+        OutputKeys,
+    >,
+    InputSeededKeys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    OutputKeys: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    Maker: SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            InputSeededKeys,
+        > + SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            OutputKeys,
+        >,
+{
+    type Parameters =
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+        <Maker as PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+    );
+    type PreExecutionContext = (InputSeededKeys,);
+    type PostExecutionContext = (OutputKeys,);
+    type Criteria = ();
+    type Outcome = (CircuitBootstrapKeysShape, CircuitBootstrapKeysShape);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension_in: LweDimension(10),
+                    glwe_dimension_out: GlweDimension(1),
+                    polynomial_size: PolynomialSize(128),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                },
+                LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension_in: LweDimension(10),
+                    glwe_dimension_out: GlweDimension(2),
+                    polynomial_size: PolynomialSize(128),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_lwe_secret_key_in =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.lwe_dimension_in,
+            );
+        let proto_glwe_secret_key_out = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.glwe_dimension_out,
+            parameters.polynomial_size,
+        );
+        let proto_seeded_cbs_pfpksk = maker
+            .new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                &proto_lwe_secret_key_in,
+                &proto_glwe_secret_key_out,
+                parameters.base_log,
+                parameters.level,
+                parameters.noise,
+            );
+        let proto_reference_cbs_pfpksk =
+            <Maker as PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+                Precision,
+                InputKeyDistribution,
+                OutputKeyDistribution,
+            >>::new_lwe_private_functional_packing_keyswitch_key(
+                maker,
+                &proto_lwe_secret_key_in,
+                &proto_glwe_secret_key_out,
+                parameters.base_log,
+                parameters.level,
+                parameters.noise,
+            );
+        (proto_seeded_cbs_pfpksk, proto_reference_cbs_pfpksk)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_seeded_cbs_pfpksk, _) = sample_proto;
+        let synth_seeded_cbs_pfpksk = maker
+            .synthesize_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                proto_seeded_cbs_pfpksk,
+            );
+        (synth_seeded_cbs_pfpksk,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_cbs_pfpksk,) = context;
+        let cbs_pfpksk = unsafe {
+            engine.transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                seeded_cbs_pfpksk,
+            )
+        };
+        (cbs_pfpksk,)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (cbs_pfpksk,) = context;
+        let (_, proto_reference_cbs_pfpksk) = sample_proto;
+        let reference_cbs_pfpksk: OutputKeys = maker
+            .synthesize_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                proto_reference_cbs_pfpksk,
+            );
+        let outcome = (
+            circuit_bootstrap_keys_shape(&cbs_pfpksk),
+            circuit_bootstrap_keys_shape(&reference_cbs_pfpksk),
+        );
+        maker.destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(cbs_pfpksk);
+        maker.destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            reference_cbs_pfpksk,
+        );
+        outcome
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        _criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        outputs
+            .iter()
+            .all(|(expanded, reference)| expanded == reference)
+    }
+}
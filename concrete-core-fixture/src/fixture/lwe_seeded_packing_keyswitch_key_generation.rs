@@ -0,0 +1,279 @@
+use crate::fixture::{fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms, Fixture};
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
+    PrototypesLwePackingKeyswitchKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPackingKeyswitchKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweSecretKey, SynthesizesLweSecretKey, SynthesizesLweSeededPackingKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
+    GlweSecretKeyEntity, LogStandardDev, LweCiphertextCount, LweDimension, LweSecretKeyEntity,
+    LweSeededPackingKeyswitchKeyEntity, LweSeededPackingKeyswitchKeyGenerationEngine,
+    PolynomialSize, Variance,
+};
+
+/// A fixture for the types implementing the `LweSeededPackingKeyswitchKeyGenerationEngine` trait.
+///
+/// The generated key is expanded, and used to pack a vector of lwe ciphertexts into a glwe
+/// ciphertext, which is then decrypted.
+pub struct LweSeededPackingKeyswitchKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPackingKeyswitchKeyGenerationParameters {
+    pub input_lwe_noise: Variance,
+    pub pksk_noise: Variance,
+    pub input_lwe_dimension: LweDimension,
+    pub input_lwe_count: LweCiphertextCount,
+    pub output_glwe_dimension: GlweDimension,
+    pub output_polynomial_size: PolynomialSize,
+    pub decomposition_level: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+}
+
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSecretKey,
+        OutputSecretKey,
+        SeededPackingKeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (InputSecretKey, OutputSecretKey, SeededPackingKeyswitchKey),
+    > for LweSeededPackingKeyswitchKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPackingKeyswitchKeyGenerationEngine<
+        InputSecretKey,
+        OutputSecretKey,
+        SeededPackingKeyswitchKey,
+    >,
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    SeededPackingKeyswitchKey: LweSeededPackingKeyswitchKeyEntity,
+    Maker: SynthesizesLweSeededPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            SeededPackingKeyswitchKey,
+        > + SynthesizesLweSecretKey<Precision, InputKeyDistribution, InputSecretKey>
+        + SynthesizesGlweSecretKey<Precision, OutputKeyDistribution, OutputSecretKey>,
+{
+    type Parameters = LweSeededPackingKeyswitchKeyGenerationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (InputSecretKey, OutputSecretKey);
+    type PostExecutionContext = (InputSecretKey, OutputSecretKey, SeededPackingKeyswitchKey);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPackingKeyswitchKeyGenerationParameters {
+                    input_lwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    pksk_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    input_lwe_dimension: LweDimension(200),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(1),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+                LweSeededPackingKeyswitchKeyGenerationParameters {
+                    input_lwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    pksk_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    input_lwe_dimension: LweDimension(200),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(2),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key_input =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.input_lwe_dimension,
+            );
+        let proto_secret_key_output = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.output_polynomial_size,
+        );
+        (proto_secret_key_input, proto_secret_key_output)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.input_lwe_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_input_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.input_lwe_noise,
+        );
+        (proto_plaintext_vector, proto_input_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_input_secret_key, proto_output_secret_key) = repetition_proto;
+        let synth_input_secret_key = maker.synthesize_lwe_secret_key(proto_input_secret_key);
+        let synth_output_secret_key = maker.synthesize_glwe_secret_key(proto_output_secret_key);
+        (synth_input_secret_key, synth_output_secret_key)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_secret_key, output_secret_key) = context;
+        let seeded_pksk = unsafe {
+            engine.generate_new_lwe_seeded_packing_keyswitch_key_unchecked(
+                &input_secret_key,
+                &output_secret_key,
+                parameters.decomposition_level,
+                parameters.decomposition_base_log,
+                parameters.pksk_noise,
+            )
+        };
+        (input_secret_key, output_secret_key, seeded_pksk)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_secret_key, output_secret_key, seeded_pksk) = context;
+        let (_, proto_output_secret_key) = repetition_proto;
+        let (proto_plaintext_vector, proto_input_ciphertext_vector) = sample_proto;
+        let proto_seeded_pksk = maker.unsynthesize_lwe_seeded_packing_keyswitch_key(seeded_pksk);
+        let proto_pksk = maker
+            .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+                &proto_seeded_pksk,
+            );
+        let proto_output_ciphertext = maker
+            .packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+                &proto_pksk,
+                proto_input_ciphertext_vector,
+            );
+        let proto_output_plaintext_vector = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+            maker,
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_secret_key(input_secret_key);
+        maker.destroy_glwe_secret_key(output_secret_key);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            maker
+                .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+                .iter()
+                .take(parameters.input_lwe_count.0)
+                .cloned()
+                .collect::<Vec<Precision::Raw>>(),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance =
+            fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                Precision::Raw,
+                _,
+                _,
+                OutputKeyDistribution,
+            >(
+                parameters.input_lwe_dimension,
+                parameters.input_lwe_noise,
+                parameters.pksk_noise,
+                parameters.decomposition_base_log,
+                parameters.decomposition_level,
+            );
+        (Variance(
+            predicted_variance.0 * parameters.input_lwe_count.0 as f64,
+        ),)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means
+            .iter()
+            .flat_map(|r| r.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let actual = actual
+            .iter()
+            .flat_map(|r| r.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,322 @@
+use crate::fixture::{fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms, Fixture};
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
+    PrototypesLwePackingKeyswitchKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPackingKeyswitchKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweSeededPackingKeyswitchKey, SynthesizesPackingKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
+    LogStandardDev, LweCiphertextCount, LweDimension, LwePackingKeyswitchKeyEntity,
+    LweSeededPackingKeyswitchKeyEntity,
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine, PolynomialSize,
+    Variance,
+};
+
+/// A fixture for the types implementing the
+/// `LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine` trait.
+///
+/// The transformed key and a key generated without a seed for the same secret keys are both used
+/// to pack the same lwe ciphertexts, and both outputs must decrypt within the predicted noise.
+pub struct LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationParameters {
+    pub input_lwe_noise: Variance,
+    pub pksk_noise: Variance,
+    pub input_lwe_dimension: LweDimension,
+    pub input_lwe_count: LweCiphertextCount,
+    pub output_glwe_dimension: GlweDimension,
+    pub output_polynomial_size: PolynomialSize,
+    pub decomposition_level: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+}
+
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSeededPackingKeyswitchKey,
+        OutputPackingKeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (InputSeededPackingKeyswitchKey, OutputPackingKeyswitchKey),
+    > for LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine<
+        InputSeededPackingKeyswitchKey,
+        OutputPackingKeyswitchKey,
+    >,
+    InputSeededPackingKeyswitchKey: LweSeededPackingKeyswitchKeyEntity,
+    OutputPackingKeyswitchKey: LwePackingKeyswitchKeyEntity,
+    Maker: SynthesizesLweSeededPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            InputSeededPackingKeyswitchKey,
+        > + SynthesizesPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            OutputPackingKeyswitchKey,
+        >,
+{
+    type Parameters = LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSeededPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweSeededPackingKeyswitchKeyProto,
+        <Maker as PrototypesLwePackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::PackingKeyswitchKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (InputSeededPackingKeyswitchKey,);
+    type PostExecutionContext = (OutputPackingKeyswitchKey,);
+    type Criteria = (Variance,);
+    type Outcome = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+    );
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationParameters {
+                    input_lwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    pksk_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    input_lwe_dimension: LweDimension(200),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(1),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+                LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationParameters {
+                    input_lwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    pksk_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-25.).get_variance(),
+                    ),
+                    input_lwe_dimension: LweDimension(200),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(2),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key_input =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.input_lwe_dimension,
+            );
+        let proto_secret_key_output = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.output_polynomial_size,
+        );
+        let proto_seeded_pksk = maker.new_lwe_seeded_packing_keyswitch_key(
+            &proto_secret_key_input,
+            &proto_secret_key_output,
+            parameters.decomposition_level,
+            parameters.decomposition_base_log,
+            parameters.pksk_noise,
+        );
+        let proto_reference_pksk = maker.new_lwe_packing_keyswitch_key(
+            &proto_secret_key_input,
+            &proto_secret_key_output,
+            parameters.decomposition_level,
+            parameters.decomposition_base_log,
+            parameters.pksk_noise,
+        );
+        (
+            proto_seeded_pksk,
+            proto_reference_pksk,
+            proto_secret_key_input,
+            proto_secret_key_output,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, _, proto_input_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.input_lwe_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_input_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.input_lwe_noise,
+        );
+        (proto_plaintext_vector, proto_input_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_seeded_pksk, ..) = repetition_proto;
+        let synth_seeded_pksk =
+            maker.synthesize_lwe_seeded_packing_keyswitch_key(proto_seeded_pksk);
+        (synth_seeded_pksk,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_pksk,) = context;
+        let pksk = unsafe {
+            engine
+                .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_unchecked(
+                    seeded_pksk,
+                )
+        };
+        (pksk,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (pksk,) = context;
+        let (_, proto_reference_pksk, _, proto_output_secret_key) = repetition_proto;
+        let (proto_plaintext_vector, proto_input_ciphertext_vector) = sample_proto;
+        let proto_pksk = maker.unsynthesize_packing_keyswitch_key(pksk);
+        let decrypt_packed = |maker: &mut Maker,
+                              proto_pksk: &<Maker as PrototypesLwePackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::PackingKeyswitchKeyProto| {
+            let proto_output_ciphertext = maker
+                .packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+                    proto_pksk,
+                    proto_input_ciphertext_vector,
+                );
+            let proto_output_plaintext_vector = <Maker as PrototypesGlweCiphertext<
+                Precision,
+                OutputKeyDistribution,
+            >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+                maker,
+                proto_output_secret_key,
+                &proto_output_ciphertext,
+            );
+            maker
+                .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+                .iter()
+                .take(parameters.input_lwe_count.0)
+                .cloned()
+                .collect::<Vec<Precision::Raw>>()
+        };
+        let expanded = decrypt_packed(maker, &proto_pksk);
+        let reference = decrypt_packed(maker, proto_reference_pksk);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            expanded,
+            reference,
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance =
+            fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                Precision::Raw,
+                _,
+                _,
+                OutputKeyDistribution,
+            >(
+                parameters.input_lwe_dimension,
+                parameters.input_lwe_noise,
+                parameters.pksk_noise,
+                parameters.decomposition_base_log,
+                parameters.decomposition_level,
+            );
+        (Variance(
+            predicted_variance.0 * parameters.input_lwe_count.0 as f64,
+        ),)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let means = outputs
+            .iter()
+            .flat_map(|(means, ..)| means.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let expanded = outputs
+            .iter()
+            .flat_map(|(_, expanded, _)| expanded.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let reference = outputs
+            .iter()
+            .flat_map(|(.., reference)| reference.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        assert_noise_distribution(&expanded, means.as_slice(), criteria.0)
+            && assert_noise_distribution(&reference, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,327 @@
+use crate::fixture::{
+    fix_estimate_private_functional_keyswitch_noise_lwe_to_glwe_with_constant_terms, Fixture,
+};
+use crate::generation::prototyping::{
+    PrototypesCleartextVector, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesLweCiphertextVector, PrototypesLwePrivateFunctionalPackingKeyswitchKey,
+    PrototypesLweSecretKey, PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesCleartextVector, SynthesizesGlweSecretKey, SynthesizesLweSecretKey,
+    SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::Numeric;
+use concrete_core::prelude::{
+    CleartextVectorEntity, DecompositionBaseLog, DecompositionLevelCount, DispersionParameter,
+    GlweDimension, GlweSecretKeyEntity, LogStandardDev, LweCiphertextCount, LweDimension,
+    LweSecretKeyEntity, LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine, PolynomialSize, StandardDev,
+    Variance,
+};
+
+/// A fixture for the types implementing the
+/// `LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine` trait.
+///
+/// The key is generated for the identity function and the constant polynomial one, so that once
+/// expanded, it packs a vector of lwe ciphertexts the same way a packing keyswitch key does.
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationParameters {
+    pub input_lwe_noise: Variance,
+    pub pfpksk_noise: StandardDev,
+    pub input_lwe_dimension: LweDimension,
+    pub input_lwe_count: LweCiphertextCount,
+    pub output_glwe_dimension: GlweDimension,
+    pub output_polynomial_size: PolynomialSize,
+    pub decomposition_level: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+}
+
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSecretKey,
+        OutputSecretKey,
+        CleartextVector,
+        SeededPrivateFunctionalPackingKeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            InputSecretKey,
+            OutputSecretKey,
+            CleartextVector,
+            SeededPrivateFunctionalPackingKeyswitchKey,
+        ),
+    > for LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine<
+        InputSecretKey,
+        OutputSecretKey,
+        SeededPrivateFunctionalPackingKeyswitchKey,
+        CleartextVector,
+        Precision::Raw,
+    >,
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    CleartextVector: CleartextVectorEntity,
+    SeededPrivateFunctionalPackingKeyswitchKey: LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+    Maker: SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            SeededPrivateFunctionalPackingKeyswitchKey,
+        > + SynthesizesLweSecretKey<Precision, InputKeyDistribution, InputSecretKey>
+        + SynthesizesGlweSecretKey<Precision, OutputKeyDistribution, OutputSecretKey>
+        + SynthesizesCleartextVector<Precision, CleartextVector>,
+{
+    type Parameters = LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesCleartextVector<Precision>>::CleartextVectorProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (InputSecretKey, OutputSecretKey, CleartextVector);
+    type PostExecutionContext = (
+        InputSecretKey,
+        OutputSecretKey,
+        CleartextVector,
+        SeededPrivateFunctionalPackingKeyswitchKey,
+    );
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationParameters {
+                    input_lwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    pfpksk_noise: StandardDev(
+                        LogStandardDev::from_log_standard_dev(-25.).get_standard_dev(),
+                    ),
+                    input_lwe_dimension: LweDimension(200),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(1),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+                LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationParameters {
+                    input_lwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    pfpksk_noise: StandardDev(
+                        LogStandardDev::from_log_standard_dev(-25.).get_standard_dev(),
+                    ),
+                    input_lwe_dimension: LweDimension(200),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(2),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key_input =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.input_lwe_dimension,
+            );
+        let proto_secret_key_output = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.output_polynomial_size,
+        );
+        let mut raw_cleartext_vector =
+            vec![Precision::Raw::ZERO; parameters.output_polynomial_size.0];
+        raw_cleartext_vector[0] = Precision::Raw::ONE;
+        let proto_cleartext_vector =
+            maker.transform_raw_vec_to_cleartext_vector(&raw_cleartext_vector);
+        (
+            proto_secret_key_input,
+            proto_secret_key_output,
+            proto_cleartext_vector,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, ..) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.input_lwe_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_input_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.input_lwe_noise,
+        );
+        (proto_plaintext_vector, proto_input_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_input_secret_key, proto_output_secret_key, proto_cleartext_vector) =
+            repetition_proto;
+        let synth_input_secret_key = maker.synthesize_lwe_secret_key(proto_input_secret_key);
+        let synth_output_secret_key = maker.synthesize_glwe_secret_key(proto_output_secret_key);
+        let synth_cleartext_vector = maker.synthesize_cleartext_vector(proto_cleartext_vector);
+        (
+            synth_input_secret_key,
+            synth_output_secret_key,
+            synth_cleartext_vector,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_secret_key, output_secret_key, cleartext_vector) = context;
+        let seeded_pfpksk = unsafe {
+            engine.generate_new_lwe_seeded_private_functional_packing_keyswitch_key_unchecked(
+                &input_secret_key,
+                &output_secret_key,
+                parameters.decomposition_level,
+                parameters.decomposition_base_log,
+                parameters.pfpksk_noise,
+                &|x| x,
+                &cleartext_vector,
+            )
+        };
+        (
+            input_secret_key,
+            output_secret_key,
+            cleartext_vector,
+            seeded_pfpksk,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_secret_key, output_secret_key, cleartext_vector, seeded_pfpksk) = context;
+        let (_, proto_output_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector, proto_input_ciphertext_vector) = sample_proto;
+        let proto_seeded_pfpksk =
+            maker.unsynthesize_lwe_seeded_private_functional_packing_keyswitch_key(seeded_pfpksk);
+        let proto_pfpksk = maker
+            .transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+                &proto_seeded_pfpksk,
+            );
+        let proto_output_ciphertext = maker
+            .private_functional_packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+                &proto_pfpksk,
+                proto_input_ciphertext_vector,
+            );
+        let proto_output_plaintext_vector = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+            maker,
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        maker.destroy_lwe_secret_key(input_secret_key);
+        maker.destroy_glwe_secret_key(output_secret_key);
+        maker.destroy_cleartext_vector(cleartext_vector);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            maker
+                .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+                .iter()
+                .take(parameters.input_lwe_count.0)
+                .cloned()
+                .collect::<Vec<Precision::Raw>>(),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance =
+            fix_estimate_private_functional_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                Precision::Raw,
+                _,
+                _,
+                OutputKeyDistribution,
+            >(
+                parameters.input_lwe_dimension,
+                parameters.input_lwe_noise,
+                parameters.pfpksk_noise,
+                parameters.decomposition_base_log,
+                parameters.decomposition_level,
+                1.,
+            );
+        (Variance(
+            predicted_variance.0 * parameters.input_lwe_count.0 as f64,
+        ),)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means = means
+            .iter()
+            .flat_map(|r| r.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let actual = actual
+            .iter()
+            .flat_map(|r| r.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
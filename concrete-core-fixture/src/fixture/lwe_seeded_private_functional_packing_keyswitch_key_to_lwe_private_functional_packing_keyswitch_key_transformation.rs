@@ -0,0 +1,342 @@
+use crate::fixture::{
+    fix_estimate_private_functional_keyswitch_noise_lwe_to_glwe_with_constant_terms, Fixture,
+};
+use crate::generation::prototyping::{
+    PrototypesCleartextVector, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesLweCiphertextVector, PrototypesLwePrivateFunctionalPackingKeyswitchKey,
+    PrototypesLweSecretKey, PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey,
+    SynthesizesPrivateFunctionalPackingKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::commons::numeric::Numeric;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
+    LogStandardDev, LweCiphertextCount, LweDimension,
+    LwePrivateFunctionalPackingKeyswitchKeyEntity,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine,
+    PolynomialSize, StandardDev, Variance,
+};
+
+/// A fixture for the types implementing the
+/// `LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine`
+/// trait.
+///
+/// The transformed key and a key generated without a seed for the same secret keys, function and
+/// polynomial are both used to pack the same lwe ciphertexts, and both outputs must decrypt within
+/// the predicted noise.
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationParameters
+{
+    pub input_lwe_noise: Variance,
+    pub pfpksk_noise: StandardDev,
+    pub input_lwe_dimension: LweDimension,
+    pub input_lwe_count: LweCiphertextCount,
+    pub output_glwe_dimension: GlweDimension,
+    pub output_polynomial_size: PolynomialSize,
+    pub decomposition_level: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+}
+
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSeededPrivateFunctionalPackingKeyswitchKey,
+        OutputPrivateFunctionalPackingKeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            InputSeededPrivateFunctionalPackingKeyswitchKey,
+            OutputPrivateFunctionalPackingKeyswitchKey,
+        ),
+    >
+    for LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine<
+        InputSeededPrivateFunctionalPackingKeyswitchKey,
+        OutputPrivateFunctionalPackingKeyswitchKey,
+    >,
+    InputSeededPrivateFunctionalPackingKeyswitchKey:
+        LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+    OutputPrivateFunctionalPackingKeyswitchKey: LwePrivateFunctionalPackingKeyswitchKeyEntity,
+    Maker: SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            InputSeededPrivateFunctionalPackingKeyswitchKey,
+        > + SynthesizesPrivateFunctionalPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            OutputPrivateFunctionalPackingKeyswitchKey,
+        >,
+{
+    type Parameters =
+        LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweSeededPrivateFunctionalPackingKeyswitchKeyProto,
+        <Maker as PrototypesLwePrivateFunctionalPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LwePrivateFunctionalPackingKeyswitchKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (InputSeededPrivateFunctionalPackingKeyswitchKey,);
+    type PostExecutionContext = (OutputPrivateFunctionalPackingKeyswitchKey,);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationParameters {
+                    input_lwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    pfpksk_noise: StandardDev(
+                        LogStandardDev::from_log_standard_dev(-25.).get_standard_dev(),
+                    ),
+                    input_lwe_dimension: LweDimension(200),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(1),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+                LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationParameters {
+                    input_lwe_noise: Variance(
+                        LogStandardDev::from_log_standard_dev(-10.).get_variance(),
+                    ),
+                    pfpksk_noise: StandardDev(
+                        LogStandardDev::from_log_standard_dev(-25.).get_standard_dev(),
+                    ),
+                    input_lwe_dimension: LweDimension(200),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(2),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key_input =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.input_lwe_dimension,
+            );
+        let proto_secret_key_output = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.output_polynomial_size,
+        );
+        let mut raw_cleartext_vector =
+            vec![Precision::Raw::ZERO; parameters.output_polynomial_size.0];
+        raw_cleartext_vector[0] = Precision::Raw::ONE;
+        let proto_cleartext_vector =
+            maker.transform_raw_vec_to_cleartext_vector(&raw_cleartext_vector);
+        let proto_seeded_pfpksk = maker.new_lwe_seeded_private_functional_packing_keyswitch_key(
+            &proto_secret_key_input,
+            &proto_secret_key_output,
+            parameters.decomposition_level,
+            parameters.decomposition_base_log,
+            parameters.pfpksk_noise,
+            &|x| x,
+            &proto_cleartext_vector,
+        );
+        let proto_reference_pfpksk = maker.new_lwe_private_functional_packing_keyswitch_key(
+            &proto_secret_key_input,
+            &proto_secret_key_output,
+            parameters.decomposition_level,
+            parameters.decomposition_base_log,
+            parameters.pfpksk_noise,
+            &|x| x,
+            &proto_cleartext_vector,
+        );
+        (
+            proto_seeded_pfpksk,
+            proto_reference_pfpksk,
+            proto_secret_key_input,
+            proto_secret_key_output,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, _, proto_input_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.input_lwe_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_input_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.input_lwe_noise,
+        );
+        (proto_plaintext_vector, proto_input_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_seeded_pfpksk, ..) = repetition_proto;
+        let synth_seeded_pfpksk =
+            maker.synthesize_lwe_seeded_private_functional_packing_keyswitch_key(proto_seeded_pfpksk);
+        (synth_seeded_pfpksk,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_pfpksk,) = context;
+        let pfpksk = unsafe {
+            engine.transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_unchecked(
+                seeded_pfpksk,
+            )
+        };
+        (pfpksk,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (pfpksk,) = context;
+        let (_, proto_reference_pfpksk, _, proto_output_secret_key) = repetition_proto;
+        let (proto_plaintext_vector, proto_input_ciphertext_vector) = sample_proto;
+        let proto_pfpksk = maker.unsynthesize_private_functional_packing_keyswitch_key(pfpksk);
+        let decrypt_packed = |maker: &mut Maker,
+                              proto_pfpksk: &<Maker as PrototypesLwePrivateFunctionalPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LwePrivateFunctionalPackingKeyswitchKeyProto| {
+            let proto_output_ciphertext = maker
+                .private_functional_packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+                    proto_pfpksk,
+                    proto_input_ciphertext_vector,
+                );
+            let proto_output_plaintext_vector = <Maker as PrototypesGlweCiphertext<
+                Precision,
+                OutputKeyDistribution,
+            >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+                maker,
+                proto_output_secret_key,
+                &proto_output_ciphertext,
+            );
+            maker
+                .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+                .iter()
+                .take(parameters.input_lwe_count.0)
+                .cloned()
+                .collect::<Vec<Precision::Raw>>()
+        };
+        let expanded = decrypt_packed(maker, &proto_pfpksk);
+        let reference = decrypt_packed(maker, proto_reference_pfpksk);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector),
+            expanded,
+            reference,
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance =
+            fix_estimate_private_functional_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                Precision::Raw,
+                _,
+                _,
+                OutputKeyDistribution,
+            >(
+                parameters.input_lwe_dimension,
+                parameters.input_lwe_noise,
+                parameters.pfpksk_noise,
+                parameters.decomposition_base_log,
+                parameters.decomposition_level,
+                1.,
+            );
+        (Variance(
+            predicted_variance.0 * parameters.input_lwe_count.0 as f64,
+        ),)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let means = outputs
+            .iter()
+            .flat_map(|(means, ..)| means.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let expanded = outputs
+            .iter()
+            .flat_map(|(_, expanded, _)| expanded.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        let reference = outputs
+            .iter()
+            .flat_map(|(.., reference)| reference.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        assert_noise_distribution(&expanded, means.as_slice(), criteria.0)
+            && assert_noise_distribution(&reference, means.as_slice(), criteria.0)
+    }
+}
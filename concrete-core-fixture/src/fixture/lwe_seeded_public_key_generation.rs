@@ -0,0 +1,153 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLwePublicKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPublicKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesLweSecretKey, SynthesizesLweSeededPublicKey};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::prelude::{
+    LweDimension, LwePublicKeyZeroEncryptionCount, LweSecretKeyEntity, LweSeededPublicKeyEntity,
+    LweSeededPublicKeyGenerationEngine, Variance,
+};
+
+/// A fixture for the types implementing the `LweSeededPublicKeyGenerationEngine` trait.
+///
+/// The generated key is expanded and used to encrypt a plaintext, which is then decrypted with the
+/// secret key.
+pub struct LweSeededPublicKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPublicKeyGenerationParameters {
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LwePublicKeyZeroEncryptionCount,
+    pub noise: Variance,
+}
+
+impl<Precision, KeyDistribution, Engine, SecretKey, SeededPublicKey>
+    Fixture<Precision, (KeyDistribution,), Engine, (SecretKey, SeededPublicKey)>
+    for LweSeededPublicKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPublicKeyGenerationEngine<SecretKey, SeededPublicKey>,
+    SecretKey: LweSecretKeyEntity,
+    SeededPublicKey: LweSeededPublicKeyEntity,
+    Maker: SynthesizesLweSecretKey<Precision, KeyDistribution, SecretKey>
+        + SynthesizesLweSeededPublicKey<Precision, KeyDistribution, SeededPublicKey>,
+{
+    type Parameters = LweSeededPublicKeyGenerationParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        Precision::Raw,
+    );
+    type PreExecutionContext = (SecretKey,);
+    type PostExecutionContext = (SecretKey, SeededPublicKey);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweSeededPublicKeyGenerationParameters {
+                lwe_dimension: LweDimension(630),
+                lwe_ciphertext_count: LwePublicKeyZeroEncryptionCount(10),
+                noise: Variance(0.00000001),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        (maker.new_lwe_secret_key(parameters.lwe_dimension),)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        (proto_plaintext, raw_plaintext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let synth_secret_key = maker.synthesize_lwe_secret_key(proto_secret_key);
+        (synth_secret_key,)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let seeded_public_key = unsafe {
+            engine.generate_new_lwe_seeded_public_key_unchecked(
+                &secret_key,
+                parameters.noise,
+                parameters.lwe_ciphertext_count,
+            )
+        };
+        (secret_key, seeded_public_key)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, seeded_public_key) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext, raw_plaintext) = sample_proto;
+        let proto_seeded_public_key = maker.unsynthesize_lwe_seeded_public_key(seeded_public_key);
+        let proto_public_key =
+            maker.transform_lwe_seeded_public_key_to_lwe_public_key(&proto_seeded_public_key);
+        let proto_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+            &proto_public_key,
+            proto_plaintext,
+        );
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_ciphertext);
+        maker.destroy_lwe_secret_key(secret_key);
+        (
+            *raw_plaintext,
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        // The encryption sums a random subset of the encryptions of zero contained in the key, so
+        // the output variance is bounded by the variance of the sum of all of them.
+        (Variance(
+            parameters.noise.0 * parameters.lwe_ciphertext_count.0 as f64,
+        ),)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLwePublicKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPublicKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesLwePublicKey, SynthesizesLweSeededPublicKey};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_core::prelude::{
+    LweDimension, LwePublicKeyEntity, LwePublicKeyZeroEncryptionCount, LweSeededPublicKeyEntity,
+    LweSeededPublicKeyToLwePublicKeyTransformationEngine, Variance,
+};
+
+/// A fixture for the types implementing the `LweSeededPublicKeyToLwePublicKeyTransformationEngine`
+/// trait.
+///
+/// The transformed key and a key generated without a seed for the same secret key are both used
+/// to encrypt the same plaintext, and both ciphertexts must decrypt within the predicted noise.
+pub struct LweSeededPublicKeyToLwePublicKeyTransformationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPublicKeyToLwePublicKeyTransformationParameters {
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LwePublicKeyZeroEncryptionCount,
+    pub noise: Variance,
+}
+
+impl<Precision, KeyDistribution, Engine, InputSeededPublicKey, OutputPublicKey>
+    Fixture<Precision, (KeyDistribution,), Engine, (InputSeededPublicKey, OutputPublicKey)>
+    for LweSeededPublicKeyToLwePublicKeyTransformationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine:
+        LweSeededPublicKeyToLwePublicKeyTransformationEngine<InputSeededPublicKey, OutputPublicKey>,
+    InputSeededPublicKey: LweSeededPublicKeyEntity,
+    OutputPublicKey: LwePublicKeyEntity,
+    Maker: SynthesizesLweSeededPublicKey<Precision, KeyDistribution, InputSeededPublicKey>
+        + SynthesizesLwePublicKey<Precision, KeyDistribution, OutputPublicKey>,
+{
+    type Parameters = LweSeededPublicKeyToLwePublicKeyTransformationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSeededPublicKey<Precision, KeyDistribution>>::LweSeededPublicKeyProto,
+        <Maker as PrototypesLwePublicKey<Precision, KeyDistribution>>::LwePublicKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        Precision::Raw,
+    );
+    type PreExecutionContext = (InputSeededPublicKey,);
+    type PostExecutionContext = (OutputPublicKey,);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweSeededPublicKeyToLwePublicKeyTransformationParameters {
+                lwe_dimension: LweDimension(630),
+                lwe_ciphertext_count: LwePublicKeyZeroEncryptionCount(10),
+                noise: Variance(0.00000001),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let proto_seeded_public_key = maker.generate_new_lwe_seeded_public_key(
+            &proto_secret_key,
+            parameters.lwe_ciphertext_count,
+            parameters.noise,
+        );
+        let proto_reference_public_key = maker.generate_new_lwe_public_key(
+            &proto_secret_key,
+            parameters.lwe_ciphertext_count,
+            parameters.noise,
+        );
+        (
+            proto_seeded_public_key,
+            proto_reference_public_key,
+            proto_secret_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        (proto_plaintext, raw_plaintext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_seeded_public_key, ..) = repetition_proto;
+        let synth_seeded_public_key =
+            maker.synthesize_lwe_seeded_public_key(proto_seeded_public_key);
+        (synth_seeded_public_key,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_public_key,) = context;
+        let public_key = unsafe {
+            engine.transform_lwe_seeded_public_key_to_lwe_public_key_unchecked(seeded_public_key)
+        };
+        (public_key,)
+    }
+
+    fn process_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (public_key,) = context;
+        let (_, proto_reference_public_key, proto_secret_key) = repetition_proto;
+        let (proto_plaintext, raw_plaintext) = sample_proto;
+        let proto_public_key = maker.unsynthesize_lwe_public_key(public_key);
+        let expanded_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+            &proto_public_key,
+            proto_plaintext,
+        );
+        let reference_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+            proto_reference_public_key,
+            proto_plaintext,
+        );
+        let expanded_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &expanded_ciphertext);
+        let reference_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &reference_ciphertext);
+        (
+            *raw_plaintext,
+            maker.transform_plaintext_to_raw(&expanded_plaintext),
+            maker.transform_plaintext_to_raw(&reference_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (Variance(
+            parameters.noise.0 * parameters.lwe_ciphertext_count.0 as f64,
+        ),)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        criteria: &Self::Criteria,
+        outputs: &[Self::Outcome],
+    ) -> bool {
+        let means = outputs.iter().map(|(mean, ..)| *mean).collect::<Vec<_>>();
+        let expanded = outputs
+            .iter()
+            .map(|(_, expanded, _)| *expanded)
+            .collect::<Vec<_>>();
+        let reference = outputs
+            .iter()
+            .map(|(.., reference)| *reference)
+            .collect::<Vec<_>>();
+        assert_noise_distribution(&expanded, means.as_slice(), criteria.0)
+            && assert_noise_distribution(&reference, means.as_slice(), criteria.0)
+    }
+}
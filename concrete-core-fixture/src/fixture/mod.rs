@@ -229,6 +229,12 @@ pub trait Fixture<
 mod cleartext_creation;
 pub use cleartext_creation::*;
 
+mod entity_deserialization;
+pub use entity_deserialization::*;
+
+mod entity_serialization;
+pub use entity_serialization::*;
+
 mod glwe_ciphertext_discarding_encryption;
 pub use glwe_ciphertext_discarding_encryption::*;
 
@@ -292,9 +298,66 @@ pub use glwe_ciphertext_vector_trivial_decryption::*;
 mod glwe_ciphertext_vector_trivial_encryption;
 pub use glwe_ciphertext_vector_trivial_encryption::*;
 
+mod glwe_ciphertext_discarding_addition;
+pub use glwe_ciphertext_discarding_addition::*;
+
+mod glwe_ciphertext_fusing_addition;
+pub use glwe_ciphertext_fusing_addition::*;
+
+mod glwe_ciphertext_discarding_subtraction;
+pub use glwe_ciphertext_discarding_subtraction::*;
+
+mod glwe_ciphertext_fusing_subtraction;
+pub use glwe_ciphertext_fusing_subtraction::*;
+
+mod glwe_ciphertext_discarding_opposite;
+pub use glwe_ciphertext_discarding_opposite::*;
+
+mod glwe_ciphertext_discarding_monomial_multiplication;
+pub use glwe_ciphertext_discarding_monomial_multiplication::*;
+
+mod glwe_ciphertext_fusing_opposite;
+pub use glwe_ciphertext_fusing_opposite::*;
+
+mod glwe_ciphertext_fusing_monomial_multiplication;
+pub use glwe_ciphertext_fusing_monomial_multiplication::*;
+
+mod glwe_ciphertext_vector_discarding_addition;
+pub use glwe_ciphertext_vector_discarding_addition::*;
+
+mod glwe_ciphertext_vector_fusing_addition;
+pub use glwe_ciphertext_vector_fusing_addition::*;
+
+mod glwe_ciphertext_vector_discarding_subtraction;
+pub use glwe_ciphertext_vector_discarding_subtraction::*;
+
+mod glwe_ciphertext_vector_fusing_subtraction;
+pub use glwe_ciphertext_vector_fusing_subtraction::*;
+
+mod glwe_ciphertext_vector_discarding_opposite;
+pub use glwe_ciphertext_vector_discarding_opposite::*;
+
+mod glwe_ciphertext_vector_fusing_opposite;
+pub use glwe_ciphertext_vector_fusing_opposite::*;
+
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+pub use glwe_ciphertext_plaintext_vector_discarding_addition::*;
+
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
+pub use glwe_ciphertext_plaintext_vector_fusing_addition::*;
+
+mod glwe_ciphertext_plaintext_vector_discarding_multiplication;
+pub use glwe_ciphertext_plaintext_vector_discarding_multiplication::*;
+
+mod glwe_ciphertext_plaintext_vector_fusing_multiplication;
+pub use glwe_ciphertext_plaintext_vector_fusing_multiplication::*;
+
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
 pub use glwe_ciphertexts_ggsw_ciphertext_fusing_cmux::*;
 
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
+pub use lwe_ciphertexts_gsw_ciphertext_fusing_cmux::*;
+
 mod lwe_ciphertext_vector_zero_encryption;
 pub use lwe_ciphertext_vector_zero_encryption::*;
 
@@ -424,15 +487,30 @@ pub use lwe_ciphertext_trivial_encryption::*;
 mod lwe_ciphertext_trivial_decryption;
 pub use lwe_ciphertext_trivial_decryption::*;
 
+mod lwe_ciphertext_discarding_blind_rotation;
+pub use lwe_ciphertext_discarding_blind_rotation::*;
+
 mod lwe_ciphertext_discarding_bootstrap_1;
 pub use lwe_ciphertext_discarding_bootstrap_1::*;
 
 mod lwe_ciphertext_discarding_bootstrap_2;
 pub use lwe_ciphertext_discarding_bootstrap_2::*;
 
+mod lwe_ciphertext_discarding_many_lut_bootstrap;
+pub use lwe_ciphertext_discarding_many_lut_bootstrap::*;
+
+mod lwe_ciphertext_discarding_multi_bit_bootstrap;
+pub use lwe_ciphertext_discarding_multi_bit_bootstrap::*;
+
+mod lwe_ciphertext_discarding_ternary_bootstrap;
+pub use lwe_ciphertext_discarding_ternary_bootstrap::*;
+
 mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
 pub use lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing::*;
 
+mod lwe_ciphertext_vector_discarding_wop_pbs;
+pub use lwe_ciphertext_vector_discarding_wop_pbs::*;
+
 mod lwe_ciphertext_discarding_bit_extraction;
 pub use lwe_ciphertext_discarding_bit_extraction::*;
 
@@ -448,12 +526,21 @@ pub use lwe_ciphertext_discarding_extraction::*;
 mod plaintext_creation;
 pub use plaintext_creation::*;
 
+mod glwe_ciphertext_discarding_keyswitch;
+pub use glwe_ciphertext_discarding_keyswitch::*;
+
+mod glwe_ciphertext_discarding_multiplication;
+pub use glwe_ciphertext_discarding_multiplication::*;
+
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
 
+mod lwe_ciphertext_gsw_ciphertext_discarding_external_product;
+pub use lwe_ciphertext_gsw_ciphertext_discarding_external_product::*;
+
 mod glwe_ciphertext_consuming_retrieval;
 pub use glwe_ciphertext_consuming_retrieval::*;
 
@@ -496,6 +583,30 @@ pub use lwe_seeded_keyswitch_key_generation::*;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
 pub use lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation::*;
 
+mod lwe_seeded_packing_keyswitch_key_generation;
+pub use lwe_seeded_packing_keyswitch_key_generation::*;
+
+mod lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation;
+pub use lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation::*;
+
+mod lwe_seeded_private_functional_packing_keyswitch_key_generation;
+pub use lwe_seeded_private_functional_packing_keyswitch_key_generation::*;
+
+mod lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation;
+pub use lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation::*;
+
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation::*;
+
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation::*;
+
+mod lwe_seeded_public_key_generation;
+pub use lwe_seeded_public_key_generation::*;
+
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
+pub use lwe_seeded_public_key_to_lwe_public_key_transformation::*;
+
 mod lwe_seeded_ciphertext_to_lwe_ciphertext_transformation;
 pub use lwe_seeded_ciphertext_to_lwe_ciphertext_transformation::*;
 
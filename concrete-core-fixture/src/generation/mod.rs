@@ -27,6 +27,8 @@
 use crate::raw::generation::RawUnsignedIntegers;
 #[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
 use concrete_core::prelude::FftEngine;
+#[cfg(feature = "backend_ntt")]
+use concrete_core::prelude::NttEngine;
 use concrete_core::prelude::{AbstractEngine, DefaultEngine, DefaultParallelEngine};
 use concrete_csprng::seeders::UnixSeeder;
 
@@ -81,6 +83,8 @@ pub struct Maker {
     default_parallel_engine: DefaultParallelEngine,
     #[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
     fft_engine: FftEngine,
+    #[cfg(feature = "backend_ntt")]
+    ntt_engine: NttEngine,
     #[cfg(feature = "backend_cuda")]
     cuda_engine: concrete_core::backends::cuda::engines::CudaEngine,
 }
@@ -93,6 +97,8 @@ impl Default for Maker {
                 .unwrap(),
             #[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
             fft_engine: FftEngine::new(()).unwrap(),
+            #[cfg(feature = "backend_ntt")]
+            ntt_engine: NttEngine::new(()).unwrap(),
             #[cfg(feature = "backend_cuda")]
             cuda_engine: concrete_core::backends::cuda::engines::CudaEngine::new(()).unwrap(),
         }
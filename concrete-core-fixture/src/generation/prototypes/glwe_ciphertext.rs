@@ -1,5 +1,6 @@
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    TernaryKeyDistribution,
 };
 use concrete_core::prelude::{GlweCiphertext32, GlweCiphertext64};
 
@@ -22,3 +23,17 @@ impl GlweCiphertextPrototype for ProtoBinaryGlweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 32 bit ternary glwe ciphertext entity.
+pub struct ProtoTernaryGlweCiphertext32(pub(crate) GlweCiphertext32);
+impl GlweCiphertextPrototype for ProtoTernaryGlweCiphertext32 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary glwe ciphertext entity.
+pub struct ProtoTernaryGlweCiphertext64(pub(crate) GlweCiphertext64);
+impl GlweCiphertextPrototype for ProtoTernaryGlweCiphertext64 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
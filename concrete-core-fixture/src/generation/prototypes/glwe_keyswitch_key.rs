@@ -0,0 +1,27 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GlweKeyswitchKey32, GlweKeyswitchKey64};
+
+/// A trait implemented by GLWE keyswitch key prototypes.
+pub trait GlweKeyswitchKeyPrototype {
+    type InputKeyDistribution: KeyDistributionMarker;
+    type OutputKeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary to binary GLWE keyswitch key entity.
+pub struct ProtoBinaryBinaryGlweKeyswitchKey32(pub(crate) GlweKeyswitchKey32);
+impl GlweKeyswitchKeyPrototype for ProtoBinaryBinaryGlweKeyswitchKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary to binary GLWE keyswitch key entity.
+pub struct ProtoBinaryBinaryGlweKeyswitchKey64(pub(crate) GlweKeyswitchKey64);
+impl GlweKeyswitchKeyPrototype for ProtoBinaryBinaryGlweKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
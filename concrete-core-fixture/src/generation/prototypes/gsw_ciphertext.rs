@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GswCiphertext32, GswCiphertext64};
+
+/// A trait implemented by gsw ciphertext prototypes.
+pub trait GswCiphertextPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary gsw ciphertext entity.
+pub struct ProtoBinaryGswCiphertext32(pub(crate) GswCiphertext32);
+impl GswCiphertextPrototype for ProtoBinaryGswCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary gsw ciphertext entity.
+pub struct ProtoBinaryGswCiphertext64(pub(crate) GswCiphertext64);
+impl GswCiphertextPrototype for ProtoBinaryGswCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
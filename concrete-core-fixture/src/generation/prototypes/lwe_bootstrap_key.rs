@@ -1,7 +1,10 @@
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    TernaryKeyDistribution,
+};
+use concrete_core::prelude::{
+    LweBootstrapKey32, LweBootstrapKey64, LweTernaryBootstrapKey32, LweTernaryBootstrapKey64,
 };
-use concrete_core::prelude::{LweBootstrapKey32, LweBootstrapKey64};
 
 /// A trait implemented by lwe bootstrap key prototypes.
 pub trait LweBootstrapKeyPrototype {
@@ -25,3 +28,19 @@ impl LweBootstrapKeyPrototype for ProtoBinaryBinaryLweBootstrapKey64 {
     type OutputKeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 32 bit ternary to binary lwe bootstrap key entity.
+pub struct ProtoTernaryBinaryLweBootstrapKey32(pub(crate) LweTernaryBootstrapKey32);
+impl LweBootstrapKeyPrototype for ProtoTernaryBinaryLweBootstrapKey32 {
+    type InputKeyDistribution = TernaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary to binary lwe bootstrap key entity.
+pub struct ProtoTernaryBinaryLweBootstrapKey64(pub(crate) LweTernaryBootstrapKey64);
+impl LweBootstrapKeyPrototype for ProtoTernaryBinaryLweBootstrapKey64 {
+    type InputKeyDistribution = TernaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
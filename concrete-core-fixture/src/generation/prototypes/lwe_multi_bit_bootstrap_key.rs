@@ -0,0 +1,29 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{LweMultiBitBootstrapKey32, LweMultiBitBootstrapKey64};
+
+/// A trait implemented by multi-bit lwe bootstrap key prototypes.
+pub trait LweMultiBitBootstrapKeyPrototype {
+    type InputKeyDistribution: KeyDistributionMarker;
+    type OutputKeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary to binary multi-bit lwe bootstrap key
+/// entity.
+pub struct ProtoBinaryBinaryLweMultiBitBootstrapKey32(pub(crate) LweMultiBitBootstrapKey32);
+impl LweMultiBitBootstrapKeyPrototype for ProtoBinaryBinaryLweMultiBitBootstrapKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary to binary multi-bit lwe bootstrap key
+/// entity.
+pub struct ProtoBinaryBinaryLweMultiBitBootstrapKey64(pub(crate) LweMultiBitBootstrapKey64);
+impl LweMultiBitBootstrapKeyPrototype for ProtoBinaryBinaryLweMultiBitBootstrapKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,41 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+};
+
+/// A trait implemented by prototypes of seeded private functional packing keyswitch keys vector
+/// used in circuit bootstrapping.
+pub trait LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysPrototype {
+    type InputKeyDistribution: KeyDistributionMarker;
+    type OutputKeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary seeded private functional packing
+/// keyswitch keys vector entity used in circuit bootstrapping.
+pub struct ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
+    pub(crate) LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+);
+impl LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysPrototype
+    for ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32
+{
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary seeded private functional packing
+/// keyswitch keys vector entity used in circuit bootstrapping.
+pub struct ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
+    pub(crate) LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+);
+impl LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysPrototype
+    for ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64
+{
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{LweSeededPublicKey32, LweSeededPublicKey64};
+
+/// A trait implemented by seeded lwe public key prototypes.
+pub trait LweSeededPublicKeyPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary seeded lwe public key entity.
+pub struct ProtoBinaryLweSeededPublicKey32(pub(crate) LweSeededPublicKey32);
+impl LweSeededPublicKeyPrototype for ProtoBinaryLweSeededPublicKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary seeded lwe public key entity.
+pub struct ProtoBinaryLweSeededPublicKey64(pub(crate) LweSeededPublicKey64);
+impl LweSeededPublicKeyPrototype for ProtoBinaryLweSeededPublicKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
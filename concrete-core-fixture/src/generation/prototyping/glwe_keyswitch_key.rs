@@ -0,0 +1,95 @@
+use crate::generation::prototypes::{
+    GlweKeyswitchKeyPrototype, ProtoBinaryBinaryGlweKeyswitchKey32,
+    ProtoBinaryBinaryGlweKeyswitchKey64,
+};
+use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweKeyswitchKeyGenerationEngine, Variance,
+};
+
+/// A trait allowing to manipulate glwe keyswitch key prototypes.
+pub trait PrototypesGlweKeyswitchKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesGlweSecretKey<Precision, InputKeyDistribution>
+    + PrototypesGlweSecretKey<Precision, OutputKeyDistribution>
+{
+    type GlweKeyswitchKeyProto: GlweKeyswitchKeyPrototype<
+        Precision = Precision,
+        InputKeyDistribution = InputKeyDistribution,
+        OutputKeyDistribution = OutputKeyDistribution,
+    >;
+    fn new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesGlweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::GlweSecretKeyProto,
+        output_key: &<Self as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweKeyswitchKeyProto;
+}
+
+impl PrototypesGlweKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type GlweKeyswitchKeyProto = ProtoBinaryBinaryGlweKeyswitchKey32;
+
+    fn new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &Self::GlweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweKeyswitchKeyProto {
+        ProtoBinaryBinaryGlweKeyswitchKey32(
+            self.default_engine
+                .generate_new_glwe_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type GlweKeyswitchKeyProto = ProtoBinaryBinaryGlweKeyswitchKey64;
+
+    fn new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &Self::GlweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweKeyswitchKeyProto {
+        ProtoBinaryBinaryGlweKeyswitchKey64(
+            self.default_engine
+                .generate_new_glwe_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
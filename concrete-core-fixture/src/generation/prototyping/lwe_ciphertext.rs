@@ -1,11 +1,12 @@
 use crate::generation::prototypes::{
     LweCiphertextPrototype, ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64,
-    ProtoPlaintext32, ProtoPlaintext64,
+    ProtoPlaintext32, ProtoPlaintext64, ProtoTernaryLweCiphertext32, ProtoTernaryLweCiphertext64,
 };
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
 use crate::generation::prototyping::plaintext::PrototypesPlaintext;
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32,
+    Precision64, TernaryKeyDistribution,
 };
 use concrete_core::prelude::{
     LweCiphertextConsumingRetrievalEngine, LweCiphertextCreationEngine,
@@ -221,3 +222,167 @@ impl PrototypesLweCiphertext<Precision64, BinaryKeyDistribution> for Maker {
             .unwrap()
     }
 }
+
+impl PrototypesLweCiphertext<Precision32, TernaryKeyDistribution> for Maker {
+    type LweCiphertextProto = ProtoTernaryLweCiphertext32;
+
+    fn trivially_encrypt_zero_to_lwe_ciphertext(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Self::LweCiphertextProto {
+        let plaintext = self.default_engine.create_plaintext_from(&0u32).unwrap();
+        ProtoTernaryLweCiphertext32(
+            self.default_engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_to_lwe_ciphertext(
+        &mut self,
+        lwe_dimension: LweDimension,
+        plaintext: &Self::PlaintextProto,
+    ) -> Self::LweCiphertextProto {
+        ProtoTernaryLweCiphertext32(
+            self.default_engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_to_lwe_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+    ) -> Self::LweCiphertextProto {
+        ProtoTernaryLweCiphertext32(
+            self.default_engine
+                .encrypt_lwe_ciphertext(&secret_key.0, &plaintext.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_lwe_ciphertext_to_plaintext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Self::PlaintextProto {
+        ProtoPlaintext32(
+            self.default_engine
+                .decrypt_lwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_lwe_ciphertext_to_plaintext(
+        &mut self,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Self::PlaintextProto {
+        ProtoPlaintext32(
+            self.default_engine
+                .trivially_decrypt_lwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_lwe_ciphertext(&mut self, raw: &[u32]) -> Self::LweCiphertextProto {
+        ProtoTernaryLweCiphertext32(
+            self.default_engine
+                .create_lwe_ciphertext_from(raw.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_ciphertext_to_raw_vec(
+        &mut self,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Vec<u32> {
+        let ciphertext = ciphertext.0.to_owned();
+        self.default_engine
+            .consume_retrieve_lwe_ciphertext(ciphertext)
+            .unwrap()
+    }
+}
+
+impl PrototypesLweCiphertext<Precision64, TernaryKeyDistribution> for Maker {
+    type LweCiphertextProto = ProtoTernaryLweCiphertext64;
+
+    fn trivially_encrypt_zero_to_lwe_ciphertext(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Self::LweCiphertextProto {
+        let plaintext = self.default_engine.create_plaintext_from(&0u64).unwrap();
+        ProtoTernaryLweCiphertext64(
+            self.default_engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_to_lwe_ciphertext(
+        &mut self,
+        lwe_dimension: LweDimension,
+        plaintext: &Self::PlaintextProto,
+    ) -> Self::LweCiphertextProto {
+        ProtoTernaryLweCiphertext64(
+            self.default_engine
+                .trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext.0)
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_to_lwe_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+    ) -> Self::LweCiphertextProto {
+        ProtoTernaryLweCiphertext64(
+            self.default_engine
+                .encrypt_lwe_ciphertext(&secret_key.0, &plaintext.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_lwe_ciphertext_to_plaintext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Self::PlaintextProto {
+        ProtoPlaintext64(
+            self.default_engine
+                .decrypt_lwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_lwe_ciphertext_to_plaintext(
+        &mut self,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Self::PlaintextProto {
+        ProtoPlaintext64(
+            self.default_engine
+                .trivially_decrypt_lwe_ciphertext(&ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_lwe_ciphertext(&mut self, raw: &[u64]) -> Self::LweCiphertextProto {
+        ProtoTernaryLweCiphertext64(
+            self.default_engine
+                .create_lwe_ciphertext_from(raw.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_ciphertext_to_raw_vec(
+        &mut self,
+        ciphertext: &Self::LweCiphertextProto,
+    ) -> Vec<u64> {
+        let ciphertext = ciphertext.0.to_owned();
+        self.default_engine
+            .consume_retrieve_lwe_ciphertext(ciphertext)
+            .unwrap()
+    }
+}
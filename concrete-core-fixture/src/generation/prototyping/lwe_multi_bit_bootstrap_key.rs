@@ -0,0 +1,96 @@
+use crate::generation::prototypes::{
+    LweMultiBitBootstrapKeyPrototype, ProtoBinaryBinaryLweMultiBitBootstrapKey32,
+    ProtoBinaryBinaryLweMultiBitBootstrapKey64,
+};
+use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
+use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, LweBskGroupingFactor,
+    LweMultiBitBootstrapKeyGenerationEngine, Variance,
+};
+
+/// A trait allowing to manipulate multi-bit LWE bootstrap key prototypes.
+pub trait PrototypesLweMultiBitBootstrapKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesLweSecretKey<Precision, InputKeyDistribution>
+    + PrototypesGlweSecretKey<Precision, OutputKeyDistribution>
+{
+    type LweMultiBitBootstrapKeyProto: LweMultiBitBootstrapKeyPrototype<
+        Precision = Precision,
+        InputKeyDistribution = InputKeyDistribution,
+        OutputKeyDistribution = OutputKeyDistribution,
+    >;
+    fn new_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> Self::LweMultiBitBootstrapKeyProto;
+}
+
+impl PrototypesLweMultiBitBootstrapKey<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweMultiBitBootstrapKeyProto = ProtoBinaryBinaryLweMultiBitBootstrapKey32;
+
+    fn new_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> Self::LweMultiBitBootstrapKeyProto {
+        ProtoBinaryBinaryLweMultiBitBootstrapKey32(
+            self.default_parallel_engine
+                .generate_new_lwe_multi_bit_bootstrap_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_base_log,
+                    decomposition_level,
+                    grouping_factor,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweMultiBitBootstrapKey<Precision64, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweMultiBitBootstrapKeyProto = ProtoBinaryBinaryLweMultiBitBootstrapKey64;
+
+    fn new_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> Self::LweMultiBitBootstrapKeyProto {
+        ProtoBinaryBinaryLweMultiBitBootstrapKey64(
+            self.default_parallel_engine
+                .generate_new_lwe_multi_bit_bootstrap_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_base_log,
+                    decomposition_level,
+                    grouping_factor,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+}
@@ -1,13 +1,17 @@
 use crate::generation::prototypes::{
     LwePackingKeyswitchKeyPrototype, ProtoBinaryBinaryLwePackingKeyswitchKey32,
-    ProtoBinaryBinaryLwePackingKeyswitchKey64,
+    ProtoBinaryBinaryLwePackingKeyswitchKey64, ProtoBinaryGlweCiphertext32,
+    ProtoBinaryGlweCiphertext64,
 };
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
+use crate::generation::prototyping::{PrototypesGlweCiphertext, PrototypesLweCiphertextVector};
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
 };
 use concrete_core::prelude::{
-    DecompositionBaseLog, DecompositionLevelCount, LwePackingKeyswitchKeyGenerationEngine, Variance,
+    DecompositionBaseLog, DecompositionLevelCount,
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
+    LwePackingKeyswitchKeyEntity, LwePackingKeyswitchKeyGenerationEngine, Variance,
 };
 
 use super::PrototypesGlweSecretKey;
@@ -20,6 +24,8 @@ pub trait PrototypesLwePackingKeyswitchKey<
 >:
     PrototypesLweSecretKey<Precision, InputKeyDistribution>
     + PrototypesGlweSecretKey<Precision, OutputKeyDistribution>
+    + PrototypesLweCiphertextVector<Precision, InputKeyDistribution>
+    + PrototypesGlweCiphertext<Precision, OutputKeyDistribution>
 {
     type PackingKeyswitchKeyProto: LwePackingKeyswitchKeyPrototype<
         Precision = Precision,
@@ -40,6 +46,14 @@ pub trait PrototypesLwePackingKeyswitchKey<
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
     ) -> Self::PackingKeyswitchKeyProto;
+    fn packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+        &mut self,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+        input: &<Self as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    ) -> <Self as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto;
 }
 
 impl PrototypesLwePackingKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
@@ -67,6 +81,26 @@ impl PrototypesLwePackingKeyswitchKey<Precision32, BinaryKeyDistribution, Binary
                 .unwrap(),
         )
     }
+
+    fn packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+        &mut self,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+        input: &Self::LweCiphertextVectorProto,
+    ) -> ProtoBinaryGlweCiphertext32 {
+        let mut output = <Self as PrototypesGlweCiphertext<Precision32, BinaryKeyDistribution>>::trivially_encrypt_zeros_to_glwe_ciphertext(
+self,
+                packing_keyswitch_key.0.output_glwe_dimension(),
+                packing_keyswitch_key.0.output_polynomial_size(),
+            );
+        self.default_engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output.0,
+                &input.0,
+                &packing_keyswitch_key.0,
+            )
+            .unwrap();
+        output
+    }
 }
 
 impl PrototypesLwePackingKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDistribution>
@@ -94,4 +128,24 @@ impl PrototypesLwePackingKeyswitchKey<Precision64, BinaryKeyDistribution, Binary
                 .unwrap(),
         )
     }
+
+    fn packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+        &mut self,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+        input: &Self::LweCiphertextVectorProto,
+    ) -> ProtoBinaryGlweCiphertext64 {
+        let mut output = <Self as PrototypesGlweCiphertext<Precision64, BinaryKeyDistribution>>::trivially_encrypt_zeros_to_glwe_ciphertext(
+self,
+                packing_keyswitch_key.0.output_glwe_dimension(),
+                packing_keyswitch_key.0.output_polynomial_size(),
+            );
+        self.default_engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output.0,
+                &input.0,
+                &packing_keyswitch_key.0,
+            )
+            .unwrap();
+        output
+    }
 }
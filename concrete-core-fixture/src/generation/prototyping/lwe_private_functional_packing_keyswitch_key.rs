@@ -3,8 +3,11 @@ pub use crate::generation::prototypes::{
     ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey32,
     ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey64,
 };
+use crate::generation::prototypes::{ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64};
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
-use crate::generation::prototyping::PrototypesCleartextVector;
+use crate::generation::prototyping::{
+    PrototypesCleartextVector, PrototypesGlweCiphertext, PrototypesLweCiphertextVector,
+};
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
 };
@@ -12,7 +15,9 @@ use concrete_core::prelude::StandardDev;
 
 use concrete_core::prelude::{
     DecompositionBaseLog, DecompositionLevelCount,
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine,
     LwePrivateFunctionalLwePackingKeyswitchKeyGenerationEngine,
+    LwePrivateFunctionalPackingKeyswitchKeyEntity,
 };
 
 use super::PrototypesGlweSecretKey;
@@ -26,6 +31,8 @@ pub trait PrototypesLwePrivateFunctionalPackingKeyswitchKey<
     PrototypesLweSecretKey<Precision, InputKeyDistribution>
     + PrototypesGlweSecretKey<Precision, OutputKeyDistribution>
     + PrototypesCleartextVector<Precision>
+    + PrototypesLweCiphertextVector<Precision, InputKeyDistribution>
+    + PrototypesGlweCiphertext<Precision, OutputKeyDistribution>
 {
     type LwePrivateFunctionalPackingKeyswitchKeyProto: LwePrivateFunctionalPackingKeyswitchKeyPrototype<
         Precision = Precision,
@@ -49,6 +56,14 @@ pub trait PrototypesLwePrivateFunctionalPackingKeyswitchKey<
         f: &dyn Fn(Precision::Raw) -> Precision::Raw,
         polynomial: &<Self as PrototypesCleartextVector<Precision>>::CleartextVectorProto,
     ) -> Self::LwePrivateFunctionalPackingKeyswitchKeyProto;
+    fn private_functional_packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+        &mut self,
+        private_functional_packing_keyswitch_key: &Self::LwePrivateFunctionalPackingKeyswitchKeyProto,
+        input: &<Self as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    ) -> <Self as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto;
 }
 
 impl
@@ -85,6 +100,30 @@ impl
                 .unwrap(),
         )
     }
+
+    fn private_functional_packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+        &mut self,
+        private_functional_packing_keyswitch_key: &Self::LwePrivateFunctionalPackingKeyswitchKeyProto,
+        input: &Self::LweCiphertextVectorProto,
+    ) -> ProtoBinaryGlweCiphertext32 {
+        let mut output = <Self as PrototypesGlweCiphertext<Precision32, BinaryKeyDistribution>>::trivially_encrypt_zeros_to_glwe_ciphertext(
+self,
+                private_functional_packing_keyswitch_key
+                    .0
+                    .output_glwe_dimension(),
+                private_functional_packing_keyswitch_key
+                    .0
+                    .output_polynomial_size(),
+            );
+        self.default_engine
+            .discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output.0,
+                &input.0,
+                &private_functional_packing_keyswitch_key.0,
+            )
+            .unwrap();
+        output
+    }
 }
 
 impl
@@ -121,4 +160,28 @@ impl
                 .unwrap(),
         )
     }
+
+    fn private_functional_packing_keyswitch_lwe_ciphertext_vector_to_glwe_ciphertext(
+        &mut self,
+        private_functional_packing_keyswitch_key: &Self::LwePrivateFunctionalPackingKeyswitchKeyProto,
+        input: &Self::LweCiphertextVectorProto,
+    ) -> ProtoBinaryGlweCiphertext64 {
+        let mut output = <Self as PrototypesGlweCiphertext<Precision64, BinaryKeyDistribution>>::trivially_encrypt_zeros_to_glwe_ciphertext(
+self,
+                private_functional_packing_keyswitch_key
+                    .0
+                    .output_glwe_dimension(),
+                private_functional_packing_keyswitch_key
+                    .0
+                    .output_polynomial_size(),
+            );
+        self.default_engine
+            .discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output.0,
+                &input.0,
+                &private_functional_packing_keyswitch_key.0,
+            )
+            .unwrap();
+        output
+    }
 }
@@ -1,11 +1,15 @@
 use crate::generation::prototypes::{
-    LwePublicKeyPrototype, ProtoBinaryLwePublicKey32, ProtoBinaryLwePublicKey64,
+    LwePublicKeyPrototype, ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64,
+    ProtoBinaryLwePublicKey32, ProtoBinaryLwePublicKey64,
+};
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
 };
-use crate::generation::prototyping::PrototypesLweSecretKey;
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
 };
 use concrete_core::prelude::{
+    LweCiphertextDiscardingPublicKeyEncryptionEngine, LwePublicKeyEntity,
     LwePublicKeyGenerationEngine, LwePublicKeyZeroEncryptionCount, Variance,
 };
 
@@ -13,7 +17,10 @@ use concrete_core::prelude::{
 pub trait PrototypesLwePublicKey<
     Precision: IntegerPrecision,
     KeyDistribution: KeyDistributionMarker,
->: PrototypesLweSecretKey<Precision, KeyDistribution>
+>:
+    PrototypesLweSecretKey<Precision, KeyDistribution>
+    + PrototypesPlaintext<Precision>
+    + PrototypesLweCiphertext<Precision, KeyDistribution>
 {
     type LwePublicKeyProto: LwePublicKeyPrototype<
         Precision = Precision,
@@ -25,6 +32,11 @@ pub trait PrototypesLwePublicKey<
         count: LwePublicKeyZeroEncryptionCount,
         noise: Variance,
     ) -> Self::LwePublicKeyProto;
+    fn encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext: &Self::PlaintextProto,
+    ) -> Self::LweCiphertextProto;
 }
 
 impl PrototypesLwePublicKey<Precision32, BinaryKeyDistribution> for Maker {
@@ -42,6 +54,23 @@ impl PrototypesLwePublicKey<Precision32, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext: &Self::PlaintextProto,
+    ) -> ProtoBinaryLweCiphertext32 {
+        let mut output = <Self as PrototypesLweCiphertext<Precision32, BinaryKeyDistribution>>::trivially_encrypt_zero_to_lwe_ciphertext(
+self,public_key.0.lwe_dimension());
+        self.default_engine
+            .discard_encrypt_lwe_ciphertext_with_public_key(
+                &public_key.0,
+                &mut output.0,
+                &plaintext.0,
+            )
+            .unwrap();
+        output
+    }
 }
 
 impl PrototypesLwePublicKey<Precision64, BinaryKeyDistribution> for Maker {
@@ -59,4 +88,21 @@ impl PrototypesLwePublicKey<Precision64, BinaryKeyDistribution> for Maker {
                 .unwrap(),
         )
     }
+
+    fn encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext: &Self::PlaintextProto,
+    ) -> ProtoBinaryLweCiphertext64 {
+        let mut output = <Self as PrototypesLweCiphertext<Precision64, BinaryKeyDistribution>>::trivially_encrypt_zero_to_lwe_ciphertext(
+self,public_key.0.lwe_dimension());
+        self.default_engine
+            .discard_encrypt_lwe_ciphertext_with_public_key(
+                &public_key.0,
+                &mut output.0,
+                &plaintext.0,
+            )
+            .unwrap();
+        output
+    }
 }
@@ -0,0 +1,148 @@
+use crate::generation::prototypes::{
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysPrototype,
+    ProtoBinaryBinaryLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ProtoBinaryBinaryLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+};
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    PrototypesLweSecretKey,
+};
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine,
+    Variance,
+};
+
+/// A trait allowing to manipulate prototypes of seeded private functional packing keyswitch keys
+/// vector used in circuit bootstrapping.
+pub trait PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+    Precision,
+    InputKeyDistribution,
+    OutputKeyDistribution,
+>
+{
+    type LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysPrototype<
+        Precision = Precision,
+        InputKeyDistribution = InputKeyDistribution,
+        OutputKeyDistribution = OutputKeyDistribution,
+    >;
+    fn new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_lwe_key: &<Self as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::LweSecretKeyProto,
+        output_glwe_key: &<Self as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::GlweSecretKeyProto,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto;
+    fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        seeded_keys: &Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+    ) -> Self::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto;
+}
+
+impl
+    PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+        Precision32,
+        BinaryKeyDistribution,
+        BinaryKeyDistribution,
+    > for Maker
+{
+    type LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto =
+        ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32;
+
+    fn new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_lwe_key: &Self::LweSecretKeyProto,
+        output_glwe_key: &Self::GlweSecretKeyProto,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto {
+        ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
+            self.default_engine
+                .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                    &input_lwe_key.0,
+                    &output_glwe_key.0,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        seeded_keys: &Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+    ) -> Self::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto {
+        ProtoBinaryBinaryLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
+            self.default_engine
+                .transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                    seeded_keys.0.to_owned(),
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl
+    PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+        Precision64,
+        BinaryKeyDistribution,
+        BinaryKeyDistribution,
+    > for Maker
+{
+    type LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto =
+        ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64;
+
+    fn new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_lwe_key: &Self::LweSecretKeyProto,
+        output_glwe_key: &Self::GlweSecretKeyProto,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto {
+        ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
+            self.default_engine
+                .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                    &input_lwe_key.0,
+                    &output_glwe_key.0,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        seeded_keys: &Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+    ) -> Self::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto {
+        ProtoBinaryBinaryLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
+            self.default_engine
+                .transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                    seeded_keys.0.to_owned(),
+                )
+                .unwrap(),
+        )
+    }
+}
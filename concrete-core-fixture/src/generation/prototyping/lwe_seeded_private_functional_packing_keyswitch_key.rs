@@ -0,0 +1,158 @@
+use crate::generation::prototypes::{
+    LweSeededPrivateFunctionalPackingKeyswitchKeyPrototype,
+    ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey32,
+    ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey64,
+    ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey32,
+    ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::generation::prototyping::{
+    PrototypesCleartextVector, PrototypesGlweSecretKey,
+    PrototypesLwePrivateFunctionalPackingKeyswitchKey, PrototypesLweSecretKey,
+};
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine,
+    StandardDev,
+};
+
+/// A trait allowing to manipulate seeded private functional packing keyswitch key prototypes.
+pub trait PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesLwePrivateFunctionalPackingKeyswitchKey<
+    Precision,
+    InputKeyDistribution,
+    OutputKeyDistribution,
+>
+{
+    type LweSeededPrivateFunctionalPackingKeyswitchKeyProto: LweSeededPrivateFunctionalPackingKeyswitchKeyPrototype<
+        Precision = Precision,
+        InputKeyDistribution = InputKeyDistribution,
+        OutputKeyDistribution = OutputKeyDistribution,
+    >;
+    #[allow(clippy::too_many_arguments)]
+    fn new_lwe_seeded_private_functional_packing_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: StandardDev,
+        f: &dyn Fn(Precision::Raw) -> Precision::Raw,
+        polynomial: &<Self as PrototypesCleartextVector<Precision>>::CleartextVectorProto,
+    ) -> Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto;
+    fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+        &mut self,
+        seeded_private_functional_packing_keyswitch_key: &Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto,
+    ) -> Self::LwePrivateFunctionalPackingKeyswitchKeyProto;
+}
+
+impl
+    PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey<
+        Precision32,
+        BinaryKeyDistribution,
+        BinaryKeyDistribution,
+    > for Maker
+{
+    type LweSeededPrivateFunctionalPackingKeyswitchKeyProto =
+        ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey32;
+
+    fn new_lwe_seeded_private_functional_packing_keyswitch_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: StandardDev,
+        f: &dyn Fn(u32) -> u32,
+        polynomial: &Self::CleartextVectorProto,
+    ) -> Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto {
+        ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey32(
+            self.default_engine
+                .generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                    f,
+                    &polynomial.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+        &mut self,
+        seeded_private_functional_packing_keyswitch_key: &Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto,
+    ) -> Self::LwePrivateFunctionalPackingKeyswitchKeyProto {
+        ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey32(
+            self.default_engine
+                .transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+                    seeded_private_functional_packing_keyswitch_key.0.to_owned(),
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl
+    PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey<
+        Precision64,
+        BinaryKeyDistribution,
+        BinaryKeyDistribution,
+    > for Maker
+{
+    type LweSeededPrivateFunctionalPackingKeyswitchKeyProto =
+        ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey64;
+
+    fn new_lwe_seeded_private_functional_packing_keyswitch_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: StandardDev,
+        f: &dyn Fn(u64) -> u64,
+        polynomial: &Self::CleartextVectorProto,
+    ) -> Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto {
+        ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey64(
+            self.default_engine
+                .generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                    f,
+                    &polynomial.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+        &mut self,
+        seeded_private_functional_packing_keyswitch_key: &Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto,
+    ) -> Self::LwePrivateFunctionalPackingKeyswitchKeyProto {
+        ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey64(
+            self.default_engine
+                .transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+                    seeded_private_functional_packing_keyswitch_key.0.to_owned(),
+                )
+                .unwrap(),
+        )
+    }
+}
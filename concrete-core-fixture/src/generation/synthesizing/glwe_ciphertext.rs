@@ -20,9 +20,14 @@ pub trait SynthesizesGlweCiphertext<
 }
 
 mod backend_default {
-    use crate::generation::prototypes::{ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64};
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64, ProtoTernaryGlweCiphertext32,
+        ProtoTernaryGlweCiphertext64,
+    };
     use crate::generation::synthesizing::SynthesizesGlweCiphertext;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+    };
     use concrete_core::prelude::{GlweCiphertext32, GlweCiphertext64};
 
     impl SynthesizesGlweCiphertext<Precision32, BinaryKeyDistribution, GlweCiphertext32> for Maker {
@@ -61,6 +66,42 @@ mod backend_default {
         fn destroy_glwe_ciphertext(&mut self, _entity: GlweCiphertext64) {}
     }
 
+    impl SynthesizesGlweCiphertext<Precision32, TernaryKeyDistribution, GlweCiphertext32> for Maker {
+        fn synthesize_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::GlweCiphertextProto,
+        ) -> GlweCiphertext32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_ciphertext(
+            &mut self,
+            entity: GlweCiphertext32,
+        ) -> Self::GlweCiphertextProto {
+            ProtoTernaryGlweCiphertext32(entity)
+        }
+
+        fn destroy_glwe_ciphertext(&mut self, _entity: GlweCiphertext32) {}
+    }
+
+    impl SynthesizesGlweCiphertext<Precision64, TernaryKeyDistribution, GlweCiphertext64> for Maker {
+        fn synthesize_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::GlweCiphertextProto,
+        ) -> GlweCiphertext64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_ciphertext(
+            &mut self,
+            entity: GlweCiphertext64,
+        ) -> Self::GlweCiphertextProto {
+            ProtoTernaryGlweCiphertext64(entity)
+        }
+
+        fn destroy_glwe_ciphertext(&mut self, _entity: GlweCiphertext64) {}
+    }
+
     use concrete_core::prelude::{
         GlweCiphertextConsumingRetrievalEngine, GlweCiphertextCreationEngine, GlweCiphertextEntity,
         GlweCiphertextView32, GlweCiphertextView64,
@@ -25,10 +25,15 @@ pub trait SynthesizesLweBootstrapKey<
 mod backend_default {
     use crate::generation::prototypes::{
         ProtoBinaryBinaryLweBootstrapKey32, ProtoBinaryBinaryLweBootstrapKey64,
+        ProtoTernaryBinaryLweBootstrapKey32, ProtoTernaryBinaryLweBootstrapKey64,
     };
     use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-    use concrete_core::prelude::{LweBootstrapKey32, LweBootstrapKey64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+    };
+    use concrete_core::prelude::{
+        LweBootstrapKey32, LweBootstrapKey64, LweTernaryBootstrapKey32, LweTernaryBootstrapKey64,
+    };
 
     impl
         SynthesizesLweBootstrapKey<
@@ -383,14 +388,68 @@ mod backend_default {
             unsafe { Vec::from_raw_parts(slice.as_ptr() as *mut u64, slice.len(), slice.len()) };
         }
     }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision32,
+            TernaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweTernaryBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> LweTernaryBootstrapKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            entity: LweTernaryBootstrapKey32,
+        ) -> Self::LweBootstrapKeyProto {
+            ProtoTernaryBinaryLweBootstrapKey32(entity)
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: LweTernaryBootstrapKey32) {}
+    }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision64,
+            TernaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweTernaryBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> LweTernaryBootstrapKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            entity: LweTernaryBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            ProtoTernaryBinaryLweBootstrapKey64(entity)
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: LweTernaryBootstrapKey64) {}
+    }
 }
 
 #[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
 mod backend_fft {
     use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+    };
     use concrete_core::prelude::{
-        FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, LweBootstrapKeyConversionEngine,
+        FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64,
+        FftFourierLweTernaryBootstrapKey32, FftFourierLweTernaryBootstrapKey64,
+        LweBootstrapKeyConversionEngine,
     };
 
     impl
@@ -414,7 +473,7 @@ mod backend_fft {
             &mut self,
             _entity: FftFourierLweBootstrapKey32,
         ) -> Self::LweBootstrapKeyProto {
-            todo!()
+            unimplemented!("The backward fourier conversion was not yet implemented");
         }
 
         fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweBootstrapKey32) {}
@@ -441,11 +500,128 @@ mod backend_fft {
             &mut self,
             _entity: FftFourierLweBootstrapKey64,
         ) -> Self::LweBootstrapKeyProto {
-            todo!()
+            unimplemented!("The backward fourier conversion was not yet implemented");
         }
 
         fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweBootstrapKey64) {}
     }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision32,
+            TernaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierLweTernaryBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> FftFourierLweTernaryBootstrapKey32 {
+            self.fft_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweTernaryBootstrapKey32,
+        ) -> Self::LweBootstrapKeyProto {
+            unimplemented!("The backward fourier conversion was not yet implemented");
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweTernaryBootstrapKey32) {}
+    }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision64,
+            TernaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierLweTernaryBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> FftFourierLweTernaryBootstrapKey64 {
+            self.fft_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweTernaryBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            unimplemented!("The backward fourier conversion was not yet implemented");
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweTernaryBootstrapKey64) {}
+    }
+}
+
+#[cfg(feature = "backend_ntt")]
+mod backend_ntt {
+    use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        LweBootstrapKeyConversionEngine, NttFourierLweBootstrapKey32, NttFourierLweBootstrapKey64,
+    };
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            NttFourierLweBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> NttFourierLweBootstrapKey32 {
+            self.ntt_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: NttFourierLweBootstrapKey32,
+        ) -> Self::LweBootstrapKeyProto {
+            unimplemented!("The backward NTT conversion was not yet implemented");
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: NttFourierLweBootstrapKey32) {}
+    }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            NttFourierLweBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> NttFourierLweBootstrapKey64 {
+            self.ntt_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: NttFourierLweBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            unimplemented!("The backward NTT conversion was not yet implemented");
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: NttFourierLweBootstrapKey64) {}
+    }
 }
 
 #[cfg(feature = "backend_cuda")]
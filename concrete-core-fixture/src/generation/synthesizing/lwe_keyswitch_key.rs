@@ -24,9 +24,12 @@ pub trait SynthesizesLweKeyswitchKey<
 mod backend_default {
     use crate::generation::prototypes::{
         ProtoBinaryBinaryLweKeyswitchKey32, ProtoBinaryBinaryLweKeyswitchKey64,
+        ProtoTernaryTernaryLweKeyswitchKey32, ProtoTernaryTernaryLweKeyswitchKey64,
     };
     use crate::generation::synthesizing::SynthesizesLweKeyswitchKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+    };
     use concrete_core::prelude::{LweKeyswitchKey32, LweKeyswitchKey64};
 
     impl
@@ -79,6 +82,56 @@ mod backend_default {
         fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey64) {}
     }
 
+    impl
+        SynthesizesLweKeyswitchKey<
+            Precision32,
+            TernaryKeyDistribution,
+            TernaryKeyDistribution,
+            LweKeyswitchKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> LweKeyswitchKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: LweKeyswitchKey32,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoTernaryTernaryLweKeyswitchKey32(entity)
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey32) {}
+    }
+
+    impl
+        SynthesizesLweKeyswitchKey<
+            Precision64,
+            TernaryKeyDistribution,
+            TernaryKeyDistribution,
+            LweKeyswitchKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> LweKeyswitchKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: LweKeyswitchKey64,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoTernaryTernaryLweKeyswitchKey64(entity)
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey64) {}
+    }
+
     use concrete_core::prelude::{
         LweKeyswitchKeyConsumingRetrievalEngine, LweKeyswitchKeyCreationEngine,
         LweKeyswitchKeyEntity, LweKeyswitchKeyMutView32, LweKeyswitchKeyMutView64,
@@ -0,0 +1,155 @@
+use crate::generation::prototyping::PrototypesLweMultiBitBootstrapKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::LweMultiBitBootstrapKeyEntity;
+
+/// A trait allowing to synthesize an actual multi-bit lwe bootstrap key entity from a prototype.
+pub trait SynthesizesLweMultiBitBootstrapKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    LweMultiBitBootstrapKey,
+>:
+    PrototypesLweMultiBitBootstrapKey<Precision, InputKeyDistribution, OutputKeyDistribution> where
+    LweMultiBitBootstrapKey: LweMultiBitBootstrapKeyEntity,
+{
+    fn synthesize_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        prototype: &Self::LweMultiBitBootstrapKeyProto,
+    ) -> LweMultiBitBootstrapKey;
+    fn unsynthesize_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        entity: LweMultiBitBootstrapKey,
+    ) -> Self::LweMultiBitBootstrapKeyProto;
+    fn destroy_lwe_multi_bit_bootstrap_key(&mut self, entity: LweMultiBitBootstrapKey);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweMultiBitBootstrapKey32, ProtoBinaryBinaryLweMultiBitBootstrapKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweMultiBitBootstrapKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{LweMultiBitBootstrapKey32, LweMultiBitBootstrapKey64};
+
+    impl
+        SynthesizesLweMultiBitBootstrapKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweMultiBitBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweMultiBitBootstrapKeyProto,
+        ) -> LweMultiBitBootstrapKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            entity: LweMultiBitBootstrapKey32,
+        ) -> Self::LweMultiBitBootstrapKeyProto {
+            ProtoBinaryBinaryLweMultiBitBootstrapKey32(entity)
+        }
+
+        fn destroy_lwe_multi_bit_bootstrap_key(&mut self, _entity: LweMultiBitBootstrapKey32) {}
+    }
+
+    impl
+        SynthesizesLweMultiBitBootstrapKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweMultiBitBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweMultiBitBootstrapKeyProto,
+        ) -> LweMultiBitBootstrapKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            entity: LweMultiBitBootstrapKey64,
+        ) -> Self::LweMultiBitBootstrapKeyProto {
+            ProtoBinaryBinaryLweMultiBitBootstrapKey64(entity)
+        }
+
+        fn destroy_lwe_multi_bit_bootstrap_key(&mut self, _entity: LweMultiBitBootstrapKey64) {}
+    }
+}
+
+#[cfg(any(feature = "backend_fft", feature = "backend_fft_parallel"))]
+mod backend_fft {
+    use crate::generation::synthesizing::SynthesizesLweMultiBitBootstrapKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        FftFourierLweMultiBitBootstrapKey32, FftFourierLweMultiBitBootstrapKey64,
+        LweMultiBitBootstrapKeyConversionEngine,
+    };
+
+    impl
+        SynthesizesLweMultiBitBootstrapKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierLweMultiBitBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweMultiBitBootstrapKeyProto,
+        ) -> FftFourierLweMultiBitBootstrapKey32 {
+            self.fft_engine
+                .convert_lwe_multi_bit_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweMultiBitBootstrapKey32,
+        ) -> Self::LweMultiBitBootstrapKeyProto {
+            unimplemented!("The backward fourier conversion was not yet implemented");
+        }
+
+        fn destroy_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweMultiBitBootstrapKey32,
+        ) {
+        }
+    }
+
+    impl
+        SynthesizesLweMultiBitBootstrapKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierLweMultiBitBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweMultiBitBootstrapKeyProto,
+        ) -> FftFourierLweMultiBitBootstrapKey64 {
+            self.fft_engine
+                .convert_lwe_multi_bit_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweMultiBitBootstrapKey64,
+        ) -> Self::LweMultiBitBootstrapKeyProto {
+            unimplemented!("The backward fourier conversion was not yet implemented");
+        }
+
+        fn destroy_lwe_multi_bit_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweMultiBitBootstrapKey64,
+        ) {
+        }
+    }
+}
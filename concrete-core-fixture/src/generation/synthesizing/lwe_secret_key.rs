@@ -16,10 +16,17 @@ pub trait SynthesizesLweSecretKey<
 }
 
 mod backend_default {
-    use crate::generation::prototypes::{ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64};
+    use crate::generation::prototypes::{
+        ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64, ProtoTernaryLweSecretKey32,
+        ProtoTernaryLweSecretKey64,
+    };
     use crate::generation::synthesizing::SynthesizesLweSecretKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-    use concrete_core::prelude::{LweSecretKey32, LweSecretKey64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+    };
+    use concrete_core::prelude::{
+        LweSecretKey32, LweSecretKey64, LweTernarySecretKey32, LweTernarySecretKey64,
+    };
 
     impl SynthesizesLweSecretKey<Precision32, BinaryKeyDistribution, LweSecretKey32> for Maker {
         fn synthesize_lwe_secret_key(
@@ -56,4 +63,40 @@ mod backend_default {
 
         fn destroy_lwe_secret_key(&mut self, _entity: LweSecretKey64) {}
     }
+
+    impl SynthesizesLweSecretKey<Precision32, TernaryKeyDistribution, LweTernarySecretKey32> for Maker {
+        fn synthesize_lwe_secret_key(
+            &mut self,
+            prototype: &Self::LweSecretKeyProto,
+        ) -> LweTernarySecretKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_secret_key(
+            &mut self,
+            entity: LweTernarySecretKey32,
+        ) -> Self::LweSecretKeyProto {
+            ProtoTernaryLweSecretKey32(entity)
+        }
+
+        fn destroy_lwe_secret_key(&mut self, _entity: LweTernarySecretKey32) {}
+    }
+
+    impl SynthesizesLweSecretKey<Precision64, TernaryKeyDistribution, LweTernarySecretKey64> for Maker {
+        fn synthesize_lwe_secret_key(
+            &mut self,
+            prototype: &Self::LweSecretKeyProto,
+        ) -> LweTernarySecretKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_secret_key(
+            &mut self,
+            entity: LweTernarySecretKey64,
+        ) -> Self::LweSecretKeyProto {
+            ProtoTernaryLweSecretKey64(entity)
+        }
+
+        fn destroy_lwe_secret_key(&mut self, _entity: LweTernarySecretKey64) {}
+    }
 }
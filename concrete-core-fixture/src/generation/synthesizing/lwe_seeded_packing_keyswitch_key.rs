@@ -0,0 +1,91 @@
+use crate::generation::prototyping::PrototypesLweSeededPackingKeyswitchKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::LweSeededPackingKeyswitchKeyEntity;
+
+pub trait SynthesizesLweSeededPackingKeyswitchKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    LweSeededPackingKeyswitchKey,
+>:
+    PrototypesLweSeededPackingKeyswitchKey<Precision, InputKeyDistribution, OutputKeyDistribution> where
+    LweSeededPackingKeyswitchKey: LweSeededPackingKeyswitchKeyEntity,
+{
+    fn synthesize_lwe_seeded_packing_keyswitch_key(
+        &mut self,
+        prototype: &Self::LweSeededPackingKeyswitchKeyProto,
+    ) -> LweSeededPackingKeyswitchKey;
+    fn unsynthesize_lwe_seeded_packing_keyswitch_key(
+        &mut self,
+        entity: LweSeededPackingKeyswitchKey,
+    ) -> Self::LweSeededPackingKeyswitchKeyProto;
+    fn destroy_lwe_seeded_packing_keyswitch_key(&mut self, entity: LweSeededPackingKeyswitchKey);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweSeededPackingKeyswitchKey32,
+        ProtoBinaryBinaryLweSeededPackingKeyswitchKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweSeededPackingKeyswitchKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{LweSeededPackingKeyswitchKey32, LweSeededPackingKeyswitchKey64};
+
+    impl
+        SynthesizesLweSeededPackingKeyswitchKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweSeededPackingKeyswitchKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_seeded_packing_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweSeededPackingKeyswitchKeyProto,
+        ) -> LweSeededPackingKeyswitchKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_packing_keyswitch_key(
+            &mut self,
+            entity: LweSeededPackingKeyswitchKey32,
+        ) -> Self::LweSeededPackingKeyswitchKeyProto {
+            ProtoBinaryBinaryLweSeededPackingKeyswitchKey32(entity)
+        }
+
+        fn destroy_lwe_seeded_packing_keyswitch_key(
+            &mut self,
+            _entity: LweSeededPackingKeyswitchKey32,
+        ) {
+        }
+    }
+
+    impl
+        SynthesizesLweSeededPackingKeyswitchKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweSeededPackingKeyswitchKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_seeded_packing_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweSeededPackingKeyswitchKeyProto,
+        ) -> LweSeededPackingKeyswitchKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_packing_keyswitch_key(
+            &mut self,
+            entity: LweSeededPackingKeyswitchKey64,
+        ) -> Self::LweSeededPackingKeyswitchKeyProto {
+            ProtoBinaryBinaryLweSeededPackingKeyswitchKey64(entity)
+        }
+
+        fn destroy_lwe_seeded_packing_keyswitch_key(
+            &mut self,
+            _entity: LweSeededPackingKeyswitchKey64,
+        ) {
+        }
+    }
+}
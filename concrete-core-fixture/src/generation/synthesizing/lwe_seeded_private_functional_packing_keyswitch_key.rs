@@ -0,0 +1,102 @@
+use crate::generation::prototyping::PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::LweSeededPrivateFunctionalPackingKeyswitchKeyEntity;
+
+pub trait SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    LweSeededPrivateFunctionalPackingKeyswitchKey,
+>:
+    PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey<
+    Precision,
+    InputKeyDistribution,
+    OutputKeyDistribution,
+> where
+    LweSeededPrivateFunctionalPackingKeyswitchKey:
+        LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+{
+    fn synthesize_lwe_seeded_private_functional_packing_keyswitch_key(
+        &mut self,
+        prototype: &Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto,
+    ) -> LweSeededPrivateFunctionalPackingKeyswitchKey;
+    fn unsynthesize_lwe_seeded_private_functional_packing_keyswitch_key(
+        &mut self,
+        entity: LweSeededPrivateFunctionalPackingKeyswitchKey,
+    ) -> Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto;
+    fn destroy_lwe_seeded_private_functional_packing_keyswitch_key(
+        &mut self,
+        entity: LweSeededPrivateFunctionalPackingKeyswitchKey,
+    );
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey32,
+        ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        LweSeededPrivateFunctionalPackingKeyswitchKey32,
+        LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    };
+
+    impl
+        SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweSeededPrivateFunctionalPackingKeyswitchKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_seeded_private_functional_packing_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto,
+        ) -> LweSeededPrivateFunctionalPackingKeyswitchKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_private_functional_packing_keyswitch_key(
+            &mut self,
+            entity: LweSeededPrivateFunctionalPackingKeyswitchKey32,
+        ) -> Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto {
+            ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey32(entity)
+        }
+
+        fn destroy_lwe_seeded_private_functional_packing_keyswitch_key(
+            &mut self,
+            _entity: LweSeededPrivateFunctionalPackingKeyswitchKey32,
+        ) {
+        }
+    }
+
+    impl
+        SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweSeededPrivateFunctionalPackingKeyswitchKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_seeded_private_functional_packing_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto,
+        ) -> LweSeededPrivateFunctionalPackingKeyswitchKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_private_functional_packing_keyswitch_key(
+            &mut self,
+            entity: LweSeededPrivateFunctionalPackingKeyswitchKey64,
+        ) -> Self::LweSeededPrivateFunctionalPackingKeyswitchKeyProto {
+            ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey64(entity)
+        }
+
+        fn destroy_lwe_seeded_private_functional_packing_keyswitch_key(
+            &mut self,
+            _entity: LweSeededPrivateFunctionalPackingKeyswitchKey64,
+        ) {
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::generation::prototyping::PrototypesLweSeededPublicKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::LweSeededPublicKeyEntity;
+
+/// A trait allowing to synthesize an actual seeded lwe public key entity from a prototype.
+pub trait SynthesizesLweSeededPublicKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    LweSeededPublicKey,
+>: PrototypesLweSeededPublicKey<Precision, KeyDistribution> where
+    LweSeededPublicKey: LweSeededPublicKeyEntity,
+{
+    fn synthesize_lwe_seeded_public_key(
+        &mut self,
+        prototype: &Self::LweSeededPublicKeyProto,
+    ) -> LweSeededPublicKey;
+    fn unsynthesize_lwe_seeded_public_key(
+        &mut self,
+        entity: LweSeededPublicKey,
+    ) -> Self::LweSeededPublicKeyProto;
+    fn destroy_lwe_seeded_public_key(&mut self, entity: LweSeededPublicKey);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryLweSeededPublicKey32, ProtoBinaryLweSeededPublicKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweSeededPublicKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{LweSeededPublicKey32, LweSeededPublicKey64};
+
+    impl SynthesizesLweSeededPublicKey<Precision32, BinaryKeyDistribution, LweSeededPublicKey32>
+        for Maker
+    {
+        fn synthesize_lwe_seeded_public_key(
+            &mut self,
+            prototype: &Self::LweSeededPublicKeyProto,
+        ) -> LweSeededPublicKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_public_key(
+            &mut self,
+            entity: LweSeededPublicKey32,
+        ) -> Self::LweSeededPublicKeyProto {
+            ProtoBinaryLweSeededPublicKey32(entity)
+        }
+
+        fn destroy_lwe_seeded_public_key(&mut self, _entity: LweSeededPublicKey32) {}
+    }
+
+    impl SynthesizesLweSeededPublicKey<Precision64, BinaryKeyDistribution, LweSeededPublicKey64>
+        for Maker
+    {
+        fn synthesize_lwe_seeded_public_key(
+            &mut self,
+            prototype: &Self::LweSeededPublicKeyProto,
+        ) -> LweSeededPublicKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_public_key(
+            &mut self,
+            entity: LweSeededPublicKey64,
+        ) -> Self::LweSeededPublicKeyProto {
+            ProtoBinaryLweSeededPublicKey64(entity)
+        }
+
+        fn destroy_lwe_seeded_public_key(&mut self, _entity: LweSeededPublicKey64) {}
+    }
+}
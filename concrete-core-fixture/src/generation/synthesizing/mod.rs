@@ -9,9 +9,12 @@ mod container;
 mod ggsw_ciphertext;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_keyswitch_key;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
 mod glwe_seeded_ciphertext;
 mod glwe_seeded_ciphertext_vector;
+mod gsw_ciphertext;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
@@ -19,14 +22,20 @@ mod lwe_ciphertext_vector_glwe_ciphertext_packing_keyswitch_key;
 mod lwe_ciphertext_vector_glwe_ciphertext_private_functional_packing_keyswitch_key;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys;
 mod lwe_keyswitch_key;
+mod lwe_multi_bit_bootstrap_key;
 mod lwe_public_key;
 mod lwe_secret_key;
 mod lwe_seeded_bootstrap_key;
 mod lwe_seeded_ciphertext;
 mod lwe_seeded_ciphertext_vector;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys;
 mod lwe_seeded_keyswitch_key;
+mod lwe_seeded_packing_keyswitch_key;
+mod lwe_seeded_private_functional_packing_keyswitch_key;
+mod lwe_seeded_public_key;
 mod plaintext;
 mod plaintext_vector;
+mod serializable_entity;
 
 pub use cleartext::*;
 pub use cleartext_vector::*;
@@ -34,9 +43,12 @@ pub use container::*;
 pub use ggsw_ciphertext::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_keyswitch_key::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use glwe_seeded_ciphertext::*;
 pub use glwe_seeded_ciphertext_vector::*;
+pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
@@ -44,11 +56,17 @@ pub use lwe_ciphertext_vector_glwe_ciphertext_packing_keyswitch_key::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_private_functional_packing_keyswitch_key::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
 pub use lwe_keyswitch_key::*;
+pub use lwe_multi_bit_bootstrap_key::*;
 pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
 pub use lwe_seeded_bootstrap_key::*;
 pub use lwe_seeded_ciphertext::*;
 pub use lwe_seeded_ciphertext_vector::*;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
 pub use lwe_seeded_keyswitch_key::*;
+pub use lwe_seeded_packing_keyswitch_key::*;
+pub use lwe_seeded_private_functional_packing_keyswitch_key::*;
+pub use lwe_seeded_public_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
+pub use serializable_entity::*;
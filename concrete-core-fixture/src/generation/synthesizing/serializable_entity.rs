@@ -0,0 +1,1528 @@
+use crate::generation::IntegerPrecision;
+use concrete_core::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    Variance,
+};
+
+/// The parameters used to generate an entity which goes through a serialization round trip.
+#[derive(Debug, Clone, Copy)]
+pub struct SerializableEntityParameters {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    /// The number of elements of vector entities, and of encryptions of zero of public keys.
+    pub count: usize,
+    pub noise: Variance,
+}
+
+/// A trait allowing to synthesize an actual serializable entity, and to turn it into an outcome
+/// which can be compared before and after a serialization round trip.
+///
+/// The prototype holds the entity along with the data needed to check it, e.g. the secret key
+/// used to decrypt a ciphertext, or a ciphertext encrypted under a secret key. An entity and the
+/// entity it deserializes to share the same prototype and outcome types, which makes it possible
+/// to check that views deserialize to equivalent owned entities.
+pub trait SynthesizesSerializableEntity<Precision: IntegerPrecision, Entity> {
+    type SerializableEntityProto;
+    type SerializableEntityOutcome: PartialEq;
+
+    fn new_serializable_entity(
+        &mut self,
+        parameters: &SerializableEntityParameters,
+    ) -> Self::SerializableEntityProto;
+    fn synthesize_serializable_entity(
+        &mut self,
+        prototype: &Self::SerializableEntityProto,
+    ) -> Entity;
+    fn unsynthesize_serializable_entity(
+        &mut self,
+        prototype: &Self::SerializableEntityProto,
+        entity: Entity,
+    ) -> Self::SerializableEntityOutcome;
+}
+
+// Implements the trait for an entity which is only compared structurally after a round trip.
+#[cfg(any(
+    feature = "backend_default_serialization",
+    feature = "backend_fft_serialization"
+))]
+macro_rules! impl_structural {
+    ($precision:ident, $entity:ty, |$maker:ident, $parameters:ident| $new:expr) => {
+        impl SynthesizesSerializableEntity<$precision, $entity> for Maker {
+            type SerializableEntityProto = $entity;
+            type SerializableEntityOutcome = $entity;
+
+            fn new_serializable_entity(
+                &mut self,
+                parameters: &SerializableEntityParameters,
+            ) -> $entity {
+                let ($maker, $parameters) = (self, parameters);
+                $new
+            }
+
+            fn synthesize_serializable_entity(&mut self, prototype: &$entity) -> $entity {
+                prototype.clone()
+            }
+
+            fn unsynthesize_serializable_entity(
+                &mut self,
+                _prototype: &$entity,
+                entity: $entity,
+            ) -> $entity {
+                entity
+            }
+        }
+    };
+}
+
+// Implements the trait for an entity whose decryptions are compared after a round trip. The
+// prototype holds the entity along with a companion used for decryption, that is a secret key
+// for ciphertexts, a ciphertext for secret keys, and the inputs of an operation using the key
+// along with the secret key decrypting its output for keys.
+#[cfg(any(
+    feature = "backend_default_serialization",
+    feature = "backend_fft_serialization"
+))]
+macro_rules! impl_decryptable {
+    (
+        $precision:ident,
+        $entity:ty,
+        $companion:ty,
+        |$maker:ident, $parameters:ident| $new:expr,
+        |$decryption_maker:ident, $decryption_companion:ident, $decryption_entity:ident|
+            $decrypt:expr
+    ) => {
+        impl SynthesizesSerializableEntity<$precision, $entity> for Maker {
+            type SerializableEntityProto = ($companion, $entity);
+            type SerializableEntityOutcome = (
+                $entity,
+                Vec<<$precision as crate::generation::IntegerPrecision>::Raw>,
+            );
+
+            fn new_serializable_entity(
+                &mut self,
+                parameters: &SerializableEntityParameters,
+            ) -> Self::SerializableEntityProto {
+                let ($maker, $parameters) = (self, parameters);
+                $new
+            }
+
+            fn synthesize_serializable_entity(
+                &mut self,
+                prototype: &Self::SerializableEntityProto,
+            ) -> $entity {
+                prototype.1.clone()
+            }
+
+            fn unsynthesize_serializable_entity(
+                &mut self,
+                prototype: &Self::SerializableEntityProto,
+                entity: $entity,
+            ) -> Self::SerializableEntityOutcome {
+                let decrypted = {
+                    let ($decryption_maker, $decryption_companion, $decryption_entity) =
+                        (&mut *self, &prototype.0, &entity);
+                    $decrypt
+                };
+                (entity, decrypted)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "backend_default_serialization")]
+mod backend_default {
+    use crate::generation::synthesizing::{
+        SerializableEntityParameters, SynthesizesSerializableEntity,
+    };
+    use crate::generation::{Maker, Precision32, Precision64};
+    use crate::raw::generation::RawUnsignedIntegers;
+    use concrete_core::commons::crypto::encoding::Plaintext as ImplPlaintext;
+    use concrete_core::commons::crypto::ggsw::StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext;
+    use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    use concrete_core::commons::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+    use concrete_core::commons::math::random::CompressionSeed;
+    use concrete_core::prelude::*;
+    use concrete_csprng::generators::SoftwareRandomGenerator;
+    use concrete_csprng::seeders::{Seeder, UnixSeeder};
+    use paste::paste;
+
+    // Implements the trait for a view, by leaking the container of an owned entity generated
+    // with the same prototype. The container is freed when the view is unsynthesized.
+    macro_rules! impl_view {
+        (
+            $precision:ident,
+            $owned:ty,
+            $view:ident,
+            $container:ty,
+            $raw:ty,
+            $consume:ident,
+            $create:ident,
+            |$entity:ident| ($($dimension:ident = $value:expr),*)
+        ) => {
+            impl<'a> SynthesizesSerializableEntity<$precision, $view<'a>> for Maker {
+                type SerializableEntityProto =
+                    <Maker as SynthesizesSerializableEntity<$precision, $owned>>::SerializableEntityProto;
+                type SerializableEntityOutcome =
+                    <Maker as SynthesizesSerializableEntity<$precision, $owned>>::SerializableEntityOutcome;
+
+                fn new_serializable_entity(
+                    &mut self,
+                    parameters: &SerializableEntityParameters,
+                ) -> Self::SerializableEntityProto {
+                    <Maker as SynthesizesSerializableEntity<$precision, $owned>>::new_serializable_entity(
+                        self, parameters,
+                    )
+                }
+
+                fn synthesize_serializable_entity(
+                    &mut self,
+                    prototype: &Self::SerializableEntityProto,
+                ) -> $view<'a> {
+                    let owned: $owned = self.synthesize_serializable_entity(prototype);
+                    $(let $dimension = { let $entity = &owned; $value };)*
+                    let container = self.default_engine.$consume(owned).unwrap();
+                    self.default_engine
+                        .$create(container.leak() as $container, $($dimension),*)
+                        .unwrap()
+                }
+
+                fn unsynthesize_serializable_entity(
+                    &mut self,
+                    prototype: &Self::SerializableEntityProto,
+                    entity: $view<'a>,
+                ) -> Self::SerializableEntityOutcome {
+                    $(let $dimension = { let $entity = &entity; $value };)*
+                    let slice = self.default_engine.$consume(entity).unwrap();
+                    // Re-construct the vector so that it frees memory when it's dropped
+                    let container = unsafe {
+                        Vec::from_raw_parts(slice.as_ptr() as *mut $raw, slice.len(), slice.len())
+                    };
+                    let owned: $owned = self
+                        .default_engine
+                        .$create(container, $($dimension),*)
+                        .unwrap();
+                    self.unsynthesize_serializable_entity(prototype, owned)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_precision {
+        ($precision:ident, $raw:ident, $bits:literal) => {
+            paste! {
+                impl_structural!($precision, [<Cleartext $bits>], |maker, _parameters| {
+                    maker
+                        .default_engine
+                        .create_cleartext_from(&$raw::uniform())
+                        .unwrap()
+                });
+
+                impl_structural!($precision, [<CleartextVector $bits>], |maker, parameters| {
+                    maker
+                        .default_engine
+                        .create_cleartext_vector_from(&$raw::uniform_vec(parameters.count))
+                        .unwrap()
+                });
+
+                impl_structural!($precision, [<Plaintext $bits>], |maker, _parameters| {
+                    maker
+                        .default_engine
+                        .create_plaintext_from(&$raw::uniform())
+                        .unwrap()
+                });
+
+                impl_structural!($precision, [<PlaintextVector $bits>], |maker, parameters| {
+                    maker
+                        .default_engine
+                        .create_plaintext_vector_from(&$raw::uniform_vec(parameters.count))
+                        .unwrap()
+                });
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweSecretKey $bits>],
+                    [<LweCiphertext $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let plaintext = engine.create_plaintext_from(&$raw::uniform()).unwrap();
+                        let ciphertext = engine
+                            .encrypt_lwe_ciphertext(&key, &plaintext, parameters.noise)
+                            .unwrap();
+                        (ciphertext, key)
+                    },
+                    |maker, ciphertext, key| {
+                        let engine = &mut maker.default_engine;
+                        let plaintext = engine.decrypt_lwe_ciphertext(key, ciphertext).unwrap();
+                        vec![engine.retrieve_plaintext(&plaintext).unwrap()]
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<GlweSecretKey $bits>],
+                    [<GlweCiphertext $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        let plaintext_vector = engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(
+                                parameters.polynomial_size.0,
+                            ))
+                            .unwrap();
+                        let ciphertext = engine
+                            .encrypt_glwe_ciphertext(&key, &plaintext_vector, parameters.noise)
+                            .unwrap();
+                        (ciphertext, key)
+                    },
+                    |maker, ciphertext, key| {
+                        let engine = &mut maker.default_engine;
+                        let plaintext_vector =
+                            engine.decrypt_glwe_ciphertext(key, ciphertext).unwrap();
+                        engine.retrieve_plaintext_vector(&plaintext_vector).unwrap()
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweCiphertext $bits>],
+                    [<LweSecretKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let plaintext = engine.create_plaintext_from(&$raw::uniform()).unwrap();
+                        let ciphertext = engine
+                            .encrypt_lwe_ciphertext(&key, &plaintext, parameters.noise)
+                            .unwrap();
+                        (key, ciphertext)
+                    },
+                    |maker, key, ciphertext| {
+                        let engine = &mut maker.default_engine;
+                        let plaintext = engine.decrypt_lwe_ciphertext(key, ciphertext).unwrap();
+                        vec![engine.retrieve_plaintext(&plaintext).unwrap()]
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweCiphertextVector $bits>],
+                    [<LweSecretKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let plaintext_vector = engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(parameters.count))
+                            .unwrap();
+                        let ciphertext_vector = engine
+                            .encrypt_lwe_ciphertext_vector(
+                                &key,
+                                &plaintext_vector,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (key, ciphertext_vector)
+                    },
+                    |maker, key, ciphertext_vector| {
+                        let engine = &mut maker.default_engine;
+                        let plaintext_vector = engine
+                            .decrypt_lwe_ciphertext_vector(key, ciphertext_vector)
+                            .unwrap();
+                        engine.retrieve_plaintext_vector(&plaintext_vector).unwrap()
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<GlweCiphertext $bits>],
+                    [<GlweSecretKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        let plaintext_vector = engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(
+                                parameters.polynomial_size.0,
+                            ))
+                            .unwrap();
+                        let ciphertext = engine
+                            .encrypt_glwe_ciphertext(&key, &plaintext_vector, parameters.noise)
+                            .unwrap();
+                        (key, ciphertext)
+                    },
+                    |maker, key, ciphertext| {
+                        let engine = &mut maker.default_engine;
+                        let plaintext_vector =
+                            engine.decrypt_glwe_ciphertext(key, ciphertext).unwrap();
+                        engine.retrieve_plaintext_vector(&plaintext_vector).unwrap()
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<GlweCiphertextVector $bits>],
+                    [<GlweSecretKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        let plaintext_vector = engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(
+                                parameters.polynomial_size.0 * parameters.count,
+                            ))
+                            .unwrap();
+                        let ciphertext_vector = engine
+                            .encrypt_glwe_ciphertext_vector(
+                                &key,
+                                &plaintext_vector,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (key, ciphertext_vector)
+                    },
+                    |maker, key, ciphertext_vector| {
+                        let engine = &mut maker.default_engine;
+                        let plaintext_vector = engine
+                            .decrypt_glwe_ciphertext_vector(key, ciphertext_vector)
+                            .unwrap();
+                        engine.retrieve_plaintext_vector(&plaintext_vector).unwrap()
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweSeededCiphertext $bits>],
+                    [<LweSecretKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let plaintext = engine.create_plaintext_from(&$raw::uniform()).unwrap();
+                        let ciphertext = engine
+                            .encrypt_lwe_seeded_ciphertext(&key, &plaintext, parameters.noise)
+                            .unwrap();
+                        (key, ciphertext)
+                    },
+                    |maker, key, seeded_ciphertext| {
+                        let engine = &mut maker.default_engine;
+                        let ciphertext: [<LweCiphertext $bits>] = engine
+                            .transform_lwe_seeded_ciphertext_to_lwe_ciphertext(
+                                seeded_ciphertext.clone(),
+                            )
+                            .unwrap();
+                        let plaintext = engine.decrypt_lwe_ciphertext(key, &ciphertext).unwrap();
+                        vec![engine.retrieve_plaintext(&plaintext).unwrap()]
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweSeededCiphertextVector $bits>],
+                    [<LweSecretKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let plaintext_vector = engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(parameters.count))
+                            .unwrap();
+                        let ciphertext_vector = engine
+                            .encrypt_lwe_seeded_ciphertext_vector(
+                                &key,
+                                &plaintext_vector,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (key, ciphertext_vector)
+                    },
+                    |maker, key, seeded_ciphertext_vector| {
+                        let engine = &mut maker.default_engine;
+                        let ciphertext_vector: [<LweCiphertextVector $bits>] = engine
+                            .transform_lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector(
+                                seeded_ciphertext_vector.clone(),
+                            )
+                            .unwrap();
+                        let plaintext_vector = engine
+                            .decrypt_lwe_ciphertext_vector(key, &ciphertext_vector)
+                            .unwrap();
+                        engine.retrieve_plaintext_vector(&plaintext_vector).unwrap()
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<GlweSeededCiphertext $bits>],
+                    [<GlweSecretKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        let plaintext_vector = engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(
+                                parameters.polynomial_size.0,
+                            ))
+                            .unwrap();
+                        let ciphertext = engine
+                            .encrypt_glwe_seeded_ciphertext(
+                                &key,
+                                &plaintext_vector,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (key, ciphertext)
+                    },
+                    |maker, key, seeded_ciphertext| {
+                        let engine = &mut maker.default_engine;
+                        let ciphertext: [<GlweCiphertext $bits>] = engine
+                            .transform_glwe_seeded_ciphertext_to_glwe_ciphertext(
+                                seeded_ciphertext.clone(),
+                            )
+                            .unwrap();
+                        let plaintext_vector =
+                            engine.decrypt_glwe_ciphertext(key, &ciphertext).unwrap();
+                        engine.retrieve_plaintext_vector(&plaintext_vector).unwrap()
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<GlweSeededCiphertextVector $bits>],
+                    [<GlweSecretKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        let plaintext_vector = engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(
+                                parameters.polynomial_size.0 * parameters.count,
+                            ))
+                            .unwrap();
+                        let ciphertext_vector = engine
+                            .encrypt_glwe_seeded_ciphertext_vector(
+                                &key,
+                                &plaintext_vector,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (key, ciphertext_vector)
+                    },
+                    |maker, key, seeded_ciphertext_vector| {
+                        let engine = &mut maker.default_engine;
+                        let ciphertext_vector: [<GlweCiphertextVector $bits>] = engine
+                            .transform_glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector(
+                                seeded_ciphertext_vector.clone(),
+                            )
+                            .unwrap();
+                        let plaintext_vector = engine
+                            .decrypt_glwe_ciphertext_vector(key, &ciphertext_vector)
+                            .unwrap();
+                        engine.retrieve_plaintext_vector(&plaintext_vector).unwrap()
+                    }
+                );
+
+                impl_view!(
+                    $precision,
+                    [<LweCiphertext $bits>],
+                    [<LweCiphertextView $bits>],
+                    &'a [$raw],
+                    $raw,
+                    consume_retrieve_lwe_ciphertext,
+                    create_lwe_ciphertext_from,
+                    |_ciphertext| ()
+                );
+
+                impl_view!(
+                    $precision,
+                    [<LweCiphertext $bits>],
+                    [<LweCiphertextMutView $bits>],
+                    &'a mut [$raw],
+                    $raw,
+                    consume_retrieve_lwe_ciphertext,
+                    create_lwe_ciphertext_from,
+                    |_ciphertext| ()
+                );
+
+                impl_view!(
+                    $precision,
+                    [<LweCiphertextVector $bits>],
+                    [<LweCiphertextVectorView $bits>],
+                    &'a [$raw],
+                    $raw,
+                    consume_retrieve_lwe_ciphertext_vector,
+                    create_lwe_ciphertext_vector_from,
+                    |ciphertext_vector| (lwe_size = ciphertext_vector.lwe_dimension().to_lwe_size())
+                );
+
+                impl_view!(
+                    $precision,
+                    [<LweCiphertextVector $bits>],
+                    [<LweCiphertextVectorMutView $bits>],
+                    &'a mut [$raw],
+                    $raw,
+                    consume_retrieve_lwe_ciphertext_vector,
+                    create_lwe_ciphertext_vector_from,
+                    |ciphertext_vector| (lwe_size = ciphertext_vector.lwe_dimension().to_lwe_size())
+                );
+
+                impl_view!(
+                    $precision,
+                    [<GlweCiphertext $bits>],
+                    [<GlweCiphertextView $bits>],
+                    &'a [$raw],
+                    $raw,
+                    consume_retrieve_glwe_ciphertext,
+                    create_glwe_ciphertext_from,
+                    |ciphertext| (polynomial_size = ciphertext.polynomial_size())
+                );
+
+                impl_view!(
+                    $precision,
+                    [<GlweCiphertext $bits>],
+                    [<GlweCiphertextMutView $bits>],
+                    &'a mut [$raw],
+                    $raw,
+                    consume_retrieve_glwe_ciphertext,
+                    create_glwe_ciphertext_from,
+                    |ciphertext| (polynomial_size = ciphertext.polynomial_size())
+                );
+
+                impl_view!(
+                    $precision,
+                    [<GlweCiphertextVector $bits>],
+                    [<GlweCiphertextVectorView $bits>],
+                    &'a [$raw],
+                    $raw,
+                    consume_retrieve_glwe_ciphertext_vector,
+                    create_glwe_ciphertext_vector_from,
+                    |ciphertext_vector| (
+                        glwe_dimension = ciphertext_vector.glwe_dimension(),
+                        polynomial_size = ciphertext_vector.polynomial_size()
+                    )
+                );
+
+                impl_view!(
+                    $precision,
+                    [<GlweCiphertextVector $bits>],
+                    [<GlweCiphertextVectorMutView $bits>],
+                    &'a mut [$raw],
+                    $raw,
+                    consume_retrieve_glwe_ciphertext_vector,
+                    create_glwe_ciphertext_vector_from,
+                    |ciphertext_vector| (
+                        glwe_dimension = ciphertext_vector.glwe_dimension(),
+                        polynomial_size = ciphertext_vector.polynomial_size()
+                    )
+                );
+
+                impl_structural!($precision, [<GgswCiphertext $bits>], |maker, parameters| {
+                    let engine = &mut maker.default_engine;
+                    let key: [<GlweSecretKey $bits>] = engine
+                        .generate_new_glwe_secret_key(
+                            parameters.glwe_dimension,
+                            parameters.polynomial_size,
+                        )
+                        .unwrap();
+                    let plaintext = engine.create_plaintext_from(&$raw::uniform()).unwrap();
+                    engine
+                        .encrypt_scalar_ggsw_ciphertext(
+                            &key,
+                            &plaintext,
+                            parameters.noise,
+                            parameters.decomposition_level_count,
+                            parameters.decomposition_base_log,
+                        )
+                        .unwrap()
+                });
+
+                impl_structural!($precision, [<GgswCiphertextVector $bits>], |maker, parameters| {
+                    let engine = &mut maker.default_engine;
+                    let key: [<GlweSecretKey $bits>] = engine
+                        .generate_new_glwe_secret_key(
+                            parameters.glwe_dimension,
+                            parameters.polynomial_size,
+                        )
+                        .unwrap();
+                    let plaintext_vector = engine
+                        .create_plaintext_vector_from(&$raw::uniform_vec(parameters.count))
+                        .unwrap();
+                    engine
+                        .encrypt_scalar_ggsw_ciphertext_vector(
+                            &key,
+                            &plaintext_vector,
+                            parameters.noise,
+                            parameters.decomposition_level_count,
+                            parameters.decomposition_base_log,
+                        )
+                        .unwrap()
+                });
+
+                // No engine encrypts seeded GGSW ciphertexts, so the ciphertext is encrypted with
+                // the primitives of the commons, and turned into an entity by deserializing it in
+                // the layout of the first version, which is read without header.
+                impl_structural!($precision, [<GgswSeededCiphertext $bits>], |_maker, parameters| {
+                    let mut seeder = UnixSeeder::new(0);
+                    let key = ImplGlweSecretKey::generate_binary(
+                        parameters.glwe_dimension,
+                        parameters.polynomial_size,
+                        &mut SecretRandomGenerator::<SoftwareRandomGenerator>::new(seeder.seed()),
+                    );
+                    let mut ciphertext = ImplStandardGgswSeededCiphertext::allocate(
+                        parameters.polynomial_size,
+                        parameters.glwe_dimension.to_glwe_size(),
+                        parameters.decomposition_level_count,
+                        parameters.decomposition_base_log,
+                        CompressionSeed {
+                            seed: seeder.seed(),
+                        },
+                    );
+                    key.encrypt_constant_seeded_ggsw::<_, _, _, _, SoftwareRandomGenerator>(
+                        &mut ciphertext,
+                        &ImplPlaintext($raw::uniform()),
+                        parameters.noise,
+                        &mut seeder,
+                    );
+                    let serialized = bincode::serialize(&(0_u32, &ciphertext)).unwrap();
+                    DefaultSerializationEngine::new(())
+                        .unwrap()
+                        .deserialize(serialized.as_slice())
+                        .unwrap()
+                });
+
+                // The keys are checked by using them after the round trip: the keyswitch and
+                // bootstrap operations are deterministic, and the public key encryptions are
+                // decoded, so the decryptions obtained with the original and the deserialized keys
+                // must match.
+                fn [<keyswitch_and_decrypt_ $bits>](
+                    maker: &mut Maker,
+                    companion: &([<LweCiphertext $bits>], [<LweSecretKey $bits>]),
+                    keyswitch_key: &[<LweKeyswitchKey $bits>],
+                ) -> Vec<$raw> {
+                    let (input, output_key) = companion;
+                    let engine = &mut maker.default_engine;
+                    let mut output: [<LweCiphertext $bits>] = engine
+                        .create_lwe_ciphertext_from($raw::zero_vec(
+                            output_key.lwe_dimension().to_lwe_size().0,
+                        ))
+                        .unwrap();
+                    engine
+                        .discard_keyswitch_lwe_ciphertext(&mut output, input, keyswitch_key)
+                        .unwrap();
+                    let plaintext = engine.decrypt_lwe_ciphertext(output_key, &output).unwrap();
+                    vec![engine.retrieve_plaintext(&plaintext).unwrap()]
+                }
+
+                fn [<new_keyswitch_companion_ $bits>](
+                    maker: &mut Maker,
+                    parameters: &SerializableEntityParameters,
+                ) -> (
+                    [<LweSecretKey $bits>],
+                    ([<LweCiphertext $bits>], [<LweSecretKey $bits>]),
+                ) {
+                    let engine = &mut maker.default_engine;
+                    let input_key: [<LweSecretKey $bits>] =
+                        engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                    let output_key: [<LweSecretKey $bits>] =
+                        engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                    let plaintext = engine.create_plaintext_from(&$raw::uniform()).unwrap();
+                    let input = engine
+                        .encrypt_lwe_ciphertext(&input_key, &plaintext, parameters.noise)
+                        .unwrap();
+                    (input_key, (input, output_key))
+                }
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweKeyswitchKey $bits>],
+                    ([<LweCiphertext $bits>], [<LweSecretKey $bits>]),
+                    |maker, parameters| {
+                        let (input_key, companion) =
+                            [<new_keyswitch_companion_ $bits>](maker, parameters);
+                        let keyswitch_key = maker
+                            .default_engine
+                            .generate_new_lwe_keyswitch_key(
+                                &input_key,
+                                &companion.1,
+                                parameters.decomposition_level_count,
+                                parameters.decomposition_base_log,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (companion, keyswitch_key)
+                    },
+                    |maker, companion, keyswitch_key| {
+                        [<keyswitch_and_decrypt_ $bits>](maker, companion, keyswitch_key)
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweSeededKeyswitchKey $bits>],
+                    ([<LweCiphertext $bits>], [<LweSecretKey $bits>]),
+                    |maker, parameters| {
+                        let (input_key, companion) =
+                            [<new_keyswitch_companion_ $bits>](maker, parameters);
+                        let seeded_keyswitch_key = maker
+                            .default_engine
+                            .generate_new_lwe_seeded_keyswitch_key(
+                                &input_key,
+                                &companion.1,
+                                parameters.decomposition_level_count,
+                                parameters.decomposition_base_log,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (companion, seeded_keyswitch_key)
+                    },
+                    |maker, companion, seeded_keyswitch_key| {
+                        let keyswitch_key: [<LweKeyswitchKey $bits>] = maker
+                            .default_engine
+                            .transform_lwe_seeded_keyswitch_key_to_lwe_keyswitch_key(
+                                seeded_keyswitch_key.clone(),
+                            )
+                            .unwrap();
+                        [<keyswitch_and_decrypt_ $bits>](maker, companion, &keyswitch_key)
+                    }
+                );
+
+                #[cfg(feature = "backend_fft")]
+                fn [<bootstrap_and_decrypt_ $bits>](
+                    maker: &mut Maker,
+                    companion: &(
+                        [<LweCiphertext $bits>],
+                        [<GlweCiphertext $bits>],
+                        [<LweSecretKey $bits>],
+                    ),
+                    bootstrap_key: &[<LweBootstrapKey $bits>],
+                ) -> Vec<$raw> {
+                    let (input, accumulator, output_key) = companion;
+                    let fourier_bootstrap_key: [<FftFourierLweBootstrapKey $bits>] =
+                        maker.fft_engine.convert_lwe_bootstrap_key(bootstrap_key).unwrap();
+                    let mut output: [<LweCiphertext $bits>] = maker
+                        .default_engine
+                        .create_lwe_ciphertext_from($raw::zero_vec(
+                            output_key.lwe_dimension().to_lwe_size().0,
+                        ))
+                        .unwrap();
+                    maker
+                        .fft_engine
+                        .discard_bootstrap_lwe_ciphertext(
+                            &mut output,
+                            input,
+                            accumulator,
+                            &fourier_bootstrap_key,
+                        )
+                        .unwrap();
+                    let engine = &mut maker.default_engine;
+                    let plaintext = engine.decrypt_lwe_ciphertext(output_key, &output).unwrap();
+                    vec![engine.retrieve_plaintext(&plaintext).unwrap()]
+                }
+
+                #[cfg(feature = "backend_fft")]
+                fn [<new_bootstrap_companion_ $bits>](
+                    maker: &mut Maker,
+                    parameters: &SerializableEntityParameters,
+                ) -> (
+                    ([<LweSecretKey $bits>], [<GlweSecretKey $bits>]),
+                    (
+                        [<LweCiphertext $bits>],
+                        [<GlweCiphertext $bits>],
+                        [<LweSecretKey $bits>],
+                    ),
+                ) {
+                    let engine = &mut maker.default_engine;
+                    let lwe_key: [<LweSecretKey $bits>] =
+                        engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                    let glwe_key: [<GlweSecretKey $bits>] = engine
+                        .generate_new_glwe_secret_key(
+                            parameters.glwe_dimension,
+                            parameters.polynomial_size,
+                        )
+                        .unwrap();
+                    let output_key: [<LweSecretKey $bits>] = engine
+                        .transform_glwe_secret_key_to_lwe_secret_key(glwe_key.clone())
+                        .unwrap();
+                    let plaintext = engine.create_plaintext_from(&$raw::uniform()).unwrap();
+                    let input = engine
+                        .encrypt_lwe_ciphertext(&lwe_key, &plaintext, parameters.noise)
+                        .unwrap();
+                    let lookup_table = engine
+                        .create_plaintext_vector_from(&$raw::uniform_vec(
+                            parameters.polynomial_size.0,
+                        ))
+                        .unwrap();
+                    let accumulator = engine
+                        .trivially_encrypt_glwe_ciphertext(
+                            parameters.glwe_dimension.to_glwe_size(),
+                            &lookup_table,
+                        )
+                        .unwrap();
+                    ((lwe_key, glwe_key), (input, accumulator, output_key))
+                }
+
+                #[cfg(feature = "backend_fft")]
+                impl_decryptable!(
+                    $precision,
+                    [<LweBootstrapKey $bits>],
+                    (
+                        [<LweCiphertext $bits>],
+                        [<GlweCiphertext $bits>],
+                        [<LweSecretKey $bits>],
+                    ),
+                    |maker, parameters| {
+                        let ((lwe_key, glwe_key), companion) =
+                            [<new_bootstrap_companion_ $bits>](maker, parameters);
+                        let bootstrap_key = maker
+                            .default_engine
+                            .generate_new_lwe_bootstrap_key(
+                                &lwe_key,
+                                &glwe_key,
+                                parameters.decomposition_base_log,
+                                parameters.decomposition_level_count,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (companion, bootstrap_key)
+                    },
+                    |maker, companion, bootstrap_key| {
+                        [<bootstrap_and_decrypt_ $bits>](maker, companion, bootstrap_key)
+                    }
+                );
+
+                #[cfg(feature = "backend_fft")]
+                impl_decryptable!(
+                    $precision,
+                    [<LweSeededBootstrapKey $bits>],
+                    (
+                        [<LweCiphertext $bits>],
+                        [<GlweCiphertext $bits>],
+                        [<LweSecretKey $bits>],
+                    ),
+                    |maker, parameters| {
+                        let ((lwe_key, glwe_key), companion) =
+                            [<new_bootstrap_companion_ $bits>](maker, parameters);
+                        let seeded_bootstrap_key = maker
+                            .default_engine
+                            .generate_new_lwe_seeded_bootstrap_key(
+                                &lwe_key,
+                                &glwe_key,
+                                parameters.decomposition_base_log,
+                                parameters.decomposition_level_count,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (companion, seeded_bootstrap_key)
+                    },
+                    |maker, companion, seeded_bootstrap_key| {
+                        let bootstrap_key: [<LweBootstrapKey $bits>] = maker
+                            .default_engine
+                            .transform_lwe_seeded_bootstrap_key_to_lwe_bootstrap_key(
+                                seeded_bootstrap_key.clone(),
+                            )
+                            .unwrap();
+                        [<bootstrap_and_decrypt_ $bits>](maker, companion, &bootstrap_key)
+                    }
+                );
+
+                // Without the fft backend, the bootstrap keys can not be used, and are only
+                // compared structurally.
+                #[cfg(not(feature = "backend_fft"))]
+                impl_structural!($precision, [<LweBootstrapKey $bits>], |maker, parameters| {
+                    let engine = &mut maker.default_engine;
+                    let lwe_key: [<LweSecretKey $bits>] =
+                        engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                    let glwe_key: [<GlweSecretKey $bits>] = engine
+                        .generate_new_glwe_secret_key(
+                            parameters.glwe_dimension,
+                            parameters.polynomial_size,
+                        )
+                        .unwrap();
+                    engine
+                        .generate_new_lwe_bootstrap_key(
+                            &lwe_key,
+                            &glwe_key,
+                            parameters.decomposition_base_log,
+                            parameters.decomposition_level_count,
+                            parameters.noise,
+                        )
+                        .unwrap()
+                });
+
+                #[cfg(not(feature = "backend_fft"))]
+                impl_structural!($precision, [<LweSeededBootstrapKey $bits>], |maker, parameters| {
+                    let engine = &mut maker.default_engine;
+                    let lwe_key: [<LweSecretKey $bits>] =
+                        engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                    let glwe_key: [<GlweSecretKey $bits>] = engine
+                        .generate_new_glwe_secret_key(
+                            parameters.glwe_dimension,
+                            parameters.polynomial_size,
+                        )
+                        .unwrap();
+                    engine
+                        .generate_new_lwe_seeded_bootstrap_key(
+                            &lwe_key,
+                            &glwe_key,
+                            parameters.decomposition_base_log,
+                            parameters.decomposition_level_count,
+                            parameters.noise,
+                        )
+                        .unwrap()
+                });
+
+                fn [<public_key_encrypt_and_decrypt_ $bits>](
+                    maker: &mut Maker,
+                    companion: &([<LweSecretKey $bits>], [<Plaintext $bits>]),
+                    public_key: &[<LwePublicKey $bits>],
+                ) -> Vec<$raw> {
+                    let (key, plaintext) = companion;
+                    let engine = &mut maker.default_engine;
+                    let mut output: [<LweCiphertext $bits>] = engine
+                        .create_lwe_ciphertext_from($raw::zero_vec(
+                            key.lwe_dimension().to_lwe_size().0,
+                        ))
+                        .unwrap();
+                    engine
+                        .discard_encrypt_lwe_ciphertext_with_public_key(
+                            public_key,
+                            &mut output,
+                            plaintext,
+                        )
+                        .unwrap();
+                    let decrypted = engine.decrypt_lwe_ciphertext(key, &output).unwrap();
+                    let decrypted = engine.retrieve_plaintext(&decrypted).unwrap();
+                    // The encryption is randomized, so the message is decoded from the 4 most
+                    // significant bits.
+                    vec![decrypted.wrapping_add(1 << ($bits - 5)) >> ($bits - 4)]
+                }
+
+                fn [<new_public_key_companion_ $bits>](
+                    maker: &mut Maker,
+                    parameters: &SerializableEntityParameters,
+                ) -> ([<LweSecretKey $bits>], [<Plaintext $bits>]) {
+                    let engine = &mut maker.default_engine;
+                    let key: [<LweSecretKey $bits>] =
+                        engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                    let plaintext = engine
+                        .create_plaintext_from(&($raw::uniform_n_msb(4)))
+                        .unwrap();
+                    (key, plaintext)
+                }
+
+                impl_decryptable!(
+                    $precision,
+                    [<LwePublicKey $bits>],
+                    ([<LweSecretKey $bits>], [<Plaintext $bits>]),
+                    |maker, parameters| {
+                        let companion = [<new_public_key_companion_ $bits>](maker, parameters);
+                        let public_key = maker
+                            .default_engine
+                            .generate_new_lwe_public_key(
+                                &companion.0,
+                                parameters.noise,
+                                LwePublicKeyZeroEncryptionCount(parameters.count),
+                            )
+                            .unwrap();
+                        (companion, public_key)
+                    },
+                    |maker, companion, public_key| {
+                        [<public_key_encrypt_and_decrypt_ $bits>](maker, companion, public_key)
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweSeededPublicKey $bits>],
+                    ([<LweSecretKey $bits>], [<Plaintext $bits>]),
+                    |maker, parameters| {
+                        let companion = [<new_public_key_companion_ $bits>](maker, parameters);
+                        let seeded_public_key = maker
+                            .default_engine
+                            .generate_new_lwe_seeded_public_key(
+                                &companion.0,
+                                parameters.noise,
+                                LwePublicKeyZeroEncryptionCount(parameters.count),
+                            )
+                            .unwrap();
+                        (companion, seeded_public_key)
+                    },
+                    |maker, companion, seeded_public_key| {
+                        let public_key: [<LwePublicKey $bits>] = maker
+                            .default_engine
+                            .transform_lwe_seeded_public_key_to_lwe_public_key(
+                                seeded_public_key.clone(),
+                            )
+                            .unwrap();
+                        [<public_key_encrypt_and_decrypt_ $bits>](maker, companion, &public_key)
+                    }
+                );
+
+                fn [<packing_keyswitch_and_decrypt_ $bits>](
+                    maker: &mut Maker,
+                    companion: &([<LweCiphertextVector $bits>], [<GlweSecretKey $bits>]),
+                    packing_keyswitch_key: &[<LwePackingKeyswitchKey $bits>],
+                ) -> Vec<$raw> {
+                    let (input, glwe_key) = companion;
+                    let engine = &mut maker.default_engine;
+                    let mut output: [<GlweCiphertext $bits>] = engine
+                        .create_glwe_ciphertext_from(
+                            $raw::zero_vec(
+                                glwe_key.glwe_dimension().to_glwe_size().0
+                                    * glwe_key.polynomial_size().0,
+                            ),
+                            glwe_key.polynomial_size(),
+                        )
+                        .unwrap();
+                    engine
+                        .discard_packing_keyswitch_lwe_ciphertext_vector(
+                            &mut output,
+                            input,
+                            packing_keyswitch_key,
+                        )
+                        .unwrap();
+                    let plaintext_vector = engine.decrypt_glwe_ciphertext(glwe_key, &output).unwrap();
+                    engine.retrieve_plaintext_vector(&plaintext_vector).unwrap()
+                }
+
+                fn [<new_packing_keyswitch_companion_ $bits>](
+                    maker: &mut Maker,
+                    parameters: &SerializableEntityParameters,
+                ) -> (
+                    [<LweSecretKey $bits>],
+                    ([<LweCiphertextVector $bits>], [<GlweSecretKey $bits>]),
+                ) {
+                    let engine = &mut maker.default_engine;
+                    let lwe_key: [<LweSecretKey $bits>] =
+                        engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                    let glwe_key: [<GlweSecretKey $bits>] = engine
+                        .generate_new_glwe_secret_key(
+                            parameters.glwe_dimension,
+                            parameters.polynomial_size,
+                        )
+                        .unwrap();
+                    let plaintext_vector = engine
+                        .create_plaintext_vector_from(&$raw::uniform_vec(parameters.count))
+                        .unwrap();
+                    let input = engine
+                        .encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, parameters.noise)
+                        .unwrap();
+                    (lwe_key, (input, glwe_key))
+                }
+
+                impl_decryptable!(
+                    $precision,
+                    [<LwePackingKeyswitchKey $bits>],
+                    ([<LweCiphertextVector $bits>], [<GlweSecretKey $bits>]),
+                    |maker, parameters| {
+                        let (lwe_key, companion) =
+                            [<new_packing_keyswitch_companion_ $bits>](maker, parameters);
+                        let packing_keyswitch_key = maker
+                            .default_engine
+                            .generate_new_lwe_packing_keyswitch_key(
+                                &lwe_key,
+                                &companion.1,
+                                parameters.decomposition_level_count,
+                                parameters.decomposition_base_log,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (companion, packing_keyswitch_key)
+                    },
+                    |maker, companion, packing_keyswitch_key| {
+                        [<packing_keyswitch_and_decrypt_ $bits>](
+                            maker,
+                            companion,
+                            packing_keyswitch_key,
+                        )
+                    }
+                );
+
+                impl_decryptable!(
+                    $precision,
+                    [<LweSeededPackingKeyswitchKey $bits>],
+                    ([<LweCiphertextVector $bits>], [<GlweSecretKey $bits>]),
+                    |maker, parameters| {
+                        let (lwe_key, companion) =
+                            [<new_packing_keyswitch_companion_ $bits>](maker, parameters);
+                        let seeded_packing_keyswitch_key = maker
+                            .default_engine
+                            .generate_new_lwe_seeded_packing_keyswitch_key(
+                                &lwe_key,
+                                &companion.1,
+                                parameters.decomposition_level_count,
+                                parameters.decomposition_base_log,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        (companion, seeded_packing_keyswitch_key)
+                    },
+                    |maker, companion, seeded_packing_keyswitch_key| {
+                        let packing_keyswitch_key: [<LwePackingKeyswitchKey $bits>] = maker
+                            .default_engine
+                            .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+                                seeded_packing_keyswitch_key.clone(),
+                            )
+                            .unwrap();
+                        [<packing_keyswitch_and_decrypt_ $bits>](
+                            maker,
+                            companion,
+                            &packing_keyswitch_key,
+                        )
+                    }
+                );
+
+                impl_structural!(
+                    $precision,
+                    [<LweSeededPrivateFunctionalPackingKeyswitchKey $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let lwe_key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let glwe_key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        let polynomial: [<CleartextVector $bits>] = engine
+                            .create_cleartext_vector_from(&$raw::one_vec(
+                                parameters.polynomial_size.0,
+                            ))
+                            .unwrap();
+                        engine
+                            .generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+                                &lwe_key,
+                                &glwe_key,
+                                parameters.decomposition_level_count,
+                                parameters.decomposition_base_log,
+                                StandardDev(parameters.noise.get_standard_dev()),
+                                &|x| x,
+                                &polynomial,
+                            )
+                            .unwrap()
+                    }
+                );
+
+                impl_structural!(
+                    $precision,
+                    [<LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let lwe_key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let glwe_key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        engine
+                            .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                                &lwe_key,
+                                &glwe_key,
+                                parameters.decomposition_base_log,
+                                parameters.decomposition_level_count,
+                                parameters.noise,
+                            )
+                            .unwrap()
+                    }
+                );
+
+                impl_structural!(
+                    $precision,
+                    [<LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys $bits>],
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let lwe_key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let glwe_key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        engine
+                            .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                                &lwe_key,
+                                &glwe_key,
+                                parameters.decomposition_base_log,
+                                parameters.decomposition_level_count,
+                                parameters.noise,
+                            )
+                            .unwrap()
+                    }
+                );
+            }
+        };
+    }
+
+    impl_precision!(Precision32, u32, 32);
+    impl_precision!(Precision64, u64, 64);
+
+    // The floating point entities do not depend on the precision of the integers, and are only
+    // synthesized for `Precision64`.
+    fn uniform_f64() -> f64 {
+        f64::from(u32::uniform()) / f64::from(u16::MAX)
+    }
+
+    fn uniform_float_encoder_config() -> FloatEncoderMinMaxConfig {
+        let min = uniform_f64();
+        FloatEncoderMinMaxConfig {
+            min,
+            max: min + 1. + uniform_f64(),
+            nb_bit_precision: u32::uniform_between(1..9) as usize,
+            nb_bit_padding: u32::uniform_between(0..2) as usize,
+        }
+    }
+
+    impl_structural!(Precision64, CleartextF64, |maker, _parameters| {
+        maker
+            .default_engine
+            .create_cleartext_from(&uniform_f64())
+            .unwrap()
+    });
+
+    impl_structural!(Precision64, CleartextVectorF64, |maker, parameters| {
+        let values: Vec<f64> = (0..parameters.count).map(|_| uniform_f64()).collect();
+        maker
+            .default_engine
+            .create_cleartext_vector_from(&values)
+            .unwrap()
+    });
+
+    // The encoders can not be cloned, so they are synthesized from their configuration.
+    impl SynthesizesSerializableEntity<Precision64, FloatEncoder> for Maker {
+        type SerializableEntityProto = FloatEncoderMinMaxConfig;
+        type SerializableEntityOutcome = FloatEncoder;
+
+        fn new_serializable_entity(
+            &mut self,
+            _parameters: &SerializableEntityParameters,
+        ) -> FloatEncoderMinMaxConfig {
+            uniform_float_encoder_config()
+        }
+
+        fn synthesize_serializable_entity(
+            &mut self,
+            prototype: &FloatEncoderMinMaxConfig,
+        ) -> FloatEncoder {
+            self.default_engine.create_encoder_from(prototype).unwrap()
+        }
+
+        fn unsynthesize_serializable_entity(
+            &mut self,
+            _prototype: &FloatEncoderMinMaxConfig,
+            entity: FloatEncoder,
+        ) -> FloatEncoder {
+            entity
+        }
+    }
+
+    impl SynthesizesSerializableEntity<Precision64, FloatEncoderVector> for Maker {
+        type SerializableEntityProto = Vec<FloatEncoderMinMaxConfig>;
+        type SerializableEntityOutcome = FloatEncoderVector;
+
+        fn new_serializable_entity(
+            &mut self,
+            parameters: &SerializableEntityParameters,
+        ) -> Vec<FloatEncoderMinMaxConfig> {
+            (0..parameters.count)
+                .map(|_| uniform_float_encoder_config())
+                .collect()
+        }
+
+        fn synthesize_serializable_entity(
+            &mut self,
+            prototype: &Vec<FloatEncoderMinMaxConfig>,
+        ) -> FloatEncoderVector {
+            self.default_engine
+                .create_encoder_vector_from(prototype.as_slice())
+                .unwrap()
+        }
+
+        fn unsynthesize_serializable_entity(
+            &mut self,
+            _prototype: &Vec<FloatEncoderMinMaxConfig>,
+            entity: FloatEncoderVector,
+        ) -> FloatEncoderVector {
+            entity
+        }
+    }
+}
+
+#[cfg(feature = "backend_fft_serialization")]
+mod backend_fft {
+    use crate::generation::synthesizing::{
+        SerializableEntityParameters, SynthesizesSerializableEntity,
+    };
+    use crate::generation::{Maker, Precision32, Precision64};
+    use crate::raw::generation::RawUnsignedIntegers;
+    use concrete_core::prelude::*;
+    use paste::paste;
+
+    macro_rules! impl_precision {
+        ($precision:ident, $raw:ident, $bits:literal) => {
+            paste! {
+                impl_structural!($precision, [<FftFourierGgswCiphertext $bits>], |maker, parameters| {
+                    let key: [<GlweSecretKey $bits>] = maker
+                        .default_engine
+                        .generate_new_glwe_secret_key(
+                            parameters.glwe_dimension,
+                            parameters.polynomial_size,
+                        )
+                        .unwrap();
+                    let plaintext = maker
+                        .default_engine
+                        .create_plaintext_from(&$raw::uniform())
+                        .unwrap();
+                    let ciphertext: [<GgswCiphertext $bits>] = maker
+                        .default_engine
+                        .encrypt_scalar_ggsw_ciphertext(
+                            &key,
+                            &plaintext,
+                            parameters.noise,
+                            parameters.decomposition_level_count,
+                            parameters.decomposition_base_log,
+                        )
+                        .unwrap();
+                    maker.fft_engine.convert_ggsw_ciphertext(&ciphertext).unwrap()
+                });
+
+                impl_structural!(
+                    $precision,
+                    [<FftFourierGgswCiphertextVector $bits>],
+                    |maker, parameters| {
+                        let key: [<GlweSecretKey $bits>] = maker
+                            .default_engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        let plaintext_vector = maker
+                            .default_engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(parameters.count))
+                            .unwrap();
+                        let ciphertext_vector: [<GgswCiphertextVector $bits>] = maker
+                            .default_engine
+                            .encrypt_scalar_ggsw_ciphertext_vector(
+                                &key,
+                                &plaintext_vector,
+                                parameters.noise,
+                                parameters.decomposition_level_count,
+                                parameters.decomposition_base_log,
+                            )
+                            .unwrap();
+                        maker
+                            .fft_engine
+                            .convert_ggsw_ciphertext_vector(&ciphertext_vector)
+                            .unwrap()
+                    }
+                );
+
+                // The bootstrap keys are checked by bootstrapping a ciphertext after the round
+                // trip, which is deterministic.
+                impl_decryptable!(
+                    $precision,
+                    [<FftFourierLweBootstrapKey $bits>],
+                    (
+                        [<LweCiphertext $bits>],
+                        [<GlweCiphertext $bits>],
+                        [<LweSecretKey $bits>],
+                    ),
+                    |maker, parameters| {
+                        let engine = &mut maker.default_engine;
+                        let lwe_key: [<LweSecretKey $bits>] =
+                            engine.generate_new_lwe_secret_key(parameters.lwe_dimension).unwrap();
+                        let glwe_key: [<GlweSecretKey $bits>] = engine
+                            .generate_new_glwe_secret_key(
+                                parameters.glwe_dimension,
+                                parameters.polynomial_size,
+                            )
+                            .unwrap();
+                        let bootstrap_key: [<LweBootstrapKey $bits>] = engine
+                            .generate_new_lwe_bootstrap_key(
+                                &lwe_key,
+                                &glwe_key,
+                                parameters.decomposition_base_log,
+                                parameters.decomposition_level_count,
+                                parameters.noise,
+                            )
+                            .unwrap();
+                        let output_key: [<LweSecretKey $bits>] = engine
+                            .transform_glwe_secret_key_to_lwe_secret_key(glwe_key)
+                            .unwrap();
+                        let plaintext = engine.create_plaintext_from(&$raw::uniform()).unwrap();
+                        let input = engine
+                            .encrypt_lwe_ciphertext(&lwe_key, &plaintext, parameters.noise)
+                            .unwrap();
+                        let lookup_table = engine
+                            .create_plaintext_vector_from(&$raw::uniform_vec(
+                                parameters.polynomial_size.0,
+                            ))
+                            .unwrap();
+                        let accumulator = engine
+                            .trivially_encrypt_glwe_ciphertext(
+                                parameters.glwe_dimension.to_glwe_size(),
+                                &lookup_table,
+                            )
+                            .unwrap();
+                        let fourier_bootstrap_key =
+                            maker.fft_engine.convert_lwe_bootstrap_key(&bootstrap_key).unwrap();
+                        ((input, accumulator, output_key), fourier_bootstrap_key)
+                    },
+                    |maker, companion, fourier_bootstrap_key| {
+                        let (input, accumulator, output_key) = companion;
+                        let mut output: [<LweCiphertext $bits>] = maker
+                            .default_engine
+                            .create_lwe_ciphertext_from($raw::zero_vec(
+                                output_key.lwe_dimension().to_lwe_size().0,
+                            ))
+                            .unwrap();
+                        maker
+                            .fft_engine
+                            .discard_bootstrap_lwe_ciphertext(
+                                &mut output,
+                                input,
+                                accumulator,
+                                fourier_bootstrap_key,
+                            )
+                            .unwrap();
+                        let engine = &mut maker.default_engine;
+                        let plaintext =
+                            engine.decrypt_lwe_ciphertext(output_key, &output).unwrap();
+                        vec![engine.retrieve_plaintext(&plaintext).unwrap()]
+                    }
+                );
+            }
+        };
+    }
+
+    impl_precision!(Precision32, u32, 32);
+    impl_precision!(Precision64, u64, 64);
+}
@@ -94,6 +94,22 @@ where
     true
 }
 
+/// Returns the empirical variance of the errors between the `tested` samples and their
+/// `expected_means`, as a fraction of the torus.
+pub fn measure_noise_variance<Raw>(tested: &[Raw], expected_means: &[Raw]) -> Variance
+where
+    Raw: RawUnsignedIntegers,
+{
+    let errors: Vec<f64> = tested
+        .iter()
+        .zip(expected_means.iter())
+        .map(|(x, y)| torus_modular_distance(*y, *x))
+        .collect();
+    let mean = errors.iter().sum::<f64>() / errors.len() as f64;
+    let variance = errors.iter().map(|x| f64::powi(x - mean, 2)).sum::<f64>();
+    Variance(variance / (errors.len() - 1) as f64)
+}
+
 fn torus_modular_distance<T: RawUnsignedIntegers>(first: T, other: T) -> f64 {
     let d0 = first.wrapping_sub(other);
     let d1 = other.wrapping_sub(first);
@@ -1,25 +1,33 @@
 use crate::{REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+};
 use concrete_csprng::seeders::UnixSeeder;
 use paste::paste;
 
 macro_rules! test {
+    ($name: ident, ($($key_dist:ident),*), $fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        #[test]
+        fn $name() {
+            let mut maker = Maker::default();
+            let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap();
+            let test_result =
+                <$fixture as Fixture<
+                    $precision,
+                    ($($key_dist,)*),
+                    DefaultEngine,
+                    ($($types,)+),
+                >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+            assert!(test_result);
+        }
+    };
     (($($key_dist:ident),*), $fixture: ident, $precision: ident, ($($types:ident),+)) => {
         paste!{
-            #[test]
-            fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
-                let mut maker = Maker::default();
-                let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap();
-                let test_result =
-                    <$fixture as Fixture<
-                        $precision,
-                        ($($key_dist,)*),
-                        DefaultEngine,
-                        ($($types,)+),
-                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
-                assert!(test_result);
+            test!{
+                [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >],
+                ($($key_dist),*), $fixture, $precision, ($($types),+)
             }
         }
     };
@@ -50,11 +58,15 @@ test! {
     ((), CleartextVectorDiscardingRetrievalFixture, (CleartextVector)),
     ((), CleartextVectorRetrievalFixture, (CleartextVector)),
     ((BinaryKeyDistribution), GlweCiphertextDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    ((TernaryKeyDistribution), GlweCiphertextDecryptionFixture, (PlaintextVector, GlweTernarySecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingDecryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), GlweCiphertextDiscardingKeyswitchFixture, (GlweKeyswitchKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingMultiplicationFixture, (GlweCiphertext, GlweRelinearizationKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextDiscardingTrivialEncryptionFixture, (PlaintextVector, GlweCiphertextMutView)),
     ((BinaryKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertext)),
+    ((TernaryKeyDistribution), GlweCiphertextEncryptionFixture, (PlaintextVector, GlweTernarySecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextTrivialEncryptionFixture, (PlaintextVector, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextZeroEncryptionFixture, (GlweSecretKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweCiphertextVector)),
@@ -72,11 +84,30 @@ test! {
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertext, Vec)),
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextView, Slice)),
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextMutView, MutSlice)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingOppositeFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingOppositeFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingMonomialMultiplicationFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingMonomialMultiplicationFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorDiscardingAdditionFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorFusingAdditionFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorDiscardingSubtractionFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorFusingSubtractionFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorDiscardingOppositeFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextVectorFusingOppositeFixture, (GlweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorDiscardingAdditionFixture, (GlweCiphertext, PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorFusingAdditionFixture, (GlweCiphertext, PlaintextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorDiscardingMultiplicationFixture, (GlweCiphertext, PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorFusingMultiplicationFixture, (GlweCiphertext, PlaintextVector)),
     ((BinaryKeyDistribution), GlweSeededCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertext)),
     ((BinaryKeyDistribution), GlweSeededCiphertextToGlweCiphertextTransformationFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweSeededCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertextVector)),
     ((BinaryKeyDistribution), GlweSeededCiphertextVectorToGlweCiphertextVectorTransformationFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertextVector, GlweCiphertextVector)),
     ((BinaryKeyDistribution), GlweToLweSecretKeyTransformationFixture, (GlweSecretKey, LweSecretKey)),
+    ((TernaryKeyDistribution), GlweToLweSecretKeyTransformationFixture, (GlweTernarySecretKey, LweTernarySecretKey)),
     ((BinaryKeyDistribution, BinaryKeyDistribution),
         LweBootstrapKeyCreationFixture, (Vec, LweBootstrapKey)),
     ((BinaryKeyDistribution, BinaryKeyDistribution),
@@ -90,11 +121,13 @@ test! {
     ((BinaryKeyDistribution, BinaryKeyDistribution),
         LweBootstrapKeyConsumingRetrievalFixture, (LweBootstrapKeyMutView, MutSlice)),
     ((BinaryKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    ((TernaryKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, LweTernarySecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextTrivialDecryptionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    ((TernaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweTernarySecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertextView)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertextMutView)),
@@ -135,6 +168,8 @@ test! {
     ((BinaryKeyDistribution), LweCiphertextPlaintextDiscardingSubtractionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextGswCiphertextDiscardingExternalProductFixture, (LweCiphertext, GswCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextsGswCiphertextFusingCmuxFixture, (LweCiphertext, LweCiphertext, GswCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector, LwePackingKeyswitchKey, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchFixture,
         (LweCiphertextVector, LwePrivateFunctionalPackingKeyswitchKey, GlweCiphertext)),
@@ -160,7 +195,22 @@ test! {
     ((BinaryKeyDistribution), LweSeededCiphertextToLweCiphertextTransformationFixture, (Plaintext, LweSecretKey, LweSeededCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), LweSeededCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweSeededCiphertextVector)),
     ((BinaryKeyDistribution), LweSeededCiphertextVectorToLweCiphertextVectorTransformationFixture, (PlaintextVector, LweSecretKey, LweSeededCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededPackingKeyswitchKeyGenerationFixture,
+        (LweSecretKey, GlweSecretKey, LweSeededPackingKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationFixture,
+        (LweSeededPackingKeyswitchKey, LwePackingKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationFixture,
+        (LweSecretKey, GlweSecretKey, CleartextVector, LweSeededPrivateFunctionalPackingKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationFixture,
+        (LweSeededPrivateFunctionalPackingKeyswitchKey, LwePrivateFunctionalPackingKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationFixture,
+        (LweSecretKey, GlweSecretKey, LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationFixture,
+        (LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
+    ((BinaryKeyDistribution), LweSeededPublicKeyGenerationFixture, (LweSecretKey, LweSeededPublicKey)),
+    ((BinaryKeyDistribution), LweSeededPublicKeyToLwePublicKeyTransformationFixture, (LweSeededPublicKey, LwePublicKey)),
     ((BinaryKeyDistribution), LweToGlweSecretKeyTransformationFixture, (LweSecretKey, GlweSecretKey)),
+    ((TernaryKeyDistribution), LweToGlweSecretKeyTransformationFixture, (LweTernarySecretKey, GlweTernarySecretKey)),
     ((), PlaintextCreationFixture, (Plaintext)),
     ((), PlaintextDiscardingRetrievalFixture, (Plaintext)),
     ((), PlaintextRetrievalFixture, (Plaintext)),
@@ -202,3 +252,140 @@ macro_rules! test_parallel {
 test_parallel! {
     ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector))
 }
+
+#[cfg(feature = "backend_default_serialization")]
+macro_rules! test_serialization {
+    (($($key_dist:ident),*), $fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_serialization_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let mut maker = Maker::default();
+                let mut engine = DefaultSerializationEngine::new(()).unwrap();
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        ($($key_dist,)*),
+                        DefaultSerializationEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(test_result);
+            }
+        }
+    };
+    ($((($($key_dist:ident),*), $fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test_serialization!{($($key_dist),*), $fixture, Precision32, ($([< $types 32 >]),+)}
+                test_serialization!{($($key_dist),*), $fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {
+    ((), EntitySerializationFixture, (Cleartext, Cleartext)),
+    ((), EntitySerializationFixture, (CleartextVector, CleartextVector)),
+    ((), EntitySerializationFixture, (Plaintext, Plaintext)),
+    ((), EntitySerializationFixture, (PlaintextVector, PlaintextVector)),
+    ((), EntitySerializationFixture, (GgswCiphertext, GgswCiphertext)),
+    ((), EntitySerializationFixture, (GgswCiphertextVector, GgswCiphertextVector)),
+    ((), EntitySerializationFixture, (GgswSeededCiphertext, GgswSeededCiphertext)),
+    ((), EntitySerializationFixture, (GlweCiphertext, GlweCiphertext)),
+    ((), EntitySerializationFixture, (GlweCiphertextView, GlweCiphertext)),
+    ((), EntitySerializationFixture, (GlweCiphertextMutView, GlweCiphertext)),
+    ((), EntitySerializationFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((), EntitySerializationFixture, (GlweCiphertextVectorView, GlweCiphertextVector)),
+    ((), EntitySerializationFixture, (GlweCiphertextVectorMutView, GlweCiphertextVector)),
+    ((), EntitySerializationFixture, (GlweSecretKey, GlweSecretKey)),
+    ((), EntitySerializationFixture, (GlweSeededCiphertext, GlweSeededCiphertext)),
+    ((), EntitySerializationFixture, (GlweSeededCiphertextVector, GlweSeededCiphertextVector)),
+    ((), EntitySerializationFixture, (LweBootstrapKey, LweBootstrapKey)),
+    ((), EntitySerializationFixture, (LweCiphertext, LweCiphertext)),
+    ((), EntitySerializationFixture, (LweCiphertextView, LweCiphertext)),
+    ((), EntitySerializationFixture, (LweCiphertextMutView, LweCiphertext)),
+    ((), EntitySerializationFixture, (LweCiphertextVector, LweCiphertextVector)),
+    ((), EntitySerializationFixture, (LweCiphertextVectorView, LweCiphertextVector)),
+    ((), EntitySerializationFixture, (LweCiphertextVectorMutView, LweCiphertextVector)),
+    ((), EntitySerializationFixture, (LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
+    ((), EntitySerializationFixture, (LweKeyswitchKey, LweKeyswitchKey)),
+    ((), EntitySerializationFixture, (LwePackingKeyswitchKey, LwePackingKeyswitchKey)),
+    ((), EntitySerializationFixture, (LwePublicKey, LwePublicKey)),
+    ((), EntitySerializationFixture, (LweSecretKey, LweSecretKey)),
+    ((), EntitySerializationFixture, (LweSeededBootstrapKey, LweSeededBootstrapKey)),
+    ((), EntitySerializationFixture, (LweSeededCiphertext, LweSeededCiphertext)),
+    ((), EntitySerializationFixture, (LweSeededCiphertextVector, LweSeededCiphertextVector)),
+    ((), EntitySerializationFixture, (LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
+    ((), EntitySerializationFixture, (LweSeededKeyswitchKey, LweSeededKeyswitchKey)),
+    ((), EntitySerializationFixture, (LweSeededPackingKeyswitchKey, LweSeededPackingKeyswitchKey)),
+    ((), EntitySerializationFixture, (LweSeededPrivateFunctionalPackingKeyswitchKey, LweSeededPrivateFunctionalPackingKeyswitchKey)),
+    ((), EntitySerializationFixture, (LweSeededPublicKey, LweSeededPublicKey)),
+    ((), EntityDeserializationFixture, (Cleartext, Cleartext)),
+    ((), EntityDeserializationFixture, (CleartextVector, CleartextVector)),
+    ((), EntityDeserializationFixture, (Plaintext, Plaintext)),
+    ((), EntityDeserializationFixture, (PlaintextVector, PlaintextVector)),
+    ((), EntityDeserializationFixture, (GgswCiphertext, GgswCiphertext)),
+    ((), EntityDeserializationFixture, (GgswCiphertextVector, GgswCiphertextVector)),
+    ((), EntityDeserializationFixture, (GgswSeededCiphertext, GgswSeededCiphertext)),
+    ((), EntityDeserializationFixture, (GlweCiphertext, GlweCiphertext)),
+    ((), EntityDeserializationFixture, (GlweCiphertextView, GlweCiphertext)),
+    ((), EntityDeserializationFixture, (GlweCiphertextMutView, GlweCiphertext)),
+    ((), EntityDeserializationFixture, (GlweCiphertextVector, GlweCiphertextVector)),
+    ((), EntityDeserializationFixture, (GlweCiphertextVectorView, GlweCiphertextVector)),
+    ((), EntityDeserializationFixture, (GlweCiphertextVectorMutView, GlweCiphertextVector)),
+    ((), EntityDeserializationFixture, (GlweSecretKey, GlweSecretKey)),
+    ((), EntityDeserializationFixture, (GlweSeededCiphertext, GlweSeededCiphertext)),
+    ((), EntityDeserializationFixture, (GlweSeededCiphertextVector, GlweSeededCiphertextVector)),
+    ((), EntityDeserializationFixture, (LweBootstrapKey, LweBootstrapKey)),
+    ((), EntityDeserializationFixture, (LweCiphertext, LweCiphertext)),
+    ((), EntityDeserializationFixture, (LweCiphertextView, LweCiphertext)),
+    ((), EntityDeserializationFixture, (LweCiphertextMutView, LweCiphertext)),
+    ((), EntityDeserializationFixture, (LweCiphertextVector, LweCiphertextVector)),
+    ((), EntityDeserializationFixture, (LweCiphertextVectorView, LweCiphertextVector)),
+    ((), EntityDeserializationFixture, (LweCiphertextVectorMutView, LweCiphertextVector)),
+    ((), EntityDeserializationFixture, (LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
+    ((), EntityDeserializationFixture, (LweKeyswitchKey, LweKeyswitchKey)),
+    ((), EntityDeserializationFixture, (LwePackingKeyswitchKey, LwePackingKeyswitchKey)),
+    ((), EntityDeserializationFixture, (LwePublicKey, LwePublicKey)),
+    ((), EntityDeserializationFixture, (LweSecretKey, LweSecretKey)),
+    ((), EntityDeserializationFixture, (LweSeededBootstrapKey, LweSeededBootstrapKey)),
+    ((), EntityDeserializationFixture, (LweSeededCiphertext, LweSeededCiphertext)),
+    ((), EntityDeserializationFixture, (LweSeededCiphertextVector, LweSeededCiphertextVector)),
+    ((), EntityDeserializationFixture, (LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
+    ((), EntityDeserializationFixture, (LweSeededKeyswitchKey, LweSeededKeyswitchKey)),
+    ((), EntityDeserializationFixture, (LweSeededPackingKeyswitchKey, LweSeededPackingKeyswitchKey)),
+    ((), EntityDeserializationFixture, (LweSeededPrivateFunctionalPackingKeyswitchKey, LweSeededPrivateFunctionalPackingKeyswitchKey)),
+    ((), EntityDeserializationFixture, (LweSeededPublicKey, LweSeededPublicKey))
+}
+
+// The floating point entities do not come in 32 and 64 bits versions, and are only tested with
+// `Precision64`.
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {(), EntitySerializationFixture, Precision64, (CleartextF64, CleartextF64)}
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {(), EntitySerializationFixture, Precision64, (CleartextVectorF64, CleartextVectorF64)}
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {(), EntitySerializationFixture, Precision64, (FloatEncoder, FloatEncoder)}
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {(), EntitySerializationFixture, Precision64, (FloatEncoderVector, FloatEncoderVector)}
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {(), EntityDeserializationFixture, Precision64, (CleartextF64, CleartextF64)}
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {(), EntityDeserializationFixture, Precision64, (CleartextVectorF64, CleartextVectorF64)}
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {(), EntityDeserializationFixture, Precision64, (FloatEncoder, FloatEncoder)}
+#[cfg(feature = "backend_default_serialization")]
+test_serialization! {(), EntityDeserializationFixture, Precision64, (FloatEncoderVector, FloatEncoderVector)}
+
+// The ternary keyswitch keys share their entity types with the binary ones, so their tests are
+// named explicitly to not collide with the binary ones.
+test! {
+    test_lwe_ciphertext_discarding_keyswitch_fixture_precision32_ternary_lwe_keyswitch_key32_lwe_ciphertext32_lwe_ciphertext32,
+    (TernaryKeyDistribution, TernaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture,
+    Precision32, (LweKeyswitchKey32, LweCiphertext32, LweCiphertext32)
+}
+test! {
+    test_lwe_ciphertext_discarding_keyswitch_fixture_precision64_ternary_lwe_keyswitch_key64_lwe_ciphertext64_lwe_ciphertext64,
+    (TernaryKeyDistribution, TernaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture,
+    Precision64, (LweKeyswitchKey64, LweCiphertext64, LweCiphertext64)
+}
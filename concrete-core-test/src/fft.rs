@@ -1,7 +1,9 @@
 use crate::{REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+};
 use paste::paste;
 
 macro_rules! test {
@@ -33,8 +35,13 @@ macro_rules! test {
 }
 
 test! {
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBlindRotationFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture2, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingManyLutBootstrapFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingMultiBitBootstrapFixture, (FftFourierLweMultiBitBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((TernaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingTernaryBootstrapFixture, (FftFourierLweTernaryBootstrapKey, FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingMultiplicationFixture, (GlweCiphertext, FftFourierGlweRelinearizationKey, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FftFourierGgswCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext,
         GlweCiphertext, FftFourierGgswCiphertext)),
@@ -42,5 +49,47 @@ test! {
         (FftFourierLweBootstrapKey, LweKeyswitchKey, LweCiphertext, LweCiphertextVector)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingFixture,
         (FftFourierLweBootstrapKey, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
-        PlaintextVector, LweCiphertextVectorView, LweCiphertextVectorMutView))
+        PlaintextVector, LweCiphertextVectorView, LweCiphertextVectorMutView)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingWopPbsFixture,
+        (FftFourierLweBootstrapKey, LweKeyswitchKey, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+        PlaintextVector, LweCiphertextVector, LweCiphertextVector))
+}
+
+#[cfg(feature = "backend_fft_serialization")]
+macro_rules! test_serialization {
+    (($($key_dist:ident),*), $fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_serialization_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let mut maker = Maker::default();
+                let mut engine = FftSerializationEngine::new(()).unwrap();
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        ($($key_dist,)*),
+                        FftSerializationEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(test_result);
+            }
+        }
+    };
+    ($((($($key_dist:ident),*), $fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test_serialization!{($($key_dist),*), $fixture, Precision32, ($([< $types 32 >]),+)}
+                test_serialization!{($($key_dist),*), $fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "backend_fft_serialization")]
+test_serialization! {
+    ((), EntitySerializationFixture, (FftFourierGgswCiphertext, FftFourierGgswCiphertext)),
+    ((), EntitySerializationFixture, (FftFourierGgswCiphertextVector, FftFourierGgswCiphertextVector)),
+    ((), EntitySerializationFixture, (FftFourierLweBootstrapKey, FftFourierLweBootstrapKey)),
+    ((), EntityDeserializationFixture, (FftFourierGgswCiphertext, FftFourierGgswCiphertext)),
+    ((), EntityDeserializationFixture, (FftFourierGgswCiphertextVector, FftFourierGgswCiphertextVector)),
+    ((), EntityDeserializationFixture, (FftFourierLweBootstrapKey, FftFourierLweBootstrapKey))
 }
@@ -18,3 +18,5 @@ pub mod cuda;
 pub mod default;
 #[cfg(all(test, feature = "backend_fft"))]
 pub mod fft;
+#[cfg(all(test, feature = "backend_ntt"))]
+pub mod ntt;
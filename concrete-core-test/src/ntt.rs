@@ -0,0 +1,177 @@
+use crate::{REPETITIONS, SAMPLE_SIZE};
+use concrete_core::prelude::*;
+use concrete_core_fixture::fixture::*;
+use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use paste::paste;
+
+macro_rules! test {
+    (($($key_dist:ident),*), $fixture: ident, $precision: ident, ($($types:ident),+)) => {
+        paste!{
+            #[test]
+            fn [< test_ $fixture:snake _ $precision:snake _ $($types:snake)_+ >]() {
+                let mut maker = Maker::default();
+                let mut engine = NttEngine::new(()).unwrap();
+                let test_result =
+                    <$fixture as Fixture<
+                        $precision,
+                        ($($key_dist,)*),
+                        NttEngine,
+                        ($($types,)+),
+                    >>::stress_all_parameters(&mut maker, &mut engine, REPETITIONS, SAMPLE_SIZE);
+                assert!(test_result);
+            }
+        }
+    };
+    ($((($($key_dist:ident),*), $fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test!{($($key_dist),*), $fixture, Precision32, ($([< $types 32 >]),+)}
+                test!{($($key_dist),*), $fixture, Precision64, ($([< $types 64 >]),+)}
+            }
+        )+
+    };
+}
+
+test! {
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (NttFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture2, (NttFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, NttFourierGgswCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext,
+        GlweCiphertext, NttFourierGgswCiphertext))
+}
+
+// Bootstraps the same ciphertexts with the FFT and the NTT backends, and checks that both outputs
+// decrypt to the same message, for every parameter set used by the bootstrap fixtures.
+#[cfg(feature = "backend_fft")]
+macro_rules! test_fft_ntt_bootstrap_comparison {
+    ($fixture: ident, $bits: literal) => {
+        paste! {
+            #[test]
+            fn [< test_fft_ntt_bootstrap_comparison_ $fixture:snake _ $bits >]() {
+                // The number of bits of message, the padding bit excluded.
+                const MESSAGE_BITS: usize = 3;
+                let message_modulus = 1 << MESSAGE_BITS;
+                let delta = 1 << ($bits - 1 - MESSAGE_BITS);
+
+                let mut default_engine =
+                    DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap();
+                let mut fft_engine = FftEngine::new(()).unwrap();
+                let mut ntt_engine = NttEngine::new(()).unwrap();
+
+                let parameters = <$fixture as Fixture<
+                    [< Precision $bits >],
+                    (BinaryKeyDistribution, BinaryKeyDistribution),
+                    NttEngine,
+                    (
+                        [< NttFourierLweBootstrapKey $bits >],
+                        [< GlweCiphertext $bits >],
+                        [< LweCiphertext $bits >],
+                        [< LweCiphertext $bits >],
+                    ),
+                >>::generate_parameters_iterator();
+
+                for parameters in parameters {
+                    let lwe_key: [< LweSecretKey $bits >] = default_engine
+                        .generate_new_lwe_secret_key(parameters.lwe_dimension)
+                        .unwrap();
+                    let glwe_key: [< GlweSecretKey $bits >] = default_engine
+                        .generate_new_glwe_secret_key(
+                            parameters.glwe_dimension,
+                            parameters.poly_size,
+                        )
+                        .unwrap();
+                    let bsk: [< LweBootstrapKey $bits >] = default_engine
+                        .generate_new_lwe_bootstrap_key(
+                            &lwe_key,
+                            &glwe_key,
+                            parameters.decomp_base_log,
+                            parameters.decomp_level_count,
+                            parameters.noise,
+                        )
+                        .unwrap();
+                    let fft_bsk: [< FftFourierLweBootstrapKey $bits >] =
+                        fft_engine.convert_lwe_bootstrap_key(&bsk).unwrap();
+                    let ntt_bsk: [< NttFourierLweBootstrapKey $bits >] =
+                        ntt_engine.convert_lwe_bootstrap_key(&bsk).unwrap();
+                    let output_key = default_engine
+                        .transform_glwe_secret_key_to_lwe_secret_key(glwe_key)
+                        .unwrap();
+
+                    // We build an identity lookup table, where each box is centered on the
+                    // message it encodes.
+                    let poly_size = parameters.poly_size.0;
+                    let box_size = poly_size / message_modulus;
+                    let lut: Vec<[< u $bits >]> = (0..poly_size)
+                        .map(|i| (((i + box_size / 2) / box_size % message_modulus) as [< u $bits >]) * delta)
+                        .collect();
+                    let lut = default_engine.create_plaintext_vector_from(&lut).unwrap();
+                    let accumulator: [< GlweCiphertext $bits >] = default_engine
+                        .trivially_encrypt_glwe_ciphertext(
+                            parameters.glwe_dimension.to_glwe_size(),
+                            &lut,
+                        )
+                        .unwrap();
+
+                    let output_lwe_size =
+                        LweSize(parameters.glwe_dimension.0 * poly_size + 1);
+                    let zero = default_engine.create_plaintext_from(&0).unwrap();
+                    for message in 0..message_modulus as [< u $bits >] {
+                        let plaintext = default_engine
+                            .create_plaintext_from(&(message * delta))
+                            .unwrap();
+                        let input: [< LweCiphertext $bits >] = default_engine
+                            .encrypt_lwe_ciphertext(&lwe_key, &plaintext, parameters.noise)
+                            .unwrap();
+
+                        let mut fft_output: [< LweCiphertext $bits >] = default_engine
+                            .trivially_encrypt_lwe_ciphertext(output_lwe_size, &zero)
+                            .unwrap();
+                        let mut ntt_output = fft_output.clone();
+                        fft_engine
+                            .discard_bootstrap_lwe_ciphertext(
+                                &mut fft_output,
+                                &input,
+                                &accumulator,
+                                &fft_bsk,
+                            )
+                            .unwrap();
+                        ntt_engine
+                            .discard_bootstrap_lwe_ciphertext(
+                                &mut ntt_output,
+                                &input,
+                                &accumulator,
+                                &ntt_bsk,
+                            )
+                            .unwrap();
+
+                        let decode = |engine: &mut DefaultEngine,
+                                      ciphertext: &[< LweCiphertext $bits >]| {
+                            let plaintext = engine
+                                .decrypt_lwe_ciphertext(&output_key, ciphertext)
+                                .unwrap();
+                            let raw: [< u $bits >] = engine.retrieve_plaintext(&plaintext).unwrap();
+                            (raw.wrapping_add(delta / 2) / delta) % message_modulus as [< u $bits >]
+                        };
+                        let fft_message = decode(&mut default_engine, &fft_output);
+                        let ntt_message = decode(&mut default_engine, &ntt_output);
+                        assert_eq!(
+                            fft_message, ntt_message,
+                            "FFT and NTT bootstraps disagree for {:?}",
+                            parameters
+                        );
+                        assert_eq!(ntt_message, message);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "backend_fft")]
+test_fft_ntt_bootstrap_comparison!(LweCiphertextDiscardingBootstrapFixture1, 32);
+#[cfg(feature = "backend_fft")]
+test_fft_ntt_bootstrap_comparison!(LweCiphertextDiscardingBootstrapFixture1, 64);
+#[cfg(feature = "backend_fft")]
+test_fft_ntt_bootstrap_comparison!(LweCiphertextDiscardingBootstrapFixture2, 32);
+#[cfg(feature = "backend_fft")]
+test_fft_ntt_bootstrap_comparison!(LweCiphertextDiscardingBootstrapFixture2, 64);
@@ -0,0 +1,197 @@
+use crate::prelude::Variance;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
+    PlaintextVector64,
+};
+use crate::specification::engines::{
+    GgswCiphertextPolynomialDiscardingEncryptionEngine,
+    GgswCiphertextPolynomialDiscardingEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextPolynomialDiscardingEncryptionEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl
+    GgswCiphertextPolynomialDiscardingEncryptionEngine<
+        GlweSecretKey32,
+        PlaintextVector32,
+        GgswCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // The monomial X
+    /// let input = vec![0_u32, 1, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key_1: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext = engine.encrypt_polynomial_ggsw_ciphertext(
+    ///     &key_1,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// // We're going to re-encrypt the input with another secret key
+    /// // For this, it is required that the second secret key uses the same GLWE dimension
+    /// // and polynomial size as the first one.
+    /// let key_2: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// engine.discard_encrypt_polynomial_ggsw_ciphertext(
+    ///     &key_2,
+    ///     &mut ciphertext,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_polynomial_ggsw_ciphertext(
+        &mut self,
+        key: &GlweSecretKey32,
+        output: &mut GgswCiphertext32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), GgswCiphertextPolynomialDiscardingEncryptionError<Self::EngineError>> {
+        GgswCiphertextPolynomialDiscardingEncryptionError::perform_generic_checks(
+            key, output, input,
+        )?;
+        unsafe {
+            self.discard_encrypt_polynomial_ggsw_ciphertext_unchecked(key, output, input, noise)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_polynomial_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        output: &mut GgswCiphertext32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        key.0.encrypt_polynomial_ggsw(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextPolynomialDiscardingEncryptionEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl
+    GgswCiphertextPolynomialDiscardingEncryptionEngine<
+        GlweSecretKey64,
+        PlaintextVector64,
+        GgswCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // The monomial X
+    /// let input = vec![0_u64, 1, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key_1: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext = engine.encrypt_polynomial_ggsw_ciphertext(
+    ///     &key_1,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// // We're going to re-encrypt the input with another secret key
+    /// // For this, it is required that the second secret key uses the same GLWE dimension
+    /// // and polynomial size as the first one.
+    /// let key_2: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// engine.discard_encrypt_polynomial_ggsw_ciphertext(
+    ///     &key_2,
+    ///     &mut ciphertext,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_polynomial_ggsw_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut GgswCiphertext64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), GgswCiphertextPolynomialDiscardingEncryptionError<Self::EngineError>> {
+        GgswCiphertextPolynomialDiscardingEncryptionError::perform_generic_checks(
+            key, output, input,
+        )?;
+        unsafe {
+            self.discard_encrypt_polynomial_ggsw_ciphertext_unchecked(key, output, input, noise)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_polynomial_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut GgswCiphertext64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        key.0.encrypt_polynomial_ggsw(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::crypto::ggsw::StandardGgswCiphertext as ImplGgswCiphertext;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, GlweSize};
+use crate::specification::engines::{
+    GgswCiphertextPolynomialTrivialEncryptionEngine, GgswCiphertextPolynomialTrivialEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextPolynomialTrivialEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl GgswCiphertextPolynomialTrivialEncryptionEngine<PlaintextVector32, GgswCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // The monomial X
+    /// let input = vec![0_u32, 1, 0, 0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext: GgswCiphertext32 = engine.trivially_encrypt_polynomial_ggsw_ciphertext(
+    ///     glwe_dimension.to_glwe_size(),
+    ///     level,
+    ///     base_log,
+    ///     &plaintext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_polynomial_ggsw_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &PlaintextVector32,
+    ) -> Result<GgswCiphertext32, GgswCiphertextPolynomialTrivialEncryptionError<Self::EngineError>>
+    {
+        unsafe {
+            Ok(self.trivially_encrypt_polynomial_ggsw_ciphertext_unchecked(
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                input,
+            ))
+        }
+    }
+
+    unsafe fn trivially_encrypt_polynomial_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &PlaintextVector32,
+    ) -> GgswCiphertext32 {
+        let ciphertext: ImplGgswCiphertext<Vec<u32>> =
+            ImplGgswCiphertext::new_trivial_polynomial_encryption(
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                &input.0,
+            );
+        GgswCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextPolynomialTrivialEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl GgswCiphertextPolynomialTrivialEncryptionEngine<PlaintextVector64, GgswCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // The monomial X
+    /// let input = vec![0_u64, 1, 0, 0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext: GgswCiphertext64 = engine.trivially_encrypt_polynomial_ggsw_ciphertext(
+    ///     glwe_dimension.to_glwe_size(),
+    ///     level,
+    ///     base_log,
+    ///     &plaintext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_polynomial_ggsw_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &PlaintextVector64,
+    ) -> Result<GgswCiphertext64, GgswCiphertextPolynomialTrivialEncryptionError<Self::EngineError>>
+    {
+        unsafe {
+            Ok(self.trivially_encrypt_polynomial_ggsw_ciphertext_unchecked(
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                input,
+            ))
+        }
+    }
+
+    unsafe fn trivially_encrypt_polynomial_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &PlaintextVector64,
+    ) -> GgswCiphertext64 {
+        let ciphertext: ImplGgswCiphertext<Vec<u64>> =
+            ImplGgswCiphertext::new_trivial_polynomial_encryption(
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                &input.0,
+            );
+        GgswCiphertext64(ciphertext)
+    }
+}
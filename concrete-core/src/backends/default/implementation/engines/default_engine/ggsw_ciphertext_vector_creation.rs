@@ -0,0 +1,187 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertextVector32, GgswCiphertextVector64,
+};
+use crate::commons::crypto::ggsw::StandardGgswCiphertextList as ImplStandardGgswCiphertextList;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize};
+use crate::specification::engines::{
+    GgswCiphertextVectorCreationEngine, GgswCiphertextVectorCreationError,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorCreationEngine`] for [`DefaultEngine`] which returns a
+/// [`GgswCiphertextVector32`].
+impl GgswCiphertextVectorCreationEngine<Vec<u32>, GgswCiphertextVector32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ciphertext_count = GgswCiphertextCount(5);
+    ///
+    /// let owned_container =
+    ///     vec![0_u32; ciphertext_count.0 * level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVector32 = engine.create_ggsw_ciphertext_vector_from(
+    ///     owned_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), ciphertext_count);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: Vec<u32>,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswCiphertextVector32, GgswCiphertextVectorCreationError<Self::EngineError>> {
+        GgswCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_size,
+            polynomial_size,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.create_ggsw_ciphertext_vector_from_unchecked(
+                container,
+                glwe_size,
+                polynomial_size,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVector32 {
+        GgswCiphertextVector32(ImplStandardGgswCiphertextList::from_container(
+            container,
+            glwe_size,
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorCreationEngine`] for [`DefaultEngine`] which returns a
+/// [`GgswCiphertextVector64`].
+impl GgswCiphertextVectorCreationEngine<Vec<u64>, GgswCiphertextVector64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(4);
+    /// let ciphertext_count = GgswCiphertextCount(5);
+    ///
+    /// let owned_container =
+    ///     vec![0_u64; ciphertext_count.0 * level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext_vector: GgswCiphertextVector64 = engine.create_ggsw_ciphertext_vector_from(
+    ///     owned_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), ciphertext_count);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: Vec<u64>,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswCiphertextVector64, GgswCiphertextVectorCreationError<Self::EngineError>> {
+        GgswCiphertextVectorCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_size,
+            polynomial_size,
+            decomposition_level_count,
+        )?;
+        Ok(unsafe {
+            self.create_ggsw_ciphertext_vector_from_unchecked(
+                container,
+                glwe_size,
+                polynomial_size,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVector64 {
+        GgswCiphertextVector64(ImplStandardGgswCiphertextList::from_container(
+            container,
+            glwe_size,
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
@@ -0,0 +1,228 @@
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, Variance,
+};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GgswCiphertextVector32, GgswCiphertextVector64, GlweSecretKey32, GlweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::crypto::ggsw::StandardGgswCiphertextList as ImplStandardGgswCiphertextList;
+use crate::specification::engines::{
+    GgswCiphertextVectorScalarEncryptionEngine, GgswCiphertextVectorScalarEncryptionError,
+};
+use crate::specification::entities::{GlweSecretKeyEntity, PlaintextVectorEntity};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorScalarEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GgswCiphertextVectorScalarEncryptionEngine<
+        GlweSecretKey32,
+        PlaintextVector32,
+        GgswCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector.ggsw_ciphertext_count(),
+    ///     GgswCiphertextCount(3)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswCiphertextVector32, GgswCiphertextVectorScalarEncryptionError<Self::EngineError>>
+    {
+        GgswCiphertextVectorScalarEncryptionError::perform_generic_checks(input)?;
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVector32 {
+        let mut ciphertext_vector = ImplStandardGgswCiphertextList::allocate(
+            0u32,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            GgswCiphertextCount(input.plaintext_count().0),
+        );
+        for (mut ciphertext, plaintext) in ciphertext_vector
+            .ciphertext_iter_mut()
+            .zip(input.0.plaintext_iter())
+        {
+            key.0.encrypt_constant_ggsw(
+                &mut ciphertext,
+                plaintext,
+                noise,
+                &mut self.encryption_generator,
+            );
+        }
+        GgswCiphertextVector32(ciphertext_vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorScalarEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GgswCiphertextVectorScalarEncryptionEngine<
+        GlweSecretKey64,
+        PlaintextVector64,
+        GgswCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector.ggsw_ciphertext_count(),
+    ///     GgswCiphertextCount(3)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswCiphertextVector64, GgswCiphertextVectorScalarEncryptionError<Self::EngineError>>
+    {
+        GgswCiphertextVectorScalarEncryptionError::perform_generic_checks(input)?;
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVector64 {
+        let mut ciphertext_vector = ImplStandardGgswCiphertextList::allocate(
+            0u64,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            GgswCiphertextCount(input.plaintext_count().0),
+        );
+        for (mut ciphertext, plaintext) in ciphertext_vector
+            .ciphertext_iter_mut()
+            .zip(input.0.plaintext_iter())
+        {
+            key.0.encrypt_constant_ggsw(
+                &mut ciphertext,
+                plaintext,
+                noise,
+                &mut self.encryption_generator,
+            );
+        }
+        GgswCiphertextVector64(ciphertext_vector)
+    }
+}
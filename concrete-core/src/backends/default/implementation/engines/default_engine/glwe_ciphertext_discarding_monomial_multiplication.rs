@@ -0,0 +1,147 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
+#[allow(deprecated)]
+use crate::prelude::MonomialDegree;
+use crate::specification::engines::{
+    GlweCiphertextDiscardingMonomialMultiplicationEngine,
+    GlweCiphertextDiscardingMonomialMultiplicationError,
+};
+
+#[allow(deprecated)]
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMonomialMultiplicationEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl GlweCiphertextDiscardingMonomialMultiplicationEngine<GlweCiphertext32, GlweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, MonomialDegree, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 4];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// // Multiplies by X, which rotates the coefficients of every polynomial by one position
+    /// engine.discard_mul_glwe_ciphertext_monomial(
+    ///     &mut ciphertext,
+    ///     &input_ciphertext,
+    ///     MonomialDegree(1),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_monomial(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        degree: MonomialDegree,
+    ) -> Result<(), GlweCiphertextDiscardingMonomialMultiplicationError<Self::EngineError>> {
+        GlweCiphertextDiscardingMonomialMultiplicationError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_mul_glwe_ciphertext_monomial_unchecked(output, input, degree) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_monomial_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        degree: MonomialDegree,
+    ) {
+        output.0.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+        output
+            .0
+            .as_mut_polynomial_list()
+            .update_with_wrapping_monic_monomial_mul(degree);
+    }
+}
+
+#[allow(deprecated)]
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMonomialMultiplicationEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl GlweCiphertextDiscardingMonomialMultiplicationEngine<GlweCiphertext64, GlweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, MonomialDegree, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 4];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// // Multiplies by X, which rotates the coefficients of every polynomial by one position
+    /// engine.discard_mul_glwe_ciphertext_monomial(
+    ///     &mut ciphertext,
+    ///     &input_ciphertext,
+    ///     MonomialDegree(1),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_monomial(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        degree: MonomialDegree,
+    ) -> Result<(), GlweCiphertextDiscardingMonomialMultiplicationError<Self::EngineError>> {
+        GlweCiphertextDiscardingMonomialMultiplicationError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_mul_glwe_ciphertext_monomial_unchecked(output, input, degree) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_monomial_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        degree: MonomialDegree,
+    ) {
+        output.0.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+        output
+            .0
+            .as_mut_polynomial_list()
+            .update_with_wrapping_monic_monomial_mul(degree);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::prelude::DeltaLog;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweRelinearizationKey32, GlweRelinearizationKey64,
+};
+use crate::commons::crypto::glwe::GlweTensorProduct as ImplGlweTensorProduct;
+use crate::specification::engines::{
+    GlweCiphertextDiscardingMultiplicationEngine, GlweCiphertextDiscardingMultiplicationError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        GlweCiphertext32,
+        GlweRelinearizationKey32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // The messages are encoded on the four most significant bits: the constant polynomials 3
+    /// // and 2 are encrypted, and their product is 6.
+    /// let delta_log = DeltaLog(28);
+    /// let mut input_1 = vec![0_u32; polynomial_size.0];
+    /// let mut input_2 = vec![0_u32; polynomial_size.0];
+    /// input_1[0] = 3 << delta_log.0;
+    /// input_2[0] = 2 << delta_log.0;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         DecompositionLevelCount(6),
+    ///         DecompositionBaseLog(4),
+    ///         noise,
+    ///     )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut output = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext(
+    ///     &mut output,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &relinearization_key,
+    ///     delta_log,
+    /// )?;
+    ///
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&key, &output)?;
+    /// let raw_output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let rounded: Vec<u32> = raw_output
+    ///     .iter()
+    ///     .map(|a| a.wrapping_add(1 << (delta_log.0 - 1)) >> delta_log.0)
+    ///     .collect();
+    /// assert_eq!(rounded, vec![6, 0, 0, 0]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        relinearization_key: &GlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        GlweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            relinearization_key,
+            delta_log,
+            32,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_unchecked(
+                output,
+                input_1,
+                input_2,
+                relinearization_key,
+                delta_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        relinearization_key: &GlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) {
+        let mut tensor_product = ImplGlweTensorProduct::allocate(
+            0u32,
+            input_1.polynomial_size(),
+            input_1.glwe_dimension(),
+        );
+        tensor_product.fill_with_tensor_product(&input_1.0, &input_2.0, delta_log);
+        relinearization_key
+            .0
+            .relinearize(&mut output.0, &tensor_product);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        GlweCiphertext64,
+        GlweRelinearizationKey64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // The messages are encoded on the four most significant bits: the constant polynomials 3
+    /// // and 2 are encrypted, and their product is 6.
+    /// let delta_log = DeltaLog(60);
+    /// let mut input_1 = vec![0_u64; polynomial_size.0];
+    /// let mut input_2 = vec![0_u64; polynomial_size.0];
+    /// input_1[0] = 3 << delta_log.0;
+    /// input_2[0] = 2 << delta_log.0;
+    /// let noise = Variance(2_f64.powf(-100.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         DecompositionLevelCount(4),
+    ///         DecompositionBaseLog(12),
+    ///         noise,
+    ///     )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut output = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext(
+    ///     &mut output,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &relinearization_key,
+    ///     delta_log,
+    /// )?;
+    ///
+    /// let decrypted = engine.decrypt_glwe_ciphertext(&key, &output)?;
+    /// let raw_output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let rounded: Vec<u64> = raw_output
+    ///     .iter()
+    ///     .map(|a| a.wrapping_add(1 << (delta_log.0 - 1)) >> delta_log.0)
+    ///     .collect();
+    /// assert_eq!(rounded, vec![6, 0, 0, 0]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        relinearization_key: &GlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        GlweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            relinearization_key,
+            delta_log,
+            64,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_unchecked(
+                output,
+                input_1,
+                input_2,
+                relinearization_key,
+                delta_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        relinearization_key: &GlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) {
+        let mut tensor_product = ImplGlweTensorProduct::allocate(
+            0u64,
+            input_1.polynomial_size(),
+            input_1.glwe_dimension(),
+        );
+        tensor_product.fill_with_tensor_product(&input_1.0, &input_2.0, delta_log);
+        relinearization_key
+            .0
+            .relinearize(&mut output.0, &tensor_product);
+    }
+}
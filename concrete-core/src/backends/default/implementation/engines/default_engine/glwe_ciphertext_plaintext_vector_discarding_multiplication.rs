@@ -0,0 +1,176 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextPlaintextVectorDiscardingMultiplicationEngine,
+    GlweCiphertextPlaintextVectorDiscardingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextPlaintextVectorDiscardingMultiplicationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    GlweCiphertextPlaintextVectorDiscardingMultiplicationEngine<
+        GlweCiphertext32,
+        PlaintextVector32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 4];
+    /// // The multiplier is the monomial X
+    /// let multiplier = vec![0_u32, 1, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&multiplier)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext_plaintext_vector(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &plaintext_vector_2,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &PlaintextVector32,
+    ) -> Result<(), GlweCiphertextPlaintextVectorDiscardingMultiplicationError<Self::EngineError>>
+    {
+        GlweCiphertextPlaintextVectorDiscardingMultiplicationError::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_plaintext_vector_unchecked(output, input_1, input_2)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &PlaintextVector32,
+    ) {
+        let multiplier = Polynomial::from_container(input_2.0.as_tensor().as_slice());
+        for (mut output_polynomial, input_polynomial) in output
+            .0
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(input_1.0.as_polynomial_list().polynomial_iter())
+        {
+            output_polynomial.fill_with_wrapping_mul(&input_polynomial, &multiplier);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextPlaintextVectorDiscardingMultiplicationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    GlweCiphertextPlaintextVectorDiscardingMultiplicationEngine<
+        GlweCiphertext64,
+        PlaintextVector64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 4];
+    /// // The multiplier is the monomial X
+    /// let multiplier = vec![0_u64, 1, 0, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&multiplier)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext_plaintext_vector(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &plaintext_vector_2,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_2.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &PlaintextVector64,
+    ) -> Result<(), GlweCiphertextPlaintextVectorDiscardingMultiplicationError<Self::EngineError>>
+    {
+        GlweCiphertextPlaintextVectorDiscardingMultiplicationError::perform_generic_checks(
+            output, input_1, input_2,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_plaintext_vector_unchecked(output, input_1, input_2)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &PlaintextVector64,
+    ) {
+        let multiplier = Polynomial::from_container(input_2.0.as_tensor().as_slice());
+        for (mut output_polynomial, input_polynomial) in output
+            .0
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(input_1.0.as_polynomial_list().polynomial_iter())
+        {
+            output_polynomial.fill_with_wrapping_mul(&input_polynomial, &multiplier);
+        }
+    }
+}
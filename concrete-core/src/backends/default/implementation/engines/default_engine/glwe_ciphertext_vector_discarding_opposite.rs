@@ -0,0 +1,136 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64,
+};
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextVectorDiscardingOppositeEngine, GlweCiphertextVectorDiscardingOppositeError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingOppositeEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl GlweCiphertextVectorDiscardingOppositeEngine<GlweCiphertextVector32, GlweCiphertextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_glwe_ciphertext_vector(&key, noise, GlweCiphertextCount(2))?;
+    ///
+    /// engine.discard_opp_glwe_ciphertext_vector(&mut output_ciphertext_vector, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_opp_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertextVector32,
+        input: &GlweCiphertextVector32,
+    ) -> Result<(), GlweCiphertextVectorDiscardingOppositeError<Self::EngineError>> {
+        GlweCiphertextVectorDiscardingOppositeError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_opp_glwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_opp_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertextVector32,
+        input: &GlweCiphertextVector32,
+    ) {
+        output.0.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+        output.0.as_mut_tensor().update_with_wrapping_neg();
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingOppositeEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl GlweCiphertextVectorDiscardingOppositeEngine<GlweCiphertextVector64, GlweCiphertextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_glwe_ciphertext_vector(&key, noise, GlweCiphertextCount(2))?;
+    ///
+    /// engine.discard_opp_glwe_ciphertext_vector(&mut output_ciphertext_vector, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_opp_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertextVector64,
+        input: &GlweCiphertextVector64,
+    ) -> Result<(), GlweCiphertextVectorDiscardingOppositeError<Self::EngineError>> {
+        GlweCiphertextVectorDiscardingOppositeError::perform_generic_checks(output, input)?;
+        unsafe { self.discard_opp_glwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_opp_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertextVector64,
+        input: &GlweCiphertextVector64,
+    ) {
+        output.0.as_mut_tensor().fill_with_copy(input.0.as_tensor());
+        output.0.as_mut_tensor().update_with_wrapping_neg();
+    }
+}
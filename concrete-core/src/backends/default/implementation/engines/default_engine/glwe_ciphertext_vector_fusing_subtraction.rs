@@ -0,0 +1,144 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64,
+};
+use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextVectorFusingSubtractionEngine, GlweCiphertextVectorFusingSubtractionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorFusingSubtractionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl GlweCiphertextVectorFusingSubtractionEngine<GlweCiphertextVector32, GlweCiphertextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; 8];
+    /// let input_2 = vec![7_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let mut ciphertext_vector_1 =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_vector_2 =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// engine.fuse_sub_glwe_ciphertext_vector(&mut ciphertext_vector_1, &ciphertext_vector_2)?;
+    /// #
+    /// assert_eq!(ciphertext_vector_1.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector_1.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector_1.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_sub_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertextVector32,
+        input: &GlweCiphertextVector32,
+    ) -> Result<(), GlweCiphertextVectorFusingSubtractionError<Self::EngineError>> {
+        GlweCiphertextVectorFusingSubtractionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_sub_glwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_sub_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertextVector32,
+        input: &GlweCiphertextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .update_with_wrapping_sub(input.0.as_tensor());
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorFusingSubtractionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl GlweCiphertextVectorFusingSubtractionEngine<GlweCiphertextVector64, GlweCiphertextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{GlweCiphertextCount, GlweDimension, PolynomialSize, Variance, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; 8];
+    /// let input_2 = vec![7_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let mut ciphertext_vector_1 =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_vector_2 =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// engine.fuse_sub_glwe_ciphertext_vector(&mut ciphertext_vector_1, &ciphertext_vector_2)?;
+    /// #
+    /// assert_eq!(ciphertext_vector_1.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector_1.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     ciphertext_vector_1.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_sub_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertextVector64,
+        input: &GlweCiphertextVector64,
+    ) -> Result<(), GlweCiphertextVectorFusingSubtractionError<Self::EngineError>> {
+        GlweCiphertextVectorFusingSubtractionError::perform_generic_checks(output, input)?;
+        unsafe { self.fuse_sub_glwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_sub_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertextVector64,
+        input: &GlweCiphertextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .update_with_wrapping_sub(input.0.as_tensor());
+    }
+}
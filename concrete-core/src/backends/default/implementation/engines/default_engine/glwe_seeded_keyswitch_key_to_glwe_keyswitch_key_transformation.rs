@@ -0,0 +1,203 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    GlweKeyswitchKey32, GlweKeyswitchKey64, GlweSeededKeyswitchKey32, GlweSeededKeyswitchKey64,
+};
+use crate::commons::crypto::glwe::GlweKeyswitchKey as ImplGlweKeyswitchKey;
+use crate::specification::engines::{
+    GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationEngine,
+    GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationError,
+};
+use crate::specification::entities::GlweSeededKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationEngine<
+        GlweSeededKeyswitchKey32,
+        GlweKeyswitchKey32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(2);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_glwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let keyswitch_key: GlweKeyswitchKey32 =
+    ///     engine.transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(seeded_keyswitch_key)?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_glwe_dimension(), input_glwe_dimension);
+    /// assert_eq!(keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(keyswitch_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(
+        &mut self,
+        glwe_seeded_keyswitch_key: GlweSeededKeyswitchKey32,
+    ) -> Result<
+        GlweKeyswitchKey32,
+        GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_unchecked(
+                glwe_seeded_keyswitch_key,
+            )
+        })
+    }
+
+    unsafe fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_unchecked(
+        &mut self,
+        glwe_seeded_keyswitch_key: GlweSeededKeyswitchKey32,
+    ) -> GlweKeyswitchKey32 {
+        let mut ksk = ImplGlweKeyswitchKey::allocate(
+            0u32,
+            glwe_seeded_keyswitch_key.decomposition_level_count(),
+            glwe_seeded_keyswitch_key.decomposition_base_log(),
+            glwe_seeded_keyswitch_key.input_glwe_dimension(),
+            glwe_seeded_keyswitch_key.output_glwe_dimension(),
+            glwe_seeded_keyswitch_key.polynomial_size(),
+        );
+
+        glwe_seeded_keyswitch_key
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut ksk);
+
+        GlweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationEngine<
+        GlweSeededKeyswitchKey64,
+        GlweKeyswitchKey64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(2);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_glwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let keyswitch_key: GlweKeyswitchKey64 =
+    ///     engine.transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(seeded_keyswitch_key)?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_glwe_dimension(), input_glwe_dimension);
+    /// assert_eq!(keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(keyswitch_key.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(
+        &mut self,
+        glwe_seeded_keyswitch_key: GlweSeededKeyswitchKey64,
+    ) -> Result<
+        GlweKeyswitchKey64,
+        GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_unchecked(
+                glwe_seeded_keyswitch_key,
+            )
+        })
+    }
+
+    unsafe fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_unchecked(
+        &mut self,
+        glwe_seeded_keyswitch_key: GlweSeededKeyswitchKey64,
+    ) -> GlweKeyswitchKey64 {
+        let mut ksk = ImplGlweKeyswitchKey::allocate(
+            0u64,
+            glwe_seeded_keyswitch_key.decomposition_level_count(),
+            glwe_seeded_keyswitch_key.decomposition_base_log(),
+            glwe_seeded_keyswitch_key.input_glwe_dimension(),
+            glwe_seeded_keyswitch_key.output_glwe_dimension(),
+            glwe_seeded_keyswitch_key.polynomial_size(),
+        );
+
+        glwe_seeded_keyswitch_key
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut ksk);
+
+        GlweKeyswitchKey64(ksk)
+    }
+}
@@ -0,0 +1,169 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GswCiphertext32, GswCiphertext64, LweCiphertext32, LweCiphertext64,
+};
+use crate::commons::math::tensor::AsMutTensor;
+use crate::specification::engines::{
+    LweCiphertextGswCiphertextDiscardingExternalProductEngine,
+    LweCiphertextGswCiphertextDiscardingExternalProductError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextGswCiphertextDiscardingExternalProductEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextGswCiphertextDiscardingExternalProductEngine<
+        LweCiphertext32,
+        GswCiphertext32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let input_gsw = 1_u32;
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_lwe = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_gsw = engine.create_plaintext_from(&input_gsw)?;
+    /// let plaintext_lwe = engine.create_plaintext_from(&input_lwe)?;
+    ///
+    /// let gsw = engine.encrypt_scalar_gsw_ciphertext(&key, &plaintext_gsw, noise, level, base_log)?;
+    /// let lwe = engine.encrypt_lwe_ciphertext(&key, &plaintext_lwe, noise)?;
+    /// let mut product = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_compute_external_product_lwe_ciphertext_gsw_ciphertext(
+    ///     &lwe,
+    ///     &gsw,
+    ///     &mut product,
+    /// )?;
+    /// #
+    /// assert_eq!(product.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &LweCiphertext32,
+        gsw_input: &GswCiphertext32,
+        output: &mut LweCiphertext32,
+    ) -> Result<(), LweCiphertextGswCiphertextDiscardingExternalProductError<Self::EngineError>>
+    {
+        LweCiphertextGswCiphertextDiscardingExternalProductError::perform_generic_checks(
+            lwe_input, gsw_input, output,
+        )?;
+        unsafe {
+            self.discard_compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+                lwe_input, gsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_input: &LweCiphertext32,
+        gsw_input: &GswCiphertext32,
+        output: &mut LweCiphertext32,
+    ) {
+        output.0.as_mut_tensor().fill_with_element(0u32);
+        gsw_input.0.external_product(&mut output.0, &lwe_input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextGswCiphertextDiscardingExternalProductEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextGswCiphertextDiscardingExternalProductEngine<
+        LweCiphertext64,
+        GswCiphertext64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let input_gsw = 1_u64;
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_lwe = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_gsw = engine.create_plaintext_from(&input_gsw)?;
+    /// let plaintext_lwe = engine.create_plaintext_from(&input_lwe)?;
+    ///
+    /// let gsw = engine.encrypt_scalar_gsw_ciphertext(&key, &plaintext_gsw, noise, level, base_log)?;
+    /// let lwe = engine.encrypt_lwe_ciphertext(&key, &plaintext_lwe, noise)?;
+    /// let mut product = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_compute_external_product_lwe_ciphertext_gsw_ciphertext(
+    ///     &lwe,
+    ///     &gsw,
+    ///     &mut product,
+    /// )?;
+    /// #
+    /// assert_eq!(product.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &LweCiphertext64,
+        gsw_input: &GswCiphertext64,
+        output: &mut LweCiphertext64,
+    ) -> Result<(), LweCiphertextGswCiphertextDiscardingExternalProductError<Self::EngineError>>
+    {
+        LweCiphertextGswCiphertextDiscardingExternalProductError::perform_generic_checks(
+            lwe_input, gsw_input, output,
+        )?;
+        unsafe {
+            self.discard_compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+                lwe_input, gsw_input, output,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_input: &LweCiphertext64,
+        gsw_input: &GswCiphertext64,
+        output: &mut LweCiphertext64,
+    ) {
+        output.0.as_mut_tensor().fill_with_element(0u64);
+        gsw_input.0.external_product(&mut output.0, &lwe_input.0);
+    }
+}
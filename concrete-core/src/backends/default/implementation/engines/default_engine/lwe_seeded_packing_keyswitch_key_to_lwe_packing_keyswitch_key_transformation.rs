@@ -0,0 +1,208 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LwePackingKeyswitchKey32, LwePackingKeyswitchKey64, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey64,
+};
+use crate::commons::crypto::glwe::LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey;
+use crate::specification::engines::{
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine,
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationError,
+};
+use crate::specification::entities::LweSeededPackingKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine`]
+/// for [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine<
+        LweSeededPackingKeyswitchKey32,
+        LwePackingKeyswitchKey32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let packing_keyswitch_key: LwePackingKeyswitchKey32 = engine
+    ///     .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+    ///         seeded_packing_keyswitch_key,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(packing_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(
+    ///     packing_keyswitch_key.output_glwe_dimension(),
+    ///     output_glwe_dimension
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+        &mut self,
+        lwe_seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey32,
+    ) -> Result<
+        LwePackingKeyswitchKey32,
+        LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_unchecked(
+                lwe_seeded_packing_keyswitch_key,
+            )
+        })
+    }
+
+    unsafe fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_unchecked(
+        &mut self,
+        lwe_seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey32,
+    ) -> LwePackingKeyswitchKey32 {
+        let mut ksk = ImplLwePackingKeyswitchKey::allocate(
+            0u32,
+            lwe_seeded_packing_keyswitch_key.decomposition_level_count(),
+            lwe_seeded_packing_keyswitch_key.decomposition_base_log(),
+            lwe_seeded_packing_keyswitch_key.input_lwe_dimension(),
+            lwe_seeded_packing_keyswitch_key.output_glwe_dimension(),
+            lwe_seeded_packing_keyswitch_key.output_polynomial_size(),
+        );
+        lwe_seeded_packing_keyswitch_key
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut ksk);
+        LwePackingKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine`]
+/// for [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine<
+        LweSeededPackingKeyswitchKey64,
+        LwePackingKeyswitchKey64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let packing_keyswitch_key: LwePackingKeyswitchKey64 = engine
+    ///     .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+    ///         seeded_packing_keyswitch_key,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(packing_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(
+    ///     packing_keyswitch_key.output_glwe_dimension(),
+    ///     output_glwe_dimension
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+        &mut self,
+        lwe_seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey64,
+    ) -> Result<
+        LwePackingKeyswitchKey64,
+        LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_unchecked(
+                lwe_seeded_packing_keyswitch_key,
+            )
+        })
+    }
+
+    unsafe fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_unchecked(
+        &mut self,
+        lwe_seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey64,
+    ) -> LwePackingKeyswitchKey64 {
+        let mut ksk = ImplLwePackingKeyswitchKey::allocate(
+            0u64,
+            lwe_seeded_packing_keyswitch_key.decomposition_level_count(),
+            lwe_seeded_packing_keyswitch_key.decomposition_base_log(),
+            lwe_seeded_packing_keyswitch_key.input_lwe_dimension(),
+            lwe_seeded_packing_keyswitch_key.output_glwe_dimension(),
+            lwe_seeded_packing_keyswitch_key.output_polynomial_size(),
+        );
+        lwe_seeded_packing_keyswitch_key
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut ksk);
+        LwePackingKeyswitchKey64(ksk)
+    }
+}
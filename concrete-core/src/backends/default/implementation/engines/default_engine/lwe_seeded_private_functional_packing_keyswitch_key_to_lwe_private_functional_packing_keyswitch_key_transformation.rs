@@ -0,0 +1,207 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LwePrivateFunctionalPackingKeyswitchKey32, LwePrivateFunctionalPackingKeyswitchKey64,
+    LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::commons::crypto::glwe::LwePrivateFunctionalPackingKeyswitchKey as ImplLwePrivateFunctionalPackingKeyswitchKey;
+use crate::specification::engines::{
+    LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationError,
+};
+use crate::specification::entities::LweSeededPrivateFunctionalPackingKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of
+/// [`LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine`]
+/// for [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine<
+        LweSeededPrivateFunctionalPackingKeyswitchKey32,
+        LwePrivateFunctionalPackingKeyswitchKey32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize, StandardDev, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = StandardDev(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u32; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector32 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk = engine.generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    ///     &|x| x,
+    ///     &polynomial,
+    /// )?;
+    ///
+    /// let pfpksk: LwePrivateFunctionalPackingKeyswitchKey32 = engine
+    ///     .transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+    ///         seeded_pfpksk,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    /// #     pfpksk.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(pfpksk.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(pfpksk.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(pfpksk.output_glwe_dimension(), output_glwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+        &mut self,
+        lwe_seeded_private_functional_packing_keyswitch_key: LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    ) -> Result<
+        LwePrivateFunctionalPackingKeyswitchKey32,
+        LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_unchecked(
+                lwe_seeded_private_functional_packing_keyswitch_key,
+            )
+        })
+    }
+
+    unsafe fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_unchecked(
+        &mut self,
+        lwe_seeded_private_functional_packing_keyswitch_key: LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    ) -> LwePrivateFunctionalPackingKeyswitchKey32 {
+        let mut pfpksk = ImplLwePrivateFunctionalPackingKeyswitchKey::allocate(
+            0u32,
+            lwe_seeded_private_functional_packing_keyswitch_key.decomposition_level_count(),
+            lwe_seeded_private_functional_packing_keyswitch_key.decomposition_base_log(),
+            lwe_seeded_private_functional_packing_keyswitch_key.input_lwe_dimension(),
+            lwe_seeded_private_functional_packing_keyswitch_key.output_glwe_dimension(),
+            lwe_seeded_private_functional_packing_keyswitch_key.output_polynomial_size(),
+        );
+        lwe_seeded_private_functional_packing_keyswitch_key
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut pfpksk);
+        LwePrivateFunctionalPackingKeyswitchKey32(pfpksk)
+    }
+}
+
+/// # Description:
+/// Implementation of
+/// [`LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine`]
+/// for [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine<
+        LweSeededPrivateFunctionalPackingKeyswitchKey64,
+        LwePrivateFunctionalPackingKeyswitchKey64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize, StandardDev, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = StandardDev(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u64; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector64 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk = engine.generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    ///     &|x| x,
+    ///     &polynomial,
+    /// )?;
+    ///
+    /// let pfpksk: LwePrivateFunctionalPackingKeyswitchKey64 = engine
+    ///     .transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+    ///         seeded_pfpksk,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    /// #     pfpksk.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(pfpksk.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(pfpksk.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(pfpksk.output_glwe_dimension(), output_glwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+        &mut self,
+        lwe_seeded_private_functional_packing_keyswitch_key: LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    ) -> Result<
+        LwePrivateFunctionalPackingKeyswitchKey64,
+        LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_unchecked(
+                lwe_seeded_private_functional_packing_keyswitch_key,
+            )
+        })
+    }
+
+    unsafe fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_unchecked(
+        &mut self,
+        lwe_seeded_private_functional_packing_keyswitch_key: LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    ) -> LwePrivateFunctionalPackingKeyswitchKey64 {
+        let mut pfpksk = ImplLwePrivateFunctionalPackingKeyswitchKey::allocate(
+            0u64,
+            lwe_seeded_private_functional_packing_keyswitch_key.decomposition_level_count(),
+            lwe_seeded_private_functional_packing_keyswitch_key.decomposition_base_log(),
+            lwe_seeded_private_functional_packing_keyswitch_key.input_lwe_dimension(),
+            lwe_seeded_private_functional_packing_keyswitch_key.output_glwe_dimension(),
+            lwe_seeded_private_functional_packing_keyswitch_key.output_polynomial_size(),
+        );
+        lwe_seeded_private_functional_packing_keyswitch_key
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut pfpksk);
+        LwePrivateFunctionalPackingKeyswitchKey64(pfpksk)
+    }
+}
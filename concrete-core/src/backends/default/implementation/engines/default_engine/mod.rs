@@ -98,34 +98,65 @@ mod cleartext_vector_encoding;
 mod cleartext_vector_retrieval;
 mod encoder_creation;
 mod encoder_vector_creation;
+mod ggsw_ciphertext_polynomial_discarding_encryption;
+mod ggsw_ciphertext_polynomial_encryption;
+mod ggsw_ciphertext_polynomial_trivial_encryption;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_vector_creation;
+mod ggsw_ciphertext_vector_scalar_encryption;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_addition;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_keyswitch;
+mod glwe_ciphertext_discarding_monomial_multiplication;
+mod glwe_ciphertext_discarding_multiplication;
+mod glwe_ciphertext_discarding_opposite;
+mod glwe_ciphertext_discarding_subtraction;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_addition;
+mod glwe_ciphertext_fusing_monomial_multiplication;
+mod glwe_ciphertext_fusing_opposite;
+mod glwe_ciphertext_fusing_subtraction;
+mod glwe_ciphertext_multiplication;
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+mod glwe_ciphertext_plaintext_vector_discarding_multiplication;
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
+mod glwe_ciphertext_plaintext_vector_fusing_multiplication;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_consuming_retrieval;
 mod glwe_ciphertext_vector_creation;
 mod glwe_ciphertext_vector_decryption;
+mod glwe_ciphertext_vector_discarding_addition;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
+mod glwe_ciphertext_vector_discarding_opposite;
+mod glwe_ciphertext_vector_discarding_subtraction;
 mod glwe_ciphertext_vector_encryption;
+mod glwe_ciphertext_vector_fusing_addition;
+mod glwe_ciphertext_vector_fusing_opposite;
+mod glwe_ciphertext_vector_fusing_subtraction;
 mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_keyswitch_key_generation;
+mod glwe_relinearization_key_generation;
 mod glwe_secret_key_generation;
 mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
 mod glwe_seeded_ciphertext_vector_encryption;
+mod glwe_seeded_keyswitch_key_generation;
+mod glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation;
 mod glwe_seeded_vector_to_glwe_ciphertext_vector_transformation;
 mod glwe_to_lwe_secret_key_transformation;
+mod gsw_ciphertext_scalar_encryption;
 mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_creation;
 mod lwe_bootstrap_key_discarding_conversion;
@@ -147,6 +178,7 @@ mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gsw_ciphertext_discarding_external_product;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
@@ -172,11 +204,13 @@ mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
 mod lwe_keyswitch_key_consuming_retrieval;
 mod lwe_keyswitch_key_creation;
 mod lwe_keyswitch_key_discarding_conversion;
 mod lwe_keyswitch_key_generation;
+mod lwe_multi_bit_bootstrap_key_generation;
 mod lwe_packing_keyswitch_key_generation;
 mod lwe_private_functional_packing_keyswitch_key_generation;
 mod lwe_public_key_generation;
@@ -186,8 +220,16 @@ mod lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation;
 mod lwe_seeded_ciphertext_encryption;
 mod lwe_seeded_ciphertext_vector_encryption;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation;
 mod lwe_seeded_keyswitch_key_generation;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
+mod lwe_seeded_packing_keyswitch_key_generation;
+mod lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_generation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation;
+mod lwe_seeded_public_key_generation;
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
 mod lwe_seeded_to_lwe_ciphertext_transformation;
 mod lwe_to_glwe_secret_key_transformation;
 mod plaintext_creation;
@@ -198,3 +240,4 @@ mod plaintext_vector_creation;
 mod plaintext_vector_decoding;
 mod plaintext_vector_discarding_retrieval;
 mod plaintext_vector_retrieval;
+mod plaintext_vector_wop_pbs_lut_generation;
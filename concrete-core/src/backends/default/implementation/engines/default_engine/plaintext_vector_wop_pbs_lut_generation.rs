@@ -0,0 +1,199 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{PlaintextVector32, PlaintextVector64};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::commons::numeric::{CastFrom, UnsignedInteger};
+use crate::prelude::{CiphertextModulusLog, ExtractedBitsCount};
+use crate::specification::engines::{
+    PlaintextVectorWopPbsLutGenerationEngine, PlaintextVectorWopPbsLutGenerationError,
+};
+
+// Fills the small look-up tables of each output block with the encoded values of `f`. Returns
+// `None` if `f` does not return one value per output block.
+fn generate_wop_pbs_luts<Scalar, F>(
+    input_bits_counts: &[ExtractedBitsCount],
+    output_bits_counts: &[ExtractedBitsCount],
+    f: F,
+) -> Option<Vec<Scalar>>
+where
+    Scalar: UnsignedInteger + CastFrom<u64>,
+    F: Fn(&[u64]) -> Vec<u64>,
+{
+    let total_input_bits: usize = input_bits_counts.iter().map(|bits| bits.0).sum();
+    let small_lut_size = 1_usize << total_input_bits;
+    let mut luts = vec![Scalar::ZERO; small_lut_size * output_bits_counts.len()];
+    let mut input_values = vec![0_u64; input_bits_counts.len()];
+
+    for index in 0..small_lut_size {
+        // The bits of the first block are the most significant bits of the index
+        let mut shift = total_input_bits;
+        for (value, bits) in input_values.iter_mut().zip(input_bits_counts.iter()) {
+            shift -= bits.0;
+            *value = ((index >> shift) & ((1 << bits.0) - 1)) as u64;
+        }
+
+        let output_values = f(&input_values);
+        if output_values.len() != output_bits_counts.len() {
+            return None;
+        }
+        for (small_lut, (&value, bits)) in luts
+            .chunks_exact_mut(small_lut_size)
+            .zip(output_values.iter().zip(output_bits_counts.iter()))
+        {
+            let value = value & (u64::MAX >> (u64::BITS as usize - bits.0));
+            small_lut[index] = Scalar::cast_from(value) << (Scalar::BITS - bits.0);
+        }
+    }
+    Some(luts)
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorWopPbsLutGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl PlaintextVectorWopPbsLutGenerationEngine<PlaintextVector32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{ExtractedBitsCount, PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // We consider an integer of 4 bits in radix representation, with two blocks of 2 bits,
+    /// // the first block holding the most significant digit.
+    /// let input_bits_counts = vec![ExtractedBitsCount(2); 2];
+    /// let output_bits_counts = vec![ExtractedBitsCount(2); 2];
+    /// // The function to evaluate increments the integer, modulo 16.
+    /// let increment = |digits: &[u64]| {
+    ///     let value = (digits[0] << 2) + digits[1];
+    ///     let result = (value + 1) % 16;
+    ///     vec![result >> 2, result & 3]
+    /// };
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let luts: PlaintextVector32 = engine.generate_wop_pbs_lut_plaintext_vector(
+    ///     &input_bits_counts,
+    ///     &output_bits_counts,
+    ///     increment,
+    /// )?;
+    /// #
+    /// // One small look-up table of 16 values per output block
+    /// assert_eq!(luts.plaintext_count(), PlaintextCount(32));
+    /// let raw_luts = engine.retrieve_plaintext_vector(&luts)?;
+    /// // The integer 7 is represented by the digits (1, 3), 7 + 1 = 8 by the digits (2, 0)
+    /// assert_eq!(raw_luts[7], 2 << (32 - 2));
+    /// assert_eq!(raw_luts[16 + 7], 0);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_wop_pbs_lut_plaintext_vector<F>(
+        &mut self,
+        input_bits_counts: &[ExtractedBitsCount],
+        output_bits_counts: &[ExtractedBitsCount],
+        f: F,
+    ) -> Result<PlaintextVector32, PlaintextVectorWopPbsLutGenerationError<Self::EngineError>>
+    where
+        F: Fn(&[u64]) -> Vec<u64>,
+    {
+        PlaintextVectorWopPbsLutGenerationError::perform_generic_checks(
+            input_bits_counts,
+            output_bits_counts,
+            CiphertextModulusLog(32),
+        )?;
+        let luts = generate_wop_pbs_luts::<u32, _>(input_bits_counts, output_bits_counts, f)
+            .ok_or(PlaintextVectorWopPbsLutGenerationError::FunctionOutputCountMismatch)?;
+        Ok(PlaintextVector32(ImplPlaintextList::from_container(luts)))
+    }
+
+    unsafe fn generate_wop_pbs_lut_plaintext_vector_unchecked<F>(
+        &mut self,
+        input_bits_counts: &[ExtractedBitsCount],
+        output_bits_counts: &[ExtractedBitsCount],
+        f: F,
+    ) -> PlaintextVector32
+    where
+        F: Fn(&[u64]) -> Vec<u64>,
+    {
+        let luts =
+            generate_wop_pbs_luts::<u32, _>(input_bits_counts, output_bits_counts, f).unwrap();
+        PlaintextVector32(ImplPlaintextList::from_container(luts))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorWopPbsLutGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl PlaintextVectorWopPbsLutGenerationEngine<PlaintextVector64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{ExtractedBitsCount, PlaintextCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // We consider an integer of 4 bits in radix representation, with two blocks of 2 bits,
+    /// // the first block holding the most significant digit.
+    /// let input_bits_counts = vec![ExtractedBitsCount(2); 2];
+    /// let output_bits_counts = vec![ExtractedBitsCount(2); 2];
+    /// // The function to evaluate increments the integer, modulo 16.
+    /// let increment = |digits: &[u64]| {
+    ///     let value = (digits[0] << 2) + digits[1];
+    ///     let result = (value + 1) % 16;
+    ///     vec![result >> 2, result & 3]
+    /// };
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let luts: PlaintextVector64 = engine.generate_wop_pbs_lut_plaintext_vector(
+    ///     &input_bits_counts,
+    ///     &output_bits_counts,
+    ///     increment,
+    /// )?;
+    /// #
+    /// // One small look-up table of 16 values per output block
+    /// assert_eq!(luts.plaintext_count(), PlaintextCount(32));
+    /// let raw_luts = engine.retrieve_plaintext_vector(&luts)?;
+    /// // The integer 7 is represented by the digits (1, 3), 7 + 1 = 8 by the digits (2, 0)
+    /// assert_eq!(raw_luts[7], 2 << (64 - 2));
+    /// assert_eq!(raw_luts[16 + 7], 0);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_wop_pbs_lut_plaintext_vector<F>(
+        &mut self,
+        input_bits_counts: &[ExtractedBitsCount],
+        output_bits_counts: &[ExtractedBitsCount],
+        f: F,
+    ) -> Result<PlaintextVector64, PlaintextVectorWopPbsLutGenerationError<Self::EngineError>>
+    where
+        F: Fn(&[u64]) -> Vec<u64>,
+    {
+        PlaintextVectorWopPbsLutGenerationError::perform_generic_checks(
+            input_bits_counts,
+            output_bits_counts,
+            CiphertextModulusLog(64),
+        )?;
+        let luts = generate_wop_pbs_luts::<u64, _>(input_bits_counts, output_bits_counts, f)
+            .ok_or(PlaintextVectorWopPbsLutGenerationError::FunctionOutputCountMismatch)?;
+        Ok(PlaintextVector64(ImplPlaintextList::from_container(luts)))
+    }
+
+    unsafe fn generate_wop_pbs_lut_plaintext_vector_unchecked<F>(
+        &mut self,
+        input_bits_counts: &[ExtractedBitsCount],
+        output_bits_counts: &[ExtractedBitsCount],
+        f: F,
+    ) -> PlaintextVector64
+    where
+        F: Fn(&[u64]) -> Vec<u64>,
+    {
+        let luts =
+            generate_wop_pbs_luts::<u64, _>(input_bits_counts, output_bits_counts, f).unwrap();
+        PlaintextVector64(ImplPlaintextList::from_container(luts))
+    }
+}
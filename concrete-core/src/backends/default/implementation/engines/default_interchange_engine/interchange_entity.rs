@@ -0,0 +1,299 @@
+use super::InterchangeEntityKind;
+use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::commons::crypto::glwe::{
+    GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
+};
+use crate::commons::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
+    LweList as ImplLweList,
+};
+use crate::commons::crypto::secret::{
+    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+};
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextEntity, GlweCiphertextMutView32, GlweCiphertextMutView64, GlweCiphertextVector32,
+    GlweCiphertextVector64, GlweCiphertextVectorEntity, GlweCiphertextVectorMutView32,
+    GlweCiphertextVectorMutView64, GlweCiphertextVectorView32, GlweCiphertextVectorView64,
+    GlweCiphertextView32, GlweCiphertextView64, GlweDimension, GlweSecretKey32, GlweSecretKey64,
+    GlweSecretKeyEntity, GlweSize, LweBootstrapKey32, LweBootstrapKey64, LweBootstrapKeyEntity,
+    LweCiphertext32, LweCiphertext64, LweCiphertextEntity, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorEntity, LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView32, LweCiphertextVectorView64, LweCiphertextView32, LweCiphertextView64,
+    LweDimension, LweKeyswitchKey32, LweKeyswitchKey64, LweKeyswitchKeyEntity, LweSecretKey32,
+    LweSecretKey64, LweSecretKeyEntity, LweSize, PolynomialSize,
+};
+
+/// A trait implemented by the scalars of the bodies of the encoded entities.
+pub(crate) trait InterchangeScalar: Copy {
+    const WIDTH: u8;
+
+    fn extend_le_bytes(&self, bytes: &mut Vec<u8>);
+
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+
+    fn is_binary(&self) -> bool;
+}
+
+macro_rules! impl_interchange_scalar {
+    ($($scalar: ty),+) => {
+        $(
+            impl InterchangeScalar for $scalar {
+                const WIDTH: u8 = std::mem::size_of::<$scalar>() as u8;
+
+                fn extend_le_bytes(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    <$scalar>::from_le_bytes(bytes.try_into().unwrap())
+                }
+
+                fn is_binary(&self) -> bool {
+                    *self <= 1
+                }
+            }
+        )+
+    };
+}
+
+impl_interchange_scalar!(u32, u64);
+
+/// A trait implemented by the entities which can be encoded in the interchange format.
+pub(crate) trait InterchangeEntity {
+    type Scalar: InterchangeScalar;
+    const KIND: InterchangeEntityKind;
+
+    /// Returns the parameters of the entity, in the order of the format.
+    fn interchange_parameters(&self) -> Vec<u64>;
+
+    /// Returns the scalars of the body of the entity.
+    fn interchange_body(&self) -> &[Self::Scalar];
+}
+
+/// A trait implemented by the entities which can be decoded from the interchange format.
+pub(crate) trait FromInterchange: InterchangeEntity + Sized {
+    /// Builds the entity from validated dimensions and body.
+    fn from_interchange(dimensions: &[usize], body: Vec<Self::Scalar>) -> Self;
+}
+
+/// Implements [`InterchangeEntity`] for a list of entity types sharing the same kind, parameters
+/// and body. The parameters and the body are computed by expressions of the entity.
+macro_rules! impl_interchange_entity {
+    (
+        $kind: ident,
+        |$entity: ident| $parameters: expr,
+        |$body_entity: ident| $body: expr,
+        $($entity_type: ty => $scalar: ty),+ $(,)?
+    ) => {
+        $(
+            impl InterchangeEntity for $entity_type {
+                type Scalar = $scalar;
+                const KIND: InterchangeEntityKind = InterchangeEntityKind::$kind;
+
+                fn interchange_parameters(&self) -> Vec<u64> {
+                    let $entity = self;
+                    $parameters
+                }
+
+                fn interchange_body(&self) -> &[$scalar] {
+                    let $body_entity = self;
+                    $body
+                }
+            }
+        )+
+    };
+}
+
+/// Implements [`FromInterchange`] for a list of owned entity types, whose inner value is built by
+/// an expression of the dimensions and the body.
+macro_rules! impl_from_interchange {
+    (
+        |$dimensions: ident, $body: ident| $inner: expr,
+        $($entity_type: ident),+ $(,)?
+    ) => {
+        $(
+            impl FromInterchange for $entity_type {
+                #[allow(unused_variables)]
+                fn from_interchange(
+                    $dimensions: &[usize],
+                    $body: Vec<<Self as InterchangeEntity>::Scalar>,
+                ) -> Self {
+                    $entity_type($inner)
+                }
+            }
+        )+
+    };
+}
+
+impl_interchange_entity!(
+    LweCiphertext,
+    |entity| vec![entity.lwe_dimension().0 as u64],
+    |entity| entity.0.as_tensor().as_slice(),
+    LweCiphertext32 => u32,
+    LweCiphertext64 => u64,
+    LweCiphertextView32<'_> => u32,
+    LweCiphertextView64<'_> => u64,
+    LweCiphertextMutView32<'_> => u32,
+    LweCiphertextMutView64<'_> => u64,
+);
+
+impl_from_interchange!(
+    |dimensions, body| ImplLweCiphertext::from_container(body),
+    LweCiphertext32,
+    LweCiphertext64,
+);
+
+impl_interchange_entity!(
+    LweCiphertextVector,
+    |entity| vec![
+        entity.lwe_dimension().0 as u64,
+        entity.lwe_ciphertext_count().0 as u64,
+    ],
+    |entity| entity.0.as_tensor().as_slice(),
+    LweCiphertextVector32 => u32,
+    LweCiphertextVector64 => u64,
+    LweCiphertextVectorView32<'_> => u32,
+    LweCiphertextVectorView64<'_> => u64,
+    LweCiphertextVectorMutView32<'_> => u32,
+    LweCiphertextVectorMutView64<'_> => u64,
+);
+
+impl_from_interchange!(
+    |dimensions, body| ImplLweList::from_container(body, LweSize(dimensions[0] + 1)),
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+);
+
+impl_interchange_entity!(
+    GlweCiphertext,
+    |entity| vec![
+        entity.glwe_dimension().0 as u64,
+        entity.polynomial_size().0 as u64,
+    ],
+    |entity| entity.0.as_tensor().as_slice(),
+    GlweCiphertext32 => u32,
+    GlweCiphertext64 => u64,
+    GlweCiphertextView32<'_> => u32,
+    GlweCiphertextView64<'_> => u64,
+    GlweCiphertextMutView32<'_> => u32,
+    GlweCiphertextMutView64<'_> => u64,
+);
+
+impl_from_interchange!(
+    |dimensions, body| ImplGlweCiphertext::from_container(body, PolynomialSize(dimensions[1]),),
+    GlweCiphertext32,
+    GlweCiphertext64,
+);
+
+impl_interchange_entity!(
+    GlweCiphertextVector,
+    |entity| vec![
+        entity.glwe_dimension().0 as u64,
+        entity.polynomial_size().0 as u64,
+        entity.glwe_ciphertext_count().0 as u64,
+    ],
+    |entity| entity.0.as_tensor().as_slice(),
+    GlweCiphertextVector32 => u32,
+    GlweCiphertextVector64 => u64,
+    GlweCiphertextVectorView32<'_> => u32,
+    GlweCiphertextVectorView64<'_> => u64,
+    GlweCiphertextVectorMutView32<'_> => u32,
+    GlweCiphertextVectorMutView64<'_> => u64,
+);
+
+impl_from_interchange!(
+    |dimensions, body| ImplGlweList::from_container(
+        body,
+        GlweDimension(dimensions[0]),
+        PolynomialSize(dimensions[1]),
+    ),
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+);
+
+impl_interchange_entity!(
+    LweSecretKey,
+    |entity| vec![entity.lwe_dimension().0 as u64],
+    |entity| entity.0.as_tensor().as_slice(),
+    LweSecretKey32 => u32,
+    LweSecretKey64 => u64,
+);
+
+impl_from_interchange!(
+    |dimensions, body| ImplLweSecretKey::binary_from_container(body),
+    LweSecretKey32,
+    LweSecretKey64,
+);
+
+impl_interchange_entity!(
+    GlweSecretKey,
+    |entity| vec![
+        entity.glwe_dimension().0 as u64,
+        entity.polynomial_size().0 as u64,
+    ],
+    |entity| entity.0.as_tensor().as_slice(),
+    GlweSecretKey32 => u32,
+    GlweSecretKey64 => u64,
+);
+
+impl_from_interchange!(
+    |dimensions, body| ImplGlweSecretKey::binary_from_container(
+        body,
+        PolynomialSize(dimensions[1]),
+    ),
+    GlweSecretKey32,
+    GlweSecretKey64,
+);
+
+impl_interchange_entity!(
+    LweKeyswitchKey,
+    |entity| vec![
+        entity.input_lwe_dimension().0 as u64,
+        entity.output_lwe_dimension().0 as u64,
+        entity.decomposition_level_count().0 as u64,
+        entity.decomposition_base_log().0 as u64,
+    ],
+    |entity| entity.0.as_tensor().as_slice(),
+    LweKeyswitchKey32 => u32,
+    LweKeyswitchKey64 => u64,
+);
+
+impl_from_interchange!(
+    |dimensions, body| ImplLweKeyswitchKey::from_container(
+        body,
+        DecompositionBaseLog(dimensions[3]),
+        DecompositionLevelCount(dimensions[2]),
+        LweDimension(dimensions[1]),
+    ),
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+);
+
+impl_interchange_entity!(
+    LweBootstrapKey,
+    |entity| vec![
+        entity.input_lwe_dimension().0 as u64,
+        entity.glwe_dimension().0 as u64,
+        entity.polynomial_size().0 as u64,
+        entity.decomposition_level_count().0 as u64,
+        entity.decomposition_base_log().0 as u64,
+    ],
+    |entity| entity.0.as_tensor().as_slice(),
+    LweBootstrapKey32 => u32,
+    LweBootstrapKey64 => u64,
+);
+
+impl_from_interchange!(
+    |dimensions, body| ImplStandardBootstrapKey::from_container(
+        body,
+        GlweSize(dimensions[1] + 1),
+        PolynomialSize(dimensions[2]),
+        DecompositionLevelCount(dimensions[3]),
+        DecompositionBaseLog(dimensions[4]),
+    ),
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+);
@@ -0,0 +1,328 @@
+use crate::prelude::sealed::AbstractEngineSeal;
+use crate::prelude::AbstractEngine;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The magic bytes starting every entity encoded in the interchange format.
+pub const INTERCHANGE_MAGIC: [u8; 4] = *b"CCIX";
+
+/// The current version of the interchange format.
+pub const INTERCHANGE_FORMAT_VERSION: u16 = 1;
+
+// The size in bytes of the fixed part of the interchange header, preceding the parameters.
+const FIXED_HEADER_SIZE: usize = 16;
+
+/// The kind of an entity encoded in the interchange format.
+///
+/// Each kind is encoded by a stable code, which never changes once assigned, and determines the
+/// parameters stored in the header and the layout of the body. The views over an entity are
+/// encoded with the same kind as the owned entity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InterchangeEntityKind {
+    LweCiphertext,
+    LweCiphertextVector,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    LweSecretKey,
+    GlweSecretKey,
+    LweKeyswitchKey,
+    LweBootstrapKey,
+}
+
+impl InterchangeEntityKind {
+    /// Returns the code encoding the kind in the interchange format.
+    pub fn code(&self) -> u16 {
+        match self {
+            InterchangeEntityKind::LweCiphertext => 0x0001,
+            InterchangeEntityKind::LweCiphertextVector => 0x0002,
+            InterchangeEntityKind::GlweCiphertext => 0x0010,
+            InterchangeEntityKind::GlweCiphertextVector => 0x0011,
+            InterchangeEntityKind::LweSecretKey => 0x0020,
+            InterchangeEntityKind::GlweSecretKey => 0x0021,
+            InterchangeEntityKind::LweKeyswitchKey => 0x0030,
+            InterchangeEntityKind::LweBootstrapKey => 0x0040,
+        }
+    }
+
+    // The number of parameters stored in the header, which are all dimensions, and must
+    // therefore be non-zero.
+    fn parameter_count(&self) -> usize {
+        match self {
+            InterchangeEntityKind::LweCiphertext | InterchangeEntityKind::LweSecretKey => 1,
+            InterchangeEntityKind::LweCiphertextVector
+            | InterchangeEntityKind::GlweCiphertext
+            | InterchangeEntityKind::GlweSecretKey => 2,
+            InterchangeEntityKind::GlweCiphertextVector => 3,
+            InterchangeEntityKind::LweKeyswitchKey => 4,
+            InterchangeEntityKind::LweBootstrapKey => 5,
+        }
+    }
+
+    fn is_secret_key(&self) -> bool {
+        matches!(
+            self,
+            InterchangeEntityKind::LweSecretKey | InterchangeEntityKind::GlweSecretKey
+        )
+    }
+
+    // The decomposition level count and base log of the keys, if any.
+    fn decomposition(&self, dimensions: &[usize]) -> Option<(usize, usize)> {
+        match self {
+            InterchangeEntityKind::LweKeyswitchKey => Some((dimensions[2], dimensions[3])),
+            InterchangeEntityKind::LweBootstrapKey => Some((dimensions[3], dimensions[4])),
+            _ => None,
+        }
+    }
+
+    // The number of scalars of the body, or `None` if it overflows.
+    fn body_length(&self, dimensions: &[usize]) -> Option<usize> {
+        let product = |factors: &[usize]| {
+            factors
+                .iter()
+                .try_fold(1usize, |acc, factor| acc.checked_mul(*factor))
+        };
+        match self {
+            InterchangeEntityKind::LweCiphertext => dimensions[0].checked_add(1),
+            InterchangeEntityKind::LweCiphertextVector => {
+                product(&[dimensions[0].checked_add(1)?, dimensions[1]])
+            }
+            InterchangeEntityKind::GlweCiphertext => {
+                product(&[dimensions[0].checked_add(1)?, dimensions[1]])
+            }
+            InterchangeEntityKind::GlweCiphertextVector => {
+                product(&[dimensions[0].checked_add(1)?, dimensions[1], dimensions[2]])
+            }
+            InterchangeEntityKind::LweSecretKey => Some(dimensions[0]),
+            InterchangeEntityKind::GlweSecretKey => product(&[dimensions[0], dimensions[1]]),
+            InterchangeEntityKind::LweKeyswitchKey => {
+                product(&[dimensions[0], dimensions[2], dimensions[1].checked_add(1)?])
+            }
+            InterchangeEntityKind::LweBootstrapKey => {
+                let glwe_size = dimensions[1].checked_add(1)?;
+                product(&[
+                    dimensions[0],
+                    dimensions[3],
+                    glwe_size,
+                    glwe_size,
+                    dimensions[2],
+                ])
+            }
+        }
+    }
+}
+
+impl Display for InterchangeEntityKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The error which can occur in the executions of the `DefaultInterchangeEngine` operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefaultInterchangeError {
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    UnexpectedEntity {
+        expected: InterchangeEntityKind,
+        found: u16,
+    },
+    UnexpectedScalarWidth {
+        expected: u8,
+        found: u8,
+    },
+    InvalidParameters,
+    InvalidSize {
+        expected: usize,
+        found: usize,
+    },
+    NonBinarySecretKey,
+}
+
+impl Display for DefaultInterchangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DefaultInterchangeError::InvalidMagic => {
+                write!(
+                    f,
+                    "The input does not start with the interchange magic bytes."
+                )
+            }
+            DefaultInterchangeError::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "The interchange format version {} is not supported.",
+                    version
+                )
+            }
+            DefaultInterchangeError::UnexpectedEntity { expected, found } => write!(
+                f,
+                "Expected an encoded {} (code {:#06x}), found the code {:#06x}.",
+                expected,
+                expected.code(),
+                found
+            ),
+            DefaultInterchangeError::UnexpectedScalarWidth { expected, found } => write!(
+                f,
+                "Expected scalars of {} bytes, found scalars of {} bytes.",
+                expected, found
+            ),
+            DefaultInterchangeError::InvalidParameters => {
+                write!(f, "The parameters of the encoded entity are invalid.")
+            }
+            DefaultInterchangeError::InvalidSize { expected, found } => write!(
+                f,
+                "Expected an encoded entity of {} bytes, found {} bytes.",
+                expected, found
+            ),
+            DefaultInterchangeError::NonBinarySecretKey => {
+                write!(f, "The encoded secret key contains non-binary values.")
+            }
+        }
+    }
+}
+
+impl Error for DefaultInterchangeError {}
+
+/// The engine encoding entities in, and decoding entities from, the interchange format.
+///
+/// Contrary to the [`DefaultSerializationEngine`](super::DefaultSerializationEngine), whose
+/// payloads are tied to the Rust representation of the entities, the interchange format is a
+/// stable and language-neutral binary layout, which is meant to be produced and consumed by
+/// clients written in other languages.
+///
+/// The layout of the encoded entities, and the code, parameters and body of each
+/// [`InterchangeEntityKind`], are specified in the
+/// [Interchange Format](https://docs.zama.ai/concrete-core) chapter of the user documentation,
+/// whose source lives in `concrete-core/docs/general_concepts/interchange_format.md`. Golden
+/// vectors of the format are checked in under `concrete-core/test_vectors/interchange`.
+pub struct DefaultInterchangeEngine;
+
+impl AbstractEngineSeal for DefaultInterchangeEngine {}
+
+impl AbstractEngine for DefaultInterchangeEngine {
+    type EngineError = DefaultInterchangeError;
+    type Parameters = ();
+
+    fn new(_parameter: Self::Parameters) -> Result<Self, Self::EngineError>
+    where
+        Self: Sized,
+    {
+        Ok(DefaultInterchangeEngine)
+    }
+}
+
+// Encodes an entity in the interchange format.
+fn encode<Entity: InterchangeEntity>(entity: &Entity) -> Vec<u8> {
+    let parameters = entity.interchange_parameters();
+    let body = entity.interchange_body();
+    let width = <Entity::Scalar as InterchangeScalar>::WIDTH;
+    let mut encoded = Vec::with_capacity(
+        FIXED_HEADER_SIZE + 8 * parameters.len() + 8 + width as usize * body.len(),
+    );
+    encoded.extend_from_slice(&INTERCHANGE_MAGIC);
+    encoded.extend_from_slice(&INTERCHANGE_FORMAT_VERSION.to_le_bytes());
+    encoded.extend_from_slice(&Entity::KIND.code().to_le_bytes());
+    encoded.push(width);
+    encoded.extend_from_slice(&[0u8; 3]);
+    encoded.extend_from_slice(&(parameters.len() as u32).to_le_bytes());
+    for parameter in parameters.iter() {
+        encoded.extend_from_slice(&parameter.to_le_bytes());
+    }
+    encoded.extend_from_slice(&(body.len() as u64).to_le_bytes());
+    for scalar in body.iter() {
+        scalar.extend_le_bytes(&mut encoded);
+    }
+    encoded
+}
+
+// Decodes an entity from the interchange format, after validating its header, its parameters
+// and its size.
+fn decode<Entity: FromInterchange>(encoded: &[u8]) -> Result<Entity, DefaultInterchangeError> {
+    let kind = Entity::KIND;
+    let width = <Entity::Scalar as InterchangeScalar>::WIDTH;
+    let check_size = |expected: usize| {
+        if encoded.len() < expected {
+            Err(DefaultInterchangeError::InvalidSize {
+                expected,
+                found: encoded.len(),
+            })
+        } else {
+            Ok(())
+        }
+    };
+    let read_u64 =
+        |offset: usize| u64::from_le_bytes(encoded[offset..offset + 8].try_into().unwrap());
+
+    check_size(FIXED_HEADER_SIZE)?;
+    if encoded[0..4] != INTERCHANGE_MAGIC {
+        return Err(DefaultInterchangeError::InvalidMagic);
+    }
+    let version = u16::from_le_bytes([encoded[4], encoded[5]]);
+    if version != INTERCHANGE_FORMAT_VERSION {
+        return Err(DefaultInterchangeError::UnsupportedVersion(version));
+    }
+    let code = u16::from_le_bytes([encoded[6], encoded[7]]);
+    if code != kind.code() {
+        return Err(DefaultInterchangeError::UnexpectedEntity {
+            expected: kind,
+            found: code,
+        });
+    }
+    if encoded[8] != width {
+        return Err(DefaultInterchangeError::UnexpectedScalarWidth {
+            expected: width,
+            found: encoded[8],
+        });
+    }
+    let parameter_count = u32::from_le_bytes(encoded[12..16].try_into().unwrap()) as usize;
+    if encoded[9..12] != [0u8; 3] || parameter_count != kind.parameter_count() {
+        return Err(DefaultInterchangeError::InvalidParameters);
+    }
+
+    let body_offset = FIXED_HEADER_SIZE + 8 * parameter_count + 8;
+    check_size(body_offset)?;
+    let dimensions = (0..parameter_count)
+        .map(|i| usize::try_from(read_u64(FIXED_HEADER_SIZE + 8 * i)).ok())
+        .map(|dimension| dimension.filter(|d| *d != 0))
+        .collect::<Option<Vec<usize>>>()
+        .ok_or(DefaultInterchangeError::InvalidParameters)?;
+    if let Some((level_count, base_log)) = kind.decomposition(&dimensions) {
+        match level_count.checked_mul(base_log) {
+            Some(bits) if bits <= 8 * width as usize => {}
+            _ => return Err(DefaultInterchangeError::InvalidParameters),
+        }
+    }
+    let body_length = kind
+        .body_length(&dimensions)
+        .ok_or(DefaultInterchangeError::InvalidParameters)?;
+    if read_u64(body_offset - 8) != body_length as u64 {
+        return Err(DefaultInterchangeError::InvalidParameters);
+    }
+    let expected = body_length
+        .checked_mul(width as usize)
+        .and_then(|body_size| body_size.checked_add(body_offset))
+        .ok_or(DefaultInterchangeError::InvalidParameters)?;
+    if encoded.len() != expected {
+        return Err(DefaultInterchangeError::InvalidSize {
+            expected,
+            found: encoded.len(),
+        });
+    }
+
+    let body: Vec<Entity::Scalar> = encoded[body_offset..]
+        .chunks_exact(width as usize)
+        .map(InterchangeScalar::from_le_bytes)
+        .collect();
+    if kind.is_secret_key() && body.iter().any(|scalar| !scalar.is_binary()) {
+        return Err(DefaultInterchangeError::NonBinarySecretKey);
+    }
+    Ok(Entity::from_interchange(&dimensions, body))
+}
+
+mod entity_deserialization;
+mod entity_serialization;
+mod interchange_entity;
+#[cfg(test)]
+mod tests;
+
+use interchange_entity::{FromInterchange, InterchangeEntity, InterchangeScalar};
@@ -0,0 +1,296 @@
+use super::{DefaultInterchangeError, InterchangeEntityKind};
+use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::commons::crypto::glwe::{GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList};
+use crate::commons::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
+    LweList as ImplLweList,
+};
+use crate::commons::crypto::secret::{
+    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+};
+use crate::prelude::*;
+
+// The golden vectors are built from the following containers, see
+// `test_vectors/interchange/README.md`.
+fn pattern_32(len: usize) -> Vec<u32> {
+    (0..len as u32)
+        .map(|i| 0x0102_0304_u32.wrapping_add(0x1111_1111_u32.wrapping_mul(i)))
+        .collect()
+}
+
+fn pattern_64(len: usize) -> Vec<u64> {
+    (0..len as u64)
+        .map(|i| 0x0102_0304_0506_0708_u64.wrapping_add(0x1111_1111_1111_1111_u64.wrapping_mul(i)))
+        .collect()
+}
+
+fn binary_pattern<Scalar: From<bool>>(len: usize) -> Vec<Scalar> {
+    (0..len).map(|i| Scalar::from(i % 3 == 0)).collect()
+}
+
+macro_rules! golden_vector_test {
+    ($test: ident, $entity_type: ident, $inner: expr) => {
+        #[test]
+        fn $test() {
+            let golden: &[u8] = include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/test_vectors/interchange/",
+                stringify!($test),
+                ".bin"
+            ));
+            let expected = $entity_type($inner);
+            let mut engine = DefaultInterchangeEngine::new(()).unwrap();
+            assert_eq!(engine.serialize(&expected).unwrap(), golden);
+            let decoded: $entity_type = engine.deserialize(golden).unwrap();
+            assert_eq!(decoded, expected);
+        }
+    };
+}
+
+golden_vector_test!(
+    lwe_ciphertext_32,
+    LweCiphertext32,
+    ImplLweCiphertext::from_container(pattern_32(4))
+);
+golden_vector_test!(
+    lwe_ciphertext_64,
+    LweCiphertext64,
+    ImplLweCiphertext::from_container(pattern_64(4))
+);
+golden_vector_test!(
+    lwe_ciphertext_vector_32,
+    LweCiphertextVector32,
+    ImplLweList::from_container(pattern_32(8), LweSize(4))
+);
+golden_vector_test!(
+    lwe_ciphertext_vector_64,
+    LweCiphertextVector64,
+    ImplLweList::from_container(pattern_64(8), LweSize(4))
+);
+golden_vector_test!(
+    glwe_ciphertext_32,
+    GlweCiphertext32,
+    ImplGlweCiphertext::from_container(pattern_32(12), PolynomialSize(4))
+);
+golden_vector_test!(
+    glwe_ciphertext_64,
+    GlweCiphertext64,
+    ImplGlweCiphertext::from_container(pattern_64(12), PolynomialSize(4))
+);
+golden_vector_test!(
+    glwe_ciphertext_vector_32,
+    GlweCiphertextVector32,
+    ImplGlweList::from_container(pattern_32(16), GlweDimension(1), PolynomialSize(4))
+);
+golden_vector_test!(
+    glwe_ciphertext_vector_64,
+    GlweCiphertextVector64,
+    ImplGlweList::from_container(pattern_64(16), GlweDimension(1), PolynomialSize(4))
+);
+golden_vector_test!(
+    lwe_secret_key_32,
+    LweSecretKey32,
+    ImplLweSecretKey::binary_from_container(binary_pattern::<u32>(5))
+);
+golden_vector_test!(
+    lwe_secret_key_64,
+    LweSecretKey64,
+    ImplLweSecretKey::binary_from_container(binary_pattern::<u64>(5))
+);
+golden_vector_test!(
+    glwe_secret_key_32,
+    GlweSecretKey32,
+    ImplGlweSecretKey::binary_from_container(binary_pattern::<u32>(8), PolynomialSize(4))
+);
+golden_vector_test!(
+    glwe_secret_key_64,
+    GlweSecretKey64,
+    ImplGlweSecretKey::binary_from_container(binary_pattern::<u64>(8), PolynomialSize(4))
+);
+golden_vector_test!(
+    lwe_keyswitch_key_32,
+    LweKeyswitchKey32,
+    ImplLweKeyswitchKey::from_container(
+        pattern_32(16),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(2),
+        LweDimension(3)
+    )
+);
+golden_vector_test!(
+    lwe_keyswitch_key_64,
+    LweKeyswitchKey64,
+    ImplLweKeyswitchKey::from_container(
+        pattern_64(16),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(2),
+        LweDimension(3)
+    )
+);
+golden_vector_test!(
+    lwe_bootstrap_key_32,
+    LweBootstrapKey32,
+    ImplStandardBootstrapKey::from_container(
+        pattern_32(64),
+        GlweSize(2),
+        PolynomialSize(4),
+        DecompositionLevelCount(2),
+        DecompositionBaseLog(4)
+    )
+);
+golden_vector_test!(
+    lwe_bootstrap_key_64,
+    LweBootstrapKey64,
+    ImplStandardBootstrapKey::from_container(
+        pattern_64(64),
+        GlweSize(2),
+        PolynomialSize(4),
+        DecompositionLevelCount(2),
+        DecompositionBaseLog(4)
+    )
+);
+const LWE_CIPHERTEXT_32: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/test_vectors/interchange/lwe_ciphertext_32.bin"
+));
+
+fn deserialize_altered_lwe_ciphertext_32(
+    alter: impl FnOnce(&mut Vec<u8>),
+) -> DefaultInterchangeError {
+    let mut altered = LWE_CIPHERTEXT_32.to_vec();
+    alter(&mut altered);
+    let mut engine = DefaultInterchangeEngine::new(()).unwrap();
+    match EntityDeserializationEngine::<_, LweCiphertext32>::deserialize(
+        &mut engine,
+        altered.as_slice(),
+    ) {
+        Err(EntityDeserializationError::Engine(error)) => error,
+        _ => panic!("The altered entity was not rejected."),
+    }
+}
+
+#[test]
+fn test_views_are_encoded_as_owned_entities() {
+    let mut container = pattern_32(4);
+    let mut engine = DefaultInterchangeEngine::new(()).unwrap();
+    let view = LweCiphertextView32(ImplLweCiphertext::from_container(container.as_slice()));
+    assert_eq!(engine.serialize(&view).unwrap(), LWE_CIPHERTEXT_32);
+    let mut_view =
+        LweCiphertextMutView32(ImplLweCiphertext::from_container(container.as_mut_slice()));
+    assert_eq!(engine.serialize(&mut_view).unwrap(), LWE_CIPHERTEXT_32);
+}
+
+#[test]
+fn test_invalid_header_is_rejected() {
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes[0] = b'X'),
+        DefaultInterchangeError::InvalidMagic
+    );
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes[4] = 2),
+        DefaultInterchangeError::UnsupportedVersion(2)
+    );
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes[6] = 0x10),
+        DefaultInterchangeError::UnexpectedEntity {
+            expected: InterchangeEntityKind::LweCiphertext,
+            found: 0x0010,
+        }
+    );
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes[8] = 8),
+        DefaultInterchangeError::UnexpectedScalarWidth {
+            expected: 4,
+            found: 8,
+        }
+    );
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes[10] = 1),
+        DefaultInterchangeError::InvalidParameters
+    );
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes[12] = 2),
+        DefaultInterchangeError::InvalidParameters
+    );
+}
+
+#[test]
+fn test_invalid_parameters_are_rejected() {
+    // A zero LWE dimension.
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes[16] = 0),
+        DefaultInterchangeError::InvalidParameters
+    );
+    // An LWE dimension overflowing the body length.
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(
+            |bytes| bytes[16..24].copy_from_slice(&u64::MAX.to_le_bytes())
+        ),
+        DefaultInterchangeError::InvalidParameters
+    );
+    // A body length disagreeing with the parameters.
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes[24] = 5),
+        DefaultInterchangeError::InvalidParameters
+    );
+
+    // A decomposition exceeding the precision of the scalars.
+    let mut altered = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_vectors/interchange/lwe_keyswitch_key_32.bin"
+    ))
+    .to_vec();
+    altered[40] = 17;
+    let mut engine = DefaultInterchangeEngine::new(()).unwrap();
+    let result: Result<LweKeyswitchKey32, _> = engine.deserialize(altered.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::Engine(
+            DefaultInterchangeError::InvalidParameters
+        ))
+    ));
+}
+
+#[test]
+fn test_invalid_size_is_rejected() {
+    let size = LWE_CIPHERTEXT_32.len();
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes.truncate(size - 1)),
+        DefaultInterchangeError::InvalidSize {
+            expected: size,
+            found: size - 1,
+        }
+    );
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes.truncate(10)),
+        DefaultInterchangeError::InvalidSize {
+            expected: 16,
+            found: 10,
+        }
+    );
+    assert_eq!(
+        deserialize_altered_lwe_ciphertext_32(|bytes| bytes.push(0)),
+        DefaultInterchangeError::InvalidSize {
+            expected: size,
+            found: size + 1,
+        }
+    );
+}
+
+#[test]
+fn test_non_binary_secret_key_is_rejected() {
+    let mut altered = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_vectors/interchange/lwe_secret_key_64.bin"
+    ))
+    .to_vec();
+    altered[32] = 2;
+    let mut engine = DefaultInterchangeEngine::new(()).unwrap();
+    let result: Result<LweSecretKey64, _> = engine.deserialize(altered.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::Engine(
+            DefaultInterchangeError::NonBinarySecretKey
+        ))
+    ));
+}
@@ -0,0 +1,239 @@
+use crate::backends::default::implementation::engines::DefaultParallelEngine;
+use crate::backends::default::implementation::entities::{
+    GlweSecretKey32, GlweSecretKey64, LweMultiBitBootstrapKey32, LweMultiBitBootstrapKey64,
+    LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::bootstrap::StandardMultiBitBootstrapKey as ImplStandardMultiBitBootstrapKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSecretKeyEntity, LweBskGroupingFactor,
+    LweSecretKeyEntity, Variance,
+};
+use crate::specification::engines::{
+    LweMultiBitBootstrapKeyGenerationEngine, LweMultiBitBootstrapKeyGenerationError,
+};
+
+/// # Description:
+/// Implementation of [`LweMultiBitBootstrapKeyGenerationEngine`] for [`DefaultParallelEngine`] that
+/// operates on 32 bits integers. It outputs a multi-bit bootstrap key in the standard domain.
+impl
+    LweMultiBitBootstrapKeyGenerationEngine<
+        LweSecretKey32,
+        GlweSecretKey32,
+        LweMultiBitBootstrapKey32,
+    > for DefaultParallelEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(6), GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let grouping_factor = LweBskGroupingFactor(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut default_parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweMultiBitBootstrapKey32 = default_parallel_engine
+    ///     .generate_new_lwe_multi_bit_bootstrap_key(
+    ///         &lwe_sk,
+    ///         &glwe_sk,
+    ///         dec_bl,
+    ///         dec_lc,
+    ///         grouping_factor,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    /// assert_eq!(bsk.grouping_factor(), grouping_factor);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> Result<LweMultiBitBootstrapKey32, LweMultiBitBootstrapKeyGenerationError<Self::EngineError>>
+    {
+        LweMultiBitBootstrapKeyGenerationError::perform_generic_checks(
+            input_key,
+            decomposition_base_log,
+            decomposition_level_count,
+            grouping_factor,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_multi_bit_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                grouping_factor,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_multi_bit_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> LweMultiBitBootstrapKey32 {
+        let mut key = ImplStandardMultiBitBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            grouping_factor,
+        );
+        key.par_fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweMultiBitBootstrapKey32(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweMultiBitBootstrapKeyGenerationEngine`] for [`DefaultParallelEngine`] that
+/// operates on 64 bits integers. It outputs a multi-bit bootstrap key in the standard domain.
+impl
+    LweMultiBitBootstrapKeyGenerationEngine<
+        LweSecretKey64,
+        GlweSecretKey64,
+        LweMultiBitBootstrapKey64,
+    > for DefaultParallelEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(6), GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let grouping_factor = LweBskGroupingFactor(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut default_parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweMultiBitBootstrapKey64 = default_parallel_engine
+    ///     .generate_new_lwe_multi_bit_bootstrap_key(
+    ///         &lwe_sk,
+    ///         &glwe_sk,
+    ///         dec_bl,
+    ///         dec_lc,
+    ///         grouping_factor,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    /// assert_eq!(bsk.grouping_factor(), grouping_factor);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> Result<LweMultiBitBootstrapKey64, LweMultiBitBootstrapKeyGenerationError<Self::EngineError>>
+    {
+        LweMultiBitBootstrapKeyGenerationError::perform_generic_checks(
+            input_key,
+            decomposition_base_log,
+            decomposition_level_count,
+            grouping_factor,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_multi_bit_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                grouping_factor,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_multi_bit_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> LweMultiBitBootstrapKey64 {
+        let mut key = ImplStandardMultiBitBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            grouping_factor,
+        );
+        key.par_fill_with_new_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweMultiBitBootstrapKey64(key)
+    }
+}
@@ -64,5 +64,6 @@ impl AbstractEngine for DefaultParallelEngine {
 mod lwe_bootstrap_key_generation;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_multi_bit_bootstrap_key_generation;
 mod lwe_public_key_generation;
 mod lwe_seeded_bootstrap_key_generation;
@@ -1,4 +1,5 @@
 #![allow(clippy::missing_safety_doc)]
+use super::deserialization_error;
 use crate::commons::crypto::bootstrap::{
     StandardBootstrapKey as ImplStandardBootstrapKey,
     StandardSeededBootstrapKey as ImplStandardSeededBootstrapKey,
@@ -10,6 +11,7 @@ use crate::commons::crypto::encoding::{
 };
 use crate::commons::crypto::ggsw::{
     StandardGgswCiphertext as ImplStandardGgswCiphertext,
+    StandardGgswCiphertextList as ImplStandardGgswCiphertextList,
     StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
 };
 use crate::commons::crypto::glwe::{
@@ -17,6 +19,9 @@ use crate::commons::crypto::glwe::{
     GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
     LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
     LwePrivateFunctionalPackingKeyswitchKeyList as ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys,
+    LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKey as ImplLweSeededPrivateFunctionalPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyList as ImplLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
 };
 use crate::commons::crypto::lwe::{
     LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
@@ -33,13 +38,14 @@ use crate::prelude::{
     DefaultSerializationEngine, DefaultSerializationError, EntityDeserializationEngine,
     EntityDeserializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
     FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
-    GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
-    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweCiphertext32,
-    GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version, GlweCiphertextVector32,
-    GlweCiphertextVector32Version, GlweCiphertextVector64, GlweCiphertextVector64Version,
-    GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64, GlweSecretKey64Version,
-    GlweSeededCiphertext32, GlweSeededCiphertext32Version, GlweSeededCiphertext64,
-    GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
+    GgswCiphertext64Version, GgswCiphertextVector32, GgswCiphertextVector32Version,
+    GgswCiphertextVector64, GgswCiphertextVector64Version, GgswSeededCiphertext32,
+    GgswSeededCiphertext32Version, GgswSeededCiphertext64, GgswSeededCiphertext64Version,
+    GlweCiphertext32, GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version,
+    GlweCiphertextVector32, GlweCiphertextVector32Version, GlweCiphertextVector64,
+    GlweCiphertextVector64Version, GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64,
+    GlweSecretKey64Version, GlweSeededCiphertext32, GlweSeededCiphertext32Version,
+    GlweSeededCiphertext64, GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
     GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
     GlweSeededCiphertextVector64Version, LweBootstrapKey32, LweBootstrapKey32Version,
     LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32, LweCiphertext32Version,
@@ -57,11 +63,23 @@ use crate::prelude::{
     LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededCiphertext32,
     LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
     LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
-    LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version,
-    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, Plaintext32, Plaintext32Version,
-    Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
-    PlaintextVector64, PlaintextVector64Version,
+    LweSeededCiphertextVector64Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+    LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version, LweSeededKeyswitchKey64,
+    LweSeededKeyswitchKey64Version, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey32Version, LweSeededPackingKeyswitchKey64,
+    LweSeededPackingKeyswitchKey64Version, LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64Version, LweSeededPublicKey32,
+    LweSeededPublicKey32Version, LweSeededPublicKey64, LweSeededPublicKey64Version, Plaintext32,
+    Plaintext32Version, Plaintext64, Plaintext64Version, PlaintextVector32,
+    PlaintextVector32Version, PlaintextVector64, PlaintextVector64Version,
 };
+use crate::specification::serialization::deserialize_with_header;
 use serde::Deserialize;
 
 /// # Description:
@@ -98,9 +116,10 @@ impl EntityDeserializationEngine<&[u8], Cleartext32> for DefaultSerializationEng
             version: Cleartext32Version,
             inner: ImplCleartext<u32>,
         }
-        let deserialized: DeserializableCleartext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableCleartext32 =
+            deserialize_with_header::<Cleartext32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableCleartext32 {
                 version: Cleartext32Version::Unsupported,
@@ -154,9 +173,10 @@ impl EntityDeserializationEngine<&[u8], Cleartext64> for DefaultSerializationEng
             version: Cleartext64Version,
             inner: ImplCleartext<u64>,
         }
-        let deserialized: DeserializableCleartext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableCleartext64 =
+            deserialize_with_header::<Cleartext64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableCleartext64 {
                 version: Cleartext64Version::Unsupported,
@@ -210,9 +230,10 @@ impl EntityDeserializationEngine<&[u8], CleartextF64> for DefaultSerializationEn
             version: CleartextF64Version,
             inner: ImplCleartext<f64>,
         }
-        let deserialized: DeserializableCleartextF64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableCleartextF64 =
+            deserialize_with_header::<CleartextF64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableCleartextF64 {
                 version: CleartextF64Version::Unsupported,
@@ -266,9 +287,10 @@ impl EntityDeserializationEngine<&[u8], CleartextVector32> for DefaultSerializat
             version: CleartextVector32Version,
             inner: ImplCleartextList<Vec<u32>>,
         }
-        let deserialized: DeserializableCleartextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableCleartextVector32 =
+            deserialize_with_header::<CleartextVector32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableCleartextVector32 {
                 version: CleartextVector32Version::Unsupported,
@@ -322,9 +344,10 @@ impl EntityDeserializationEngine<&[u8], CleartextVector64> for DefaultSerializat
             version: CleartextVector64Version,
             inner: ImplCleartextList<Vec<u64>>,
         }
-        let deserialized: DeserializableCleartextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableCleartextVector64 =
+            deserialize_with_header::<CleartextVector64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableCleartextVector64 {
                 version: CleartextVector64Version::Unsupported,
@@ -378,9 +401,10 @@ impl EntityDeserializationEngine<&[u8], CleartextVectorF64> for DefaultSerializa
             version: CleartextVectorF64Version,
             inner: ImplCleartextList<Vec<f64>>,
         }
-        let deserialized: DeserializableCleartextVectorF64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableCleartextVectorF64 =
+            deserialize_with_header::<CleartextVectorF64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableCleartextVectorF64 {
                 version: CleartextVectorF64Version::Unsupported,
@@ -450,9 +474,10 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext32> for DefaultSerializati
             version: GgswCiphertext32Version,
             inner: ImplStandardGgswCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableGgswCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGgswCiphertext32 =
+            deserialize_with_header::<GgswCiphertext32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGgswCiphertext32 {
                 version: GgswCiphertext32Version::Unsupported,
@@ -522,9 +547,10 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext64> for DefaultSerializati
             version: GgswCiphertext64Version,
             inner: ImplStandardGgswCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableGgswCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGgswCiphertext64 =
+            deserialize_with_header::<GgswCiphertext64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGgswCiphertext64 {
                 version: GgswCiphertext64Version::Unsupported,
@@ -544,6 +570,168 @@ impl EntityDeserializationEngine<&[u8], GgswCiphertext64> for DefaultSerializati
     }
 }
 
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GGSW ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], GgswCiphertextVector32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GgswCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGgswCiphertextVector32 {
+            version: GgswCiphertextVector32Version,
+            inner: ImplStandardGgswCiphertextList<Vec<u32>>,
+        }
+        let deserialized: DeserializableGgswCiphertextVector32 =
+            deserialize_with_header::<GgswCiphertextVector32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGgswCiphertextVector32 {
+                version: GgswCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGgswCiphertextVector32 {
+                version: GgswCiphertextVector32Version::V0,
+                inner,
+            } => Ok(GgswCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GGSW ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], GgswCiphertextVector64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GgswCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGgswCiphertextVector64 {
+            version: GgswCiphertextVector64Version,
+            inner: ImplStandardGgswCiphertextList<Vec<u64>>,
+        }
+        let deserialized: DeserializableGgswCiphertextVector64 =
+            deserialize_with_header::<GgswCiphertextVector64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGgswCiphertextVector64 {
+                version: GgswCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGgswCiphertextVector64 {
+                version: GgswCiphertextVector64Version::V0,
+                inner,
+            } => Ok(GgswCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GgswCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
 /// operates on 32 bits integers. It deserializes a seeded GGSW ciphertext entity.
@@ -558,8 +746,12 @@ impl EntityDeserializationEngine<&[u8], GgswSeededCiphertext32> for DefaultSeria
             version: GgswSeededCiphertext32Version,
             inner: ImplStandardGgswSeededCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableGgswSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableGgswSeededCiphertext32 =
+            deserialize_with_header::<GgswSeededCiphertext32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGgswSeededCiphertext32 {
@@ -594,8 +786,12 @@ impl EntityDeserializationEngine<&[u8], GgswSeededCiphertext64> for DefaultSeria
             version: GgswSeededCiphertext64Version,
             inner: ImplStandardGgswSeededCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableGgswSeededCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableGgswSeededCiphertext64 =
+            deserialize_with_header::<GgswSeededCiphertext64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGgswSeededCiphertext64 {
@@ -662,9 +858,10 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext32> for DefaultSerializati
             version: GlweCiphertext32Version,
             inner: ImplGlweCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGlweCiphertext32 =
+            deserialize_with_header::<GlweCiphertext32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweCiphertext32 {
                 version: GlweCiphertext32Version::Unsupported,
@@ -730,9 +927,10 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertext64> for DefaultSerializati
             version: GlweCiphertext64Version,
             inner: ImplGlweCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGlweCiphertext64 =
+            deserialize_with_header::<GlweCiphertext64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweCiphertext64 {
                 version: GlweCiphertext64Version::Unsupported,
@@ -798,8 +996,12 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector32> for DefaultSeria
             version: GlweCiphertextVector32Version,
             inner: ImplGlweList<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweCiphertextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableGlweCiphertextVector32 =
+            deserialize_with_header::<GlweCiphertextVector32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweCiphertextVector32 {
@@ -866,8 +1068,12 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector64> for DefaultSeria
             version: GlweCiphertextVector64Version,
             inner: ImplGlweList<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweCiphertextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableGlweCiphertextVector64 =
+            deserialize_with_header::<GlweCiphertextVector64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweCiphertextVector64 {
@@ -927,9 +1133,10 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializatio
             version: GlweSecretKey32Version,
             inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
         }
-        let deserialized: DeserializableGlweSecretKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGlweSecretKey32 =
+            deserialize_with_header::<GlweSecretKey32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweSecretKey32 {
                 version: GlweSecretKey32Version::Unsupported,
@@ -988,9 +1195,10 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializatio
             version: GlweSecretKey64Version,
             inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
         }
-        let deserialized: DeserializableGlweSecretKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGlweSecretKey64 =
+            deserialize_with_header::<GlweSecretKey64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweSecretKey64 {
                 version: GlweSecretKey64Version::Unsupported,
@@ -1057,8 +1265,12 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSeria
             version: GlweSeededCiphertext32Version,
             inner: ImplGlweSeededCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableGlweSeededCiphertext32 =
+            deserialize_with_header::<GlweSeededCiphertext32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweSeededCiphertext32 {
@@ -1126,8 +1338,12 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSeria
             version: GlweSeededCiphertext64Version,
             inner: ImplGlweSeededCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableGlweSeededCiphertext64 =
+            deserialize_with_header::<GlweSeededCiphertext64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweSeededCiphertext64 {
@@ -1197,9 +1413,12 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
             inner: ImplGlweSeededList<Vec<u32>>,
         }
         let deserialized: DeserializableGlweSeededCiphertextVector32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            deserialize_with_header::<GlweSeededCiphertextVector32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweSeededCiphertextVector32 {
                 version: GlweSeededCiphertextVector32Version::Unsupported,
@@ -1268,9 +1487,12 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
             inner: ImplGlweSeededList<Vec<u64>>,
         }
         let deserialized: DeserializableGlweSeededCiphertextVector64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            deserialize_with_header::<GlweSeededCiphertextVector64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableGlweSeededCiphertextVector64 {
                 version: GlweSeededCiphertextVector64Version::Unsupported,
@@ -1336,9 +1558,10 @@ impl EntityDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializat
             version: LweBootstrapKey32Version,
             inner: ImplStandardBootstrapKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweBootstrapKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweBootstrapKey32 =
+            deserialize_with_header::<LweBootstrapKey32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweBootstrapKey32 {
                 version: LweBootstrapKey32Version::Unsupported,
@@ -1404,9 +1627,10 @@ impl EntityDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializat
             version: LweBootstrapKey64Version,
             inner: ImplStandardBootstrapKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweBootstrapKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweBootstrapKey64 =
+            deserialize_with_header::<LweBootstrapKey64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweBootstrapKey64 {
                 version: LweBootstrapKey64Version::Unsupported,
@@ -1469,9 +1693,10 @@ impl EntityDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializatio
             version: LweCiphertext32Version,
             inner: ImplLweCiphertext<Vec<u32>>,
         }
-        let deserialized: DeserializableLweCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweCiphertext32 =
+            deserialize_with_header::<LweCiphertext32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweCiphertext32 {
                 version: LweCiphertext32Version::Unsupported,
@@ -1535,9 +1760,10 @@ impl EntityDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializatio
             version: LweCiphertext64Version,
             inner: ImplLweCiphertext<Vec<u64>>,
         }
-        let deserialized: DeserializableLweCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweCiphertext64 =
+            deserialize_with_header::<LweCiphertext64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweCiphertext64 {
                 version: LweCiphertext64Version::Unsupported,
@@ -1601,8 +1827,12 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerial
             version: LweCiphertextVector32Version,
             inner: ImplLweList<Vec<u32>>,
         }
-        let deserialized: DeserializableLweCiphertextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableLweCiphertextVector32 =
+            deserialize_with_header::<LweCiphertextVector32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweCiphertextVector32 {
@@ -1667,8 +1897,12 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerial
             version: LweCiphertextVector64Version,
             inner: ImplLweList<Vec<u64>>,
         }
-        let deserialized: DeserializableLweCiphertextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableLweCiphertextVector64 =
+            deserialize_with_header::<LweCiphertextVector64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweCiphertextVector64 {
@@ -1755,9 +1989,13 @@ impl EntityDeserializationEngine<&[u8], LweCircuitBootstrapPrivateFunctionalPack
             inner: ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys<Vec<u32>>,
         }
         let deserialized: DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            deserialize_with_header::<
+                LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+                _,
+                _,
+            >(serialized, bincode::deserialize)
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
                 version:
@@ -1849,9 +2087,13 @@ impl EntityDeserializationEngine<&[u8], LweCircuitBootstrapPrivateFunctionalPack
             inner: ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys<Vec<u64>>,
         }
         let deserialized: DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            deserialize_with_header::<
+                LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+                _,
+                _,
+            >(serialized, bincode::deserialize)
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
                 version:
@@ -1929,9 +2171,10 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializat
             version: LweKeyswitchKey32Version,
             inner: ImplLweKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweKeyswitchKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweKeyswitchKey32 =
+            deserialize_with_header::<LweKeyswitchKey32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweKeyswitchKey32 {
                 version: LweKeyswitchKey32Version::Unsupported,
@@ -2003,9 +2246,10 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializat
             version: LweKeyswitchKey64Version,
             inner: ImplLweKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweKeyswitchKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweKeyswitchKey64 =
+            deserialize_with_header::<LweKeyswitchKey64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweKeyswitchKey64 {
                 version: LweKeyswitchKey64Version::Unsupported,
@@ -2062,9 +2306,10 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerialization
             version: LweSecretKey32Version,
             inner: ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
         }
-        let deserialized: DeserializableLweSecretKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSecretKey32 =
+            deserialize_with_header::<LweSecretKey32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSecretKey32 {
                 version: LweSecretKey32Version::Unsupported,
@@ -2121,9 +2366,10 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerialization
             version: LweSecretKey64Version,
             inner: ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
         }
-        let deserialized: DeserializableLweSecretKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSecretKey64 =
+            deserialize_with_header::<LweSecretKey64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSecretKey64 {
                 version: LweSecretKey64Version::Unsupported,
@@ -2190,8 +2436,12 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey32> for DefaultSeri
             version: LweSeededBootstrapKey32Version,
             inner: ImplStandardSeededBootstrapKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSeededBootstrapKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableLweSeededBootstrapKey32 =
+            deserialize_with_header::<LweSeededBootstrapKey32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSeededBootstrapKey32 {
@@ -2259,8 +2509,12 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey64> for DefaultSeri
             version: LweSeededBootstrapKey64Version,
             inner: ImplStandardSeededBootstrapKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSeededBootstrapKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableLweSeededBootstrapKey64 =
+            deserialize_with_header::<LweSeededBootstrapKey64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSeededBootstrapKey64 {
@@ -2325,8 +2579,12 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext32> for DefaultSerial
             version: LweSeededCiphertext32Version,
             inner: ImplLweSeededCiphertext<u32>,
         }
-        let deserialized: DeserializableLweSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableLweSeededCiphertext32 =
+            deserialize_with_header::<LweSeededCiphertext32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSeededCiphertext32 {
@@ -2391,8 +2649,12 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext64> for DefaultSerial
             version: LweSeededCiphertext64Version,
             inner: ImplLweSeededCiphertext<u64>,
         }
-        let deserialized: DeserializableLweSeededCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableLweSeededCiphertext64 =
+            deserialize_with_header::<LweSeededCiphertext64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSeededCiphertext64 {
@@ -2460,9 +2722,12 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector32>
             inner: ImplLweSeededList<Vec<u32>>,
         }
         let deserialized: DeserializableLweSeededCiphertextVector32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            deserialize_with_header::<LweSeededCiphertextVector32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSeededCiphertextVector32 {
                 version: LweSeededCiphertextVector32Version::Unsupported,
@@ -2529,9 +2794,12 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector64>
             inner: ImplLweSeededList<Vec<u64>>,
         }
         let deserialized: DeserializableLweSeededCiphertextVector64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            deserialize_with_header::<LweSeededCiphertextVector64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSeededCiphertextVector64 {
                 version: LweSeededCiphertextVector64Version::Unsupported,
@@ -2603,8 +2871,12 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey32> for DefaultSeri
             version: LweSeededKeyswitchKey32Version,
             inner: ImplLweSeededKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSeededKeyswitchKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableLweSeededKeyswitchKey32 =
+            deserialize_with_header::<LweSeededKeyswitchKey32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSeededKeyswitchKey32 {
@@ -2677,8 +2949,12 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey64> for DefaultSeri
             version: LweSeededKeyswitchKey64Version,
             inner: ImplLweSeededKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSeededKeyswitchKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializableLweSeededKeyswitchKey64 =
+            deserialize_with_header::<LweSeededKeyswitchKey64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLweSeededKeyswitchKey64 {
@@ -2751,8 +3027,12 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey32> for DefaultSer
             version: LwePackingKeyswitchKey32Version,
             inner: ImplLwePackingKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializablePackingKeyswitchKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializablePackingKeyswitchKey32 =
+            deserialize_with_header::<LwePackingKeyswitchKey32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializablePackingKeyswitchKey32 {
@@ -2825,8 +3105,12 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey64> for DefaultSer
             version: LwePackingKeyswitchKey64Version,
             inner: ImplLwePackingKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializablePackingKeyswitchKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
+        let deserialized: DeserializablePackingKeyswitchKey64 =
+            deserialize_with_header::<LwePackingKeyswitchKey64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializablePackingKeyswitchKey64 {
@@ -2892,9 +3176,10 @@ impl EntityDeserializationEngine<&[u8], LwePublicKey32> for DefaultSerialization
             version: LwePublicKey32Version,
             inner: ImplLweList<Vec<u32>>,
         }
-        let deserialized: DeserializableLwePublicKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLwePublicKey32 =
+            deserialize_with_header::<LwePublicKey32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLwePublicKey32 {
                 version: LwePublicKey32Version::Unsupported,
@@ -2959,9 +3244,10 @@ impl EntityDeserializationEngine<&[u8], LwePublicKey64> for DefaultSerialization
             version: LwePublicKey64Version,
             inner: ImplLweList<Vec<u64>>,
         }
-        let deserialized: DeserializableLwePublicKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLwePublicKey64 =
+            deserialize_with_header::<LwePublicKey64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableLwePublicKey64 {
                 version: LwePublicKey64Version::Unsupported,
@@ -3018,9 +3304,10 @@ impl EntityDeserializationEngine<&[u8], Plaintext32> for DefaultSerializationEng
             version: Plaintext32Version,
             inner: ImplPlaintext<u32>,
         }
-        let deserialized: DeserializablePlaintext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializablePlaintext32 =
+            deserialize_with_header::<Plaintext32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializablePlaintext32 {
                 version: Plaintext32Version::Unsupported,
@@ -3076,9 +3363,10 @@ impl EntityDeserializationEngine<&[u8], Plaintext64> for DefaultSerializationEng
             version: Plaintext64Version,
             inner: ImplPlaintext<u64>,
         }
-        let deserialized: DeserializablePlaintext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializablePlaintext64 =
+            deserialize_with_header::<Plaintext64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializablePlaintext64 {
                 version: Plaintext64Version::Unsupported,
@@ -3135,9 +3423,10 @@ impl EntityDeserializationEngine<&[u8], PlaintextVector32> for DefaultSerializat
             version: PlaintextVector32Version,
             inner: ImplPlaintextList<Vec<u32>>,
         }
-        let deserialized: DeserializablePlaintextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializablePlaintextVector32 =
+            deserialize_with_header::<PlaintextVector32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializablePlaintextVector32 {
                 version: PlaintextVector32Version::Unsupported,
@@ -3194,9 +3483,10 @@ impl EntityDeserializationEngine<&[u8], PlaintextVector64> for DefaultSerializat
             version: PlaintextVector64Version,
             inner: ImplPlaintextList<Vec<u64>>,
         }
-        let deserialized: DeserializablePlaintextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializablePlaintextVector64 =
+            deserialize_with_header::<PlaintextVector64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializablePlaintextVector64 {
                 version: PlaintextVector64Version::Unsupported,
@@ -3256,9 +3546,10 @@ impl EntityDeserializationEngine<&[u8], FloatEncoder> for DefaultSerializationEn
             version: FloatEncoderVersion,
             inner: ImplFloatEncoder,
         }
-        let deserialized: DeserializableFloatEncoder = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableFloatEncoder =
+            deserialize_with_header::<FloatEncoder, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableFloatEncoder {
                 version: FloatEncoderVersion::Unsupported,
@@ -3322,9 +3613,10 @@ impl EntityDeserializationEngine<&[u8], FloatEncoderVector> for DefaultSerializa
             version: FloatEncoderVectorVersion,
             inner: Vec<ImplFloatEncoder>,
         }
-        let deserialized: DeserializableFloatEncoderVector = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableFloatEncoderVector =
+            deserialize_with_header::<FloatEncoderVector, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             DeserializableFloatEncoderVector {
                 version: FloatEncoderVectorVersion::Unsupported,
@@ -3343,3 +3635,673 @@ impl EntityDeserializationEngine<&[u8], FloatEncoderVector> for DefaultSerializa
         self.deserialize(serialized).unwrap()
     }
 }
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded LWE public key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPublicKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey32 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_public_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_public_key, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededPublicKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPublicKey32 {
+            version: LweSeededPublicKey32Version,
+            inner: ImplLweSeededList<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweSeededPublicKey32 =
+            deserialize_with_header::<LweSeededPublicKey32, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededPublicKey32 {
+                version: LweSeededPublicKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPublicKey32 {
+                version: LweSeededPublicKey32Version::V0,
+                inner,
+            } => Ok(LweSeededPublicKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededPublicKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE public key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPublicKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey64 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_public_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_public_key, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededPublicKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPublicKey64 {
+            version: LweSeededPublicKey64Version,
+            inner: ImplLweSeededList<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweSeededPublicKey64 =
+            deserialize_with_header::<LweSeededPublicKey64, _, _>(serialized, bincode::deserialize)
+                .map_err(deserialization_error)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededPublicKey64 {
+                version: LweSeededPublicKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPublicKey64 {
+                version: LweSeededPublicKey64Version::V0,
+                inner,
+            } => Ok(LweSeededPublicKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededPublicKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded packing keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPackingKeyswitchKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededPackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPackingKeyswitchKey32 {
+            version: LweSeededPackingKeyswitchKey32Version,
+            inner: ImplLweSeededPackingKeyswitchKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweSeededPackingKeyswitchKey32 =
+            deserialize_with_header::<LweSeededPackingKeyswitchKey32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededPackingKeyswitchKey32 {
+                version: LweSeededPackingKeyswitchKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPackingKeyswitchKey32 {
+                version: LweSeededPackingKeyswitchKey32Version::V0,
+                inner,
+            } => Ok(LweSeededPackingKeyswitchKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPackingKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded packing keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPackingKeyswitchKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededPackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPackingKeyswitchKey64 {
+            version: LweSeededPackingKeyswitchKey64Version,
+            inner: ImplLweSeededPackingKeyswitchKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweSeededPackingKeyswitchKey64 =
+            deserialize_with_header::<LweSeededPackingKeyswitchKey64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededPackingKeyswitchKey64 {
+                version: LweSeededPackingKeyswitchKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPackingKeyswitchKey64 {
+                version: LweSeededPackingKeyswitchKey64Version::V0,
+                inner,
+            } => Ok(LweSeededPackingKeyswitchKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPackingKeyswitchKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded private functional packing keyswitch key
+/// entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPrivateFunctionalPackingKeyswitchKey32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     StandardDev, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = StandardDev(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u32; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector32 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk = engine.generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    ///     &|x| x,
+    ///     &polynomial,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_pfpksk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_pfpksk, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<
+        LweSeededPrivateFunctionalPackingKeyswitchKey32,
+        EntityDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey32 {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+            inner: ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey32 =
+            deserialize_with_header::<LweSeededPrivateFunctionalPackingKeyswitchKey32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey32 {
+                version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey32 {
+                version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version::V0,
+                inner,
+            } => Ok(LweSeededPrivateFunctionalPackingKeyswitchKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPrivateFunctionalPackingKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded private functional packing keyswitch key
+/// entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPrivateFunctionalPackingKeyswitchKey64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     StandardDev, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = StandardDev(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u64; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector64 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk = engine.generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    ///     &|x| x,
+    ///     &polynomial,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_pfpksk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_pfpksk, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<
+        LweSeededPrivateFunctionalPackingKeyswitchKey64,
+        EntityDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey64 {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version,
+            inner: ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey64 =
+            deserialize_with_header::<LweSeededPrivateFunctionalPackingKeyswitchKey64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey64 {
+                version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey64 {
+                version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version::V0,
+                inner,
+            } => Ok(LweSeededPrivateFunctionalPackingKeyswitchKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPrivateFunctionalPackingKeyswitchKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded circuit bootstrap private functional
+/// packing keyswitch keys entity.
+impl
+    EntityDeserializationEngine<
+        &[u8],
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk = engine
+    ///     .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_cbs_pfpksk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_cbs_pfpksk, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        EntityDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+            inner: ImplLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = deserialize_with_header::<LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32, _, _>(serialized, bincode::deserialize)
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+                version:
+                    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+                version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::V0,
+                inner,
+            } => Ok(LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded circuit bootstrap private functional
+/// packing keyswitch keys entity.
+impl
+    EntityDeserializationEngine<
+        &[u8],
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk = engine
+    ///     .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_cbs_pfpksk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_cbs_pfpksk, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        EntityDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+            inner: ImplLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = deserialize_with_header::<LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, _, _>(serialized, bincode::deserialize)
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+                version:
+                    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+                version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::V0,
+                inner,
+            } => Ok(LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::missing_safety_doc)]
 
+use super::serialization_error;
 use crate::commons::crypto::bootstrap::{
     StandardBootstrapKey as ImplStandardBootstrapKey,
     StandardSeededBootstrapKey as ImplStandardSeededBootstrapKey,
@@ -11,6 +12,7 @@ use crate::commons::crypto::encoding::{
 };
 use crate::commons::crypto::ggsw::{
     StandardGgswCiphertext as ImplStandardGgswCiphertext,
+    StandardGgswCiphertextList as ImplStandardGgswCiphertextList,
     StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
 };
 use crate::commons::crypto::glwe::{
@@ -18,6 +20,9 @@ use crate::commons::crypto::glwe::{
     GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
     LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
     LwePrivateFunctionalPackingKeyswitchKeyList as ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys,
+    LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKey as ImplLweSeededPrivateFunctionalPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyList as ImplLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
 };
 use crate::commons::crypto::lwe::{
     LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
@@ -31,17 +36,18 @@ use crate::prelude::{
     BinaryKeyKind, Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
     CleartextF64Version, CleartextVector32, CleartextVector32Version, CleartextVector64,
     CleartextVector64Version, CleartextVectorF64, CleartextVectorF64Version,
-    DefaultSerializationEngine, DefaultSerializationError, EntitySerializationEngine,
-    EntitySerializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
-    FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
-    GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
-    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweCiphertext32,
-    GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version, GlweCiphertextMutView32,
-    GlweCiphertextMutView64, GlweCiphertextVector32, GlweCiphertextVector32Version,
-    GlweCiphertextVector64, GlweCiphertextVector64Version, GlweCiphertextVectorMutView32,
-    GlweCiphertextVectorMutView64, GlweCiphertextVectorView32, GlweCiphertextVectorView64,
-    GlweCiphertextView32, GlweCiphertextView64, GlweSecretKey32, GlweSecretKey32Version,
-    GlweSecretKey64, GlweSecretKey64Version, GlweSeededCiphertext32, GlweSeededCiphertext32Version,
+    DefaultSerializationEngine, EntitySerializationEngine, EntitySerializationError, FloatEncoder,
+    FloatEncoderVector, FloatEncoderVectorVersion, FloatEncoderVersion, GgswCiphertext32,
+    GgswCiphertext32Version, GgswCiphertext64, GgswCiphertext64Version, GgswCiphertextVector32,
+    GgswCiphertextVector32Version, GgswCiphertextVector64, GgswCiphertextVector64Version,
+    GgswSeededCiphertext32, GgswSeededCiphertext32Version, GgswSeededCiphertext64,
+    GgswSeededCiphertext64Version, GlweCiphertext32, GlweCiphertext32Version, GlweCiphertext64,
+    GlweCiphertext64Version, GlweCiphertextMutView32, GlweCiphertextMutView64,
+    GlweCiphertextVector32, GlweCiphertextVector32Version, GlweCiphertextVector64,
+    GlweCiphertextVector64Version, GlweCiphertextVectorMutView32, GlweCiphertextVectorMutView64,
+    GlweCiphertextVectorView32, GlweCiphertextVectorView64, GlweCiphertextView32,
+    GlweCiphertextView64, GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64,
+    GlweSecretKey64Version, GlweSeededCiphertext32, GlweSeededCiphertext32Version,
     GlweSeededCiphertext64, GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
     GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
     GlweSeededCiphertextVector64Version, LweBootstrapKey32, LweBootstrapKey32Version,
@@ -62,11 +68,23 @@ use crate::prelude::{
     LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededCiphertext32,
     LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
     LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
-    LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version,
-    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, Plaintext32, Plaintext32Version,
-    Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
-    PlaintextVector64, PlaintextVector64Version,
+    LweSeededCiphertextVector64Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+    LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version, LweSeededKeyswitchKey64,
+    LweSeededKeyswitchKey64Version, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey32Version, LweSeededPackingKeyswitchKey64,
+    LweSeededPackingKeyswitchKey64Version, LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64Version, LweSeededPublicKey32,
+    LweSeededPublicKey32Version, LweSeededPublicKey64, LweSeededPublicKey64Version, Plaintext32,
+    Plaintext32Version, Plaintext64, Plaintext64Version, PlaintextVector32,
+    PlaintextVector32Version, PlaintextVector64, PlaintextVector64Version,
 };
+use crate::specification::serialization::serialize_with_header;
 use serde::Serialize;
 
 /// # Description:
@@ -107,9 +125,11 @@ impl EntitySerializationEngine<Cleartext32, Vec<u8>> for DefaultSerializationEng
             version: Cleartext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &Cleartext32) -> Vec<u8> {
@@ -155,9 +175,11 @@ impl EntitySerializationEngine<Cleartext64, Vec<u8>> for DefaultSerializationEng
             version: Cleartext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &Cleartext64) -> Vec<u8> {
@@ -203,9 +225,11 @@ impl EntitySerializationEngine<CleartextF64, Vec<u8>> for DefaultSerializationEn
             version: CleartextF64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &CleartextF64) -> Vec<u8> {
@@ -251,9 +275,11 @@ impl EntitySerializationEngine<CleartextVector32, Vec<u8>> for DefaultSerializat
             version: CleartextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &CleartextVector32) -> Vec<u8> {
@@ -299,9 +325,11 @@ impl EntitySerializationEngine<CleartextVector64, Vec<u8>> for DefaultSerializat
             version: CleartextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &CleartextVector64) -> Vec<u8> {
@@ -347,9 +375,11 @@ impl EntitySerializationEngine<CleartextVectorF64, Vec<u8>> for DefaultSerializa
             version: CleartextVectorF64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &CleartextVectorF64) -> Vec<u8> {
@@ -411,9 +441,11 @@ impl EntitySerializationEngine<GgswCiphertext32, Vec<u8>> for DefaultSerializati
             version: GgswCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertext32) -> Vec<u8> {
@@ -475,9 +507,11 @@ impl EntitySerializationEngine<GgswCiphertext64, Vec<u8>> for DefaultSerializati
             version: GgswCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertext64) -> Vec<u8> {
@@ -485,6 +519,148 @@ impl EntitySerializationEngine<GgswCiphertext64, Vec<u8>> for DefaultSerializati
     }
 }
 
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a GGSW ciphertext vector entity.
+impl EntitySerializationEngine<GgswCiphertextVector32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &GgswCiphertextVector32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableGgswCiphertextVector32<'a> {
+            version: GgswCiphertextVector32Version,
+            inner: &'a ImplStandardGgswCiphertextList<Vec<u32>>,
+        }
+        let serializable = SerializableGgswCiphertextVector32 {
+            version: GgswCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a GGSW ciphertext vector entity.
+impl EntitySerializationEngine<GgswCiphertextVector64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &GgswCiphertextVector64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableGgswCiphertextVector64<'a> {
+            version: GgswCiphertextVector64Version,
+            inner: &'a ImplStandardGgswCiphertextList<Vec<u64>>,
+        }
+        let serializable = SerializableGgswCiphertextVector64 {
+            version: GgswCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &GgswCiphertextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
 /// on 32 bits integers. It serializes a seeded GGSW ciphertext entity.
@@ -503,9 +679,11 @@ impl EntitySerializationEngine<GgswSeededCiphertext32, Vec<u8>> for DefaultSeria
             version: GgswSeededCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswSeededCiphertext32) -> Vec<u8> {
@@ -531,9 +709,11 @@ impl EntitySerializationEngine<GgswSeededCiphertext64, Vec<u8>> for DefaultSeria
             version: GgswSeededCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GgswSeededCiphertext64) -> Vec<u8> {
@@ -591,9 +771,11 @@ impl EntitySerializationEngine<GlweCiphertext32, Vec<u8>> for DefaultSerializati
             version: GlweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertext32) -> Vec<u8> {
@@ -651,9 +833,11 @@ impl EntitySerializationEngine<GlweCiphertext64, Vec<u8>> for DefaultSerializati
             version: GlweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertext64) -> Vec<u8> {
@@ -717,9 +901,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextView32<'b>, Vec<u8>>
             version: GlweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextView32<'b>) -> Vec<u8> {
@@ -783,9 +969,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextView64<'b>, Vec<u8>>
             version: GlweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextView64<'b>) -> Vec<u8> {
@@ -849,9 +1037,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextMutView32<'b>, Vec<u8>>
             version: GlweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextMutView32<'b>) -> Vec<u8> {
@@ -915,9 +1105,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextMutView64<'b>, Vec<u8>>
             version: GlweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextMutView64<'b>) -> Vec<u8> {
@@ -977,9 +1169,11 @@ impl EntitySerializationEngine<GlweCiphertextVector32, Vec<u8>> for DefaultSeria
             version: GlweCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVector32) -> Vec<u8> {
@@ -1039,9 +1233,11 @@ impl EntitySerializationEngine<GlweCiphertextVector64, Vec<u8>> for DefaultSeria
             version: GlweCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVector64) -> Vec<u8> {
@@ -1112,9 +1308,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextVectorView32<'b>, Vec<u8>>
             version: GlweCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVectorView32<'b>) -> Vec<u8> {
@@ -1185,9 +1383,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextVectorView64<'b>, Vec<u8>>
             version: GlweCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweCiphertextVectorView64<'b>) -> Vec<u8> {
@@ -1257,9 +1457,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextVectorMutView32<'b>, Vec<u8>>
             version: GlweCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(
@@ -1332,9 +1534,11 @@ impl<'b> EntitySerializationEngine<GlweCiphertextVectorMutView64<'b>, Vec<u8>>
             version: GlweCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(
@@ -1388,9 +1592,11 @@ impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializatio
             version: GlweSecretKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey32) -> Vec<u8> {
@@ -1441,9 +1647,11 @@ impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializatio
             version: GlweSecretKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey64) -> Vec<u8> {
@@ -1502,9 +1710,11 @@ impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSeria
             version: GlweSeededCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext32) -> Vec<u8> {
@@ -1563,9 +1773,11 @@ impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSeria
             version: GlweSeededCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext64) -> Vec<u8> {
@@ -1625,9 +1837,11 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
             version: GlweSeededCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector32) -> Vec<u8> {
@@ -1687,9 +1901,11 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
             version: GlweSeededCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector64) -> Vec<u8> {
@@ -1747,9 +1963,11 @@ impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializat
             version: LweBootstrapKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey32) -> Vec<u8> {
@@ -1807,9 +2025,11 @@ impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializat
             version: LweBootstrapKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey64) -> Vec<u8> {
@@ -1864,9 +2084,11 @@ impl EntitySerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializatio
             version: LweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext32) -> Vec<u8> {
@@ -1922,9 +2144,11 @@ impl EntitySerializationEngine<LweCiphertext64, Vec<u8>> for DefaultSerializatio
             version: LweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext64) -> Vec<u8> {
@@ -1984,9 +2208,11 @@ impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
             version: LweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView32<'b>) -> Vec<u8> {
@@ -2048,9 +2274,11 @@ impl<'b> EntitySerializationEngine<LweCiphertextView64<'b>, Vec<u8>>
             version: LweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView64<'b>) -> Vec<u8> {
@@ -2111,9 +2339,11 @@ impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
             version: LweCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView32<'b>) -> Vec<u8> {
@@ -2175,9 +2405,11 @@ impl<'b> EntitySerializationEngine<LweCiphertextMutView64<'b>, Vec<u8>>
             version: LweCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView64<'b>) -> Vec<u8> {
@@ -2251,9 +2483,11 @@ impl EntitySerializationEngine<LweCircuitBootstrapPrivateFunctionalPackingKeyswi
             version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(
@@ -2330,9 +2564,11 @@ impl EntitySerializationEngine<LweCircuitBootstrapPrivateFunctionalPackingKeyswi
             version: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(
@@ -2391,9 +2627,11 @@ impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerial
             version: LweCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector32) -> Vec<u8> {
@@ -2449,9 +2687,11 @@ impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerial
             version: LweCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector64) -> Vec<u8> {
@@ -2516,9 +2756,11 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
             version: LweCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView32<'b>) -> Vec<u8> {
@@ -2583,9 +2825,11 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
             version: LweCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView64<'b>) -> Vec<u8> {
@@ -2652,9 +2896,11 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
             version: LweCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView32<'b>) -> Vec<u8> {
@@ -2721,9 +2967,11 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
             version: LweCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView64<'b>) -> Vec<u8> {
@@ -2787,9 +3035,11 @@ impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializat
             version: LweKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey32) -> Vec<u8> {
@@ -2853,9 +3103,11 @@ impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializat
             version: LweKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey64) -> Vec<u8> {
@@ -2904,9 +3156,11 @@ impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerialization
             version: LweSecretKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey32) -> Vec<u8> {
@@ -2955,9 +3209,11 @@ impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerialization
             version: LweSecretKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey64) -> Vec<u8> {
@@ -3016,9 +3272,11 @@ impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSeri
             version: LweSeededBootstrapKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey32) -> Vec<u8> {
@@ -3078,9 +3336,11 @@ impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSeri
             version: LweSeededBootstrapKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey64) -> Vec<u8> {
@@ -3136,9 +3396,11 @@ impl EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>> for DefaultSerial
             version: LweSeededCiphertext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext32) -> Vec<u8> {
@@ -3194,9 +3456,11 @@ impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerial
             version: LweSeededCiphertext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext64) -> Vec<u8> {
@@ -3254,9 +3518,11 @@ impl EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
             version: LweSeededCiphertextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector32) -> Vec<u8> {
@@ -3314,9 +3580,11 @@ impl EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
             version: LweSeededCiphertextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector64) -> Vec<u8> {
@@ -3380,9 +3648,11 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>> for DefaultSeri
             version: LweSeededKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey32) -> Vec<u8> {
@@ -3446,9 +3716,11 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>> for DefaultSeri
             version: LweSeededKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey64) -> Vec<u8> {
@@ -3512,9 +3784,11 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>> for DefaultSer
             version: LwePackingKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey32) -> Vec<u8> {
@@ -3578,9 +3852,11 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>> for DefaultSer
             version: LwePackingKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey64) -> Vec<u8> {
@@ -3637,9 +3913,11 @@ impl EntitySerializationEngine<LwePublicKey32, Vec<u8>> for DefaultSerialization
             version: LwePublicKey32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LwePublicKey32) -> Vec<u8> {
@@ -3696,9 +3974,11 @@ impl EntitySerializationEngine<LwePublicKey64, Vec<u8>> for DefaultSerialization
             version: LwePublicKey64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &LwePublicKey64) -> Vec<u8> {
@@ -3747,9 +4027,11 @@ impl EntitySerializationEngine<Plaintext32, Vec<u8>> for DefaultSerializationEng
             version: Plaintext32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &Plaintext32) -> Vec<u8> {
@@ -3797,9 +4079,11 @@ impl EntitySerializationEngine<Plaintext64, Vec<u8>> for DefaultSerializationEng
             version: Plaintext64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &Plaintext64) -> Vec<u8> {
@@ -3848,9 +4132,11 @@ impl EntitySerializationEngine<PlaintextVector32, Vec<u8>> for DefaultSerializat
             version: PlaintextVector32Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &PlaintextVector32) -> Vec<u8> {
@@ -3899,9 +4185,11 @@ impl EntitySerializationEngine<PlaintextVector64, Vec<u8>> for DefaultSerializat
             version: PlaintextVector64Version::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &PlaintextVector64) -> Vec<u8> {
@@ -3953,9 +4241,11 @@ impl EntitySerializationEngine<FloatEncoder, Vec<u8>> for DefaultSerializationEn
             version: FloatEncoderVersion::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &FloatEncoder) -> Vec<u8> {
@@ -4011,12 +4301,600 @@ impl EntitySerializationEngine<FloatEncoderVector, Vec<u8>> for DefaultSerializa
             version: FloatEncoderVectorVersion::V0,
             inner: &entity.0,
         };
-        bincode::serialize(&serializable)
-            .map_err(DefaultSerializationError::Serialization)
-            .map_err(EntitySerializationError::Engine)
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
     }
 
     unsafe fn serialize_unchecked(&mut self, entity: &FloatEncoderVector) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded LWE public key entity.
+impl EntitySerializationEngine<LweSeededPublicKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey32 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_public_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_public_key, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededPublicKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededPublicKey32<'a> {
+            version: LweSeededPublicKey32Version,
+            inner: &'a ImplLweSeededList<Vec<u32>>,
+        }
+        let serializable = SerializableLweSeededPublicKey32 {
+            version: LweSeededPublicKey32Version::V0,
+            inner: &entity.0,
+        };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPublicKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded LWE public key entity.
+impl EntitySerializationEngine<LweSeededPublicKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount, *};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey64 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_public_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_public_key, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededPublicKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededPublicKey64<'a> {
+            version: LweSeededPublicKey64Version,
+            inner: &'a ImplLweSeededList<Vec<u64>>,
+        }
+        let serializable = SerializableLweSeededPublicKey64 {
+            version: LweSeededPublicKey64Version::V0,
+            inner: &entity.0,
+        };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPublicKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPackingKeyswitchKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededPackingKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededPackingKeyswitchKey32<'a> {
+            version: LweSeededPackingKeyswitchKey32Version,
+            inner: &'a ImplLweSeededPackingKeyswitchKey<Vec<u32>>,
+        }
+        let serializable = SerializableLweSeededPackingKeyswitchKey32 {
+            version: LweSeededPackingKeyswitchKey32Version::V0,
+            inner: &entity.0,
+        };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPackingKeyswitchKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPackingKeyswitchKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key = engine.generate_new_lwe_seeded_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededPackingKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededPackingKeyswitchKey64<'a> {
+            version: LweSeededPackingKeyswitchKey64Version,
+            inner: &'a ImplLweSeededPackingKeyswitchKey<Vec<u64>>,
+        }
+        let serializable = SerializableLweSeededPackingKeyswitchKey64 {
+            version: LweSeededPackingKeyswitchKey64Version::V0,
+            inner: &entity.0,
+        };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPackingKeyswitchKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded private functional packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPrivateFunctionalPackingKeyswitchKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     StandardDev, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = StandardDev(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u32; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector32 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk = engine.generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    ///     &|x| x,
+    ///     &polynomial,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_pfpksk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_pfpksk, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededPrivateFunctionalPackingKeyswitchKey32<'a> {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+            inner: &'a ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u32>>,
+        }
+        let serializable = SerializableLweSeededPrivateFunctionalPackingKeyswitchKey32 {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version::V0,
+            inner: &entity.0,
+        };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    ) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded private functional packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPrivateFunctionalPackingKeyswitchKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     StandardDev, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = StandardDev(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u64; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector64 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk = engine.generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    ///     &|x| x,
+    ///     &polynomial,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_pfpksk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_pfpksk, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededPrivateFunctionalPackingKeyswitchKey64<'a> {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version,
+            inner: &'a ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u64>>,
+        }
+        let serializable = SerializableLweSeededPrivateFunctionalPackingKeyswitchKey64 {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version::V0,
+            inner: &entity.0,
+        };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    ) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded circuit bootstrap private functional packing
+/// keyswitch keys entity.
+impl
+    EntitySerializationEngine<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        Vec<u8>,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk = engine
+    ///     .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_cbs_pfpksk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_cbs_pfpksk, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32<'a> {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+            inner: &'a ImplLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<Vec<u32>>,
+        }
+        let serializable =
+            SerializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+                version:
+                    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::V0,
+                inner: &entity.0,
+            };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded circuit bootstrap private functional packing
+/// keyswitch keys entity.
+impl
+    EntitySerializationEngine<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        Vec<u8>,
+    > for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk = engine
+    ///     .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_cbs_pfpksk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_cbs_pfpksk, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64<'a> {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+            inner: &'a ImplLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<Vec<u64>>,
+        }
+        let serializable =
+            SerializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+                version:
+                    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::V0,
+                inner: &entity.0,
+            };
+        serialize_with_header(entity, |writer| {
+            bincode::serialize_into(writer, &serializable)
+        })
+        .map_err(serialization_error)
+        .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
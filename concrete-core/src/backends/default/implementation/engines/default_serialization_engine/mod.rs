@@ -1,5 +1,6 @@
 use crate::prelude::sealed::AbstractEngineSeal;
 use crate::prelude::AbstractEngine;
+use crate::specification::serialization::{EnvelopeError, SerializationHeaderError};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -9,6 +10,7 @@ pub enum DefaultSerializationError {
     Serialization(bincode::Error),
     Deserialization(bincode::Error),
     UnsupportedVersion,
+    Header(SerializationHeaderError),
 }
 
 #[allow(unused_variables)]
@@ -28,12 +30,33 @@ impl Display for DefaultSerializationError {
                     "The version used to serialize the entity is not supported."
                 )
             }
+            DefaultSerializationError::Header(header_error) => {
+                write!(f, "Invalid serialization header: {}", header_error)
+            }
         }
     }
 }
 
 impl Error for DefaultSerializationError {}
 
+fn serialization_error(error: EnvelopeError<bincode::Error>) -> DefaultSerializationError {
+    match error {
+        EnvelopeError::Header(header_error) => DefaultSerializationError::Header(header_error),
+        EnvelopeError::Payload(bincode_error) => {
+            DefaultSerializationError::Serialization(bincode_error)
+        }
+    }
+}
+
+fn deserialization_error(error: EnvelopeError<bincode::Error>) -> DefaultSerializationError {
+    match error {
+        EnvelopeError::Header(header_error) => DefaultSerializationError::Header(header_error),
+        EnvelopeError::Payload(bincode_error) => {
+            DefaultSerializationError::Deserialization(bincode_error)
+        }
+    }
+}
+
 pub struct DefaultSerializationEngine;
 
 impl AbstractEngineSeal for DefaultSerializationEngine {}
@@ -52,3 +75,7 @@ impl AbstractEngine for DefaultSerializationEngine {
 
 mod entity_deserialization;
 mod entity_serialization;
+mod entity_stream_deserialization;
+mod entity_stream_serialization;
+mod serialization_header_inspection;
+mod serialized_entity;
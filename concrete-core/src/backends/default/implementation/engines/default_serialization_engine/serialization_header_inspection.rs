@@ -0,0 +1,122 @@
+#![allow(clippy::missing_safety_doc)]
+
+use crate::prelude::{
+    DefaultSerializationEngine, DefaultSerializationError, SerializationHeaderInspectionEngine,
+    SerializationHeaderInspectionError,
+};
+use crate::specification::serialization::{decode_header, read_header, SerializationHeader};
+use std::io::Read;
+
+/// # Description:
+/// Implementation of [`SerializationHeaderInspectionEngine`] for [`DefaultSerializationEngine`]
+/// that operates on serialized entities held in memory.
+impl SerializationHeaderInspectionEngine<&[u8]> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    ///
+    /// let header = serialization_engine.inspect_serialization_header(serialized.as_slice())?;
+    /// assert_eq!(header.kind, SerializedEntityKind::LweCiphertext);
+    /// assert_eq!(header.precision, SerializedEntityPrecision::Unsigned32);
+    /// assert_eq!(header.parameters.lwe_dimension, Some(lwe_dimension));
+    /// assert_eq!(header.serialized_size(), serialized.len() as u64);
+    /// header.validate(serialized.as_slice())?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn inspect_serialization_header(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<SerializationHeader, SerializationHeaderInspectionError<Self::EngineError>> {
+        decode_header(serialized)
+            .map_err(DefaultSerializationError::Header)
+            .map_err(SerializationHeaderInspectionError::Engine)
+    }
+
+    unsafe fn inspect_serialization_header_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> SerializationHeader {
+        self.inspect_serialization_header(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`SerializationHeaderInspectionEngine`] for [`DefaultSerializationEngine`]
+/// that operates on readers. Only the bytes of the header are consumed from the reader.
+impl<R: Read> SerializationHeaderInspectionEngine<&mut R> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext_vector, &mut serialized)?;
+    ///
+    /// let mut reader = serialized.as_slice();
+    /// let header = serialization_engine.inspect_serialization_header(&mut reader)?;
+    /// assert_eq!(header.kind, SerializedEntityKind::LweCiphertextVector);
+    /// assert_eq!(header.precision, SerializedEntityPrecision::Unsigned64);
+    /// assert_eq!(header.parameters.lwe_dimension, Some(lwe_dimension));
+    /// assert_eq!(header.parameters.count, Some(3));
+    /// assert_eq!(
+    ///     reader.len(),
+    ///     header.payload_size as usize + SERIALIZATION_TRAILER_SIZE
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn inspect_serialization_header(
+        &mut self,
+        serialized: &mut R,
+    ) -> Result<SerializationHeader, SerializationHeaderInspectionError<Self::EngineError>> {
+        read_header(serialized)
+            .map_err(DefaultSerializationError::Header)
+            .map_err(SerializationHeaderInspectionError::Engine)
+    }
+
+    unsafe fn inspect_serialization_header_unchecked(
+        &mut self,
+        serialized: &mut R,
+    ) -> SerializationHeader {
+        self.inspect_serialization_header(serialized).unwrap()
+    }
+}
@@ -0,0 +1,750 @@
+use crate::prelude::{
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorEntity, CleartextVectorF64, EncoderVectorEntity, FloatEncoder,
+    FloatEncoderVector, GgswCiphertext32, GgswCiphertext64, GgswCiphertextEntity,
+    GgswCiphertextVector32, GgswCiphertextVector64, GgswCiphertextVectorEntity,
+    GgswSeededCiphertext32, GgswSeededCiphertext64, GgswSeededCiphertextEntity, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextEntity, GlweCiphertextMutView32, GlweCiphertextMutView64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweCiphertextVectorEntity,
+    GlweCiphertextVectorMutView32, GlweCiphertextVectorMutView64, GlweCiphertextVectorView32,
+    GlweCiphertextVectorView64, GlweCiphertextView32, GlweCiphertextView64, GlweSecretKey32,
+    GlweSecretKey64, GlweSecretKeyEntity, GlweSeededCiphertext32, GlweSeededCiphertext64,
+    GlweSeededCiphertextEntity, GlweSeededCiphertextVector32, GlweSeededCiphertextVector64,
+    GlweSeededCiphertextVectorEntity, LweBootstrapKey32, LweBootstrapKey64, LweBootstrapKeyEntity,
+    LweCiphertext32, LweCiphertext64, LweCiphertextEntity, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorEntity, LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView32, LweCiphertextVectorView64, LweCiphertextView32, LweCiphertextView64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweKeyswitchKeyEntity, LwePackingKeyswitchKey32, LwePackingKeyswitchKey64,
+    LwePackingKeyswitchKeyEntity, LwePublicKey32, LwePublicKey64, LwePublicKeyEntity,
+    LweSecretKey32, LweSecretKey64, LweSecretKeyEntity, LweSeededBootstrapKey32,
+    LweSeededBootstrapKey64, LweSeededBootstrapKeyEntity, LweSeededCiphertext32,
+    LweSeededCiphertext64, LweSeededCiphertextEntity, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64, LweSeededCiphertextVectorEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, LweSeededKeyswitchKey32,
+    LweSeededKeyswitchKey64, LweSeededKeyswitchKeyEntity, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey64, LweSeededPackingKeyswitchKeyEntity,
+    LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyEntity, LweSeededPublicKey32,
+    LweSeededPublicKey64, LweSeededPublicKeyEntity, Plaintext32, Plaintext64, PlaintextVector32,
+    PlaintextVector64, PlaintextVectorEntity, SerializedEntityParameters,
+};
+use crate::specification::serialization::impl_serialized_entity;
+
+impl_serialized_entity!(
+    Cleartext,
+    Unsigned32,
+    |_entity| SerializedEntityParameters::default(),
+    Cleartext32,
+);
+
+impl_serialized_entity!(
+    Cleartext,
+    Unsigned64,
+    |_entity| SerializedEntityParameters::default(),
+    Cleartext64,
+);
+
+impl_serialized_entity!(
+    Cleartext,
+    Float64,
+    |_entity| SerializedEntityParameters::default(),
+    CleartextF64,
+);
+
+impl_serialized_entity!(
+    CleartextVector,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        count: Some(entity.cleartext_count().0),
+        ..Default::default()
+    },
+    CleartextVector32,
+);
+
+impl_serialized_entity!(
+    CleartextVector,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        count: Some(entity.cleartext_count().0),
+        ..Default::default()
+    },
+    CleartextVector64,
+);
+
+impl_serialized_entity!(
+    CleartextVector,
+    Float64,
+    |entity| SerializedEntityParameters {
+        count: Some(entity.cleartext_count().0),
+        ..Default::default()
+    },
+    CleartextVectorF64,
+);
+
+impl_serialized_entity!(
+    Plaintext,
+    Unsigned32,
+    |_entity| SerializedEntityParameters::default(),
+    Plaintext32,
+);
+
+impl_serialized_entity!(
+    Plaintext,
+    Unsigned64,
+    |_entity| SerializedEntityParameters::default(),
+    Plaintext64,
+);
+
+impl_serialized_entity!(
+    PlaintextVector,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        count: Some(entity.plaintext_count().0),
+        ..Default::default()
+    },
+    PlaintextVector32,
+);
+
+impl_serialized_entity!(
+    PlaintextVector,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        count: Some(entity.plaintext_count().0),
+        ..Default::default()
+    },
+    PlaintextVector64,
+);
+
+impl_serialized_entity!(
+    FloatEncoder,
+    Float64,
+    |_entity| SerializedEntityParameters::default(),
+    FloatEncoder,
+);
+
+impl_serialized_entity!(
+    FloatEncoderVector,
+    Float64,
+    |entity| SerializedEntityParameters {
+        count: Some(entity.encoder_count().0),
+        ..Default::default()
+    },
+    FloatEncoderVector,
+);
+
+impl_serialized_entity!(
+    LweCiphertext,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        ..Default::default()
+    },
+    LweCiphertext32,
+    LweCiphertextView32<'_>,
+    LweCiphertextMutView32<'_>,
+);
+
+impl_serialized_entity!(
+    LweCiphertext,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        ..Default::default()
+    },
+    LweCiphertext64,
+    LweCiphertextView64<'_>,
+    LweCiphertextMutView64<'_>,
+);
+
+impl_serialized_entity!(
+    LweCiphertextVector,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        count: Some(entity.lwe_ciphertext_count().0),
+        ..Default::default()
+    },
+    LweCiphertextVector32,
+    LweCiphertextVectorView32<'_>,
+    LweCiphertextVectorMutView32<'_>,
+);
+
+impl_serialized_entity!(
+    LweCiphertextVector,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        count: Some(entity.lwe_ciphertext_count().0),
+        ..Default::default()
+    },
+    LweCiphertextVector64,
+    LweCiphertextVectorView64<'_>,
+    LweCiphertextVectorMutView64<'_>,
+);
+
+impl_serialized_entity!(
+    LweSeededCiphertext,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        ..Default::default()
+    },
+    LweSeededCiphertext32,
+);
+
+impl_serialized_entity!(
+    LweSeededCiphertext,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        ..Default::default()
+    },
+    LweSeededCiphertext64,
+);
+
+impl_serialized_entity!(
+    LweSeededCiphertextVector,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        count: Some(entity.lwe_ciphertext_count().0),
+        ..Default::default()
+    },
+    LweSeededCiphertextVector32,
+);
+
+impl_serialized_entity!(
+    LweSeededCiphertextVector,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        count: Some(entity.lwe_ciphertext_count().0),
+        ..Default::default()
+    },
+    LweSeededCiphertextVector64,
+);
+
+impl_serialized_entity!(
+    GlweCiphertext,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        ..Default::default()
+    },
+    GlweCiphertext32,
+    GlweCiphertextView32<'_>,
+    GlweCiphertextMutView32<'_>,
+);
+
+impl_serialized_entity!(
+    GlweCiphertext,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        ..Default::default()
+    },
+    GlweCiphertext64,
+    GlweCiphertextView64<'_>,
+    GlweCiphertextMutView64<'_>,
+);
+
+impl_serialized_entity!(
+    GlweCiphertextVector,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        count: Some(entity.glwe_ciphertext_count().0),
+        ..Default::default()
+    },
+    GlweCiphertextVector32,
+    GlweCiphertextVectorView32<'_>,
+    GlweCiphertextVectorMutView32<'_>,
+);
+
+impl_serialized_entity!(
+    GlweCiphertextVector,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        count: Some(entity.glwe_ciphertext_count().0),
+        ..Default::default()
+    },
+    GlweCiphertextVector64,
+    GlweCiphertextVectorView64<'_>,
+    GlweCiphertextVectorMutView64<'_>,
+);
+
+impl_serialized_entity!(
+    GlweSeededCiphertext,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        ..Default::default()
+    },
+    GlweSeededCiphertext32,
+);
+
+impl_serialized_entity!(
+    GlweSeededCiphertext,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        ..Default::default()
+    },
+    GlweSeededCiphertext64,
+);
+
+impl_serialized_entity!(
+    GlweSeededCiphertextVector,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        count: Some(entity.glwe_ciphertext_count().0),
+        ..Default::default()
+    },
+    GlweSeededCiphertextVector32,
+);
+
+impl_serialized_entity!(
+    GlweSeededCiphertextVector,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        count: Some(entity.glwe_ciphertext_count().0),
+        ..Default::default()
+    },
+    GlweSeededCiphertextVector64,
+);
+
+impl_serialized_entity!(
+    GgswCiphertext,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    GgswCiphertext32,
+);
+
+impl_serialized_entity!(
+    GgswCiphertext,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    GgswCiphertext64,
+);
+
+impl_serialized_entity!(
+    GgswCiphertextVector,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        count: Some(entity.ggsw_ciphertext_count().0),
+        ..Default::default()
+    },
+    GgswCiphertextVector32,
+);
+
+impl_serialized_entity!(
+    GgswCiphertextVector,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        count: Some(entity.ggsw_ciphertext_count().0),
+        ..Default::default()
+    },
+    GgswCiphertextVector64,
+);
+
+impl_serialized_entity!(
+    GgswSeededCiphertext,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    GgswSeededCiphertext32,
+);
+
+impl_serialized_entity!(
+    GgswSeededCiphertext,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    GgswSeededCiphertext64,
+);
+
+impl_serialized_entity!(
+    LweSecretKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        ..Default::default()
+    },
+    LweSecretKey32,
+);
+
+impl_serialized_entity!(
+    LweSecretKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        ..Default::default()
+    },
+    LweSecretKey64,
+);
+
+impl_serialized_entity!(
+    GlweSecretKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        ..Default::default()
+    },
+    GlweSecretKey32,
+);
+
+impl_serialized_entity!(
+    GlweSecretKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        ..Default::default()
+    },
+    GlweSecretKey64,
+);
+
+impl_serialized_entity!(
+    LwePublicKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        count: Some(entity.lwe_zero_encryption_count().0),
+        ..Default::default()
+    },
+    LwePublicKey32,
+);
+
+impl_serialized_entity!(
+    LwePublicKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        count: Some(entity.lwe_zero_encryption_count().0),
+        ..Default::default()
+    },
+    LwePublicKey64,
+);
+
+impl_serialized_entity!(
+    LweSeededPublicKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        count: Some(entity.lwe_zero_encryption_count().0),
+        ..Default::default()
+    },
+    LweSeededPublicKey32,
+);
+
+impl_serialized_entity!(
+    LweSeededPublicKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.lwe_dimension()),
+        count: Some(entity.lwe_zero_encryption_count().0),
+        ..Default::default()
+    },
+    LweSeededPublicKey64,
+);
+
+impl_serialized_entity!(
+    LweKeyswitchKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        output_lwe_dimension: Some(entity.output_lwe_dimension()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweKeyswitchKey32,
+);
+
+impl_serialized_entity!(
+    LweKeyswitchKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        output_lwe_dimension: Some(entity.output_lwe_dimension()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweKeyswitchKey64,
+);
+
+impl_serialized_entity!(
+    LweSeededKeyswitchKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        output_lwe_dimension: Some(entity.output_lwe_dimension()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweSeededKeyswitchKey32,
+);
+
+impl_serialized_entity!(
+    LweSeededKeyswitchKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        output_lwe_dimension: Some(entity.output_lwe_dimension()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweSeededKeyswitchKey64,
+);
+
+impl_serialized_entity!(
+    LwePackingKeyswitchKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LwePackingKeyswitchKey32,
+);
+
+impl_serialized_entity!(
+    LwePackingKeyswitchKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LwePackingKeyswitchKey64,
+);
+
+impl_serialized_entity!(
+    LweSeededPackingKeyswitchKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweSeededPackingKeyswitchKey32,
+);
+
+impl_serialized_entity!(
+    LweSeededPackingKeyswitchKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweSeededPackingKeyswitchKey64,
+);
+
+impl_serialized_entity!(
+    LweSeededPrivateFunctionalPackingKeyswitchKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweSeededPrivateFunctionalPackingKeyswitchKey32,
+);
+
+impl_serialized_entity!(
+    LweSeededPrivateFunctionalPackingKeyswitchKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+);
+
+impl_serialized_entity!(
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        count: Some(entity.key_count().0),
+        ..Default::default()
+    },
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+);
+
+impl_serialized_entity!(
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        count: Some(entity.key_count().0),
+        ..Default::default()
+    },
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+);
+
+impl_serialized_entity!(
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        count: Some(entity.key_count().0),
+        ..Default::default()
+    },
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+);
+
+impl_serialized_entity!(
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.output_glwe_dimension()),
+        polynomial_size: Some(entity.output_polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        count: Some(entity.key_count().0),
+        ..Default::default()
+    },
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+);
+
+impl_serialized_entity!(
+    LweBootstrapKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweBootstrapKey32,
+);
+
+impl_serialized_entity!(
+    LweBootstrapKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweBootstrapKey64,
+);
+
+impl_serialized_entity!(
+    LweSeededBootstrapKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweSeededBootstrapKey32,
+);
+
+impl_serialized_entity!(
+    LweSeededBootstrapKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    LweSeededBootstrapKey64,
+);
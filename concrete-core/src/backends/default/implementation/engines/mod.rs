@@ -13,5 +13,10 @@ mod default_serialization_engine;
 #[cfg(feature = "backend_default_serialization")]
 pub use default_serialization_engine::*;
 
+#[cfg(feature = "backend_default_interchange")]
+mod default_interchange_engine;
+#[cfg(feature = "backend_default_interchange")]
+pub use default_interchange_engine::*;
+
 mod activated_generator;
 pub use activated_generator::ActivatedRandomGenerator;
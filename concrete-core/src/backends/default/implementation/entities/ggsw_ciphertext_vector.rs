@@ -0,0 +1,81 @@
+use crate::commons::crypto::ggsw::StandardGgswCiphertextList as ImplStandardGgswCiphertextList;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    PolynomialSize,
+};
+use crate::specification::entities::markers::GgswCiphertextVectorKind;
+use crate::specification::entities::{AbstractEntity, GgswCiphertextVectorEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a vector of GGSW ciphertexts with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GgswCiphertextVector32(pub(crate) ImplStandardGgswCiphertextList<Vec<u32>>);
+impl AbstractEntity for GgswCiphertextVector32 {
+    type Kind = GgswCiphertextVectorKind;
+}
+impl GgswCiphertextVectorEntity for GgswCiphertextVector32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        self.0.ciphertext_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GgswCiphertextVector32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a vector of GGSW ciphertexts with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GgswCiphertextVector64(pub(crate) ImplStandardGgswCiphertextList<Vec<u64>>);
+impl AbstractEntity for GgswCiphertextVector64 {
+    type Kind = GgswCiphertextVectorKind;
+}
+impl GgswCiphertextVectorEntity for GgswCiphertextVector64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        self.0.ciphertext_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GgswCiphertextVector64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
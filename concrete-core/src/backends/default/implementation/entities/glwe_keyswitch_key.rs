@@ -0,0 +1,62 @@
+use crate::commons::crypto::glwe::GlweKeyswitchKey as ImplGlweKeyswitchKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweKeyswitchKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweKeyswitchKeyEntity};
+
+/// A structure representing a GLWE keyswitch key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweKeyswitchKey32(pub(crate) ImplGlweKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for GlweKeyswitchKey32 {
+    type Kind = GlweKeyswitchKeyKind;
+}
+impl GlweKeyswitchKeyEntity for GlweKeyswitchKey32 {
+    fn input_glwe_dimension(&self) -> GlweDimension {
+        self.0.input_glwe_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing a GLWE keyswitch key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweKeyswitchKey64(pub(crate) ImplGlweKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for GlweKeyswitchKey64 {
+    type Kind = GlweKeyswitchKeyKind;
+}
+impl GlweKeyswitchKeyEntity for GlweKeyswitchKey64 {
+    fn input_glwe_dimension(&self) -> GlweDimension {
+        self.0.input_glwe_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
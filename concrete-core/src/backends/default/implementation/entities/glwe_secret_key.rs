@@ -1,5 +1,5 @@
 use crate::commons::crypto::secret::GlweSecretKey as ImpGlweSecretKey;
-use crate::prelude::{BinaryKeyKind, GlweDimension, PolynomialSize};
+use crate::prelude::{BinaryKeyKind, GlweDimension, PolynomialSize, TernaryKeyKind};
 use crate::specification::entities::markers::GlweSecretKeyKind;
 use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
 #[cfg(feature = "backend_default_serialization")]
@@ -52,3 +52,37 @@ pub(crate) enum GlweSecretKey64Version {
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing a GLWE secret key with 32 bits of precision, whose polynomial
+/// coefficients are sampled uniformly among -1, 0 and 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTernarySecretKey32(pub(crate) ImpGlweSecretKey<TernaryKeyKind, Vec<u32>>);
+impl AbstractEntity for GlweTernarySecretKey32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweTernarySecretKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE secret key with 64 bits of precision, whose polynomial
+/// coefficients are sampled uniformly among -1, 0 and 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTernarySecretKey64(pub(crate) ImpGlweSecretKey<TernaryKeyKind, Vec<u64>>);
+impl AbstractEntity for GlweTernarySecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweTernarySecretKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
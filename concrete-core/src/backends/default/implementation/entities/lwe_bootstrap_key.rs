@@ -218,3 +218,67 @@ impl LweBootstrapKeyEntity for LweBootstrapKeyView64<'_> {
         self.0.level_count()
     }
 }
+
+/// A structure representing an LWE bootstrap key with 32 bits of precision, for a ternary input
+/// LWE secret key.
+///
+/// Every coefficient of the input key is encrypted in two GGSW ciphertexts, encrypting the
+/// indicators of the coefficient being respectively equal to $1$ and to $-1$.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryBootstrapKey32(pub(crate) ImplStandardBootstrapKey<Vec<u32>>);
+impl AbstractEntity for LweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweTernaryBootstrapKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, for a ternary input
+/// LWE secret key.
+///
+/// Every coefficient of the input key is encrypted in two GGSW ciphertexts, encrypting the
+/// indicators of the coefficient being respectively equal to $1$ and to $-1$.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryBootstrapKey64(pub(crate) ImplStandardBootstrapKey<Vec<u64>>);
+impl AbstractEntity for LweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweTernaryBootstrapKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
@@ -0,0 +1,71 @@
+use crate::commons::crypto::bootstrap::StandardMultiBitBootstrapKey as ImplStandardMultiBitBootstrapKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    LweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::LweMultiBitBootstrapKeyKind;
+use crate::specification::entities::{AbstractEntity, LweMultiBitBootstrapKeyEntity};
+
+/// A structure representing a multi-bit LWE bootstrap key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweMultiBitBootstrapKey32(pub(crate) ImplStandardMultiBitBootstrapKey<Vec<u32>>);
+impl AbstractEntity for LweMultiBitBootstrapKey32 {
+    type Kind = LweMultiBitBootstrapKeyKind;
+}
+impl LweMultiBitBootstrapKeyEntity for LweMultiBitBootstrapKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+
+    fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.0.grouping_factor()
+    }
+}
+
+/// A structure representing a multi-bit LWE bootstrap key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweMultiBitBootstrapKey64(pub(crate) ImplStandardMultiBitBootstrapKey<Vec<u64>>);
+impl AbstractEntity for LweMultiBitBootstrapKey64 {
+    type Kind = LweMultiBitBootstrapKeyKind;
+}
+impl LweMultiBitBootstrapKeyEntity for LweMultiBitBootstrapKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+
+    fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.0.grouping_factor()
+    }
+}
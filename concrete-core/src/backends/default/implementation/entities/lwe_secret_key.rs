@@ -1,5 +1,5 @@
 use crate::commons::crypto::secret::LweSecretKey as ImpLweSecretKey;
-use crate::prelude::{BinaryKeyKind, LweDimension};
+use crate::prelude::{BinaryKeyKind, LweDimension, TernaryKeyKind};
 use crate::specification::entities::markers::LweSecretKeyKind;
 use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
 #[cfg(feature = "backend_default_serialization")]
@@ -44,3 +44,29 @@ pub(crate) enum LweSecretKey64Version {
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing an LWE secret key with 32 bits of precision, whose coefficients are
+/// sampled uniformly among -1, 0 and 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernarySecretKey32(pub(crate) ImpLweSecretKey<TernaryKeyKind, Vec<u32>>);
+impl AbstractEntity for LweTernarySecretKey32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweTernarySecretKey32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+/// A structure representing an LWE secret key with 64 bits of precision, whose coefficients are
+/// sampled uniformly among -1, 0 and 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernarySecretKey64(pub(crate) ImpLweSecretKey<TernaryKeyKind, Vec<u64>>);
+impl AbstractEntity for LweTernarySecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweTernarySecretKey64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
@@ -0,0 +1,90 @@
+use crate::commons::crypto::glwe::LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey;
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::markers::LweSeededPackingKeyswitchKeyKind;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    LweSeededPackingKeyswitchKeyEntity, PolynomialSize,
+};
+use crate::specification::entities::AbstractEntity;
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a seeded packing keyswitch key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPackingKeyswitchKey32(pub(crate) ImplLweSeededPackingKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for LweSeededPackingKeyswitchKey32 {
+    type Kind = LweSeededPackingKeyswitchKeyKind;
+}
+impl LweSeededPackingKeyswitchKeyEntity for LweSeededPackingKeyswitchKey32 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSeededPackingKeyswitchKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a seeded packing keyswitch key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPackingKeyswitchKey64(pub(crate) ImplLweSeededPackingKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for LweSeededPackingKeyswitchKey64 {
+    type Kind = LweSeededPackingKeyswitchKeyKind;
+}
+impl LweSeededPackingKeyswitchKeyEntity for LweSeededPackingKeyswitchKey64 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSeededPackingKeyswitchKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,100 @@
+use crate::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKey as ImplLweSeededPrivateFunctionalPackingKeyswitchKey;
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::markers::LweSeededPrivateFunctionalPackingKeyswitchKeyKind;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyEntity, PolynomialSize,
+};
+use crate::specification::entities::AbstractEntity;
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a seeded private functional packing keyswitch key with 32 bits of
+/// precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKey32(
+    pub(crate) ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u32>>,
+);
+impl AbstractEntity for LweSeededPrivateFunctionalPackingKeyswitchKey32 {
+    type Kind = LweSeededPrivateFunctionalPackingKeyswitchKeyKind;
+}
+impl LweSeededPrivateFunctionalPackingKeyswitchKeyEntity
+    for LweSeededPrivateFunctionalPackingKeyswitchKey32
+{
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSeededPrivateFunctionalPackingKeyswitchKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a seeded private functional packing keyswitch key with 64 bits of
+/// precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKey64(
+    pub(crate) ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u64>>,
+);
+impl AbstractEntity for LweSeededPrivateFunctionalPackingKeyswitchKey64 {
+    type Kind = LweSeededPrivateFunctionalPackingKeyswitchKeyKind;
+}
+impl LweSeededPrivateFunctionalPackingKeyswitchKeyEntity
+    for LweSeededPrivateFunctionalPackingKeyswitchKey64
+{
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSeededPrivateFunctionalPackingKeyswitchKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -181,3 +181,71 @@ impl GgswCiphertextConversionEngine<GgswCiphertext64, FftFourierGgswCiphertext64
         output
     }
 }
+
+#[cfg(test)]
+mod unit_test_polynomial_ggsw {
+    use crate::commons::test_tools::new_random_generator;
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_external_product_with_polynomial_ggsw() -> Result<(), Box<dyn Error>> {
+        let glwe_dimension = GlweDimension(1);
+        let polynomial_size = PolynomialSize(1024);
+        let level = DecompositionLevelCount(3);
+        let base_log = DecompositionBaseLog(10);
+        let noise = Variance(2_f64.powf(-100.));
+        let message_modulus = 16_u64;
+        let delta = 1_u64 << 60;
+        let degree = 3;
+
+        // Unix seeder must be given a secret input.
+        // Here we just give it 0, which is totally unsafe.
+        const UNSAFE_SECRET: u128 = 0;
+        let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut fft_engine = FftEngine::new(())?;
+        let mut generator = new_random_generator();
+
+        let key: GlweSecretKey64 =
+            default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+
+        // The GGSW ciphertext encrypts the monomial X^degree
+        let mut monomial = vec![0_u64; polynomial_size.0];
+        monomial[degree] = 1;
+        let monomial = default_engine.create_plaintext_vector_from(&monomial)?;
+        let ggsw: GgswCiphertext64 = default_engine
+            .encrypt_polynomial_ggsw_ciphertext(&key, &monomial, noise, level, base_log)?;
+        let fourier_ggsw: FftFourierGgswCiphertext64 = fft_engine.convert_ggsw_ciphertext(&ggsw)?;
+
+        let messages: Vec<u64> = (0..polynomial_size.0)
+            .map(|_| generator.random_uniform::<u64>() % message_modulus)
+            .collect();
+        let encoded: Vec<u64> = messages.iter().map(|m| m * delta).collect();
+        let plaintext_vector = default_engine.create_plaintext_vector_from(&encoded)?;
+        let glwe = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+        let mut product = glwe.clone();
+        fft_engine.discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+            &glwe,
+            &fourier_ggsw,
+            &mut product,
+        )?;
+
+        let decrypted = default_engine.decrypt_glwe_ciphertext(&key, &product)?;
+        let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+        let decoded: Vec<u64> = decrypted
+            .iter()
+            .map(|d| (d.wrapping_add(delta / 2) / delta) % message_modulus)
+            .collect();
+
+        // Multiplying by X^degree is a negacyclic rotation of the coefficients
+        let mut expected: Vec<u64> = messages.clone();
+        expected.rotate_right(degree);
+        expected
+            .iter_mut()
+            .take(degree)
+            .for_each(|m| *m = m.wrapping_neg() % message_modulus);
+        assert_eq!(decoded, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::ggsw::fill_with_forward_fourier_scratch;
+use crate::backends::fft::private::crypto::wop_pbs::FourierGgswCiphertextList;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    FftFourierGgswCiphertextVector32, FftFourierGgswCiphertextVector64, GgswCiphertextVector32,
+    GgswCiphertextVector64, GgswCiphertextVectorConversionEngine,
+    GgswCiphertextVectorConversionError,
+};
+use crate::specification::entities::GgswCiphertextVectorEntity;
+use aligned_vec::avec;
+use concrete_fft::c64;
+
+impl From<FftError> for GgswCiphertextVectorConversionError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GgswCiphertextVectorConversionEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers. It converts a GGSW ciphertext vector from the standard to the Fourier domain.
+impl GgswCiphertextVectorConversionEngine<GgswCiphertextVector32, FftFourierGgswCiphertextVector32>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext vector in the standard domain
+    /// let ciphertext_vector = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext_vector: FftFourierGgswCiphertextVector32 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ciphertext_vector)?;
+    ///
+    /// assert_eq!(fourier_ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(fourier_ciphertext_vector.decomposition_base_log(), base_log);
+    /// assert_eq!(fourier_ciphertext_vector.decomposition_level_count(), level);
+    /// assert_eq!(
+    ///     fourier_ciphertext_vector.ggsw_ciphertext_count(),
+    ///     GgswCiphertextCount(3)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext_vector(
+        &mut self,
+        input: &GgswCiphertextVector32,
+    ) -> Result<
+        FftFourierGgswCiphertextVector32,
+        GgswCiphertextVectorConversionError<Self::EngineError>,
+    > {
+        FftError::perform_fft_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_ggsw_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        input: &GgswCiphertextVector32,
+    ) -> FftFourierGgswCiphertextVector32 {
+        let mut output = {
+            let glwe_size = input.glwe_dimension().to_glwe_size();
+            let polynomial_size = input.polynomial_size();
+            let count = input.ggsw_ciphertext_count().0;
+            FourierGgswCiphertextList::new(
+                avec![
+                    c64::default();
+                    count
+                        * polynomial_size.0
+                        * glwe_size.0
+                        * glwe_size.0
+                        * input.decomposition_level_count().0
+                        / 2
+                ]
+                .into_boxed_slice(),
+                count,
+                polynomial_size,
+                glwe_size,
+                input.decomposition_base_log(),
+                input.decomposition_level_count(),
+            )
+        };
+
+        let fft = Fft::new(input.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        for (fourier_ggsw, ggsw) in output
+            .as_mut_view()
+            .into_ggsw_iter()
+            .zip(input.0.ciphertext_iter())
+        {
+            fourier_ggsw.fill_with_forward_fourier(ggsw.as_view(), fft, self.stack());
+        }
+        FftFourierGgswCiphertextVector32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GgswCiphertextVectorConversionEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers. It converts a GGSW ciphertext vector from the standard to the Fourier domain.
+impl GgswCiphertextVectorConversionEngine<GgswCiphertextVector64, FftFourierGgswCiphertextVector64>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext vector in the standard domain
+    /// let ciphertext_vector = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext_vector: FftFourierGgswCiphertextVector64 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ciphertext_vector)?;
+    ///
+    /// assert_eq!(fourier_ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(fourier_ciphertext_vector.decomposition_base_log(), base_log);
+    /// assert_eq!(fourier_ciphertext_vector.decomposition_level_count(), level);
+    /// assert_eq!(
+    ///     fourier_ciphertext_vector.ggsw_ciphertext_count(),
+    ///     GgswCiphertextCount(3)
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext_vector(
+        &mut self,
+        input: &GgswCiphertextVector64,
+    ) -> Result<
+        FftFourierGgswCiphertextVector64,
+        GgswCiphertextVectorConversionError<Self::EngineError>,
+    > {
+        FftError::perform_fft_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_ggsw_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        input: &GgswCiphertextVector64,
+    ) -> FftFourierGgswCiphertextVector64 {
+        let mut output = {
+            let glwe_size = input.glwe_dimension().to_glwe_size();
+            let polynomial_size = input.polynomial_size();
+            let count = input.ggsw_ciphertext_count().0;
+            FourierGgswCiphertextList::new(
+                avec![
+                    c64::default();
+                    count
+                        * polynomial_size.0
+                        * glwe_size.0
+                        * glwe_size.0
+                        * input.decomposition_level_count().0
+                        / 2
+                ]
+                .into_boxed_slice(),
+                count,
+                polynomial_size,
+                glwe_size,
+                input.decomposition_base_log(),
+                input.decomposition_level_count(),
+            )
+        };
+
+        let fft = Fft::new(input.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        for (fourier_ggsw, ggsw) in output
+            .as_mut_view()
+            .into_ggsw_iter()
+            .zip(input.0.ciphertext_iter())
+        {
+            fourier_ggsw.fill_with_forward_fourier(ggsw.as_view(), fft, self.stack());
+        }
+        FftFourierGgswCiphertextVector64(output)
+    }
+}
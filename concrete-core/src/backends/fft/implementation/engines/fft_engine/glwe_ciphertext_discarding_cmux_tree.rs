@@ -0,0 +1,299 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::wop_pbs::{
+    cmux_tree_memory_optimized, cmux_tree_memory_optimized_scratch,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{
+    FftFourierGgswCiphertextVector32, FftFourierGgswCiphertextVector64, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextDiscardingCmuxTreeEngine,
+    GlweCiphertextDiscardingCmuxTreeError, GlweCiphertextEntity, PlaintextVector32,
+    PlaintextVector64,
+};
+
+impl From<FftError> for GlweCiphertextDiscardingCmuxTreeError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingCmuxTreeEngine`] for [`FftEngine`] that operates
+/// on 32 bit integers.
+impl
+    GlweCiphertextDiscardingCmuxTreeEngine<
+        PlaintextVector32,
+        FftFourierGgswCiphertextVector32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The selectors encrypt the bits of the index of the selected polynomial, MSB first
+    /// let selector_bits = vec![1_u32, 0];
+    /// // The lookup table contains one polynomial per possible index, here a hard-set encoding is
+    /// // applied (shift by 20 bits)
+    /// let lut: Vec<u32> = (0..4_u32)
+    ///     .flat_map(|i| vec![i << 20; polynomial_size.0])
+    ///     .collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let selector_plaintexts = default_engine.create_plaintext_vector_from(&selector_bits)?;
+    /// let selectors = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &selector_plaintexts,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let fourier_selectors: FftFourierGgswCiphertextVector32 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&selectors)?;
+    /// let lut = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let zeros = default_engine.create_plaintext_vector_from(&vec![0_u32; polynomial_size.0])?;
+    /// let mut output: GlweCiphertext32 =
+    ///     default_engine.trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &zeros)?;
+    ///
+    /// fft_engine.discard_cmux_tree_glwe_ciphertext(&mut output, &lut, &fourier_selectors)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_tree_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        lut: &PlaintextVector32,
+        selectors: &FftFourierGgswCiphertextVector32,
+    ) -> Result<(), GlweCiphertextDiscardingCmuxTreeError<Self::EngineError>> {
+        GlweCiphertextDiscardingCmuxTreeError::perform_generic_checks(output, lut, selectors)?;
+        FftError::perform_fft_checks(output.polynomial_size())?;
+        unsafe { self.discard_cmux_tree_glwe_ciphertext_unchecked(output, lut, selectors) };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_tree_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        lut: &PlaintextVector32,
+        selectors: &FftFourierGgswCiphertextVector32,
+    ) {
+        let glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            cmux_tree_memory_optimized_scratch::<u32>(
+                polynomial_size,
+                glwe_size,
+                selectors.0.count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let lut = PolynomialList::from_container(lut.0.as_tensor().as_slice(), polynomial_size);
+        let stack = self.stack();
+        cmux_tree_memory_optimized(
+            output.0.as_mut_view(),
+            lut,
+            selectors.0.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingCmuxTreeEngine`] for [`FftEngine`] that operates
+/// on 64 bit integers.
+impl
+    GlweCiphertextDiscardingCmuxTreeEngine<
+        PlaintextVector64,
+        FftFourierGgswCiphertextVector64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The selectors encrypt the bits of the index of the selected polynomial, MSB first
+    /// let selector_bits = vec![1_u64, 0];
+    /// // The lookup table contains one polynomial per possible index, here a hard-set encoding is
+    /// // applied (shift by 50 bits)
+    /// let lut: Vec<u64> = (0..4_u64)
+    ///     .flat_map(|i| vec![i << 50; polynomial_size.0])
+    ///     .collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let selector_plaintexts = default_engine.create_plaintext_vector_from(&selector_bits)?;
+    /// let selectors = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &selector_plaintexts,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let fourier_selectors: FftFourierGgswCiphertextVector64 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&selectors)?;
+    /// let lut = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let zeros = default_engine.create_plaintext_vector_from(&vec![0_u64; polynomial_size.0])?;
+    /// let mut output: GlweCiphertext64 =
+    ///     default_engine.trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &zeros)?;
+    ///
+    /// fft_engine.discard_cmux_tree_glwe_ciphertext(&mut output, &lut, &fourier_selectors)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_cmux_tree_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        lut: &PlaintextVector64,
+        selectors: &FftFourierGgswCiphertextVector64,
+    ) -> Result<(), GlweCiphertextDiscardingCmuxTreeError<Self::EngineError>> {
+        GlweCiphertextDiscardingCmuxTreeError::perform_generic_checks(output, lut, selectors)?;
+        FftError::perform_fft_checks(output.polynomial_size())?;
+        unsafe { self.discard_cmux_tree_glwe_ciphertext_unchecked(output, lut, selectors) };
+        Ok(())
+    }
+
+    unsafe fn discard_cmux_tree_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        lut: &PlaintextVector64,
+        selectors: &FftFourierGgswCiphertextVector64,
+    ) {
+        let glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            cmux_tree_memory_optimized_scratch::<u64>(
+                polynomial_size,
+                glwe_size,
+                selectors.0.count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let lut = PolynomialList::from_container(lut.0.as_tensor().as_slice(), polynomial_size);
+        let stack = self.stack();
+        cmux_tree_memory_optimized(
+            output.0.as_mut_view(),
+            lut,
+            selectors.0.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
+
+#[cfg(test)]
+mod unit_test_cmux_tree {
+    use crate::commons::test_tools::new_random_generator;
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_cmux_tree_selects_lut_polynomial() -> Result<(), Box<dyn Error>> {
+        let glwe_dimension = GlweDimension(1);
+        let polynomial_size = PolynomialSize(256);
+        let level = DecompositionLevelCount(3);
+        let base_log = DecompositionBaseLog(10);
+        let noise = Variance(2_f64.powf(-100.));
+        let message_modulus = 16_u64;
+        let delta = 1_u64 << 60;
+        let selector_count = 3;
+
+        // Unix seeder must be given a secret input.
+        // Here we just give it 0, which is totally unsafe.
+        const UNSAFE_SECRET: u128 = 0;
+        let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut fft_engine = FftEngine::new(())?;
+        let mut generator = new_random_generator();
+
+        let key: GlweSecretKey64 =
+            default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+
+        let messages: Vec<u64> = (0..(1 << selector_count) * polynomial_size.0)
+            .map(|_| generator.random_uniform::<u64>() % message_modulus)
+            .collect();
+        let encoded: Vec<u64> = messages.iter().map(|m| m * delta).collect();
+        let lut = default_engine.create_plaintext_vector_from(&encoded)?;
+        let zeros = default_engine.create_plaintext_vector_from(&vec![0_u64; polynomial_size.0])?;
+
+        for index in 0..(1_usize << selector_count) {
+            // The first selector encrypts the most significant bit of the index
+            let bits: Vec<u64> = (0..selector_count)
+                .rev()
+                .map(|i| ((index >> i) & 1) as u64)
+                .collect();
+            let bits = default_engine.create_plaintext_vector_from(&bits)?;
+            let selectors = default_engine
+                .encrypt_scalar_ggsw_ciphertext_vector(&key, &bits, noise, level, base_log)?;
+            let selectors: FftFourierGgswCiphertextVector64 =
+                fft_engine.convert_ggsw_ciphertext_vector(&selectors)?;
+            let mut output: GlweCiphertext64 = default_engine
+                .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &zeros)?;
+
+            fft_engine.discard_cmux_tree_glwe_ciphertext(&mut output, &lut, &selectors)?;
+
+            let decrypted = default_engine.decrypt_glwe_ciphertext(&key, &output)?;
+            let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+            let decoded: Vec<u64> = decrypted
+                .iter()
+                .map(|d| (d.wrapping_add(delta / 2) / delta) % message_modulus)
+                .collect();
+            let expected = &messages[index * polynomial_size.0..(index + 1) * polynomial_size.0];
+            assert_eq!(decoded, expected);
+        }
+
+        Ok(())
+    }
+}
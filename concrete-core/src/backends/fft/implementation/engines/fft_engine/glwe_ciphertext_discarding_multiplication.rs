@@ -0,0 +1,306 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::relinearization::{
+    relinearize, relinearize_scratch, tensor_product, tensor_product_scratch,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::crypto::glwe::GlweTensorProduct as ImplGlweTensorProduct;
+use crate::prelude::{
+    DeltaLog, FftFourierGlweRelinearizationKey32, FftFourierGlweRelinearizationKey64,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextDiscardingMultiplicationEngine,
+    GlweCiphertextDiscardingMultiplicationError, GlweCiphertextEntity,
+};
+use dyn_stack::{ReborrowMut, StackReq};
+
+impl From<FftError> for GlweCiphertextDiscardingMultiplicationError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`FftEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        GlweCiphertext32,
+        FftFourierGlweRelinearizationKey32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // The messages are encoded on the four most significant bits: the constant polynomials 3
+    /// // and 2 are encrypted, and their product is 6.
+    /// let delta_log = DeltaLog(28);
+    /// let mut input_1 = vec![0_u32; polynomial_size.0];
+    /// let mut input_2 = vec![0_u32; polynomial_size.0];
+    /// input_1[0] = 3 << delta_log.0;
+    /// input_2[0] = 2 << delta_log.0;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = default_engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         DecompositionLevelCount(6),
+    ///         DecompositionBaseLog(4),
+    ///         noise,
+    ///     )?;
+    /// let relinearization_key: FftFourierGlweRelinearizationKey32 =
+    ///     fft_engine.convert_glwe_relinearization_key(&relinearization_key)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut output = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// fft_engine.discard_mul_glwe_ciphertext(
+    ///     &mut output,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &relinearization_key,
+    ///     delta_log,
+    /// )?;
+    ///
+    /// let decrypted = default_engine.decrypt_glwe_ciphertext(&key, &output)?;
+    /// let raw_output = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let rounded: Vec<u32> = raw_output
+    ///     .iter()
+    ///     .map(|a| a.wrapping_add(1 << (delta_log.0 - 1)) >> delta_log.0)
+    ///     .collect();
+    /// assert_eq!(rounded[0], 6);
+    /// assert!(rounded[1..].iter().all(|a| *a == 0));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        relinearization_key: &FftFourierGlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        FftError::perform_fft_checks(input_1.polynomial_size())?;
+        GlweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            relinearization_key,
+            delta_log,
+            32,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_unchecked(
+                output,
+                input_1,
+                input_2,
+                relinearization_key,
+                delta_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        relinearization_key: &FftFourierGlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) {
+        let glwe_size = input_1.glwe_dimension().to_glwe_size();
+        let polynomial_size = input_1.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            StackReq::try_any_of([
+                tensor_product_scratch::<u32>(glwe_size, polynomial_size, fft).unwrap(),
+                relinearize_scratch::<u32>(glwe_size, polynomial_size, fft).unwrap(),
+            ])
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let mut stack = self.stack();
+
+        let mut tensor_product_buffer =
+            ImplGlweTensorProduct::allocate(0u32, polynomial_size, input_1.glwe_dimension());
+        tensor_product(
+            tensor_product_buffer.as_mut_view(),
+            input_1.0.as_view(),
+            input_2.0.as_view(),
+            delta_log,
+            fft,
+            stack.rb_mut(),
+        );
+        relinearize(
+            output.0.as_mut_view(),
+            relinearization_key.0.as_view(),
+            tensor_product_buffer.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`FftEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        GlweCiphertext64,
+        FftFourierGlweRelinearizationKey64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // The messages are encoded on the four most significant bits: the constant polynomials 3
+    /// // and 2 are encrypted, and their product is 6.
+    /// let delta_log = DeltaLog(60);
+    /// let mut input_1 = vec![0_u64; polynomial_size.0];
+    /// let mut input_2 = vec![0_u64; polynomial_size.0];
+    /// input_1[0] = 3 << delta_log.0;
+    /// input_2[0] = 2 << delta_log.0;
+    /// let noise = Variance(2_f64.powf(-100.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = default_engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         DecompositionLevelCount(4),
+    ///         DecompositionBaseLog(12),
+    ///         noise,
+    ///     )?;
+    /// let relinearization_key: FftFourierGlweRelinearizationKey64 =
+    ///     fft_engine.convert_glwe_relinearization_key(&relinearization_key)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut output = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// fft_engine.discard_mul_glwe_ciphertext(
+    ///     &mut output,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &relinearization_key,
+    ///     delta_log,
+    /// )?;
+    ///
+    /// let decrypted = default_engine.decrypt_glwe_ciphertext(&key, &output)?;
+    /// let raw_output = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let rounded: Vec<u64> = raw_output
+    ///     .iter()
+    ///     .map(|a| a.wrapping_add(1 << (delta_log.0 - 1)) >> delta_log.0)
+    ///     .collect();
+    /// assert_eq!(rounded[0], 6);
+    /// assert!(rounded[1..].iter().all(|a| *a == 0));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        relinearization_key: &FftFourierGlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        FftError::perform_fft_checks(input_1.polynomial_size())?;
+        GlweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input_1,
+            input_2,
+            relinearization_key,
+            delta_log,
+            64,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_unchecked(
+                output,
+                input_1,
+                input_2,
+                relinearization_key,
+                delta_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        relinearization_key: &FftFourierGlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) {
+        let glwe_size = input_1.glwe_dimension().to_glwe_size();
+        let polynomial_size = input_1.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            StackReq::try_any_of([
+                tensor_product_scratch::<u64>(glwe_size, polynomial_size, fft).unwrap(),
+                relinearize_scratch::<u64>(glwe_size, polynomial_size, fft).unwrap(),
+            ])
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let mut stack = self.stack();
+
+        let mut tensor_product_buffer =
+            ImplGlweTensorProduct::allocate(0u64, polynomial_size, input_1.glwe_dimension());
+        tensor_product(
+            tensor_product_buffer.as_mut_view(),
+            input_1.0.as_view(),
+            input_2.0.as_view(),
+            delta_log,
+            fft,
+            stack.rb_mut(),
+        );
+        relinearize(
+            output.0.as_mut_view(),
+            relinearization_key.0.as_view(),
+            tensor_product_buffer.as_view(),
+            fft,
+            stack,
+        );
+    }
+}
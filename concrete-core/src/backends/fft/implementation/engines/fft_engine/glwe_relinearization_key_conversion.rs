@@ -0,0 +1,231 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::relinearization::{
+    fill_with_forward_fourier_scratch, FourierGlweRelinearizationKey,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::crypto::glwe::tensor_product_quadratic_count;
+use crate::prelude::{
+    FftFourierGlweRelinearizationKey32, FftFourierGlweRelinearizationKey64,
+    GlweRelinearizationKey32, GlweRelinearizationKey64, GlweRelinearizationKeyConversionEngine,
+    GlweRelinearizationKeyConversionError, GlweRelinearizationKeyEntity,
+};
+use aligned_vec::avec;
+use concrete_fft::c64;
+
+impl From<FftError> for GlweRelinearizationKeyConversionError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweRelinearizationKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers. It converts a GLWE relinearization key from the standard to the Fourier
+/// domain.
+impl
+    GlweRelinearizationKeyConversionEngine<
+        GlweRelinearizationKey32,
+        FftFourierGlweRelinearizationKey32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// // We generate a relinearization key in the standard domain
+    /// let relinearization_key: GlweRelinearizationKey32 =
+    ///     default_engine.generate_new_glwe_relinearization_key(&key, level, base_log, noise)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_relinearization_key: FftFourierGlweRelinearizationKey32 =
+    ///     fft_engine.convert_glwe_relinearization_key(&relinearization_key)?;
+    ///
+    /// assert_eq!(fourier_relinearization_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(
+    ///     fourier_relinearization_key.polynomial_size(),
+    ///     polynomial_size
+    /// );
+    /// assert_eq!(
+    ///     fourier_relinearization_key.decomposition_base_log(),
+    ///     base_log
+    /// );
+    /// assert_eq!(
+    ///     fourier_relinearization_key.decomposition_level_count(),
+    ///     level
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_relinearization_key(
+        &mut self,
+        input: &GlweRelinearizationKey32,
+    ) -> Result<
+        FftFourierGlweRelinearizationKey32,
+        GlweRelinearizationKeyConversionError<Self::EngineError>,
+    > {
+        FftError::perform_fft_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_relinearization_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_relinearization_key_unchecked(
+        &mut self,
+        input: &GlweRelinearizationKey32,
+    ) -> FftFourierGlweRelinearizationKey32 {
+        let glwe_size = input.glwe_dimension().to_glwe_size();
+        let mut output = FourierGlweRelinearizationKey::new(
+            avec![
+                c64::default();
+                (input.polynomial_size().0
+                    * glwe_size.0
+                    * tensor_product_quadratic_count(input.glwe_dimension()).0
+                    * input.decomposition_level_count().0)
+                    / 2
+            ]
+            .into_boxed_slice(),
+            input.polynomial_size(),
+            glwe_size,
+            input.decomposition_base_log(),
+            input.decomposition_level_count(),
+        );
+
+        let fft = Fft::new(input.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.as_view(), fft, self.stack());
+        FftFourierGlweRelinearizationKey32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweRelinearizationKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers. It converts a GLWE relinearization key from the standard to the Fourier
+/// domain.
+impl
+    GlweRelinearizationKeyConversionEngine<
+        GlweRelinearizationKey64,
+        FftFourierGlweRelinearizationKey64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// // We generate a relinearization key in the standard domain
+    /// let relinearization_key: GlweRelinearizationKey64 =
+    ///     default_engine.generate_new_glwe_relinearization_key(&key, level, base_log, noise)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_relinearization_key: FftFourierGlweRelinearizationKey64 =
+    ///     fft_engine.convert_glwe_relinearization_key(&relinearization_key)?;
+    ///
+    /// assert_eq!(fourier_relinearization_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(
+    ///     fourier_relinearization_key.polynomial_size(),
+    ///     polynomial_size
+    /// );
+    /// assert_eq!(
+    ///     fourier_relinearization_key.decomposition_base_log(),
+    ///     base_log
+    /// );
+    /// assert_eq!(
+    ///     fourier_relinearization_key.decomposition_level_count(),
+    ///     level
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_relinearization_key(
+        &mut self,
+        input: &GlweRelinearizationKey64,
+    ) -> Result<
+        FftFourierGlweRelinearizationKey64,
+        GlweRelinearizationKeyConversionError<Self::EngineError>,
+    > {
+        FftError::perform_fft_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_relinearization_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_relinearization_key_unchecked(
+        &mut self,
+        input: &GlweRelinearizationKey64,
+    ) -> FftFourierGlweRelinearizationKey64 {
+        let glwe_size = input.glwe_dimension().to_glwe_size();
+        let mut output = FourierGlweRelinearizationKey::new(
+            avec![
+                c64::default();
+                (input.polynomial_size().0
+                    * glwe_size.0
+                    * tensor_product_quadratic_count(input.glwe_dimension()).0
+                    * input.decomposition_level_count().0)
+                    / 2
+            ]
+            .into_boxed_slice(),
+            input.polynomial_size(),
+            glwe_size,
+            input.decomposition_base_log(),
+            input.decomposition_level_count(),
+        );
+
+        let fft = Fft::new(input.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.as_view(), fft, self.stack());
+        FftFourierGlweRelinearizationKey64(output)
+    }
+}
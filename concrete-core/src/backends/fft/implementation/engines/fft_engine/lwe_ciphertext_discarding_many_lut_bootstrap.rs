@@ -0,0 +1,264 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::bootstrap::bootstrap_scratch;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
+    GlweCiphertextEntity, LweCiphertext32, LweCiphertext64, LweCiphertextVector32,
+    LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingManyLutBootstrapEngine, LweCiphertextDiscardingManyLutBootstrapError,
+};
+
+impl From<FftError> for LweCiphertextDiscardingManyLutBootstrapError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingManyLutBootstrapEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextDiscardingManyLutBootstrapEngine<
+        FftFourierLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertextVector32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // 2 bits messages, with one bit of padding
+    /// let message_modulus = 4_usize;
+    /// let delta = 1_u32 << (32 - 3);
+    /// let input = 3_u32 * delta;
+    /// // Two lookup tables are interleaved in the accumulator: the identity, and x -> x + 1
+    /// let lut_count = LweCiphertextCount(2);
+    /// let box_size = poly_size.0 / message_modulus;
+    /// let mut lut: Vec<u32> = (0..poly_size.0)
+    ///     .map(|i| (((i / box_size + i % lut_count.0) % message_modulus) as u32) * delta)
+    ///     .collect();
+    /// lut[..box_size / 2]
+    ///     .iter_mut()
+    ///     .for_each(|coef| *coef = coef.wrapping_neg());
+    /// lut.rotate_left(box_size / 2);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output =
+    ///     default_engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, lut_count)?;
+    ///
+    /// fft_engine.discard_many_lut_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), lut_count);
+    ///
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&lwe_sk_output, &output)?;
+    /// let decoded: Vec<u32> = default_engine
+    ///     .retrieve_plaintext_vector(&decrypted)?
+    ///     .iter()
+    ///     .map(|p| (p.wrapping_add(delta / 2) / delta) % message_modulus as u32)
+    ///     .collect();
+    /// assert_eq!(decoded, vec![3, 0]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_many_lut_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingManyLutBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingManyLutBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe {
+            self.discard_many_lut_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_many_lut_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u32>(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().many_lut_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingManyLutBootstrapEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextDiscardingManyLutBootstrapEngine<
+        FftFourierLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertextVector64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // 2 bits messages, with one bit of padding
+    /// let message_modulus = 4_usize;
+    /// let delta = 1_u64 << (64 - 3);
+    /// let input = 3_u64 * delta;
+    /// // Two lookup tables are interleaved in the accumulator: the identity, and x -> x + 1
+    /// let lut_count = LweCiphertextCount(2);
+    /// let box_size = poly_size.0 / message_modulus;
+    /// let mut lut: Vec<u64> = (0..poly_size.0)
+    ///     .map(|i| (((i / box_size + i % lut_count.0) % message_modulus) as u64) * delta)
+    ///     .collect();
+    /// lut[..box_size / 2]
+    ///     .iter_mut()
+    ///     .for_each(|coef| *coef = coef.wrapping_neg());
+    /// lut.rotate_left(box_size / 2);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output =
+    ///     default_engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, lut_count)?;
+    ///
+    /// fft_engine.discard_many_lut_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), lut_count);
+    ///
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&lwe_sk_output, &output)?;
+    /// let decoded: Vec<u64> = default_engine
+    ///     .retrieve_plaintext_vector(&decrypted)?
+    ///     .iter()
+    ///     .map(|p| (p.wrapping_add(delta / 2) / delta) % message_modulus as u64)
+    ///     .collect();
+    /// assert_eq!(decoded, vec![3, 0]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_many_lut_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingManyLutBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingManyLutBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe {
+            self.discard_many_lut_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_many_lut_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u64>(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().many_lut_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
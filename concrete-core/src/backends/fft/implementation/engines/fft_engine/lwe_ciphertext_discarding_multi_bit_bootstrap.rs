@@ -0,0 +1,343 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::multi_bit_bootstrap::multi_bit_bootstrap_scratch;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    FftFourierLweMultiBitBootstrapKey32, FftFourierLweMultiBitBootstrapKey64, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextEntity, LweCiphertext32, LweCiphertext64,
+    LweCiphertextDiscardingMultiBitBootstrapEngine, LweCiphertextDiscardingMultiBitBootstrapError,
+};
+
+impl From<FftError> for LweCiphertextDiscardingMultiBitBootstrapError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingMultiBitBootstrapEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextDiscardingMultiBitBootstrapEngine<
+        FftFourierLweMultiBitBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(6),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let grouping_factor = LweBskGroupingFactor(3);
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweMultiBitBootstrapKey32 = default_engine.generate_new_lwe_multi_bit_bootstrap_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     grouping_factor,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweMultiBitBootstrapKey32 =
+    ///     fft_engine.convert_lwe_multi_bit_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// fft_engine.discard_multi_bit_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_bit_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweMultiBitBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingMultiBitBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingMultiBitBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe { self.discard_multi_bit_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_bit_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweMultiBitBootstrapKey32,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            multi_bit_bootstrap_scratch::<u32>(
+                acc.0.size(),
+                acc.0.polynomial_size(),
+                bsk.0.decomposition_level_count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingMultiBitBootstrapEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextDiscardingMultiBitBootstrapEngine<
+        FftFourierLweMultiBitBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(6),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let grouping_factor = LweBskGroupingFactor(3);
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweMultiBitBootstrapKey64 = default_engine.generate_new_lwe_multi_bit_bootstrap_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     grouping_factor,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweMultiBitBootstrapKey64 =
+    ///     fft_engine.convert_lwe_multi_bit_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// fft_engine.discard_multi_bit_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_bit_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweMultiBitBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingMultiBitBootstrapError<Self::EngineError>> {
+        FftError::perform_fft_checks(acc.polynomial_size())?;
+        LweCiphertextDiscardingMultiBitBootstrapError::perform_generic_checks(
+            output, input, acc, bsk,
+        )?;
+        unsafe { self.discard_multi_bit_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_bit_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweMultiBitBootstrapKey64,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            multi_bit_bootstrap_scratch::<u64>(
+                acc.0.size(),
+                acc.0.polynomial_size(),
+                bsk.0.decomposition_level_count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        bsk.0.as_view().bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc.0.as_view(),
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod unit_test_multi_bit_pbs {
+    use crate::commons::test_tools::new_random_generator;
+    use crate::prelude::*;
+    use std::error::Error;
+
+    fn test_multi_bit_pbs_identity(grouping_factor: LweBskGroupingFactor) -> Result<(), Box<dyn Error>> {
+        let lwe_dimension = LweDimension(600);
+        let glwe_dimension = GlweDimension(1);
+        let polynomial_size = PolynomialSize(2048);
+        let lwe_noise = Variance(2_f64.powf(-40.));
+        let glwe_noise = Variance(2_f64.powf(-100.));
+        let pbs_base_log = DecompositionBaseLog(15);
+        let pbs_level = DecompositionLevelCount(2);
+        let payload_modulus = 16_u64;
+
+        // Value of the shift we multiply our messages by, keeping one bit of padding
+        let delta = (1_u64 << 63) / payload_modulus;
+
+        // Unix seeder must be given a secret input.
+        // Here we just give it 0, which is totally unsafe.
+        const UNSAFE_SECRET: u128 = 0;
+
+        let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut default_parallel_engine =
+            DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut fft_engine = FftEngine::new(())?;
+        let mut generator = new_random_generator();
+
+        let small_lwe_secret_key: LweSecretKey64 =
+            default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+        let glwe_secret_key: GlweSecretKey64 =
+            default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+        let large_lwe_secret_key =
+            default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_secret_key.clone())?;
+
+        let bootstrap_key: LweMultiBitBootstrapKey64 = default_parallel_engine
+            .generate_new_lwe_multi_bit_bootstrap_key(
+                &small_lwe_secret_key,
+                &glwe_secret_key,
+                pbs_base_log,
+                pbs_level,
+                grouping_factor,
+                glwe_noise,
+            )?;
+        let fourier_bsk: FftFourierLweMultiBitBootstrapKey64 =
+            fft_engine.convert_lwe_multi_bit_bootstrap_key(&bootstrap_key)?;
+
+        // The identity lookup table, with each box centered on its value
+        let box_size = polynomial_size.0 / payload_modulus as usize;
+        let mut lut = vec![0_u64; polynomial_size.0];
+        for (i, chunk) in lut.chunks_exact_mut(box_size).enumerate() {
+            chunk.fill(i as u64 * delta);
+        }
+        for a_i in lut[0..box_size / 2].iter_mut() {
+            *a_i = a_i.wrapping_neg();
+        }
+        lut.rotate_left(box_size / 2);
+        let lut = default_engine.create_plaintext_vector_from(&lut)?;
+        let accumulator =
+            default_engine.trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &lut)?;
+
+        for _ in 0..50 {
+            let message = generator.random_uniform::<u64>() % payload_modulus;
+            let plaintext = default_engine.create_plaintext_from(&(message * delta))?;
+            let input =
+                default_engine.encrypt_lwe_ciphertext(&small_lwe_secret_key, &plaintext, lwe_noise)?;
+            let mut output =
+                default_engine.zero_encrypt_lwe_ciphertext(&large_lwe_secret_key, lwe_noise)?;
+
+            fft_engine.discard_multi_bit_bootstrap_lwe_ciphertext(
+                &mut output,
+                &input,
+                &accumulator,
+                &fourier_bsk,
+            )?;
+
+            let decrypted = default_engine.decrypt_lwe_ciphertext(&large_lwe_secret_key, &output)?;
+            let mut decrypted_u64 = 0_u64;
+            default_engine.discard_retrieve_plaintext(&mut decrypted_u64, &decrypted)?;
+            let decoded = decrypted_u64.wrapping_add(delta / 2) / delta % payload_modulus;
+            assert_eq!(decoded, message);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_bit_pbs_grouping_factor_2() -> Result<(), Box<dyn Error>> {
+        test_multi_bit_pbs_identity(LweBskGroupingFactor(2))
+    }
+
+    #[test]
+    fn test_multi_bit_pbs_grouping_factor_3() -> Result<(), Box<dyn Error>> {
+        test_multi_bit_pbs_identity(LweBskGroupingFactor(3))
+    }
+}
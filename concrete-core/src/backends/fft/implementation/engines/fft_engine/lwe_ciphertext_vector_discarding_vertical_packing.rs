@@ -0,0 +1,360 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::wop_pbs::{vertical_packing, vertical_packing_scratch};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{
+    FftFourierGgswCiphertextVector32, FftFourierGgswCiphertextVector64, GgswCiphertextVectorEntity,
+    LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorDiscardingVerticalPackingEngine,
+    LweCiphertextVectorDiscardingVerticalPackingError, LweCiphertextVectorEntity,
+    PlaintextVector32, PlaintextVector64, PolynomialCount,
+};
+
+impl From<FftError> for LweCiphertextVectorDiscardingVerticalPackingError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingVerticalPackingEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextVectorDiscardingVerticalPackingEngine<
+        PlaintextVector32,
+        FftFourierGgswCiphertextVector32,
+        LweCiphertextVector32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // We will have a message with 9 bits of information, the 8 least significant bits are
+    /// // handled by the blind rotation of a polynomial of size 256, the last one by a cmux tree.
+    /// let message_bits = 9;
+    /// let cleartext = 42_u32;
+    /// // The selectors encrypt the bits of the index to look up, MSB first. Here, they are
+    /// // encrypted directly with the GLWE secret key.
+    /// let selector_bits: Vec<u32> = (0..message_bits)
+    ///     .rev()
+    ///     .map(|i| (cleartext >> i) & 1)
+    ///     .collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_sk: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    ///
+    /// let selector_plaintexts = default_engine.create_plaintext_vector_from(&selector_bits)?;
+    /// let selectors = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &glwe_sk,
+    ///     &selector_plaintexts,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let fourier_selectors: FftFourierGgswCiphertextVector32 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&selectors)?;
+    ///
+    /// // Create a look-up table we want to apply during vertical packing, here just the identity
+    /// // with the proper encoding (shift by 23 bits).
+    /// let lut: Vec<u32> = (0..1 << message_bits)
+    ///     .map(|i| i << (32 - message_bits))
+    ///     .collect();
+    /// let lut = default_engine.create_plaintext_vector_from(&lut)?;
+    ///
+    /// // We use the zero encryption engine to allocate the output ciphertext vector
+    /// let mut output =
+    ///     default_engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk, noise, LweCiphertextCount(1))?;
+    ///
+    /// fft_engine.discard_vertical_packing_lwe_ciphertext_vector(
+    ///     &mut output,
+    ///     &lut,
+    ///     &fourier_selectors,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(1));
+    /// assert_eq!(output.lwe_dimension(), lwe_sk.lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_vertical_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        luts: &PlaintextVector32,
+        selectors: &FftFourierGgswCiphertextVector32,
+    ) -> Result<(), LweCiphertextVectorDiscardingVerticalPackingError<Self::EngineError>> {
+        FftError::perform_fft_checks(selectors.polynomial_size())?;
+        LweCiphertextVectorDiscardingVerticalPackingError::perform_generic_checks(
+            output, luts, selectors,
+        )?;
+        unsafe {
+            self.discard_vertical_packing_lwe_ciphertext_vector_unchecked(output, luts, selectors)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_vertical_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        luts: &PlaintextVector32,
+        selectors: &FftFourierGgswCiphertextVector32,
+    ) {
+        let polynomial_size = selectors.0.polynomial_size();
+        let luts = PolynomialList::from_container(luts.0.as_tensor().as_slice(), polynomial_size);
+        let small_lut_size =
+            PolynomialCount(luts.polynomial_count().0 / output.lwe_ciphertext_count().0);
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            vertical_packing_scratch::<u32>(
+                selectors.0.glwe_size(),
+                polynomial_size,
+                small_lut_size,
+                selectors.0.count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        for (lut, lwe_out) in luts
+            .sublist_iter(small_lut_size)
+            .zip(output.0.ciphertext_iter_mut())
+        {
+            vertical_packing(lut, lwe_out, selectors.0.as_view(), fft, self.stack());
+        }
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingVerticalPackingEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextVectorDiscardingVerticalPackingEngine<
+        PlaintextVector64,
+        FftFourierGgswCiphertextVector64,
+        LweCiphertextVector64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(10);
+    /// let noise = Variance(2_f64.powf(-100.));
+    ///
+    /// // We will have a message with 9 bits of information, the 8 least significant bits are
+    /// // handled by the blind rotation of a polynomial of size 256, the last one by a cmux tree.
+    /// let message_bits = 9;
+    /// let cleartext = 42_u64;
+    /// // The selectors encrypt the bits of the index to look up, MSB first. Here, they are
+    /// // encrypted directly with the GLWE secret key.
+    /// let selector_bits: Vec<u64> = (0..message_bits)
+    ///     .rev()
+    ///     .map(|i| (cleartext >> i) & 1)
+    ///     .collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_sk: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    ///
+    /// let selector_plaintexts = default_engine.create_plaintext_vector_from(&selector_bits)?;
+    /// let selectors = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &glwe_sk,
+    ///     &selector_plaintexts,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// let fourier_selectors: FftFourierGgswCiphertextVector64 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&selectors)?;
+    ///
+    /// // Create a look-up table we want to apply during vertical packing, here just the identity
+    /// // with the proper encoding (shift by 55 bits).
+    /// let lut: Vec<u64> = (0..1 << message_bits)
+    ///     .map(|i| i << (64 - message_bits))
+    ///     .collect();
+    /// let lut = default_engine.create_plaintext_vector_from(&lut)?;
+    ///
+    /// // We use the zero encryption engine to allocate the output ciphertext vector
+    /// let mut output =
+    ///     default_engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk, noise, LweCiphertextCount(1))?;
+    ///
+    /// fft_engine.discard_vertical_packing_lwe_ciphertext_vector(
+    ///     &mut output,
+    ///     &lut,
+    ///     &fourier_selectors,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(1));
+    /// assert_eq!(output.lwe_dimension(), lwe_sk.lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_vertical_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        luts: &PlaintextVector64,
+        selectors: &FftFourierGgswCiphertextVector64,
+    ) -> Result<(), LweCiphertextVectorDiscardingVerticalPackingError<Self::EngineError>> {
+        FftError::perform_fft_checks(selectors.polynomial_size())?;
+        LweCiphertextVectorDiscardingVerticalPackingError::perform_generic_checks(
+            output, luts, selectors,
+        )?;
+        unsafe {
+            self.discard_vertical_packing_lwe_ciphertext_vector_unchecked(output, luts, selectors)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_vertical_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        luts: &PlaintextVector64,
+        selectors: &FftFourierGgswCiphertextVector64,
+    ) {
+        let polynomial_size = selectors.0.polynomial_size();
+        let luts = PolynomialList::from_container(luts.0.as_tensor().as_slice(), polynomial_size);
+        let small_lut_size =
+            PolynomialCount(luts.polynomial_count().0 / output.lwe_ciphertext_count().0);
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            vertical_packing_scratch::<u64>(
+                selectors.0.glwe_size(),
+                polynomial_size,
+                small_lut_size,
+                selectors.0.count(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        for (lut, lwe_out) in luts
+            .sublist_iter(small_lut_size)
+            .zip(output.0.ciphertext_iter_mut())
+        {
+            vertical_packing(lut, lwe_out, selectors.0.as_view(), fft, self.stack());
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test_vertical_packing {
+    use crate::commons::test_tools::new_random_generator;
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_vertical_packing_selects_lut_values() -> Result<(), Box<dyn Error>> {
+        let glwe_dimension = GlweDimension(1);
+        let polynomial_size = PolynomialSize(256);
+        let level = DecompositionLevelCount(3);
+        let base_log = DecompositionBaseLog(10);
+        let noise = Variance(2_f64.powf(-100.));
+        let message_modulus = 16_u64;
+        let delta = 1_u64 << 60;
+        // 8 selectors are used by the blind rotation and 2 by the cmux tree
+        let selector_count = 10;
+        let output_count = 2;
+
+        // Unix seeder must be given a secret input.
+        // Here we just give it 0, which is totally unsafe.
+        const UNSAFE_SECRET: u128 = 0;
+        let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut fft_engine = FftEngine::new(())?;
+        let mut generator = new_random_generator();
+
+        let glwe_sk: GlweSecretKey64 =
+            default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+        let lwe_sk: LweSecretKey64 =
+            default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+
+        let small_lut_size = 1 << selector_count;
+        let messages: Vec<u64> = (0..output_count * small_lut_size)
+            .map(|_| generator.random_uniform::<u64>() % message_modulus)
+            .collect();
+        let encoded: Vec<u64> = messages.iter().map(|m| m * delta).collect();
+        let luts = default_engine.create_plaintext_vector_from(&encoded)?;
+
+        for _ in 0..4 {
+            let index = generator.random_uniform::<u64>() as usize % small_lut_size;
+            // The first selector encrypts the most significant bit of the index
+            let bits: Vec<u64> = (0..selector_count)
+                .rev()
+                .map(|i| ((index >> i) & 1) as u64)
+                .collect();
+            let bits = default_engine.create_plaintext_vector_from(&bits)?;
+            let selectors = default_engine
+                .encrypt_scalar_ggsw_ciphertext_vector(&glwe_sk, &bits, noise, level, base_log)?;
+            let selectors: FftFourierGgswCiphertextVector64 =
+                fft_engine.convert_ggsw_ciphertext_vector(&selectors)?;
+            let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+                &lwe_sk,
+                noise,
+                LweCiphertextCount(output_count),
+            )?;
+
+            fft_engine.discard_vertical_packing_lwe_ciphertext_vector(
+                &mut output,
+                &luts,
+                &selectors,
+            )?;
+
+            let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&lwe_sk, &output)?;
+            let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+            let decoded: Vec<u64> = decrypted
+                .iter()
+                .map(|d| (d.wrapping_add(delta / 2) / delta) % message_modulus)
+                .collect();
+            let expected: Vec<u64> = (0..output_count)
+                .map(|i| messages[i * small_lut_size + index])
+                .collect();
+            assert_eq!(decoded, expected);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,599 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::entities::{FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64};
+use crate::backends::fft::private::crypto::wop_pbs::{
+    circuit_bootstrap_boolean_vertical_packing, circuit_bootstrap_boolean_vertical_packing_scratch,
+    extract_bits, extract_bits_scratch,
+};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::crypto::lwe::LweList;
+use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::prelude::{
+    CiphertextCount, CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount, DeltaLog,
+    ExtractedBitsCount, LweBootstrapKeyEntity, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorDiscardingWopPbsEngine, LweCiphertextVectorDiscardingWopPbsError,
+    LweCiphertextVectorEntity, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, LweKeyswitchKey32,
+    LweKeyswitchKey64, LweKeyswitchKeyEntity, PlaintextVector32, PlaintextVector64,
+};
+use dyn_stack::StackReq;
+
+impl From<FftError> for LweCiphertextVectorDiscardingWopPbsError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingWopPbsEngine`] for [`FftEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingWopPbsEngine<
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+        FftFourierLweBootstrapKey32,
+        LweKeyswitchKey32,
+        PlaintextVector32,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let polynomial_size = PolynomialSize(512);
+    /// let glwe_dimension = GlweDimension(1);
+    /// let lwe_dimension = LweDimension(10);
+    /// let noise = Variance(2_f64.powf(-120.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut default_parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    ///
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_small_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let lwe_big_sk: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    ///
+    /// let std_bsk: LweBootstrapKey32 = default_parallel_engine.generate_new_lwe_bootstrap_key(
+    ///     &lwe_small_sk,
+    ///     &glwe_sk,
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(7),
+    ///     noise,
+    /// )?;
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&std_bsk)?;
+    /// let ksk: LweKeyswitchKey32 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &lwe_big_sk,
+    ///     &lwe_small_sk,
+    ///     DecompositionLevelCount(9),
+    ///     DecompositionBaseLog(1),
+    ///     noise,
+    /// )?;
+    /// let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = default_engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &lwe_big_sk,
+    ///         &glwe_sk,
+    ///         DecompositionBaseLog(4),
+    ///         DecompositionLevelCount(7),
+    ///         noise,
+    ///     )?;
+    ///
+    /// // We work on an integer of 10 bits represented in radix form, with two blocks of 5 bits,
+    /// // the first block holding the most significant digit.
+    /// let blocks_bits = [ExtractedBitsCount(5), ExtractedBitsCount(5)];
+    /// let cleartext = 42_u32;
+    /// let digits = [cleartext >> 5, cleartext & 31];
+    /// let encoded: Vec<u32> = digits.iter().map(|d| d << (32 - 5)).collect();
+    /// let input_plaintexts = default_engine.create_plaintext_vector_from(&encoded)?;
+    /// let input =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_big_sk, &input_plaintexts, noise)?;
+    ///
+    /// // The function we evaluate increments the integer, the blocks are recomposed and
+    /// // decomposed by the closure.
+    /// let luts: PlaintextVector32 =
+    ///     default_engine.generate_wop_pbs_lut_plaintext_vector(&blocks_bits, &blocks_bits, |m| {
+    ///         let value = ((m[0] << 5) + m[1] + 1) % (1 << 10);
+    ///         vec![value >> 5, value & 31]
+    ///     })?;
+    ///
+    /// // We use the zero encryption engine to allocate the output ciphertext vector
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_big_sk,
+    ///     noise,
+    ///     LweCiphertextCount(blocks_bits.len()),
+    /// )?;
+    ///
+    /// fft_engine.discard_wop_pbs_lwe_ciphertext_vector(
+    ///     &mut output,
+    ///     &input,
+    ///     &fourier_bsk,
+    ///     &ksk,
+    ///     &luts,
+    ///     &blocks_bits,
+    ///     DecompositionLevelCount(7),
+    ///     DecompositionBaseLog(4),
+    ///     &cbs_pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(2));
+    /// assert_eq!(output.lwe_dimension(), lwe_big_sk.lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_wop_pbs_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+        luts: &PlaintextVector32,
+        extracted_bits_counts: &[ExtractedBitsCount],
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<(), LweCiphertextVectorDiscardingWopPbsError<Self::EngineError>> {
+        FftError::perform_fft_checks(bsk.polynomial_size())?;
+        LweCiphertextVectorDiscardingWopPbsError::perform_generic_checks(
+            output,
+            input,
+            bsk,
+            ksk,
+            luts,
+            extracted_bits_counts,
+            cbs_level_count,
+            cbs_base_log,
+            cbs_pfpksk,
+            CiphertextModulusLog(32),
+        )?;
+        unsafe {
+            self.discard_wop_pbs_lwe_ciphertext_vector_unchecked(
+                output,
+                input,
+                bsk,
+                ksk,
+                luts,
+                extracted_bits_counts,
+                cbs_level_count,
+                cbs_base_log,
+                cbs_pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_wop_pbs_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        ksk: &LweKeyswitchKey32,
+        luts: &PlaintextVector32,
+        extracted_bits_counts: &[ExtractedBitsCount],
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) {
+        let luts =
+            PolynomialList::from_container(luts.0.as_tensor().as_slice(), bsk.polynomial_size());
+        let total_bits: usize = extracted_bits_counts.iter().map(|bits| bits.0).sum();
+        let extracted_bits_lwe_size = ksk.output_lwe_dimension().to_lwe_size();
+
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            StackReq::try_any_of([
+                extract_bits_scratch::<u32>(
+                    input.lwe_dimension(),
+                    ksk.output_lwe_dimension(),
+                    bsk.glwe_dimension().to_glwe_size(),
+                    bsk.polynomial_size(),
+                    fft,
+                )
+                .unwrap(),
+                circuit_bootstrap_boolean_vertical_packing_scratch::<u32>(
+                    CiphertextCount(total_bits),
+                    CiphertextCount(output.lwe_ciphertext_count().0),
+                    extracted_bits_lwe_size,
+                    luts.polynomial_count(),
+                    bsk.output_lwe_dimension().to_lwe_size(),
+                    cbs_pfpksk.output_polynomial_size(),
+                    bsk.glwe_dimension().to_glwe_size(),
+                    cbs_level_count,
+                    fft,
+                )
+                .unwrap(),
+            ])
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+
+        // The bits of all the blocks are extracted in a single list, the bits of the first block
+        // being the most significant ones.
+        let mut extracted_bits = vec![0u32; total_bits * extracted_bits_lwe_size.0];
+        let mut remaining_bits = extracted_bits.as_mut_slice();
+        for (lwe_in, bits_count) in input.0.ciphertext_iter().zip(extracted_bits_counts) {
+            let (block_bits, tail) =
+                remaining_bits.split_at_mut(bits_count.0 * extracted_bits_lwe_size.0);
+            extract_bits(
+                LweList::from_container(block_bits, extracted_bits_lwe_size),
+                lwe_in,
+                ksk.0.as_view(),
+                bsk.0.as_view(),
+                DeltaLog(32 - bits_count.0),
+                *bits_count,
+                fft,
+                self.stack(),
+            );
+            remaining_bits = tail;
+        }
+
+        circuit_bootstrap_boolean_vertical_packing(
+            luts,
+            bsk.0.as_view(),
+            output.0.as_mut_view(),
+            LweList::from_container(extracted_bits.as_slice(), extracted_bits_lwe_size),
+            cbs_pfpksk.0.as_view(),
+            cbs_level_count,
+            cbs_base_log,
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingWopPbsEngine`] for [`FftEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingWopPbsEngine<
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+        FftFourierLweBootstrapKey64,
+        LweKeyswitchKey64,
+        PlaintextVector64,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let polynomial_size = PolynomialSize(512);
+    /// let glwe_dimension = GlweDimension(1);
+    /// let lwe_dimension = LweDimension(10);
+    /// let noise = Variance(2_f64.powf(-120.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut default_parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    ///
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_small_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let lwe_big_sk: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    ///
+    /// let std_bsk: LweBootstrapKey64 = default_parallel_engine.generate_new_lwe_bootstrap_key(
+    ///     &lwe_small_sk,
+    ///     &glwe_sk,
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(9),
+    ///     noise,
+    /// )?;
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&std_bsk)?;
+    /// let ksk: LweKeyswitchKey64 = default_engine.generate_new_lwe_keyswitch_key(
+    ///     &lwe_big_sk,
+    ///     &lwe_small_sk,
+    ///     DecompositionLevelCount(7),
+    ///     DecompositionBaseLog(4),
+    ///     noise,
+    /// )?;
+    /// let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = default_engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &lwe_big_sk,
+    ///         &glwe_sk,
+    ///         DecompositionBaseLog(4),
+    ///         DecompositionLevelCount(9),
+    ///         noise,
+    ///     )?;
+    ///
+    /// // We work on an integer of 10 bits represented in radix form, with two blocks of 5 bits,
+    /// // the first block holding the most significant digit.
+    /// let blocks_bits = [ExtractedBitsCount(5), ExtractedBitsCount(5)];
+    /// let cleartext = 42_u64;
+    /// let digits = [cleartext >> 5, cleartext & 31];
+    /// let encoded: Vec<u64> = digits.iter().map(|d| d << (64 - 5)).collect();
+    /// let input_plaintexts = default_engine.create_plaintext_vector_from(&encoded)?;
+    /// let input =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_big_sk, &input_plaintexts, noise)?;
+    ///
+    /// // The function we evaluate increments the integer, the blocks are recomposed and
+    /// // decomposed by the closure.
+    /// let luts: PlaintextVector64 =
+    ///     default_engine.generate_wop_pbs_lut_plaintext_vector(&blocks_bits, &blocks_bits, |m| {
+    ///         let value = ((m[0] << 5) + m[1] + 1) % (1 << 10);
+    ///         vec![value >> 5, value & 31]
+    ///     })?;
+    ///
+    /// // We use the zero encryption engine to allocate the output ciphertext vector
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_big_sk,
+    ///     noise,
+    ///     LweCiphertextCount(blocks_bits.len()),
+    /// )?;
+    ///
+    /// fft_engine.discard_wop_pbs_lwe_ciphertext_vector(
+    ///     &mut output,
+    ///     &input,
+    ///     &fourier_bsk,
+    ///     &ksk,
+    ///     &luts,
+    ///     &blocks_bits,
+    ///     DecompositionLevelCount(4),
+    ///     DecompositionBaseLog(6),
+    ///     &cbs_pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(2));
+    /// assert_eq!(output.lwe_dimension(), lwe_big_sk.lwe_dimension());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_wop_pbs_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+        luts: &PlaintextVector64,
+        extracted_bits_counts: &[ExtractedBitsCount],
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<(), LweCiphertextVectorDiscardingWopPbsError<Self::EngineError>> {
+        FftError::perform_fft_checks(bsk.polynomial_size())?;
+        LweCiphertextVectorDiscardingWopPbsError::perform_generic_checks(
+            output,
+            input,
+            bsk,
+            ksk,
+            luts,
+            extracted_bits_counts,
+            cbs_level_count,
+            cbs_base_log,
+            cbs_pfpksk,
+            CiphertextModulusLog(64),
+        )?;
+        unsafe {
+            self.discard_wop_pbs_lwe_ciphertext_vector_unchecked(
+                output,
+                input,
+                bsk,
+                ksk,
+                luts,
+                extracted_bits_counts,
+                cbs_level_count,
+                cbs_base_log,
+                cbs_pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_wop_pbs_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        ksk: &LweKeyswitchKey64,
+        luts: &PlaintextVector64,
+        extracted_bits_counts: &[ExtractedBitsCount],
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) {
+        let luts =
+            PolynomialList::from_container(luts.0.as_tensor().as_slice(), bsk.polynomial_size());
+        let total_bits: usize = extracted_bits_counts.iter().map(|bits| bits.0).sum();
+        let extracted_bits_lwe_size = ksk.output_lwe_dimension().to_lwe_size();
+
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            StackReq::try_any_of([
+                extract_bits_scratch::<u64>(
+                    input.lwe_dimension(),
+                    ksk.output_lwe_dimension(),
+                    bsk.glwe_dimension().to_glwe_size(),
+                    bsk.polynomial_size(),
+                    fft,
+                )
+                .unwrap(),
+                circuit_bootstrap_boolean_vertical_packing_scratch::<u64>(
+                    CiphertextCount(total_bits),
+                    CiphertextCount(output.lwe_ciphertext_count().0),
+                    extracted_bits_lwe_size,
+                    luts.polynomial_count(),
+                    bsk.output_lwe_dimension().to_lwe_size(),
+                    cbs_pfpksk.output_polynomial_size(),
+                    bsk.glwe_dimension().to_glwe_size(),
+                    cbs_level_count,
+                    fft,
+                )
+                .unwrap(),
+            ])
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+
+        // The bits of all the blocks are extracted in a single list, the bits of the first block
+        // being the most significant ones.
+        let mut extracted_bits = vec![0u64; total_bits * extracted_bits_lwe_size.0];
+        let mut remaining_bits = extracted_bits.as_mut_slice();
+        for (lwe_in, bits_count) in input.0.ciphertext_iter().zip(extracted_bits_counts) {
+            let (block_bits, tail) =
+                remaining_bits.split_at_mut(bits_count.0 * extracted_bits_lwe_size.0);
+            extract_bits(
+                LweList::from_container(block_bits, extracted_bits_lwe_size),
+                lwe_in,
+                ksk.0.as_view(),
+                bsk.0.as_view(),
+                DeltaLog(64 - bits_count.0),
+                *bits_count,
+                fft,
+                self.stack(),
+            );
+            remaining_bits = tail;
+        }
+
+        circuit_bootstrap_boolean_vertical_packing(
+            luts,
+            bsk.0.as_view(),
+            output.0.as_mut_view(),
+            LweList::from_container(extracted_bits.as_slice(), extracted_bits_lwe_size),
+            cbs_pfpksk.0.as_view(),
+            cbs_level_count,
+            cbs_base_log,
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod unit_test_wop_pbs {
+    use crate::commons::test_tools::new_random_generator;
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_wop_pbs_evaluates_radix_function() -> Result<(), Box<dyn Error>> {
+        let polynomial_size = PolynomialSize(512);
+        let glwe_dimension = GlweDimension(1);
+        let lwe_dimension = LweDimension(10);
+        let noise = Variance(2_f64.powf(-120.));
+        let block_bits = 5;
+        let block_modulus = 1_u64 << block_bits;
+        let blocks_bits = [
+            ExtractedBitsCount(block_bits),
+            ExtractedBitsCount(block_bits),
+        ];
+
+        // Unix seeder must be given a secret input.
+        // Here we just give it 0, which is totally unsafe.
+        const UNSAFE_SECRET: u128 = 0;
+        let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+        let mut fft_engine = FftEngine::new(())?;
+        let mut generator = new_random_generator();
+
+        let glwe_sk: GlweSecretKey64 =
+            default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+        let lwe_small_sk: LweSecretKey64 =
+            default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+        let lwe_big_sk: LweSecretKey64 =
+            default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+        let bsk: LweBootstrapKey64 = default_engine.generate_new_lwe_bootstrap_key(
+            &lwe_small_sk,
+            &glwe_sk,
+            DecompositionBaseLog(4),
+            DecompositionLevelCount(9),
+            noise,
+        )?;
+        let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+        let ksk: LweKeyswitchKey64 = default_engine.generate_new_lwe_keyswitch_key(
+            &lwe_big_sk,
+            &lwe_small_sk,
+            DecompositionLevelCount(7),
+            DecompositionBaseLog(4),
+            noise,
+        )?;
+        let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = default_engine
+            .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                &lwe_big_sk,
+                &glwe_sk,
+                DecompositionBaseLog(4),
+                DecompositionLevelCount(9),
+                noise,
+            )?;
+
+        // The function multiplies the 10 bits integer by 3, the first block holding the most
+        // significant digit.
+        let function = |value: u64| (3 * value) % (block_modulus * block_modulus);
+        let luts: PlaintextVector64 = default_engine.generate_wop_pbs_lut_plaintext_vector(
+            &blocks_bits,
+            &blocks_bits,
+            |m| {
+                let value = function(m[0] * block_modulus + m[1]);
+                vec![value / block_modulus, value % block_modulus]
+            },
+        )?;
+
+        for _ in 0..2 {
+            let cleartext = generator.random_uniform::<u64>() % (block_modulus * block_modulus);
+            let encoded = vec![
+                (cleartext / block_modulus) << (64 - block_bits),
+                (cleartext % block_modulus) << (64 - block_bits),
+            ];
+            let plaintexts = default_engine.create_plaintext_vector_from(&encoded)?;
+            let input =
+                default_engine.encrypt_lwe_ciphertext_vector(&lwe_big_sk, &plaintexts, noise)?;
+            let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+                &lwe_big_sk,
+                noise,
+                LweCiphertextCount(2),
+            )?;
+
+            fft_engine.discard_wop_pbs_lwe_ciphertext_vector(
+                &mut output,
+                &input,
+                &bsk,
+                &ksk,
+                &luts,
+                &blocks_bits,
+                DecompositionLevelCount(4),
+                DecompositionBaseLog(6),
+                &cbs_pfpksk,
+            )?;
+
+            let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&lwe_big_sk, &output)?;
+            let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+            let delta = 1_u64 << (64 - block_bits);
+            let decoded: Vec<u64> = decrypted
+                .iter()
+                .map(|d| (d.wrapping_add(delta / 2) / delta) % block_modulus)
+                .collect();
+            let expected = function(cleartext);
+            assert_eq!(
+                decoded,
+                vec![expected / block_modulus, expected % block_modulus]
+            );
+        }
+
+        Ok(())
+    }
+}
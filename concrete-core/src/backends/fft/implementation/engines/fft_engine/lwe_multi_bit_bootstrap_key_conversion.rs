@@ -0,0 +1,240 @@
+use super::{FftEngine, FftError};
+use crate::backends::fft::private::crypto::ggsw::fill_with_forward_fourier_scratch;
+use crate::backends::fft::private::crypto::multi_bit_bootstrap::FourierLweMultiBitBootstrapKey;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::commons::crypto::bootstrap::multi_bit_standard::ggsw_per_multi_bit_group;
+use crate::prelude::{
+    FftFourierLweMultiBitBootstrapKey32, FftFourierLweMultiBitBootstrapKey64,
+    LweMultiBitBootstrapKey32, LweMultiBitBootstrapKey64, LweMultiBitBootstrapKeyConversionEngine,
+    LweMultiBitBootstrapKeyConversionError, LweMultiBitBootstrapKeyEntity,
+};
+use aligned_vec::avec;
+use concrete_fft::c64;
+
+impl From<FftError> for LweMultiBitBootstrapKeyConversionError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweMultiBitBootstrapKeyConversionEngine`] for [`FftEngine`] that operates
+/// on 32 bit integers. It converts a multi-bit bootstrap key from the standard to the Fourier
+/// domain.
+impl
+    LweMultiBitBootstrapKeyConversionEngine<
+        LweMultiBitBootstrapKey32,
+        FftFourierLweMultiBitBootstrapKey32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let grouping_factor = LweBskGroupingFactor(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweMultiBitBootstrapKey32 = default_engine.generate_new_lwe_multi_bit_bootstrap_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     grouping_factor,
+    ///     noise,
+    /// )?;
+    ///
+    /// let fourier_bsk: FftFourierLweMultiBitBootstrapKey32 =
+    ///     fft_engine.convert_lwe_multi_bit_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    /// assert_eq!(fourier_bsk.grouping_factor(), grouping_factor);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input: &LweMultiBitBootstrapKey32,
+    ) -> Result<
+        FftFourierLweMultiBitBootstrapKey32,
+        LweMultiBitBootstrapKeyConversionError<Self::EngineError>,
+    > {
+        FftError::perform_fft_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_lwe_multi_bit_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_multi_bit_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweMultiBitBootstrapKey32,
+    ) -> FftFourierLweMultiBitBootstrapKey32 {
+        let glwe_size = input.0.glwe_size();
+        let ggsw_count = input.0.key_size().0 / input.0.grouping_factor().0
+            * ggsw_per_multi_bit_group(input.0.grouping_factor());
+
+        let boxed = avec![
+            c64::default();
+            input.0.polynomial_size().0
+                * ggsw_count
+                * input.0.level_count().0
+                * glwe_size.0
+                * glwe_size.0
+                / 2
+        ]
+        .into_boxed_slice();
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierLweMultiBitBootstrapKey::new(
+            boxed,
+            input.0.key_size(),
+            input.0.polynomial_size(),
+            input.0.glwe_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+            input.0.grouping_factor(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.as_view(), fft, stack);
+        FftFourierLweMultiBitBootstrapKey32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweMultiBitBootstrapKeyConversionEngine`] for [`FftEngine`] that operates
+/// on 64 bit integers. It converts a multi-bit bootstrap key from the standard to the Fourier
+/// domain.
+impl
+    LweMultiBitBootstrapKeyConversionEngine<
+        LweMultiBitBootstrapKey64,
+        FftFourierLweMultiBitBootstrapKey64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let grouping_factor = LweBskGroupingFactor(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweMultiBitBootstrapKey64 = default_engine.generate_new_lwe_multi_bit_bootstrap_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     grouping_factor,
+    ///     noise,
+    /// )?;
+    ///
+    /// let fourier_bsk: FftFourierLweMultiBitBootstrapKey64 =
+    ///     fft_engine.convert_lwe_multi_bit_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    /// assert_eq!(fourier_bsk.grouping_factor(), grouping_factor);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input: &LweMultiBitBootstrapKey64,
+    ) -> Result<
+        FftFourierLweMultiBitBootstrapKey64,
+        LweMultiBitBootstrapKeyConversionError<Self::EngineError>,
+    > {
+        FftError::perform_fft_checks(input.polynomial_size())?;
+        Ok(unsafe { self.convert_lwe_multi_bit_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_multi_bit_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweMultiBitBootstrapKey64,
+    ) -> FftFourierLweMultiBitBootstrapKey64 {
+        let glwe_size = input.0.glwe_size();
+        let ggsw_count = input.0.key_size().0 / input.0.grouping_factor().0
+            * ggsw_per_multi_bit_group(input.0.grouping_factor());
+
+        let boxed = avec![
+            c64::default();
+            input.0.polynomial_size().0
+                * ggsw_count
+                * input.0.level_count().0
+                * glwe_size.0
+                * glwe_size.0
+                / 2
+        ]
+        .into_boxed_slice();
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierLweMultiBitBootstrapKey::new(
+            boxed,
+            input.0.key_size(),
+            input.0.polynomial_size(),
+            input.0.glwe_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+            input.0.grouping_factor(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.as_view(), fft, stack);
+        FftFourierLweMultiBitBootstrapKey64(output)
+    }
+}
@@ -62,10 +62,21 @@ impl AbstractEngine for FftEngine {
 
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
+mod ggsw_ciphertext_vector_conversion;
+mod glwe_ciphertext_discarding_cmux_tree;
+mod glwe_ciphertext_discarding_multiplication;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_multiplication;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_relinearization_key_conversion;
 mod lwe_bootstrap_key_conversion;
 mod lwe_ciphertext_discarding_bit_extraction;
+mod lwe_ciphertext_discarding_blind_rotation;
 mod lwe_ciphertext_discarding_bootstrap;
 mod lwe_ciphertext_discarding_circuit_bootstrap_boolean;
+mod lwe_ciphertext_discarding_many_lut_bootstrap;
+mod lwe_ciphertext_discarding_multi_bit_bootstrap;
 mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
+mod lwe_ciphertext_vector_discarding_vertical_packing;
+mod lwe_ciphertext_vector_discarding_wop_pbs;
+mod lwe_multi_bit_bootstrap_key_conversion;
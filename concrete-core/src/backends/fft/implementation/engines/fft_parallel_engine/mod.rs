@@ -52,4 +52,5 @@ impl AbstractEngine for FftParallelEngine {
     }
 }
 
+mod ggsw_ciphertext_vector_conversion;
 mod lwe_ciphertext_vector_discarding_bootstrap;
@@ -1,15 +1,19 @@
 #![allow(clippy::missing_safety_doc)]
 
-use super::{FftSerializationEngine, FftSerializationError};
+use super::{deserialization_error, FftSerializationEngine, FftSerializationError};
 use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::backends::fft::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::backends::fft::private::crypto::wop_pbs::FourierGgswCiphertextList;
 use crate::prelude::{
     EntityDeserializationEngine, EntityDeserializationError, FftFourierGgswCiphertext32,
     FftFourierGgswCiphertext32Version, FftFourierGgswCiphertext64,
-    FftFourierGgswCiphertext64Version, FftFourierLweBootstrapKey32,
+    FftFourierGgswCiphertext64Version, FftFourierGgswCiphertextVector32,
+    FftFourierGgswCiphertextVector32Version, FftFourierGgswCiphertextVector64,
+    FftFourierGgswCiphertextVector64Version, FftFourierLweBootstrapKey32,
     FftFourierLweBootstrapKey32Version, FftFourierLweBootstrapKey64,
     FftFourierLweBootstrapKey64Version,
 };
+use crate::specification::serialization::deserialize_with_header;
 use aligned_vec::ABox;
 use concrete_fft::c64;
 use serde::Deserialize;
@@ -70,8 +74,12 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSeria
             version: FftFourierGgswCiphertext32Version,
             inner: FourierGgswCiphertext<ABox<[c64]>>,
         }
-        let deserialized: SerializableFftFourierGgswCiphertext32 = bincode::deserialize(serialized)
-            .map_err(FftSerializationError::Deserialization)
+        let deserialized: SerializableFftFourierGgswCiphertext32 =
+            deserialize_with_header::<FftFourierGgswCiphertext32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             SerializableFftFourierGgswCiphertext32 {
@@ -149,8 +157,12 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
             version: FftFourierGgswCiphertext64Version,
             inner: FourierGgswCiphertext<ABox<[c64]>>,
         }
-        let deserialized: SerializableFftFourierGgswCiphertext64 = bincode::deserialize(serialized)
-            .map_err(FftSerializationError::Deserialization)
+        let deserialized: SerializableFftFourierGgswCiphertext64 =
+            deserialize_with_header::<FftFourierGgswCiphertext64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             SerializableFftFourierGgswCiphertext64 {
@@ -171,6 +183,190 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
     }
 }
 
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
+/// on 32 bits integers. It deserializes a GGSW ciphertext vector in the Fourier domain.
+impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertextVector32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext vector in the standard domain
+    /// let ciphertext_vector = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext_vector: FftFourierGgswCiphertextVector32 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ciphertext_vector)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierGgswCiphertextVector32, EntityDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierGgswCiphertextVector32 {
+            version: FftFourierGgswCiphertextVector32Version,
+            inner: FourierGgswCiphertextList<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierGgswCiphertextVector32 =
+            deserialize_with_header::<FftFourierGgswCiphertextVector32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierGgswCiphertextVector32 {
+                version: FftFourierGgswCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierGgswCiphertextVector32 {
+                version: FftFourierGgswCiphertextVector32Version::V0,
+                inner,
+            } => Ok(FftFourierGgswCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierGgswCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
+/// on 64 bits integers. It deserializes a GGSW ciphertext vector in the Fourier domain.
+impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertextVector64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext vector in the standard domain
+    /// let ciphertext_vector = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext_vector: FftFourierGgswCiphertextVector64 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ciphertext_vector)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierGgswCiphertextVector64, EntityDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierGgswCiphertextVector64 {
+            version: FftFourierGgswCiphertextVector64Version,
+            inner: FourierGgswCiphertextList<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierGgswCiphertextVector64 =
+            deserialize_with_header::<FftFourierGgswCiphertextVector64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierGgswCiphertextVector64 {
+                version: FftFourierGgswCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierGgswCiphertextVector64 {
+                version: FftFourierGgswCiphertextVector64Version::V0,
+                inner,
+            } => Ok(FftFourierGgswCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierGgswCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`FftSerializationEngine`] that operates
 /// on 32 bits integers. It deserializes an LWE bootstrap key in the Fourier domain.
@@ -221,9 +417,12 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
             inner: FourierLweBootstrapKey<ABox<[c64]>>,
         }
         let deserialized: SerializableFftFourierLweBootstrapKey32 =
-            bincode::deserialize(serialized)
-                .map_err(FftSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            deserialize_with_header::<FftFourierLweBootstrapKey32, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             SerializableFftFourierLweBootstrapKey32 {
                 version: FftFourierLweBootstrapKey32Version::Unsupported,
@@ -293,9 +492,12 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
             inner: FourierLweBootstrapKey<ABox<[c64]>>,
         }
         let deserialized: SerializableFftFourierLweBootstrapKey64 =
-            bincode::deserialize(serialized)
-                .map_err(FftSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+            deserialize_with_header::<FftFourierLweBootstrapKey64, _, _>(
+                serialized,
+                bincode::deserialize,
+            )
+            .map_err(deserialization_error)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
             SerializableFftFourierLweBootstrapKey64 {
                 version: FftFourierLweBootstrapKey64Version::Unsupported,
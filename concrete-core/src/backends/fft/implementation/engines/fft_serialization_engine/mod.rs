@@ -1,5 +1,6 @@
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
+use crate::specification::serialization::{EnvelopeError, SerializationHeaderError};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -9,6 +10,13 @@ pub enum FftSerializationError {
     Serialization(bincode::Error),
     Deserialization(bincode::Error),
     UnsupportedVersion,
+    Header(SerializationHeaderError),
+    Io(std::io::Error),
+    MisalignedRegion,
+    InvalidRegionSize { expected: usize, found: usize },
+    InvalidRegionLayout,
+    IncompatibleFftPlan,
+    UnsupportedEndianness,
 }
 
 impl Display for FftSerializationError {
@@ -26,13 +34,112 @@ impl Display for FftSerializationError {
                     "The version used to serialize the entity is not supported."
                 )
             }
+            FftSerializationError::Header(header_error) => {
+                write!(f, "Invalid serialization header: {}", header_error)
+            }
+            FftSerializationError::Io(io_error) => {
+                write!(f, "Failed to write the zero-copy layout: {}", io_error)
+            }
+            FftSerializationError::MisalignedRegion => {
+                write!(
+                    f,
+                    "The region is not aligned on {} bytes.",
+                    FFT_ZERO_COPY_ALIGNMENT
+                )
+            }
+            FftSerializationError::InvalidRegionSize { expected, found } => {
+                write!(
+                    f,
+                    "The region is {} bytes long, while its layout describes {} bytes.",
+                    found, expected
+                )
+            }
+            FftSerializationError::InvalidRegionLayout => {
+                write!(
+                    f,
+                    "The region does not contain the zero-copy layout of the expected entity."
+                )
+            }
+            FftSerializationError::IncompatibleFftPlan => {
+                write!(
+                    f,
+                    "The FFT plan used in this process for this polynomial size stores the \
+                    Fourier coefficients in another order than the region."
+                )
+            }
+            FftSerializationError::UnsupportedEndianness => {
+                write!(
+                    f,
+                    "The zero-copy layout is only supported on little-endian platforms."
+                )
+            }
         }
     }
 }
 
 impl Error for FftSerializationError {}
 
+fn serialization_error(error: EnvelopeError<bincode::Error>) -> FftSerializationError {
+    match error {
+        EnvelopeError::Header(header_error) => FftSerializationError::Header(header_error),
+        EnvelopeError::Payload(bincode_error) => {
+            FftSerializationError::Serialization(bincode_error)
+        }
+    }
+}
+
+fn deserialization_error(error: EnvelopeError<bincode::Error>) -> FftSerializationError {
+    match error {
+        EnvelopeError::Header(header_error) => FftSerializationError::Header(header_error),
+        EnvelopeError::Payload(bincode_error) => {
+            FftSerializationError::Deserialization(bincode_error)
+        }
+    }
+}
+
+/// The alignment in bytes required for the regions loaded without copy.
+pub const FFT_ZERO_COPY_ALIGNMENT: usize = 128;
+
 /// The serialization engine exposed by the fft backend.
+///
+/// # Zero-copy layout
+///
+/// Besides the serialization to a portable format, this engine can write the Fourier bootstrap
+/// keys and GGSW ciphertexts in a layout matching their memory representation (see
+/// [`EntityZeroCopySerializationEngine`](crate::prelude::EntityZeroCopySerializationEngine)).
+/// Views over those entities can then be loaded from a region containing this layout, for
+/// instance a read-only memory map of a file, without copying their data (see
+/// [`EntityZeroCopyLoadingEngine`](crate::prelude::EntityZeroCopyLoadingEngine)).
+///
+/// The layout starts with a header of 128 bytes, whose integers are encoded in little-endian
+/// order:
+///
+/// | Offset | Size | Content                                                                  |
+/// |--------|------|--------------------------------------------------------------------------|
+/// | 0      | 8    | The magic bytes `CCFFTZCL`                                               |
+/// | 8      | 2    | The version of the layout, currently `1`                                 |
+/// | 10     | 2    | The code of the [`SerializedEntityKind`](crate::prelude::SerializedEntityKind) |
+/// | 12     | 1    | The code of the [`SerializedEntityPrecision`](crate::prelude::SerializedEntityPrecision) |
+/// | 13     | 3    | Reserved, always zero                                                    |
+/// | 16     | 8    | The size of the ordered FFT the FFT plan is built over                   |
+/// | 24     | 8    | The input LWE dimension of a bootstrap key, zero for a GGSW ciphertext   |
+/// | 32     | 8    | The GLWE dimension                                                       |
+/// | 40     | 8    | The polynomial size                                                      |
+/// | 48     | 8    | The decomposition level count                                            |
+/// | 56     | 8    | The decomposition base log                                               |
+/// | 64     | 8    | The number of complex Fourier coefficients `n`                           |
+/// | 72     | 56   | Reserved, always zero                                                    |
+///
+/// The header is followed by the `n` Fourier coefficients, each stored as two little-endian `f64`
+/// (real part, then imaginary part), which makes the region exactly `128 + 16 * n` bytes long.
+/// The coefficients are stored in the plan-specific order of the FFT, which only depends on the
+/// size of the ordered FFT the plan is built over. When loading a region, the FFT plan of the
+/// process for this polynomial size is created with the same base size if it does not exist
+/// yet, and the region is rejected if an existing plan uses another base size. The region must
+/// start on an address aligned on [`FFT_ZERO_COPY_ALIGNMENT`] bytes (which is the case of memory
+/// maps, as they are page-aligned), and must not be modified while views are loaded over it.
+///
+/// The layout is only supported on little-endian platforms.
 pub struct FftSerializationEngine;
 
 impl AbstractEngineSeal for FftSerializationEngine {}
@@ -48,3 +155,10 @@ impl AbstractEngine for FftSerializationEngine {
 
 mod deserialization;
 mod serialization;
+mod serialization_header_inspection;
+mod serialized_entity;
+mod stream_deserialization;
+mod stream_serialization;
+mod zero_copy_layout;
+mod zero_copy_loading;
+mod zero_copy_serialization;
@@ -1,15 +1,19 @@
 #![allow(clippy::missing_safety_doc)]
 
-use super::{FftSerializationEngine, FftSerializationError};
+use super::{serialization_error, FftSerializationEngine};
 use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKeyView;
 use crate::backends::fft::private::crypto::ggsw::FourierGgswCiphertextView;
+use crate::backends::fft::private::crypto::wop_pbs::FourierGgswCiphertextListView;
 use crate::prelude::{
     EntitySerializationEngine, EntitySerializationError, FftFourierGgswCiphertext32,
     FftFourierGgswCiphertext32Version, FftFourierGgswCiphertext64,
-    FftFourierGgswCiphertext64Version, FftFourierLweBootstrapKey32,
+    FftFourierGgswCiphertext64Version, FftFourierGgswCiphertextVector32,
+    FftFourierGgswCiphertextVector32Version, FftFourierGgswCiphertextVector64,
+    FftFourierGgswCiphertextVector64Version, FftFourierLweBootstrapKey32,
     FftFourierLweBootstrapKey32Version, FftFourierLweBootstrapKey64,
     FftFourierLweBootstrapKey64Version,
 };
+use crate::specification::serialization::serialize_with_header;
 use serde::Serialize;
 
 /// # Description:
@@ -64,7 +68,7 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext32, Vec<u8>> for FftSeria
         &mut self,
         entity: &FftFourierGgswCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        let entity = entity.0.as_view();
+        let view = entity.0.as_view();
         #[derive(Serialize)]
         struct SerializableFftFourierGgswCiphertext32<'a> {
             version: FftFourierGgswCiphertext32Version,
@@ -72,10 +76,10 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext32, Vec<u8>> for FftSeria
         }
         let value = SerializableFftFourierGgswCiphertext32 {
             version: FftFourierGgswCiphertext32Version::V0,
-            inner: entity,
+            inner: view,
         };
-        bincode::serialize(&value)
-            .map_err(FftSerializationError::Serialization)
+        serialize_with_header(entity, |writer| bincode::serialize_into(writer, &value))
+            .map_err(serialization_error)
             .map_err(EntitySerializationError::Engine)
     }
 
@@ -136,7 +140,7 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext64, Vec<u8>> for FftSeria
         &mut self,
         entity: &FftFourierGgswCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        let entity = entity.0.as_view();
+        let view = entity.0.as_view();
         #[derive(Serialize)]
         struct SerializableFftFourierGgswCiphertext64<'a> {
             version: FftFourierGgswCiphertext64Version,
@@ -144,10 +148,10 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext64, Vec<u8>> for FftSeria
         }
         let value = SerializableFftFourierGgswCiphertext64 {
             version: FftFourierGgswCiphertext64Version::V0,
-            inner: entity,
+            inner: view,
         };
-        bincode::serialize(&value)
-            .map_err(FftSerializationError::Serialization)
+        serialize_with_header(entity, |writer| bincode::serialize_into(writer, &value))
+            .map_err(serialization_error)
             .map_err(EntitySerializationError::Engine)
     }
 
@@ -156,6 +160,160 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext64, Vec<u8>> for FftSeria
     }
 }
 
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`FftSerializationEngine`] that operates on
+/// 32 bits integers. It serializes a GGSW ciphertext vector in the Fourier domain.
+impl EntitySerializationEngine<FftFourierGgswCiphertextVector32, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext vector in the standard domain
+    /// let ciphertext_vector = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext_vector: FftFourierGgswCiphertextVector32 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ciphertext_vector)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &FftFourierGgswCiphertextVector32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let view = entity.0.as_view();
+        #[derive(Serialize)]
+        struct SerializableFftFourierGgswCiphertextVector32<'a> {
+            version: FftFourierGgswCiphertextVector32Version,
+            inner: FourierGgswCiphertextListView<'a>,
+        }
+        let value = SerializableFftFourierGgswCiphertextVector32 {
+            version: FftFourierGgswCiphertextVector32Version::V0,
+            inner: view,
+        };
+        serialize_with_header(entity, |writer| bincode::serialize_into(writer, &value))
+            .map_err(serialization_error)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierGgswCiphertextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`FftSerializationEngine`] that operates on
+/// 64 bits integers. It serializes a GGSW ciphertext vector in the Fourier domain.
+impl EntitySerializationEngine<FftFourierGgswCiphertextVector64, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext vector in the standard domain
+    /// let ciphertext_vector = default_engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext_vector: FftFourierGgswCiphertextVector64 =
+    ///     fft_engine.convert_ggsw_ciphertext_vector(&ciphertext_vector)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext_vector, recovered);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &FftFourierGgswCiphertextVector64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let view = entity.0.as_view();
+        #[derive(Serialize)]
+        struct SerializableFftFourierGgswCiphertextVector64<'a> {
+            version: FftFourierGgswCiphertextVector64Version,
+            inner: FourierGgswCiphertextListView<'a>,
+        }
+        let value = SerializableFftFourierGgswCiphertextVector64 {
+            version: FftFourierGgswCiphertextVector64Version::V0,
+            inner: view,
+        };
+        serialize_with_header(entity, |writer| bincode::serialize_into(writer, &value))
+            .map_err(serialization_error)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &FftFourierGgswCiphertextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`FftSerializationEngine`] that operates on
 /// 32 bits integers. It serializes an LWE bootstrap key in the Fourier domain.
@@ -200,7 +358,7 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey32, Vec<u8>> for FftSeri
         &mut self,
         entity: &FftFourierLweBootstrapKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        let entity = entity.0.as_view();
+        let view = entity.0.as_view();
         #[derive(Serialize)]
         struct SerializableFftFourierLweBootstrapKey32<'a> {
             version: FftFourierLweBootstrapKey32Version,
@@ -208,10 +366,10 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey32, Vec<u8>> for FftSeri
         }
         let value = SerializableFftFourierLweBootstrapKey32 {
             version: FftFourierLweBootstrapKey32Version::V0,
-            inner: entity,
+            inner: view,
         };
-        bincode::serialize(&value)
-            .map_err(FftSerializationError::Serialization)
+        serialize_with_header(entity, |writer| bincode::serialize_into(writer, &value))
+            .map_err(serialization_error)
             .map_err(EntitySerializationError::Engine)
     }
 
@@ -264,7 +422,7 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>> for FftSeri
         &mut self,
         entity: &FftFourierLweBootstrapKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        let entity = entity.0.as_view();
+        let view = entity.0.as_view();
         #[derive(Serialize)]
         struct SerializableFftFourierLweBootstrapKey64<'a> {
             version: FftFourierLweBootstrapKey64Version,
@@ -272,10 +430,10 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>> for FftSeri
         }
         let value = SerializableFftFourierLweBootstrapKey64 {
             version: FftFourierLweBootstrapKey64Version::V0,
-            inner: entity,
+            inner: view,
         };
-        bincode::serialize(&value)
-            .map_err(FftSerializationError::Serialization)
+        serialize_with_header(entity, |writer| bincode::serialize_into(writer, &value))
+            .map_err(serialization_error)
             .map_err(EntitySerializationError::Engine)
     }
 
@@ -0,0 +1,140 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::{FftSerializationEngine, FftSerializationError};
+use crate::prelude::{SerializationHeaderInspectionEngine, SerializationHeaderInspectionError};
+use crate::specification::serialization::{decode_header, read_header, SerializationHeader};
+use std::io::Read;
+
+/// # Description:
+/// Implementation of [`SerializationHeaderInspectionEngine`] for [`FftSerializationEngine`] that
+/// operates on serialized entities held in memory.
+impl SerializationHeaderInspectionEngine<&[u8]> for FftSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    ///
+    /// let header = serialization_engine.inspect_serialization_header(serialized.as_slice())?;
+    /// assert_eq!(header.kind, SerializedEntityKind::FourierLweBootstrapKey);
+    /// assert_eq!(header.precision, SerializedEntityPrecision::Unsigned64);
+    /// assert_eq!(header.parameters.lwe_dimension, Some(lwe_dim));
+    /// assert_eq!(header.parameters.glwe_dimension, Some(glwe_dim));
+    /// assert_eq!(header.parameters.polynomial_size, Some(poly_size));
+    /// assert_eq!(header.parameters.decomposition_level_count, Some(dec_lc));
+    /// assert_eq!(header.parameters.decomposition_base_log, Some(dec_bl));
+    /// header.validate(serialized.as_slice())?;
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn inspect_serialization_header(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<SerializationHeader, SerializationHeaderInspectionError<Self::EngineError>> {
+        decode_header(serialized)
+            .map_err(FftSerializationError::Header)
+            .map_err(SerializationHeaderInspectionError::Engine)
+    }
+
+    unsafe fn inspect_serialization_header_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> SerializationHeader {
+        self.inspect_serialization_header(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`SerializationHeaderInspectionEngine`] for [`FftSerializationEngine`] that
+/// operates on readers. Only the bytes of the header are consumed from the reader.
+impl<R: Read> SerializationHeaderInspectionEngine<&mut R> for FftSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_ciphertext, &mut serialized)?;
+    ///
+    /// let mut reader = serialized.as_slice();
+    /// let header = serialization_engine.inspect_serialization_header(&mut reader)?;
+    /// assert_eq!(header.kind, SerializedEntityKind::FourierGgswCiphertext);
+    /// assert_eq!(header.precision, SerializedEntityPrecision::Unsigned32);
+    /// assert_eq!(header.parameters.glwe_dimension, Some(glwe_dimension));
+    /// assert_eq!(
+    ///     reader.len(),
+    ///     header.payload_size as usize + SERIALIZATION_TRAILER_SIZE
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn inspect_serialization_header(
+        &mut self,
+        serialized: &mut R,
+    ) -> Result<SerializationHeader, SerializationHeaderInspectionError<Self::EngineError>> {
+        read_header(serialized)
+            .map_err(FftSerializationError::Header)
+            .map_err(SerializationHeaderInspectionError::Engine)
+    }
+
+    unsafe fn inspect_serialization_header_unchecked(
+        &mut self,
+        serialized: &mut R,
+    ) -> SerializationHeader {
+        self.inspect_serialization_header(serialized).unwrap()
+    }
+}
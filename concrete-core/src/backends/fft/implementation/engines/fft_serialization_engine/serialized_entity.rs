@@ -0,0 +1,89 @@
+use crate::prelude::{
+    FftFourierGgswCiphertext32, FftFourierGgswCiphertext64, FftFourierGgswCiphertextVector32,
+    FftFourierGgswCiphertextVector64, FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64,
+    GgswCiphertextEntity, GgswCiphertextVectorEntity, LweBootstrapKeyEntity,
+    SerializedEntityParameters,
+};
+use crate::specification::serialization::impl_serialized_entity;
+
+impl_serialized_entity!(
+    FourierGgswCiphertext,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    FftFourierGgswCiphertext32,
+);
+
+impl_serialized_entity!(
+    FourierGgswCiphertext,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    FftFourierGgswCiphertext64,
+);
+
+impl_serialized_entity!(
+    FourierGgswCiphertextVector,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        count: Some(entity.ggsw_ciphertext_count().0),
+        ..Default::default()
+    },
+    FftFourierGgswCiphertextVector32,
+);
+
+impl_serialized_entity!(
+    FourierGgswCiphertextVector,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        count: Some(entity.ggsw_ciphertext_count().0),
+        ..Default::default()
+    },
+    FftFourierGgswCiphertextVector64,
+);
+
+impl_serialized_entity!(
+    FourierLweBootstrapKey,
+    Unsigned32,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    FftFourierLweBootstrapKey32,
+);
+
+impl_serialized_entity!(
+    FourierLweBootstrapKey,
+    Unsigned64,
+    |entity| SerializedEntityParameters {
+        lwe_dimension: Some(entity.input_lwe_dimension()),
+        glwe_dimension: Some(entity.glwe_dimension()),
+        polynomial_size: Some(entity.polynomial_size()),
+        decomposition_level_count: Some(entity.decomposition_level_count()),
+        decomposition_base_log: Some(entity.decomposition_base_log()),
+        ..Default::default()
+    },
+    FftFourierLweBootstrapKey64,
+);
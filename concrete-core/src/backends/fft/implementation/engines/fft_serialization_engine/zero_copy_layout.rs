@@ -0,0 +1,178 @@
+//! Reading and writing of the zero-copy layout documented in [`FftSerializationEngine`].
+#[cfg(doc)]
+use super::FftSerializationEngine;
+use super::{FftSerializationError, FFT_ZERO_COPY_ALIGNMENT};
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    SerializedEntityKind, SerializedEntityPrecision,
+};
+use concrete_fft::c64;
+use std::io::Write;
+use std::mem::{size_of, size_of_val};
+
+const MAGIC: [u8; 8] = *b"CCFFTZCL";
+const LAYOUT_VERSION: u16 = 1;
+const HEADER_SIZE: usize = 128;
+
+/// The parameters stored in the header of the zero-copy layout.
+pub(super) struct ZeroCopyLayout {
+    pub kind: SerializedEntityKind,
+    pub precision: SerializedEntityPrecision,
+    pub fft_base_size: usize,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+}
+
+impl ZeroCopyLayout {
+    // Returns the number of Fourier coefficients described by the layout, if it does not overflow.
+    fn coefficient_count(&self) -> Option<usize> {
+        let ggsw_count = match self.kind {
+            SerializedEntityKind::FourierLweBootstrapKey => self.lwe_dimension.0,
+            _ => 1,
+        };
+        let glwe_size = self.glwe_dimension.0.checked_add(1)?;
+        ggsw_count
+            .checked_mul(self.polynomial_size.0 / 2)?
+            .checked_mul(self.decomposition_level_count.0)?
+            .checked_mul(glwe_size)?
+            .checked_mul(glwe_size)
+    }
+
+    // Checks that the parameters describe a valid entity of the given kind.
+    fn is_valid(&self) -> bool {
+        let valid_lwe_dimension = match self.kind {
+            SerializedEntityKind::FourierLweBootstrapKey => self.lwe_dimension.0 != 0,
+            _ => self.lwe_dimension.0 == 0,
+        };
+        valid_lwe_dimension
+            && self.glwe_dimension.0 != 0
+            && self.polynomial_size.0.is_power_of_two()
+            && self.polynomial_size.0 >= 32
+            && self.decomposition_level_count.0 != 0
+            && self.decomposition_base_log.0 != 0
+    }
+}
+
+// Parameters which do not fit in a `usize`, which may happen on 32 bits targets, can not describe
+// an entity of this platform.
+fn read_u64(bytes: &[u8], offset: usize) -> Result<usize, FftSerializationError> {
+    let mut array = [0u8; 8];
+    array.copy_from_slice(&bytes[offset..offset + 8]);
+    usize::try_from(u64::from_le_bytes(array))
+        .map_err(|_| FftSerializationError::InvalidRegionLayout)
+}
+
+/// Writes the zero-copy layout of an entity.
+pub(super) fn write_zero_copy_layout<W: Write>(
+    writer: &mut W,
+    layout: &ZeroCopyLayout,
+    data: &[c64],
+) -> Result<(), FftSerializationError> {
+    if cfg!(target_endian = "big") {
+        return Err(FftSerializationError::UnsupportedEndianness);
+    }
+    debug_assert_eq!(layout.coefficient_count(), Some(data.len()));
+
+    let mut header = [0u8; HEADER_SIZE];
+    header[0..8].copy_from_slice(&MAGIC);
+    header[8..10].copy_from_slice(&LAYOUT_VERSION.to_le_bytes());
+    header[10..12].copy_from_slice(&layout.kind.code().to_le_bytes());
+    header[12] = layout.precision.code();
+    let parameters = [
+        layout.fft_base_size,
+        layout.lwe_dimension.0,
+        layout.glwe_dimension.0,
+        layout.polynomial_size.0,
+        layout.decomposition_level_count.0,
+        layout.decomposition_base_log.0,
+        data.len(),
+    ];
+    for (i, parameter) in parameters.iter().enumerate() {
+        let offset = 16 + 8 * i;
+        header[offset..offset + 8].copy_from_slice(&(*parameter as u64).to_le_bytes());
+    }
+    writer
+        .write_all(&header)
+        .map_err(FftSerializationError::Io)?;
+
+    // SAFETY: `c64` is made of two `f64`, which are stored in little-endian order on this
+    // platform, and any value can be read as bytes.
+    let bytes =
+        unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, size_of_val(data)) };
+    writer.write_all(bytes).map_err(FftSerializationError::Io)
+}
+
+/// Reads the zero-copy layout of an entity of the given kind and precision from a region, and
+/// returns its parameters along with the Fourier coefficients borrowed from the region.
+pub(super) fn read_zero_copy_layout(
+    region: &[u8],
+    kind: SerializedEntityKind,
+    precision: SerializedEntityPrecision,
+) -> Result<(ZeroCopyLayout, &[c64]), FftSerializationError> {
+    if cfg!(target_endian = "big") {
+        return Err(FftSerializationError::UnsupportedEndianness);
+    }
+    if (region.as_ptr() as usize) % FFT_ZERO_COPY_ALIGNMENT != 0 {
+        return Err(FftSerializationError::MisalignedRegion);
+    }
+    if region.len() < HEADER_SIZE {
+        return Err(FftSerializationError::InvalidRegionSize {
+            expected: HEADER_SIZE,
+            found: region.len(),
+        });
+    }
+    if region[0..8] != MAGIC
+        || u16::from_le_bytes([region[8], region[9]]) != LAYOUT_VERSION
+        || u16::from_le_bytes([region[10], region[11]]) != kind.code()
+        || region[12] != precision.code()
+        || region[13..16].iter().any(|byte| *byte != 0)
+        || region[72..HEADER_SIZE].iter().any(|byte| *byte != 0)
+    {
+        return Err(FftSerializationError::InvalidRegionLayout);
+    }
+
+    let layout = ZeroCopyLayout {
+        kind,
+        precision,
+        fft_base_size: read_u64(region, 16)?,
+        lwe_dimension: LweDimension(read_u64(region, 24)?),
+        glwe_dimension: GlweDimension(read_u64(region, 32)?),
+        polynomial_size: PolynomialSize(read_u64(region, 40)?),
+        decomposition_level_count: DecompositionLevelCount(read_u64(region, 48)?),
+        decomposition_base_log: DecompositionBaseLog(read_u64(region, 56)?),
+    };
+    let coefficient_count = read_u64(region, 64)?;
+    if !layout.is_valid() || layout.coefficient_count() != Some(coefficient_count) {
+        return Err(FftSerializationError::InvalidRegionLayout);
+    }
+    let expected = coefficient_count
+        .checked_mul(size_of::<c64>())
+        .and_then(|size| size.checked_add(HEADER_SIZE))
+        .ok_or(FftSerializationError::InvalidRegionLayout)?;
+    if region.len() != expected {
+        return Err(FftSerializationError::InvalidRegionSize {
+            expected,
+            found: region.len(),
+        });
+    }
+
+    if Fft::with_base_size(layout.polynomial_size, layout.fft_base_size).is_none() {
+        return Err(FftSerializationError::IncompatibleFftPlan);
+    }
+
+    // SAFETY: the coefficients start at an offset of `HEADER_SIZE` bytes of an address aligned
+    // on `FFT_ZERO_COPY_ALIGNMENT`, which is a multiple of the alignment of `c64`, and the size
+    // of the region was checked above. Any bit pattern is a valid `c64`, stored in little-endian
+    // order on this platform.
+    let data = unsafe {
+        std::slice::from_raw_parts(
+            region[HEADER_SIZE..].as_ptr() as *const c64,
+            coefficient_count,
+        )
+    };
+    Ok((layout, data))
+}
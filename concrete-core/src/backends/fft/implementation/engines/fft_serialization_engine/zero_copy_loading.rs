@@ -0,0 +1,392 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::zero_copy_layout::read_zero_copy_layout;
+use super::{FftSerializationEngine, FftSerializationError};
+use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKey;
+use crate::backends::fft::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::prelude::{
+    EntityZeroCopyLoadingEngine, EntityZeroCopyLoadingError, FftFourierGgswCiphertextView32,
+    FftFourierGgswCiphertextView64, FftFourierLweBootstrapKeyView32,
+    FftFourierLweBootstrapKeyView64, SerializedEntityKind, SerializedEntityPrecision,
+};
+use concrete_fft::c64;
+
+fn load_bootstrap_key(
+    region: &[u8],
+    precision: SerializedEntityPrecision,
+) -> Result<FourierLweBootstrapKey<&[c64]>, FftSerializationError> {
+    let (layout, data) = read_zero_copy_layout(
+        region,
+        SerializedEntityKind::FourierLweBootstrapKey,
+        precision,
+    )?;
+    Ok(FourierLweBootstrapKey::new(
+        data,
+        layout.lwe_dimension,
+        layout.polynomial_size,
+        layout.glwe_dimension.to_glwe_size(),
+        layout.decomposition_base_log,
+        layout.decomposition_level_count,
+    ))
+}
+
+fn load_ggsw_ciphertext(
+    region: &[u8],
+    precision: SerializedEntityPrecision,
+) -> Result<FourierGgswCiphertext<&[c64]>, FftSerializationError> {
+    let (layout, data) = read_zero_copy_layout(
+        region,
+        SerializedEntityKind::FourierGgswCiphertext,
+        precision,
+    )?;
+    Ok(FourierGgswCiphertext::new(
+        data,
+        layout.polynomial_size,
+        layout.glwe_dimension.to_glwe_size(),
+        layout.decomposition_base_log,
+        layout.decomposition_level_count,
+    ))
+}
+
+/// # Description:
+/// Implementation of [`EntityZeroCopyLoadingEngine`] for [`FftSerializationEngine`] that operates
+/// on 32 bits integers. It loads a view over an LWE bootstrap key in the Fourier domain from a
+/// region containing the zero-copy layout described in the documentation of
+/// [`FftSerializationEngine`].
+impl<'a> EntityZeroCopyLoadingEngine<&'a [u8], FftFourierLweBootstrapKeyView32<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use aligned_vec::{AVec, ConstAlign};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_zero_copy_into(&bsk, &mut serialized)?;
+    /// let region = AVec::<u8, ConstAlign<FFT_ZERO_COPY_ALIGNMENT>>::from_slice(
+    ///     FFT_ZERO_COPY_ALIGNMENT,
+    ///     &serialized,
+    /// );
+    /// let bsk_view: FftFourierLweBootstrapKeyView32 =
+    ///     serialization_engine.load_zero_copy(&*region)?;
+    ///
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    /// let mut expected = output.clone();
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk_view)?;
+    /// fft_engine.discard_bootstrap_lwe_ciphertext(&mut expected, &input, &acc, &bsk)?;
+    /// assert_eq!(output, expected);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_zero_copy(
+        &mut self,
+        region: &'a [u8],
+    ) -> Result<FftFourierLweBootstrapKeyView32<'a>, EntityZeroCopyLoadingError<Self::EngineError>>
+    {
+        load_bootstrap_key(region, SerializedEntityPrecision::Unsigned32)
+            .map(FftFourierLweBootstrapKeyView32)
+            .map_err(EntityZeroCopyLoadingError::Engine)
+    }
+
+    unsafe fn load_zero_copy_unchecked(
+        &mut self,
+        region: &'a [u8],
+    ) -> FftFourierLweBootstrapKeyView32<'a> {
+        self.load_zero_copy(region).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityZeroCopyLoadingEngine`] for [`FftSerializationEngine`] that operates
+/// on 64 bits integers. It loads a view over an LWE bootstrap key in the Fourier domain from a
+/// region containing the zero-copy layout described in the documentation of
+/// [`FftSerializationEngine`].
+impl<'a> EntityZeroCopyLoadingEngine<&'a [u8], FftFourierLweBootstrapKeyView64<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use aligned_vec::{AVec, ConstAlign};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_zero_copy_into(&bsk, &mut serialized)?;
+    /// let region = AVec::<u8, ConstAlign<FFT_ZERO_COPY_ALIGNMENT>>::from_slice(
+    ///     FFT_ZERO_COPY_ALIGNMENT,
+    ///     &serialized,
+    /// );
+    ///
+    /// // Misaligned and truncated regions are rejected.
+    /// let misaligned: Result<FftFourierLweBootstrapKeyView64, _> =
+    ///     serialization_engine.load_zero_copy(&region[1..]);
+    /// assert!(misaligned.is_err());
+    /// let truncated: Result<FftFourierLweBootstrapKeyView64, _> =
+    ///     serialization_engine.load_zero_copy(&region[..region.len() - 16]);
+    /// assert!(truncated.is_err());
+    ///
+    /// // So are regions whose reserved header bytes are not zero.
+    /// let mut tampered = region.clone();
+    /// tampered[100] = 1;
+    /// let tampered_result: Result<FftFourierLweBootstrapKeyView64, _> =
+    ///     serialization_engine.load_zero_copy(&*tampered);
+    /// assert!(tampered_result.is_err());
+    ///
+    /// let bsk_view: FftFourierLweBootstrapKeyView64 =
+    ///     serialization_engine.load_zero_copy(&*region)?;
+    ///
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    /// let mut expected = output.clone();
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk_view)?;
+    /// fft_engine.discard_bootstrap_lwe_ciphertext(&mut expected, &input, &acc, &bsk)?;
+    /// assert_eq!(output, expected);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_zero_copy(
+        &mut self,
+        region: &'a [u8],
+    ) -> Result<FftFourierLweBootstrapKeyView64<'a>, EntityZeroCopyLoadingError<Self::EngineError>>
+    {
+        load_bootstrap_key(region, SerializedEntityPrecision::Unsigned64)
+            .map(FftFourierLweBootstrapKeyView64)
+            .map_err(EntityZeroCopyLoadingError::Engine)
+    }
+
+    unsafe fn load_zero_copy_unchecked(
+        &mut self,
+        region: &'a [u8],
+    ) -> FftFourierLweBootstrapKeyView64<'a> {
+        self.load_zero_copy(region).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityZeroCopyLoadingEngine`] for [`FftSerializationEngine`] that operates
+/// on 32 bits integers. It loads a view over a GGSW ciphertext in the Fourier domain from a region
+/// containing the zero-copy layout described in the documentation of [`FftSerializationEngine`].
+impl<'a> EntityZeroCopyLoadingEngine<&'a [u8], FftFourierGgswCiphertextView32<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use aligned_vec::{AVec, ConstAlign};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_zero_copy_into(&fourier_ciphertext, &mut serialized)?;
+    /// let region = AVec::<u8, ConstAlign<FFT_ZERO_COPY_ALIGNMENT>>::from_slice(
+    ///     FFT_ZERO_COPY_ALIGNMENT,
+    ///     &serialized,
+    /// );
+    ///
+    /// let view: FftFourierGgswCiphertextView32 = serialization_engine.load_zero_copy(&*region)?;
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.polynomial_size(), polynomial_size);
+    /// assert_eq!(view.decomposition_base_log(), base_log);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_zero_copy(
+        &mut self,
+        region: &'a [u8],
+    ) -> Result<FftFourierGgswCiphertextView32<'a>, EntityZeroCopyLoadingError<Self::EngineError>>
+    {
+        load_ggsw_ciphertext(region, SerializedEntityPrecision::Unsigned32)
+            .map(FftFourierGgswCiphertextView32)
+            .map_err(EntityZeroCopyLoadingError::Engine)
+    }
+
+    unsafe fn load_zero_copy_unchecked(
+        &mut self,
+        region: &'a [u8],
+    ) -> FftFourierGgswCiphertextView32<'a> {
+        self.load_zero_copy(region).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityZeroCopyLoadingEngine`] for [`FftSerializationEngine`] that operates
+/// on 64 bits integers. It loads a view over a GGSW ciphertext in the Fourier domain from a region
+/// containing the zero-copy layout described in the documentation of [`FftSerializationEngine`].
+impl<'a> EntityZeroCopyLoadingEngine<&'a [u8], FftFourierGgswCiphertextView64<'a>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use aligned_vec::{AVec, ConstAlign};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_zero_copy_into(&fourier_ciphertext, &mut serialized)?;
+    /// let region = AVec::<u8, ConstAlign<FFT_ZERO_COPY_ALIGNMENT>>::from_slice(
+    ///     FFT_ZERO_COPY_ALIGNMENT,
+    ///     &serialized,
+    /// );
+    ///
+    /// // A region holding another entity is rejected.
+    /// let wrong_precision: Result<FftFourierGgswCiphertextView32, _> =
+    ///     serialization_engine.load_zero_copy(&*region);
+    /// assert!(wrong_precision.is_err());
+    ///
+    /// let view: FftFourierGgswCiphertextView64 = serialization_engine.load_zero_copy(&*region)?;
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.polynomial_size(), polynomial_size);
+    /// assert_eq!(view.decomposition_base_log(), base_log);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_zero_copy(
+        &mut self,
+        region: &'a [u8],
+    ) -> Result<FftFourierGgswCiphertextView64<'a>, EntityZeroCopyLoadingError<Self::EngineError>>
+    {
+        load_ggsw_ciphertext(region, SerializedEntityPrecision::Unsigned64)
+            .map(FftFourierGgswCiphertextView64)
+            .map_err(EntityZeroCopyLoadingError::Engine)
+    }
+
+    unsafe fn load_zero_copy_unchecked(
+        &mut self,
+        region: &'a [u8],
+    ) -> FftFourierGgswCiphertextView64<'a> {
+        self.load_zero_copy(region).unwrap()
+    }
+}
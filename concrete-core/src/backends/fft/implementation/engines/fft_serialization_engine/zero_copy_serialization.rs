@@ -0,0 +1,347 @@
+#![allow(clippy::missing_safety_doc)]
+
+use super::zero_copy_layout::{write_zero_copy_layout, ZeroCopyLayout};
+use super::{FftSerializationEngine, FftSerializationError};
+use crate::backends::fft::private::crypto::bootstrap::FourierLweBootstrapKeyView;
+use crate::backends::fft::private::crypto::ggsw::FourierGgswCiphertextView;
+use crate::backends::fft::private::math::fft::Fft;
+use crate::prelude::{
+    EntityZeroCopySerializationEngine, EntityZeroCopySerializationError,
+    FftFourierGgswCiphertext32, FftFourierGgswCiphertext64, FftFourierLweBootstrapKey32,
+    FftFourierLweBootstrapKey64, LweDimension, SerializedEntityKind, SerializedEntityPrecision,
+};
+use std::io::Write;
+
+fn write_bootstrap_key<W: Write>(
+    key: FourierLweBootstrapKeyView<'_>,
+    precision: SerializedEntityPrecision,
+    writer: &mut W,
+) -> Result<(), FftSerializationError> {
+    let layout = ZeroCopyLayout {
+        kind: SerializedEntityKind::FourierLweBootstrapKey,
+        precision,
+        fft_base_size: Fft::new(key.polynomial_size()).as_view().base_size(),
+        lwe_dimension: key.key_size(),
+        glwe_dimension: key.glwe_size().to_glwe_dimension(),
+        polynomial_size: key.polynomial_size(),
+        decomposition_level_count: key.decomposition_level_count(),
+        decomposition_base_log: key.decomposition_base_log(),
+    };
+    write_zero_copy_layout(writer, &layout, key.data())
+}
+
+fn write_ggsw_ciphertext<W: Write>(
+    ciphertext: FourierGgswCiphertextView<'_>,
+    precision: SerializedEntityPrecision,
+    writer: &mut W,
+) -> Result<(), FftSerializationError> {
+    let layout = ZeroCopyLayout {
+        kind: SerializedEntityKind::FourierGgswCiphertext,
+        precision,
+        fft_base_size: Fft::new(ciphertext.polynomial_size()).as_view().base_size(),
+        lwe_dimension: LweDimension(0),
+        glwe_dimension: ciphertext.glwe_size().to_glwe_dimension(),
+        polynomial_size: ciphertext.polynomial_size(),
+        decomposition_level_count: ciphertext.decomposition_level_count(),
+        decomposition_base_log: ciphertext.decomposition_base_log(),
+    };
+    write_zero_copy_layout(writer, &layout, ciphertext.data())
+}
+
+/// # Description:
+/// Implementation of [`EntityZeroCopySerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It writes an LWE bootstrap key in the Fourier domain, in the
+/// zero-copy layout described in the documentation of [`FftSerializationEngine`].
+impl<W: Write> EntityZeroCopySerializationEngine<FftFourierLweBootstrapKey32, W>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use aligned_vec::{AVec, ConstAlign};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_zero_copy_into(&fourier_bsk, &mut serialized)?;
+    ///
+    /// // The bytes would usually be written to a file and memory-mapped, which provides an aligned
+    /// // region.
+    /// let region = AVec::<u8, ConstAlign<FFT_ZERO_COPY_ALIGNMENT>>::from_slice(
+    ///     FFT_ZERO_COPY_ALIGNMENT,
+    ///     &serialized,
+    /// );
+    /// let view: FftFourierLweBootstrapKeyView32 = serialization_engine.load_zero_copy(&*region)?;
+    /// assert_eq!(view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(view.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_zero_copy_into(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+        writer: &mut W,
+    ) -> Result<(), EntityZeroCopySerializationError<Self::EngineError>> {
+        write_bootstrap_key(
+            entity.0.as_view(),
+            SerializedEntityPrecision::Unsigned32,
+            writer,
+        )
+        .map_err(EntityZeroCopySerializationError::Engine)
+    }
+
+    unsafe fn serialize_zero_copy_into_unchecked(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey32,
+        writer: &mut W,
+    ) {
+        self.serialize_zero_copy_into(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityZeroCopySerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It writes an LWE bootstrap key in the Fourier domain, in the
+/// zero-copy layout described in the documentation of [`FftSerializationEngine`].
+impl<W: Write> EntityZeroCopySerializationEngine<FftFourierLweBootstrapKey64, W>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use aligned_vec::{AVec, ConstAlign};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_zero_copy_into(&fourier_bsk, &mut serialized)?;
+    ///
+    /// // The bytes would usually be written to a file and memory-mapped, which provides an aligned
+    /// // region.
+    /// let region = AVec::<u8, ConstAlign<FFT_ZERO_COPY_ALIGNMENT>>::from_slice(
+    ///     FFT_ZERO_COPY_ALIGNMENT,
+    ///     &serialized,
+    /// );
+    /// let view: FftFourierLweBootstrapKeyView64 = serialization_engine.load_zero_copy(&*region)?;
+    /// assert_eq!(view.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(view.glwe_dimension(), glwe_dim);
+    /// assert_eq!(view.polynomial_size(), poly_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_zero_copy_into(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+        writer: &mut W,
+    ) -> Result<(), EntityZeroCopySerializationError<Self::EngineError>> {
+        write_bootstrap_key(
+            entity.0.as_view(),
+            SerializedEntityPrecision::Unsigned64,
+            writer,
+        )
+        .map_err(EntityZeroCopySerializationError::Engine)
+    }
+
+    unsafe fn serialize_zero_copy_into_unchecked(
+        &mut self,
+        entity: &FftFourierLweBootstrapKey64,
+        writer: &mut W,
+    ) {
+        self.serialize_zero_copy_into(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityZeroCopySerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It writes a GGSW ciphertext in the Fourier domain, in the
+/// zero-copy layout described in the documentation of [`FftSerializationEngine`].
+impl<W: Write> EntityZeroCopySerializationEngine<FftFourierGgswCiphertext32, W>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use aligned_vec::{AVec, ConstAlign};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_zero_copy_into(&fourier_ciphertext, &mut serialized)?;
+    ///
+    /// let region = AVec::<u8, ConstAlign<FFT_ZERO_COPY_ALIGNMENT>>::from_slice(
+    ///     FFT_ZERO_COPY_ALIGNMENT,
+    ///     &serialized,
+    /// );
+    /// let view: FftFourierGgswCiphertextView32 = serialization_engine.load_zero_copy(&*region)?;
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_zero_copy_into(
+        &mut self,
+        entity: &FftFourierGgswCiphertext32,
+        writer: &mut W,
+    ) -> Result<(), EntityZeroCopySerializationError<Self::EngineError>> {
+        write_ggsw_ciphertext(
+            entity.0.as_view(),
+            SerializedEntityPrecision::Unsigned32,
+            writer,
+        )
+        .map_err(EntityZeroCopySerializationError::Engine)
+    }
+
+    unsafe fn serialize_zero_copy_into_unchecked(
+        &mut self,
+        entity: &FftFourierGgswCiphertext32,
+        writer: &mut W,
+    ) {
+        self.serialize_zero_copy_into(entity, writer).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityZeroCopySerializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It writes a GGSW ciphertext in the Fourier domain, in the
+/// zero-copy layout described in the documentation of [`FftSerializationEngine`].
+impl<W: Write> EntityZeroCopySerializationEngine<FftFourierGgswCiphertext64, W>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use aligned_vec::{AVec, ConstAlign};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, Variance, *,
+    /// };
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized = Vec::new();
+    /// serialization_engine.serialize_zero_copy_into(&fourier_ciphertext, &mut serialized)?;
+    ///
+    /// let region = AVec::<u8, ConstAlign<FFT_ZERO_COPY_ALIGNMENT>>::from_slice(
+    ///     FFT_ZERO_COPY_ALIGNMENT,
+    ///     &serialized,
+    /// );
+    /// let view: FftFourierGgswCiphertextView64 = serialization_engine.load_zero_copy(&*region)?;
+    /// assert_eq!(view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(view.decomposition_level_count(), level);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize_zero_copy_into(
+        &mut self,
+        entity: &FftFourierGgswCiphertext64,
+        writer: &mut W,
+    ) -> Result<(), EntityZeroCopySerializationError<Self::EngineError>> {
+        write_ggsw_ciphertext(
+            entity.0.as_view(),
+            SerializedEntityPrecision::Unsigned64,
+            writer,
+        )
+        .map_err(EntityZeroCopySerializationError::Engine)
+    }
+
+    unsafe fn serialize_zero_copy_into_unchecked(
+        &mut self,
+        entity: &FftFourierGgswCiphertext64,
+        writer: &mut W,
+    ) {
+        self.serialize_zero_copy_into(entity, writer).unwrap()
+    }
+}
@@ -60,6 +60,62 @@ impl GgswCiphertextEntity for FftFourierGgswCiphertext64 {
     }
 }
 
+/// A structure representing a view of a GGSW ciphertext with 32 bits of precision in the Fourier
+/// domain.
+///
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq)]
+pub struct FftFourierGgswCiphertextView32<'a>(pub(crate) FourierGgswCiphertext<&'a [c64]>);
+impl AbstractEntity for FftFourierGgswCiphertextView32<'_> {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for FftFourierGgswCiphertextView32<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing a view of a GGSW ciphertext with 64 bits of precision in the Fourier
+/// domain.
+///
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq)]
+pub struct FftFourierGgswCiphertextView64<'a>(pub(crate) FourierGgswCiphertext<&'a [c64]>);
+impl AbstractEntity for FftFourierGgswCiphertextView64<'_> {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for FftFourierGgswCiphertextView64<'_> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
 #[cfg(feature = "backend_fft_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum FftFourierGgswCiphertext32Version {
@@ -0,0 +1,65 @@
+use super::super::super::private::crypto::relinearization::FourierGlweRelinearizationKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweRelinearizationKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweRelinearizationKeyEntity};
+use aligned_vec::ABox;
+use concrete_fft::c64;
+
+/// A structure representing a GLWE relinearization key with 32 bits of precision in the Fourier
+/// domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGlweRelinearizationKey32(
+    pub(crate) FourierGlweRelinearizationKey<ABox<[c64]>>,
+);
+
+/// A structure representing a GLWE relinearization key with 64 bits of precision in the Fourier
+/// domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierGlweRelinearizationKey64(
+    pub(crate) FourierGlweRelinearizationKey<ABox<[c64]>>,
+);
+
+impl AbstractEntity for FftFourierGlweRelinearizationKey32 {
+    type Kind = GlweRelinearizationKeyKind;
+}
+impl AbstractEntity for FftFourierGlweRelinearizationKey64 {
+    type Kind = GlweRelinearizationKeyKind;
+}
+
+impl GlweRelinearizationKeyEntity for FftFourierGlweRelinearizationKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
+impl GlweRelinearizationKeyEntity for FftFourierGlweRelinearizationKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
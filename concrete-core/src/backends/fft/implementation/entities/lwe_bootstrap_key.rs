@@ -64,6 +64,70 @@ impl LweBootstrapKeyEntity for FftFourierLweBootstrapKey64 {
     }
 }
 
+/// A structure representing a view of an LWE bootstrap key with 32 bits of precision, in the
+/// Fourier domain.
+///
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq)]
+pub struct FftFourierLweBootstrapKeyView32<'a>(pub(crate) FourierLweBootstrapKey<&'a [c64]>);
+impl AbstractEntity for FftFourierLweBootstrapKeyView32<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FftFourierLweBootstrapKeyView32<'_> {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
+/// A structure representing a view of an LWE bootstrap key with 64 bits of precision, in the
+/// Fourier domain.
+///
+/// This view is not Clone as Clone for a slice is not defined. It is not Deserialize either,
+/// as Deserialize of a slice is not defined. Immutable variant.
+#[derive(Debug, PartialEq)]
+pub struct FftFourierLweBootstrapKeyView64<'a>(pub(crate) FourierLweBootstrapKey<&'a [c64]>);
+impl AbstractEntity for FftFourierLweBootstrapKeyView64<'_> {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FftFourierLweBootstrapKeyView64<'_> {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
 #[cfg(feature = "backend_fft_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum FftFourierLweBootstrapKey32Version {
@@ -78,3 +142,61 @@ pub(crate) enum FftFourierLweBootstrapKey64Version {
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing an LWE bootstrap key with 32 bits of precision, for a ternary input
+/// LWE secret key, in the Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweTernaryBootstrapKey32(pub(crate) FourierLweBootstrapKey<ABox<[c64]>>);
+impl AbstractEntity for FftFourierLweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FftFourierLweTernaryBootstrapKey32 {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        crate::prelude::LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, for a ternary input
+/// LWE secret key, in the Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweTernaryBootstrapKey64(pub(crate) FourierLweBootstrapKey<ABox<[c64]>>);
+impl AbstractEntity for FftFourierLweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FftFourierLweTernaryBootstrapKey64 {
+    fn glwe_dimension(&self) -> crate::prelude::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> crate::prelude::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> crate::prelude::LweDimension {
+        crate::prelude::LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> crate::prelude::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> crate::prelude::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
@@ -0,0 +1,81 @@
+use super::super::super::private::crypto::multi_bit_bootstrap::FourierLweMultiBitBootstrapKey;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    LweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::LweMultiBitBootstrapKeyKind;
+use crate::specification::entities::{AbstractEntity, LweMultiBitBootstrapKeyEntity};
+use aligned_vec::ABox;
+use concrete_fft::c64;
+
+/// A structure representing a multi-bit LWE bootstrap key with 32 bits of precision, in the
+/// Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweMultiBitBootstrapKey32(
+    pub(crate) FourierLweMultiBitBootstrapKey<ABox<[c64]>>,
+);
+
+/// A structure representing a multi-bit LWE bootstrap key with 64 bits of precision, in the
+/// Fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweMultiBitBootstrapKey64(
+    pub(crate) FourierLweMultiBitBootstrapKey<ABox<[c64]>>,
+);
+
+impl AbstractEntity for FftFourierLweMultiBitBootstrapKey32 {
+    type Kind = LweMultiBitBootstrapKeyKind;
+}
+impl AbstractEntity for FftFourierLweMultiBitBootstrapKey64 {
+    type Kind = LweMultiBitBootstrapKeyKind;
+}
+
+impl LweMultiBitBootstrapKeyEntity for FftFourierLweMultiBitBootstrapKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.0.grouping_factor()
+    }
+}
+impl LweMultiBitBootstrapKeyEntity for FftFourierLweMultiBitBootstrapKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.0.grouping_factor()
+    }
+}
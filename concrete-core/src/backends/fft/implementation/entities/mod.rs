@@ -2,7 +2,13 @@
 //! Concrete-FFT backend.
 
 mod ggsw_ciphertext;
+mod ggsw_ciphertext_vector;
+mod glwe_relinearization_key;
 mod lwe_bootstrap_key;
+mod lwe_multi_bit_bootstrap_key;
 
 pub use ggsw_ciphertext::*;
+pub use ggsw_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
 pub use lwe_bootstrap_key::*;
+pub use lwe_multi_bit_bootstrap_key::*;
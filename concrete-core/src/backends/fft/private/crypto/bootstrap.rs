@@ -163,7 +163,8 @@ impl<'a> FourierLweBootstrapKeyMutView<'a> {
     }
 }
 
-/// Returns the required memory for [`FourierLweBootstrapKeyView::blind_rotate`].
+/// Returns the required memory for [`FourierLweBootstrapKeyView::blind_rotate`] and
+/// [`FourierLweBootstrapKeyView::ternary_blind_rotate`].
 pub fn blind_rotate_scratch<Scalar>(
     glwe_size: GlweSize,
     polynomial_size: PolynomialSize,
@@ -173,7 +174,9 @@ pub fn blind_rotate_scratch<Scalar>(
         .try_and(cmux_scratch::<Scalar>(glwe_size, polynomial_size, fft)?)
 }
 
-/// Returns the required memory for [`FourierLweBootstrapKeyView::bootstrap`].
+/// Returns the required memory for [`FourierLweBootstrapKeyView::bootstrap`],
+/// [`FourierLweBootstrapKeyView::many_lut_bootstrap`] and
+/// [`FourierLweBootstrapKeyView::ternary_bootstrap`].
 pub fn bootstrap_scratch<Scalar>(
     glwe_size: GlweSize,
     polynomial_size: PolynomialSize,
@@ -186,9 +189,23 @@ pub fn bootstrap_scratch<Scalar>(
 
 impl<'a> FourierLweBootstrapKeyView<'a> {
     pub fn blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lut: GlweCiphertext<&'_ mut [Scalar]>,
+        lwe: &[Scalar],
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        self.many_lut_blind_rotate(lut, lwe, LutCountLog(0), fft, stack);
+    }
+
+    /// Blind rotates the accumulator, rounding the modulus switched input to a multiple of
+    /// `2^lut_count_log`, so that the `2^lut_count_log` first coefficients of the rotated
+    /// accumulator each hold the evaluation of a distinct lookup table.
+    pub fn many_lut_blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
         mut lut: GlweCiphertext<&'_ mut [Scalar]>,
         lwe: &[Scalar],
+        lut_count_log: LutCountLog,
         fft: FftView<'_>,
         mut stack: DynStack<'_>,
     ) {
@@ -199,7 +216,7 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             *lwe_body,
             lut_poly_size,
             ModulusSwitchOffset(0),
-            LutCountLog(0),
+            lut_count_log,
         );
         lut.as_mut_view()
             .into_polynomial_list()
@@ -233,7 +250,7 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
                             *lwe_mask_element,
                             lut_poly_size,
                             ModulusSwitchOffset(0),
-                            LutCountLog(0),
+                            lut_count_log,
                         ),
                     ));
                 }
@@ -271,6 +288,146 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             MonomialDegree(0),
         );
     }
+
+    /// Bootstraps `lwe_in` once, and extracts one output ciphertext per lookup table packed in the
+    /// accumulator. The number of lookup tables is the number of ciphertexts in `lwe_list_out`,
+    /// and must be a power of two.
+    pub fn many_lut_bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_list_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertext<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let lwe_size = self.output_lwe_dimension().to_lwe_size().0;
+        debug_assert_eq!(lwe_list_out.len() % lwe_size, 0);
+        let lut_count = lwe_list_out.len() / lwe_size;
+        debug_assert!(lut_count.is_power_of_two());
+        let lut_count_log = LutCountLog(lut_count.trailing_zeros() as usize);
+
+        let (mut local_accumulator_data, stack) = stack.collect_aligned(
+            CACHELINE_ALIGN,
+            accumulator.as_view().into_container().iter().copied(),
+        );
+        let mut local_accumulator = GlweCiphertext::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+        );
+        self.many_lut_blind_rotate(
+            local_accumulator.as_mut_view(),
+            lwe_in,
+            lut_count_log,
+            fft,
+            stack,
+        );
+        for (lut_index, lwe_out) in lwe_list_out.chunks_exact_mut(lwe_size).enumerate() {
+            local_accumulator.as_view().fill_lwe_with_sample_extraction(
+                &mut LweCiphertext::from_container(lwe_out),
+                MonomialDegree(lut_index),
+            );
+        }
+    }
+
+    /// Blind rotates the accumulator with a bootstrapping key encrypting a ternary secret key.
+    ///
+    /// Every coefficient of the key is encrypted in two consecutive GGSW ciphertexts, encrypting
+    /// the indicators of the coefficient being equal to $1$ and to $-1$. For every mask element
+    /// $a\_i$, a first cmux rotates the accumulator by $X^{a\_i}$ if $s\_i = 1$, and a second one
+    /// rotates it by $X^{-a\_i}$ if $s\_i = -1$.
+    pub fn ternary_blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        mut lut: GlweCiphertext<&'_ mut [Scalar]>,
+        lwe: &[Scalar],
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+        debug_assert_eq!(2 * lwe_mask.len(), self.key_size.0);
+
+        let lut_poly_size = lut.polynomial_size();
+        let modulus_switch = |input: Scalar| {
+            pbs_modulus_switch(input, lut_poly_size, ModulusSwitchOffset(0), LutCountLog(0))
+        };
+        lut.as_mut_view()
+            .into_polynomial_list()
+            .into_polynomial_iter()
+            .for_each(|mut poly| {
+                poly.update_with_wrapping_unit_monomial_div(MonomialDegree(modulus_switch(
+                    *lwe_body,
+                )));
+            });
+
+        // We initialize the ct_0 used for the successive cmuxes
+        let mut ct0 = lut;
+        let mut ggsw_iter = self.into_ggsw_iter();
+
+        for lwe_mask_element in lwe_mask.iter() {
+            let positive_ggsw = ggsw_iter.next().unwrap();
+            let negative_ggsw = ggsw_iter.next().unwrap();
+            if *lwe_mask_element == Scalar::ZERO {
+                continue;
+            }
+            let monomial_degree = MonomialDegree(modulus_switch(*lwe_mask_element));
+
+            for (bootstrap_key_ggsw, is_positive) in [(positive_ggsw, true), (negative_ggsw, false)]
+            {
+                let stack = stack.rb_mut();
+                // We copy ct_0 to ct_1
+                let (mut ct1, stack) = stack.collect_aligned(
+                    CACHELINE_ALIGN,
+                    ct0.as_view().into_container().iter().copied(),
+                );
+                let mut ct1 = GlweCiphertext::from_container(&mut *ct1, ct0.polynomial_size());
+
+                // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat} or ct_1 * X^{-a_hat}
+                for mut poly in ct1
+                    .as_mut_view()
+                    .into_polynomial_list()
+                    .into_polynomial_iter()
+                {
+                    if is_positive {
+                        poly.update_with_wrapping_monic_monomial_mul(monomial_degree);
+                    } else {
+                        poly.update_with_wrapping_unit_monomial_div(monomial_degree);
+                    }
+                }
+
+                cmux(
+                    ct0.as_mut_view(),
+                    ct1.as_mut_view(),
+                    bootstrap_key_ggsw,
+                    fft,
+                    stack,
+                );
+            }
+        }
+    }
+
+    /// Bootstraps `lwe_in` with a bootstrapping key encrypting a ternary secret key, see
+    /// [`ternary_blind_rotate`](`Self::ternary_blind_rotate`).
+    pub fn ternary_bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertext<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let (mut local_accumulator_data, stack) = stack.collect_aligned(
+            CACHELINE_ALIGN,
+            accumulator.as_view().into_container().iter().copied(),
+        );
+        let mut local_accumulator = GlweCiphertext::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+        );
+        self.ternary_blind_rotate(local_accumulator.as_mut_view(), lwe_in, fft, stack);
+        local_accumulator.as_view().fill_lwe_with_sample_extraction(
+            &mut LweCiphertext::from_container(&mut *lwe_out),
+            MonomialDegree(0),
+        );
+    }
 }
 
 /// This function switches modulus for a single coefficient of a ciphertext,
@@ -470,7 +470,7 @@ pub fn external_product<Scalar: UnsignedTorus>(
 }
 
 #[cfg_attr(__profiling, inline(never))]
-fn collect_next_term<'a, Scalar: UnsignedTorus>(
+pub(crate) fn collect_next_term<'a, Scalar: UnsignedTorus>(
     decomposition: &mut TensorSignedDecompositionLendingIter<'_, Scalar>,
     substack1: &'a mut DynStack,
     align: usize,
@@ -645,7 +645,7 @@ unsafe fn update_with_fmadd_scalar(
 ///
 ///  - if `is_output_uninit` is false, `output_fourier` must not hold any uninitialized values.
 #[cfg_attr(__profiling, inline(never))]
-unsafe fn update_with_fmadd(
+pub(crate) unsafe fn update_with_fmadd(
     output_fft_buffer: &mut [MaybeUninit<c64>],
     ggsw_row: FourierGgswLevelRowView,
     fourier: &[c64],
@@ -1,3 +1,5 @@
 pub mod bootstrap;
 pub mod ggsw;
+pub mod multi_bit_bootstrap;
+pub mod relinearization;
 pub mod wop_pbs;
@@ -0,0 +1,370 @@
+use super::super::math::fft::FftView;
+use super::bootstrap::pbs_modulus_switch;
+use super::ggsw::{external_product, external_product_scratch, FourierGgswCiphertext};
+use crate::backends::fft::private::math::fft::FourierPolynomialList;
+use crate::backends::fft::private::math::polynomial::FourierPolynomialUninitMutView;
+use crate::commons::crypto::bootstrap::multi_bit_standard::ggsw_per_multi_bit_group;
+use crate::commons::crypto::bootstrap::StandardMultiBitBootstrapKey;
+use crate::commons::crypto::glwe::GlweCiphertext;
+use crate::commons::crypto::lwe::LweCiphertext;
+use crate::commons::math::polynomial::Polynomial;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::math::tensor::ContainerOwned;
+use crate::commons::math::tensor::{Container, Split};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::CastInto;
+use crate::commons::utils::izip;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweBskGroupingFactor,
+    LweDimension, ModulusSwitchOffset, MonomialDegree, PolynomialSize,
+};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "C: ContainerOwned"))
+)]
+pub struct FourierLweMultiBitBootstrapKey<C: Container<Element = c64>> {
+    fourier: FourierPolynomialList<C>,
+    key_size: LweDimension,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+}
+
+pub type FourierLweMultiBitBootstrapKeyView<'a> = FourierLweMultiBitBootstrapKey<&'a [c64]>;
+pub type FourierLweMultiBitBootstrapKeyMutView<'a> = FourierLweMultiBitBootstrapKey<&'a mut [c64]>;
+
+impl<C: Container<Element = c64>> FourierLweMultiBitBootstrapKey<C> {
+    pub fn new(
+        data: C,
+        key_size: LweDimension,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+    ) -> Self {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert_eq!(key_size.0 % grouping_factor.0, 0);
+        assert_eq!(
+            data.container_len(),
+            key_size.0 / grouping_factor.0
+                * ggsw_per_multi_bit_group(grouping_factor)
+                * polynomial_size.0
+                / 2
+                * decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+        );
+        Self {
+            fourier: FourierPolynomialList {
+                data,
+                polynomial_size,
+            },
+            key_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            grouping_factor,
+        }
+    }
+
+    /// Returns an iterator over the GGSW ciphertexts composing the key.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = FourierGgswCiphertext<C>>
+    where
+        C: Split,
+    {
+        let ggsw_count = self.key_size.0 / self.grouping_factor.0
+            * ggsw_per_multi_bit_group(self.grouping_factor);
+        self.fourier.data.split_into(ggsw_count).map(move |slice| {
+            FourierGgswCiphertext::new(
+                slice,
+                self.fourier.polynomial_size,
+                self.glwe_size,
+                self.decomposition_base_log,
+                self.decomposition_level_count,
+            )
+        })
+    }
+
+    pub fn key_size(&self) -> LweDimension {
+        self.key_size
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.grouping_factor
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension((self.glwe_size.0 - 1) * self.polynomial_size().0)
+    }
+
+    pub fn data(self) -> C {
+        self.fourier.data
+    }
+
+    pub fn as_view(&self) -> FourierLweMultiBitBootstrapKeyView<'_> {
+        FourierLweMultiBitBootstrapKeyView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_ref(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            key_size: self.key_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierLweMultiBitBootstrapKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierLweMultiBitBootstrapKeyMutView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_mut(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            key_size: self.key_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+}
+
+impl<'a> FourierLweMultiBitBootstrapKeyMutView<'a> {
+    /// Fills a multi-bit bootstrapping key with the Fourier transform of a multi-bit
+    /// bootstrapping key in the standard domain.
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
+        mut self,
+        coef_bsk: StandardMultiBitBootstrapKey<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        for (fourier_ggsw, standard_ggsw) in
+            izip!(self.as_mut_view().into_ggsw_iter(), coef_bsk.ggsw_iter())
+        {
+            fourier_ggsw.fill_with_forward_fourier(standard_ggsw, fft, stack.rb_mut());
+        }
+    }
+}
+
+/// Returns the required memory for [`FourierLweMultiBitBootstrapKeyView::blind_rotate`].
+pub fn multi_bit_blind_rotate_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    // The GGSW ciphertext combining the ones of a group.
+    let combined_ggsw = StackReq::try_new_aligned::<c64>(
+        polynomial_size.0 / 2 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0,
+        align,
+    )?;
+    // The polynomial X^k - 1, in the standard and the Fourier domains.
+    let monomial = StackReq::try_new_aligned::<Scalar>(polynomial_size.0, align)?;
+    let fourier_monomial = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
+    // The copy of the accumulator used as input of the external product.
+    let accumulator_copy =
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, align)?;
+
+    combined_ggsw
+        .try_and(monomial)?
+        .try_and(fourier_monomial)?
+        .try_and(StackReq::try_any_of([
+            fft.forward_scratch()?,
+            accumulator_copy.try_and(external_product_scratch::<Scalar>(
+                glwe_size,
+                polynomial_size,
+                fft,
+            )?)?,
+        ])?)
+}
+
+/// Returns the required memory for [`FourierLweMultiBitBootstrapKeyView::bootstrap`].
+pub fn multi_bit_bootstrap_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    multi_bit_blind_rotate_scratch::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        decomposition_level_count,
+        fft,
+    )?
+    .try_and(StackReq::try_new_aligned::<Scalar>(
+        glwe_size.0 * polynomial_size.0,
+        CACHELINE_ALIGN,
+    )?)
+}
+
+impl<'a> FourierLweMultiBitBootstrapKeyView<'a> {
+    /// Blind rotates the accumulator, processing the mask of `lwe` by groups of `grouping_factor`
+    /// coefficients.
+    ///
+    /// For each group, the GGSW ciphertexts of the group are combined in the Fourier domain into
+    /// a single GGSW ciphertext encrypting `X^k - 1`, where `k` is the rotation selected by the
+    /// secret key coefficients of the group. A single external product with the accumulator then
+    /// rotates it by `X^k`.
+    pub fn blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        mut lut: GlweCiphertext<&'_ mut [Scalar]>,
+        lwe: &[Scalar],
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+
+        let lut_poly_size = lut.polynomial_size();
+        let modulus_switch = |input: Scalar| {
+            pbs_modulus_switch(input, lut_poly_size, ModulusSwitchOffset(0), LutCountLog(0))
+        };
+        lut.as_mut_view()
+            .into_polynomial_list()
+            .into_polynomial_iter()
+            .for_each(|mut poly| {
+                poly.update_with_wrapping_unit_monomial_div(MonomialDegree(modulus_switch(
+                    *lwe_body,
+                )));
+            });
+
+        let align = CACHELINE_ALIGN;
+        let poly_size = lut_poly_size.0;
+        let grouping_factor = self.grouping_factor.0;
+        let ggsw_per_group = ggsw_per_multi_bit_group(self.grouping_factor);
+        let ggsw_len =
+            poly_size / 2 * self.glwe_size.0 * self.glwe_size.0 * self.decomposition_level_count.0;
+
+        let glwe_size = self.glwe_size;
+        let decomposition_base_log = self.decomposition_base_log;
+        let decomposition_level_count = self.decomposition_level_count;
+        let mut ggsw_iter = self.into_ggsw_iter();
+
+        for mask_group in lwe_mask.chunks_exact(grouping_factor) {
+            let group_ggsws = ggsw_iter.by_ref().take(ggsw_per_group);
+            if mask_group.iter().all(|a| *a == Scalar::ZERO) {
+                group_ggsws.for_each(drop);
+                continue;
+            }
+
+            let stack = stack.rb_mut();
+            let (mut combined, stack) =
+                stack.make_aligned_with::<c64, _>(ggsw_len, align, |_| c64::default());
+            let (mut monomial, stack) = stack.make_aligned_with(poly_size, align, |_| Scalar::ZERO);
+            let (mut fourier_monomial, mut stack) =
+                stack.make_aligned_uninit::<c64>(poly_size / 2, align);
+
+            for (pattern, ggsw) in izip!(1..=ggsw_per_group, group_ggsws) {
+                // The first coefficient of the group is matched against the most significant bit
+                // of the pattern.
+                let degree = mask_group
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| (pattern >> (grouping_factor - 1 - i)) & 1 == 1)
+                    .map(|(_, a)| modulus_switch(*a))
+                    .sum::<usize>()
+                    % (2 * poly_size);
+                if degree == 0 {
+                    continue;
+                }
+
+                // We compute X^degree - 1 in the Fourier domain.
+                monomial.fill(Scalar::ZERO);
+                monomial[0] = monomial[0].wrapping_sub(Scalar::ONE);
+                if degree < poly_size {
+                    monomial[degree] = monomial[degree].wrapping_add(Scalar::ONE);
+                } else {
+                    monomial[degree - poly_size] =
+                        monomial[degree - poly_size].wrapping_sub(Scalar::ONE);
+                }
+                let fourier_monomial = fft
+                    .forward_as_integer(
+                        FourierPolynomialUninitMutView {
+                            data: &mut fourier_monomial,
+                        },
+                        Polynomial::from_container(&*monomial),
+                        stack.rb_mut(),
+                    )
+                    .data;
+
+                for (combined_poly, ggsw_poly) in izip!(
+                    combined.chunks_exact_mut(poly_size / 2),
+                    ggsw.data().chunks_exact(poly_size / 2)
+                ) {
+                    for (c, g, m) in izip!(combined_poly, ggsw_poly, fourier_monomial.iter()) {
+                        *c += *g * *m;
+                    }
+                }
+            }
+
+            // We compute acc <- acc + combined \boxdot acc
+            let (acc_copy, stack) =
+                stack.collect_aligned(align, lut.as_view().into_container().iter().copied());
+            let acc_copy = GlweCiphertext::from_container(&*acc_copy, lut_poly_size);
+            external_product(
+                lut.as_mut_view(),
+                FourierGgswCiphertext::new(
+                    &*combined,
+                    lut_poly_size,
+                    glwe_size,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                ),
+                acc_copy,
+                fft,
+                stack,
+            );
+        }
+    }
+
+    pub fn bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertext<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let (mut local_accumulator_data, stack) = stack.collect_aligned(
+            CACHELINE_ALIGN,
+            accumulator.as_view().into_container().iter().copied(),
+        );
+        let mut local_accumulator = GlweCiphertext::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+        );
+        self.blind_rotate(local_accumulator.as_mut_view(), lwe_in, fft, stack);
+        local_accumulator.as_view().fill_lwe_with_sample_extraction(
+            &mut LweCiphertext::from_container(&mut *lwe_out),
+            MonomialDegree(0),
+        );
+    }
+}
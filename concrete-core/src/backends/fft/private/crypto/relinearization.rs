@@ -0,0 +1,493 @@
+use core::mem::MaybeUninit;
+
+use super::super::math::decomposition::TensorSignedDecompositionLendingIter;
+use super::super::math::fft::{FftView, FourierPolynomialList};
+use super::super::math::polynomial::{
+    FourierPolynomialUninitMutView, FourierPolynomialView, PolynomialUninitMutView,
+};
+use super::super::{as_mut_uninit, assume_init_mut};
+use super::ggsw::{collect_next_term, update_with_fmadd, FourierGgswLevelMatrixView};
+use crate::commons::crypto::glwe::{
+    rescale_tensor_product_coefficient, tensor_product_quadratic_count, tensor_product_terms,
+    GlweCiphertext, GlweTensorProduct, StandardGlweRelinearizationKey,
+};
+use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::commons::math::polynomial::Polynomial;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::math::tensor::ContainerOwned;
+use crate::commons::math::tensor::{
+    AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Container, Split,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::CastFrom;
+use crate::commons::utils::izip;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweSize, PolynomialSize,
+};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A GLWE relinearization key in the Fourier domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "C: ContainerOwned"))
+)]
+pub struct FourierGlweRelinearizationKey<C: Container<Element = c64>> {
+    fourier: FourierPolynomialList<C>,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type FourierGlweRelinearizationKeyView<'a> = FourierGlweRelinearizationKey<&'a [c64]>;
+pub type FourierGlweRelinearizationKeyMutView<'a> = FourierGlweRelinearizationKey<&'a mut [c64]>;
+
+impl<C: Container<Element = c64>> FourierGlweRelinearizationKey<C> {
+    pub fn new(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert_eq!(
+            data.container_len(),
+            polynomial_size.0 / 2
+                * glwe_size.0
+                * tensor_product_quadratic_count(glwe_size.to_glwe_dimension()).0
+                * decomposition_level_count.0
+        );
+
+        Self {
+            fourier: FourierPolynomialList {
+                data,
+                polynomial_size,
+            },
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.fourier.data
+    }
+
+    pub fn as_view(&self) -> FourierGlweRelinearizationKeyView<'_>
+    where
+        C: AsRef<[c64]>,
+    {
+        FourierGlweRelinearizationKeyView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_ref(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGlweRelinearizationKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierGlweRelinearizationKeyMutView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_mut(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+impl<'a> FourierGlweRelinearizationKeyView<'a> {
+    /// Returns an iterator over the level matrices.
+    ///
+    /// Every row of a level matrix contains the encryption of the product of two polynomials of
+    /// the secret key, in the order of the quadratic part of the tensor product.
+    pub fn into_levels(self) -> impl DoubleEndedIterator<Item = FourierGgswLevelMatrixView<'a>> {
+        let row_count = tensor_product_quadratic_count(self.glwe_size.to_glwe_dimension()).0;
+        self.fourier
+            .data
+            .split_into(self.decomposition_level_count.0)
+            .enumerate()
+            .map(move |(i, slice)| {
+                FourierGgswLevelMatrixView::new(
+                    slice,
+                    self.fourier.polynomial_size,
+                    self.glwe_size,
+                    row_count,
+                    DecompositionLevel(i + 1),
+                )
+            })
+    }
+}
+
+/// Returns the required memory for
+/// [`FourierGlweRelinearizationKeyMutView::fill_with_forward_fourier`].
+pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+impl<'a> FourierGlweRelinearizationKeyMutView<'a> {
+    /// Fills a relinearization key with the Fourier transform of a relinearization key in the
+    /// standard domain.
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
+        self,
+        coef_rlk: StandardGlweRelinearizationKey<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(coef_rlk.polynomial_size(), self.polynomial_size());
+        let poly_size = coef_rlk.polynomial_size().0;
+
+        for (fourier_poly, coef_poly) in izip!(
+            self.data().into_chunks(poly_size / 2),
+            coef_rlk.into_container().into_chunks(poly_size)
+        ) {
+            // SAFETY: forward_as_torus doesn't write any uninitialized values into its output
+            fft.forward_as_torus(
+                FourierPolynomialUninitMutView {
+                    data: unsafe { as_mut_uninit(fourier_poly) },
+                },
+                Polynomial::from_container(coef_poly),
+                stack.rb_mut(),
+            );
+        }
+    }
+}
+
+/// Returns the required memory for [`relinearize`].
+pub fn relinearize_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let pair_count = tensor_product_quadratic_count(glwe_size.to_glwe_dimension()).0;
+    let standard_scratch =
+        StackReq::try_new_aligned::<Scalar>(pair_count * polynomial_size.0, align)?;
+    let fourier_scratch =
+        StackReq::try_new_aligned::<c64>(glwe_size.0 * polynomial_size.0 / 2, align)?;
+    let fourier_scratch_single = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
+
+    let substack3 = fft.forward_scratch()?;
+    let substack2 = substack3.try_and(fourier_scratch_single)?;
+    let substack1 = substack2.try_and(standard_scratch)?;
+    let substack0 = StackReq::try_any_of([
+        substack1.try_and(standard_scratch)?,
+        fft.backward_scratch()?,
+    ])?;
+    substack0.try_and(fourier_scratch)
+}
+
+/// Relinearizes `tensor_product` using `relinearization_key`, and writes the result to `out`.
+#[cfg_attr(__profiling, inline(never))]
+pub fn relinearize<Scalar: UnsignedTorus>(
+    mut out: GlweCiphertext<&'_ mut [Scalar]>,
+    relinearization_key: FourierGlweRelinearizationKeyView<'_>,
+    tensor_product: GlweTensorProduct<&'_ [Scalar]>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    // we check that the polynomial sizes match
+    debug_assert_eq!(relinearization_key.polynomial_size(), out.polynomial_size());
+    debug_assert_eq!(tensor_product.polynomial_size(), out.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(relinearization_key.glwe_size(), out.size());
+    debug_assert_eq!(tensor_product.glwe_dimension().to_glwe_size(), out.size());
+
+    let align = CACHELINE_ALIGN;
+    let poly_size = out.polynomial_size().0;
+
+    // The linear part of the tensor product is already encrypted under the original key.
+    out.as_mut_tensor()
+        .fill_with_copy(tensor_product.as_linear_part().as_tensor());
+
+    let decomposer = SignedDecomposer::<Scalar>::new(
+        relinearization_key.decomposition_base_log(),
+        relinearization_key.decomposition_level_count(),
+    );
+
+    let (mut output_fft_buffer, mut substack0) =
+        stack.make_aligned_uninit::<c64>(poly_size / 2 * out.size().0, align);
+    // output_fft_buffer is initially uninitialized, see `external_product`.
+    let output_fft_buffer = &mut *output_fft_buffer;
+    let mut is_output_uninit = true;
+
+    {
+        // We decompose the quadratic part of the tensor product, and multiply every level of the
+        // decomposition with the corresponding level matrix of the key, as in an external
+        // product.
+        let quadratic_part = tensor_product.as_quadratic_part();
+        let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
+            quadratic_part
+                .as_tensor()
+                .as_slice()
+                .iter()
+                .map(|s| decomposer.closest_representable(*s)),
+            DecompositionBaseLog(decomposer.base_log),
+            DecompositionLevelCount(decomposer.level_count),
+            substack0.rb_mut(),
+        );
+
+        relinearization_key
+            .into_levels()
+            .rev()
+            .for_each(|level_matrix| {
+                let (level, decomp_term, mut substack2) =
+                    collect_next_term(&mut decomposition, &mut substack1, align);
+                debug_assert_eq!(level_matrix.decomposition_level(), level);
+
+                izip!(
+                    level_matrix.into_rows(),
+                    decomp_term.chunks_exact(poly_size)
+                )
+                .for_each(|(row, poly)| {
+                    let (mut fourier, substack3) = substack2
+                        .rb_mut()
+                        .make_aligned_uninit::<c64>(poly_size / 2, align);
+                    let fourier = fft
+                        .forward_as_integer(
+                            FourierPolynomialUninitMutView { data: &mut fourier },
+                            Polynomial::from_container(poly),
+                            substack3,
+                        )
+                        .data;
+
+                    // SAFETY: see comment above definition of `output_fft_buffer`
+                    unsafe {
+                        update_with_fmadd(
+                            output_fft_buffer,
+                            row,
+                            fourier,
+                            is_output_uninit,
+                            poly_size,
+                        )
+                    };
+
+                    is_output_uninit = false;
+                });
+            });
+    }
+
+    if !is_output_uninit {
+        // SAFETY: output_fft_buffer is initialized, since `is_output_uninit` is false
+        let output_fft_buffer = &*unsafe { assume_init_mut(output_fft_buffer) };
+        izip!(
+            out.as_mut_view()
+                .into_polynomial_list()
+                .into_polynomial_iter(),
+            output_fft_buffer
+                .into_chunks(poly_size / 2)
+                .map(|slice| FourierPolynomialView { data: slice }),
+        )
+        .for_each(|(out, fourier)| {
+            fft.add_backward_as_torus(out, fourier, substack0.rb_mut());
+        });
+    }
+}
+
+// The coefficients are split in limbs of 16 bits before being multiplied in the Fourier domain,
+// so that every product of limbs can be computed without error with 64 bits floats.
+const LIMB_BITS: usize = 16;
+
+/// Returns the required memory for [`tensor_product`].
+pub fn tensor_product_scratch<Scalar: UnsignedTorus>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let limb_count = Scalar::BITS / LIMB_BITS;
+    let fourier_limbs =
+        StackReq::try_new_aligned::<c64>(glwe_size.0 * limb_count * polynomial_size.0 / 2, align)?;
+    let fourier_accumulator = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
+    let standard_limbs = StackReq::try_new_aligned::<u64>(limb_count * polynomial_size.0, align)?;
+    let wide_accumulator = StackReq::try_new_aligned::<u128>(polynomial_size.0, align)?;
+    StackReq::try_all_of([
+        fourier_limbs,
+        fourier_limbs,
+        fourier_accumulator,
+        standard_limbs,
+        wide_accumulator,
+        StackReq::try_any_of([fft.forward_scratch()?, fft.backward_scratch()?])?,
+    ])
+}
+
+/// Splits the polynomials of `glwe` in signed limbs, and writes their Fourier transforms in
+/// `fourier`, one polynomial after the other, and the limbs of a polynomial from the least
+/// significant to the most significant one.
+fn forward_limbs<Scalar>(
+    fourier: &mut [MaybeUninit<c64>],
+    glwe: GlweCiphertext<&'_ [Scalar]>,
+    limbs: &mut [u64],
+    as_torus: bool,
+    fft: FftView<'_>,
+    mut stack: DynStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    u128: CastFrom<Scalar::Signed>,
+{
+    let poly_size = glwe.polynomial_size().0;
+    let limb_count = Scalar::BITS / LIMB_BITS;
+    let mut fourier_polys = fourier.chunks_exact_mut(poly_size / 2);
+
+    for polynomial in glwe.as_tensor().as_slice().chunks_exact(poly_size) {
+        // We decompose every coefficient in balanced limbs, the last one carrying the sign.
+        for (i, coef) in polynomial.iter().enumerate() {
+            let mut value = u128::cast_from(coef.into_signed()) as i128;
+            for limb_index in 0..limb_count {
+                let limb = if limb_index == limb_count - 1 {
+                    value
+                } else {
+                    (value << (128 - LIMB_BITS)) >> (128 - LIMB_BITS)
+                };
+                limbs[limb_index * poly_size + i] = limb as i64 as u64;
+                value = (value - limb) >> LIMB_BITS;
+            }
+        }
+
+        for limb in limbs.chunks_exact(poly_size) {
+            let fourier = FourierPolynomialUninitMutView {
+                data: fourier_polys.next().unwrap(),
+            };
+            if as_torus {
+                fft.forward_as_torus(fourier, Polynomial::from_container(limb), stack.rb_mut());
+            } else {
+                fft.forward_as_integer(fourier, Polynomial::from_container(limb), stack.rb_mut());
+            }
+        }
+    }
+}
+
+/// Computes the tensor product of `lhs` and `rhs`, divided by $2^{\mathsf{delta\\_log}}$, and
+/// writes it to `out`.
+///
+/// The products are computed exactly, by splitting the coefficients in limbs small enough for
+/// the products of limbs to be exactly represented in the Fourier domain.
+#[cfg_attr(__profiling, inline(never))]
+pub fn tensor_product<Scalar>(
+    mut out: GlweTensorProduct<&'_ mut [Scalar]>,
+    lhs: GlweCiphertext<&'_ [Scalar]>,
+    rhs: GlweCiphertext<&'_ [Scalar]>,
+    delta_log: DeltaLog,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) where
+    Scalar: UnsignedTorus + CastFrom<u128>,
+    u128: CastFrom<Scalar::Signed>,
+{
+    // we check that the polynomial sizes match
+    debug_assert_eq!(out.polynomial_size(), lhs.polynomial_size());
+    debug_assert_eq!(out.polynomial_size(), rhs.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(out.glwe_dimension().to_glwe_size(), lhs.size());
+    debug_assert_eq!(out.glwe_dimension().to_glwe_size(), rhs.size());
+
+    let align = CACHELINE_ALIGN;
+    let poly_size = out.polynomial_size().0;
+    let limb_count = Scalar::BITS / LIMB_BITS;
+    let limbs_len = lhs.size().0 * limb_count * poly_size / 2;
+
+    let (mut lhs_fourier, stack) = stack.make_aligned_uninit::<c64>(limbs_len, align);
+    let (mut rhs_fourier, stack) = stack.make_aligned_uninit::<c64>(limbs_len, align);
+    let (mut accumulator, stack) = stack.make_aligned_uninit::<c64>(poly_size / 2, align);
+    let (mut limbs, stack) =
+        stack.make_aligned_with::<u64, _>(limb_count * poly_size, align, |_| 0);
+    let (mut wide_accumulator, mut stack) =
+        stack.make_aligned_with::<u128, _>(poly_size, align, |_| 0);
+
+    // The limbs of the left hand side are seen as integers, and the ones of the right hand side
+    // as torus elements, such that the backward transform of their product gives the exact
+    // integer product modulo 2^64.
+    forward_limbs(
+        &mut lhs_fourier,
+        lhs,
+        &mut limbs,
+        false,
+        fft,
+        stack.rb_mut(),
+    );
+    forward_limbs(&mut rhs_fourier, rhs, &mut limbs, true, fft, stack.rb_mut());
+    // SAFETY: forward_limbs initializes the whole buffers
+    let lhs_fourier = &*unsafe { assume_init_mut(&mut lhs_fourier) };
+    let rhs_fourier = &*unsafe { assume_init_mut(&mut rhs_fourier) };
+    let limb_range = |poly: usize, limb: usize| {
+        let start = (poly * limb_count + limb) * poly_size / 2;
+        start..start + poly_size / 2
+    };
+
+    let terms = tensor_product_terms(out.glwe_dimension());
+    for (mut out_poly, term) in izip!(
+        out.as_mut_polynomial_list().polynomial_iter_mut(),
+        terms.into_iter()
+    ) {
+        wide_accumulator.iter_mut().for_each(|a| *a = 0);
+
+        // We gather the products of the limbs by order of magnitude, and bring each of these
+        // partial products back to the standard domain, where they are exactly represented.
+        for shift in 0..2 * limb_count - 1 {
+            accumulator.iter_mut().for_each(|a| {
+                a.write(c64::default());
+            });
+            // SAFETY: the accumulator was initialized just above
+            let accumulator = &mut *unsafe { assume_init_mut(&mut accumulator) };
+
+            for &(lhs_index, rhs_index) in term.iter() {
+                for lhs_limb in shift.saturating_sub(limb_count - 1)..=shift.min(limb_count - 1) {
+                    let rhs_limb = shift - lhs_limb;
+                    for (acc, lhs, rhs) in izip!(
+                        accumulator.iter_mut(),
+                        lhs_fourier[limb_range(lhs_index, lhs_limb)].iter(),
+                        rhs_fourier[limb_range(rhs_index, rhs_limb)].iter()
+                    ) {
+                        *acc += lhs * rhs;
+                    }
+                }
+            }
+
+            let partial = &mut limbs[..poly_size];
+            fft.backward_as_torus(
+                // SAFETY: backward_as_torus doesn't write any uninitialized values into its output
+                PolynomialUninitMutView::from_container(unsafe { as_mut_uninit(partial) }),
+                FourierPolynomialView { data: accumulator },
+                stack.rb_mut(),
+            );
+            for (wide, partial) in izip!(wide_accumulator.iter_mut(), partial.iter()) {
+                *wide = wide.wrapping_add((*partial as i64 as i128 as u128) << (LIMB_BITS * shift));
+            }
+        }
+
+        for (out, wide) in izip!(
+            out_poly.as_mut_tensor().as_mut_slice().iter_mut(),
+            wide_accumulator.iter()
+        ) {
+            *out = rescale_tensor_product_coefficient(*wide, delta_log);
+        }
+    }
+}
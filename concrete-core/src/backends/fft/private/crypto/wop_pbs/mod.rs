@@ -15,6 +15,8 @@ use crate::commons::crypto::ggsw::StandardGgswCiphertext;
 use crate::commons::crypto::glwe::{GlweCiphertext, LwePrivateFunctionalPackingKeyswitchKeyList};
 use crate::commons::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
 use crate::commons::math::polynomial::PolynomialList;
+#[cfg(feature = "backend_fft_serialization")]
+use crate::commons::math::tensor::ContainerOwned;
 use crate::commons::math::tensor::{AsMutTensor, AsRefTensor, Container, Split};
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::numeric::CastInto;
@@ -412,6 +414,11 @@ pub struct GlweCiphertextList<C: Container> {
 }
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "C: ContainerOwned"))
+)]
 pub struct FourierGgswCiphertextList<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
     count: usize,
@@ -928,11 +935,10 @@ pub fn vertical_packing_scratch<Scalar>(
     ggsw_list_count: usize,
     fft: FftView<'_>,
 ) -> Result<StackReq, SizeOverflow> {
-    let bits = core::mem::size_of::<Scalar>() * 8;
-
     // Get the base 2 logarithm (rounded down) of the number of polynomials in the list i.e. if
     // there is one polynomial, the number will be 0
-    let log_lut_number: usize = bits - 1 - lut_polynomial_count.0.leading_zeros() as usize;
+    let log_lut_number: usize =
+        usize::BITS as usize - 1 - lut_polynomial_count.0.leading_zeros() as usize;
 
     let log_number_of_luts_for_cmux_tree = if log_lut_number > ggsw_list_count {
         // this means that we dont have enough GGSW to perform the CMux tree, we can only do the
@@ -979,7 +985,7 @@ pub fn vertical_packing<Scalar: UnsignedTorus + CastInto<usize>>(
     // Get the base 2 logarithm (rounded down) of the number of polynomials in the list i.e. if
     // there is one polynomial, the number will be 0
     let log_lut_number: usize =
-        Scalar::BITS - 1 - lut.polynomial_count().0.leading_zeros() as usize;
+        usize::BITS as usize - 1 - lut.polynomial_count().0.leading_zeros() as usize;
 
     let log_number_of_luts_for_cmux_tree = if log_lut_number > ggsw_list.count() {
         // this means that we dont have enough GGSW to perform the CMux tree, we can only do the
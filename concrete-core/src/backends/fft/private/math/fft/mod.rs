@@ -13,6 +13,7 @@ use crate::commons::utils::izip;
 use crate::prelude::PolynomialSize;
 use aligned_vec::{avec, ABox};
 use concrete_fft::c64;
+use concrete_fft::ordered::{Method as OrderedMethod, Plan as OrderedPlan};
 use concrete_fft::unordered::{Method, Plan};
 use dyn_stack::{DynStack, SizeOverflow, StackReq};
 use once_cell::sync::OnceCell;
@@ -180,6 +181,59 @@ impl Fft {
             plan: get_plan().unwrap(),
         }
     }
+
+    /// Real polynomial of size `size`, with a plan permuting the Fourier coefficients like the
+    /// plans built over an ordered FFT of size `base_size`.
+    ///
+    /// The order in which the Fourier coefficients are stored in memory only depends on the size
+    /// of the ordered FFT the plan is built over. If no plan was created for this polynomial size
+    /// yet, a plan with the given base size is created, and used by all the subsequent FFTs of
+    /// this size. Returns `None` if the existing plan has a different base size, or if
+    /// `base_size` is not a valid base size for this polynomial size.
+    pub fn with_base_size(size: PolynomialSize, base_size: usize) -> Option<Self> {
+        let n = size.0;
+        if !n.is_power_of_two()
+            || n < 2
+            || !base_size.is_power_of_two()
+            || base_size > n / 2
+            || (base_size != n / 2 && base_size < 32)
+            || base_size.trailing_zeros() >= 17
+        {
+            return None;
+        }
+
+        let global_plans = plans();
+        let mut plans = global_plans.write().unwrap();
+        let cell = plans
+            .entry(n)
+            .or_insert_with(|| Arc::new(OnceCell::new()))
+            .clone();
+        drop(plans);
+
+        let plan = cell
+            .get_or_init(|| {
+                let base_algo =
+                    OrderedPlan::new(base_size, OrderedMethod::Measure(Duration::from_millis(10)))
+                        .algo();
+                Arc::new((
+                    Twisties::new(n / 2),
+                    Plan::new(
+                        n / 2,
+                        Method::UserProvided {
+                            base_algo,
+                            base_n: base_size,
+                        },
+                    ),
+                ))
+            })
+            .clone();
+
+        if plan.1.algo().1 == base_size {
+            Some(Self { plan })
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg_attr(__profiling, inline(never))]
@@ -344,6 +398,12 @@ impl<'a> FftView<'a> {
         PolynomialSize(2 * self.plan.fft_size())
     }
 
+    /// Returns the size of the ordered FFT this FFT is built over, which determines the order of
+    /// the Fourier coefficients in memory.
+    pub fn base_size(self) -> usize {
+        self.plan.algo().1
+    }
+
     /// Serializes data in the Fourier domain.
     #[cfg(feature = "backend_fft_serialization")]
     #[cfg_attr(docsrs, doc(cfg(feature = "backend_fft_serialization")))]
@@ -597,8 +657,6 @@ impl<'de, C: ContainerOwned<Element = c64>> serde::Deserialize<'de> for FourierP
                 self,
                 mut seq: A,
             ) -> Result<Self::Value, A::Error> {
-                use crate::commons::math::tensor::Split;
-
                 let str = "sequence of two fields and Fourier polynomials";
                 let polynomial_size = match seq.next_element::<PolynomialSize>()? {
                     Some(polynomial_size) => polynomial_size,
@@ -625,25 +683,58 @@ impl<'de, C: ContainerOwned<Element = c64>> serde::Deserialize<'de> for FourierP
                     }
                 }
 
-                let mut data =
-                    C::collect((0..(polynomial_size.0 / 2 * chunk_count)).map(|_| c64::default()));
+                if chunk_count == 0 {
+                    return Ok(FourierPolynomialList {
+                        data: C::collect(std::iter::empty()),
+                        polynomial_size,
+                    });
+                }
+                if !polynomial_size.0.is_power_of_two() || polynomial_size.0 < 2 {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(polynomial_size.0 as u64),
+                        &"a power of two polynomial size",
+                    ));
+                }
 
-                if chunk_count != 0 {
-                    let fft = Fft::new(polynomial_size);
-                    for (i, buf) in data.as_mut().split_into(chunk_count).enumerate() {
+                // The polynomials are deserialized one at a time, and the list grows as they are
+                // read, so that a corrupted chunk count makes the deserialization fail at the end
+                // of the input, instead of allocating the announced size upfront.
+                let fft = Fft::new(polynomial_size);
+                let mut polynomial = vec![c64::default(); polynomial_size.0 / 2];
+                let mut read_count = 0;
+                let mut position = polynomial.len();
+                let mut error = None;
+                let coefficients = std::iter::from_fn(|| {
+                    if position == polynomial.len() {
+                        if read_count == chunk_count {
+                            return None;
+                        }
                         match seq.next_element_seed(FillFourier {
                             fft: fft.as_view(),
-                            buf,
-                        })? {
-                            Some(()) => (),
-                            None => {
-                                return Err(serde::de::Error::invalid_length(
-                                    i,
+                            buf: &mut polynomial,
+                        }) {
+                            Ok(Some(())) => (),
+                            Ok(None) => {
+                                error = Some(serde::de::Error::invalid_length(
+                                    read_count,
                                     &&*format!("sequence of {chunk_count} Fourier polynomials"),
-                                ))
+                                ));
+                                return None;
+                            }
+                            Err(seq_error) => {
+                                error = Some(seq_error);
+                                return None;
                             }
-                        };
+                        }
+                        read_count += 1;
+                        position = 0;
                     }
+                    position += 1;
+                    Some(polynomial[position - 1])
+                });
+                let data = C::collect(coefficients);
+                if let Some(error) = error {
+                    return Err(error);
                 }
 
                 Ok(FourierPolynomialList {
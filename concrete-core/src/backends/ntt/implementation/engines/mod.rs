@@ -0,0 +1,4 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the NTT backend.
+
+mod ntt_engine;
+pub use ntt_engine::*;
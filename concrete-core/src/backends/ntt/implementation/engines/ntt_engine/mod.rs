@@ -0,0 +1,125 @@
+use crate::backends::ntt::private::crypto::bootstrap::BootstrapBuffers;
+use crate::backends::ntt::private::math::params::{ntt_32, ntt_64};
+use crate::backends::ntt::private::math::transform::Ntt;
+use crate::backends::ntt::private::math::ALLOWED_POLY_SIZE;
+use crate::prelude::{GlweSize, PolynomialSize};
+use std::collections::HashMap;
+
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+
+/// Error that can occur in the execution of FHE operations by the [`NttEngine`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum NttError {
+    UnsupportedPolynomialSize,
+}
+
+impl core::fmt::Display for NttError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NttError::UnsupportedPolynomialSize => f.write_str(
+                "The NTT backend only supports polynomials of sizes that are powers of two \
+                    between 128 and 4096.",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NttError {}
+
+impl NttError {
+    pub fn perform_ntt_checks(polynomial_size: PolynomialSize) -> Result<(), NttError> {
+        if ALLOWED_POLY_SIZE.contains(&polynomial_size.0) {
+            Ok(())
+        } else {
+            Err(NttError::UnsupportedPolynomialSize)
+        }
+    }
+}
+
+/// The main engine exposed by the NTT backend.
+///
+/// The polynomial products are computed in the NTT domain modulo a prime close to $2^{32}$ (for 32
+/// bits entities) or $2^{64}$ (for 64 bits entities), which makes the results of the operations
+/// exactly reproducible across machines.
+///
+/// The NTT tables and the scratch buffers are built on first use and kept by the engine, so that
+/// successive operations on entities of the same sizes do not pay for their creation again.
+pub struct NttEngine {
+    // The NTT tables, indexed by polynomial size.
+    ntts_32: HashMap<usize, Ntt<u64>>,
+    ntts_64: HashMap<usize, Ntt<u128>>,
+    // The scratch buffers, indexed by polynomial size and glwe size.
+    buffers_32: HashMap<(usize, usize), BootstrapBuffers<u32, u64>>,
+    buffers_64: HashMap<(usize, usize), BootstrapBuffers<u64, u128>>,
+}
+
+impl NttEngine {
+    pub(crate) fn ntt_32(&mut self, poly_size: PolynomialSize) -> &mut Ntt<u64> {
+        self.ntts_32
+            .entry(poly_size.0)
+            .or_insert_with(|| ntt_32(poly_size))
+    }
+
+    pub(crate) fn ntt_64(&mut self, poly_size: PolynomialSize) -> &mut Ntt<u128> {
+        self.ntts_64
+            .entry(poly_size.0)
+            .or_insert_with(|| ntt_64(poly_size))
+    }
+
+    pub(crate) fn buffers_32(
+        &mut self,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+    ) -> &mut BootstrapBuffers<u32, u64> {
+        let ntts = &mut self.ntts_32;
+        self.buffers_32
+            .entry((poly_size.0, glwe_size.0))
+            .or_insert_with(|| {
+                let ntt = ntts
+                    .entry(poly_size.0)
+                    .or_insert_with(|| ntt_32(poly_size))
+                    .clone();
+                BootstrapBuffers::new(poly_size, glwe_size, ntt)
+            })
+    }
+
+    pub(crate) fn buffers_64(
+        &mut self,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+    ) -> &mut BootstrapBuffers<u64, u128> {
+        let ntts = &mut self.ntts_64;
+        self.buffers_64
+            .entry((poly_size.0, glwe_size.0))
+            .or_insert_with(|| {
+                let ntt = ntts
+                    .entry(poly_size.0)
+                    .or_insert_with(|| ntt_64(poly_size))
+                    .clone();
+                BootstrapBuffers::new(poly_size, glwe_size, ntt)
+            })
+    }
+}
+
+impl AbstractEngineSeal for NttEngine {}
+impl AbstractEngine for NttEngine {
+    type EngineError = NttError;
+    type Parameters = ();
+
+    fn new(_parameter: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Ok(NttEngine {
+            ntts_32: HashMap::new(),
+            ntts_64: HashMap::new(),
+            buffers_32: HashMap::new(),
+            buffers_64: HashMap::new(),
+        })
+    }
+}
+
+mod ggsw_ciphertext_conversion;
+mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod lwe_bootstrap_key_conversion;
+mod lwe_ciphertext_discarding_bootstrap;
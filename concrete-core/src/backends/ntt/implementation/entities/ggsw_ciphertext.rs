@@ -0,0 +1,58 @@
+use crate::backends::ntt::private::crypto::ggsw::NttGgswCiphertext;
+use crate::backends::ntt::private::math::mod_q::ModQ;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GgswCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GgswCiphertextEntity};
+
+/// A structure representing a GGSW ciphertext with 32 bits of precision in the NTT domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttFourierGgswCiphertext32(pub(crate) NttGgswCiphertext<Vec<ModQ<u64>>>);
+
+/// A structure representing a GGSW ciphertext with 64 bits of precision in the NTT domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttFourierGgswCiphertext64(pub(crate) NttGgswCiphertext<Vec<ModQ<u128>>>);
+
+impl AbstractEntity for NttFourierGgswCiphertext32 {
+    type Kind = GgswCiphertextKind;
+}
+impl AbstractEntity for NttFourierGgswCiphertext64 {
+    type Kind = GgswCiphertextKind;
+}
+
+impl GgswCiphertextEntity for NttFourierGgswCiphertext32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+impl GgswCiphertextEntity for NttFourierGgswCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
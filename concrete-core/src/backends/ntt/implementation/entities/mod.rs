@@ -0,0 +1,8 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the NTT
+//! backend.
+
+mod ggsw_ciphertext;
+mod lwe_bootstrap_key;
+
+pub use ggsw_ciphertext::*;
+pub use lwe_bootstrap_key::*;
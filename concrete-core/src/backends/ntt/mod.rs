@@ -1,4 +1,9 @@
 //! An accelerated backend using the NTT.
+//!
+//! This backend performs the polynomial multiplications of the bootstrap in the NTT domain, using
+//! exact modular arithmetic. As such, its results do not depend on floating-point rounding.
 
-#[doc(hidden)]
+mod implementation;
+#[cfg_attr(not(feature = "__private_docs"), doc(hidden))]
 pub mod private;
+pub use implementation::{engines, entities};
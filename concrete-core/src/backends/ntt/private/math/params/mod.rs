@@ -1,3 +1,7 @@
+use crate::backends::ntt::private::math::mod_q::ModQ;
+use crate::backends::ntt::private::math::transform::Ntt;
+use crate::prelude::PolynomialSize;
+
 pub mod params_32_1024;
 pub mod params_32_128;
 pub mod params_32_2048;
@@ -11,3 +15,146 @@ pub mod params_64_2048;
 pub mod params_64_256;
 pub mod params_64_4096;
 pub mod params_64_512;
+
+macro_rules! ntt_from_params {
+    ($poly_size: ident, $modulus: expr, $roots: expr, $roots_inv: expr, $n_inv: expr) => {{
+        let roots = $roots.iter().map(|a| ModQ::new(*a, $modulus)).collect();
+        let roots_inv = $roots_inv.iter().map(|a| ModQ::new(*a, $modulus)).collect();
+        let n_inv = ModQ::new($n_inv, $modulus);
+        Ntt::new($poly_size, $poly_size.log2(), roots, roots_inv, n_inv)
+    }};
+}
+
+/// Returns an NTT transformer for polynomials of size `poly_size`, using the precomputed
+/// parameters suited to 32 bits torus coefficients.
+///
+/// # Panics
+///
+/// Panics if `poly_size` is not one of the sizes contained in `ALLOWED_POLY_SIZE`.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::backends::ntt::private::math::params::ntt_32;
+/// use concrete_core::backends::ntt::private::math::params::params_32_1024::MOD_32_1024;
+/// use concrete_core::prelude::PolynomialSize;
+/// let ntt = ntt_32(PolynomialSize(1024));
+/// assert_eq!(ntt.get_zero_mod_q().get_mod(), MOD_32_1024);
+/// ```
+pub fn ntt_32(poly_size: PolynomialSize) -> Ntt<u64> {
+    match poly_size.0 {
+        128 => ntt_from_params!(
+            poly_size,
+            params_32_128::MOD_32_128,
+            params_32_128::ROOTS_32_128,
+            params_32_128::INVROOTS_32_128,
+            params_32_128::NINV_32_128
+        ),
+        256 => ntt_from_params!(
+            poly_size,
+            params_32_256::MOD_32_256,
+            params_32_256::ROOTS_32_256,
+            params_32_256::INVROOTS_32_256,
+            params_32_256::NINV_32_256
+        ),
+        512 => ntt_from_params!(
+            poly_size,
+            params_32_512::MOD_32_512,
+            params_32_512::ROOTS_32_512,
+            params_32_512::INVROOTS_32_512,
+            params_32_512::NINV_32_512
+        ),
+        1024 => ntt_from_params!(
+            poly_size,
+            params_32_1024::MOD_32_1024,
+            params_32_1024::ROOTS_32_1024,
+            params_32_1024::INVROOTS_32_1024,
+            params_32_1024::NINV_32_1024
+        ),
+        2048 => ntt_from_params!(
+            poly_size,
+            params_32_2048::MOD_32_2048,
+            params_32_2048::ROOTS_32_2048,
+            params_32_2048::INVROOTS_32_2048,
+            params_32_2048::NINV_32_2048
+        ),
+        4096 => ntt_from_params!(
+            poly_size,
+            params_32_4096::MOD_32_4096,
+            params_32_4096::ROOTS_32_4096,
+            params_32_4096::INVROOTS_32_4096,
+            params_32_4096::NINV_32_4096
+        ),
+        _ => panic!(
+            "The size chosen is not valid ({}). Check ALLOWED_POLY_SIZE.",
+            poly_size.0
+        ),
+    }
+}
+
+/// Returns an NTT transformer for polynomials of size `poly_size`, using the precomputed
+/// parameters suited to 64 bits torus coefficients.
+///
+/// # Panics
+///
+/// Panics if `poly_size` is not one of the sizes contained in `ALLOWED_POLY_SIZE`.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::backends::ntt::private::math::params::ntt_64;
+/// use concrete_core::backends::ntt::private::math::params::params_64_1024::MOD_64_1024;
+/// use concrete_core::prelude::PolynomialSize;
+/// let ntt = ntt_64(PolynomialSize(1024));
+/// assert_eq!(ntt.get_zero_mod_q().get_mod(), MOD_64_1024);
+/// ```
+pub fn ntt_64(poly_size: PolynomialSize) -> Ntt<u128> {
+    match poly_size.0 {
+        128 => ntt_from_params!(
+            poly_size,
+            params_64_128::MOD_64_128,
+            params_64_128::ROOTS_64_128,
+            params_64_128::INVROOTS_64_128,
+            params_64_128::NINV_64_128
+        ),
+        256 => ntt_from_params!(
+            poly_size,
+            params_64_256::MOD_64_256,
+            params_64_256::ROOTS_64_256,
+            params_64_256::INVROOTS_64_256,
+            params_64_256::NINV_64_256
+        ),
+        512 => ntt_from_params!(
+            poly_size,
+            params_64_512::MOD_64_512,
+            params_64_512::ROOTS_64_512,
+            params_64_512::INVROOTS_64_512,
+            params_64_512::NINV_64_512
+        ),
+        1024 => ntt_from_params!(
+            poly_size,
+            params_64_1024::MOD_64_1024,
+            params_64_1024::ROOTS_64_1024,
+            params_64_1024::INVROOTS_64_1024,
+            params_64_1024::NINV_64_1024
+        ),
+        2048 => ntt_from_params!(
+            poly_size,
+            params_64_2048::MOD_64_2048,
+            params_64_2048::ROOTS_64_2048,
+            params_64_2048::INVROOTS_64_2048,
+            params_64_2048::NINV_64_2048
+        ),
+        4096 => ntt_from_params!(
+            poly_size,
+            params_64_4096::MOD_64_4096,
+            params_64_4096::ROOTS_64_4096,
+            params_64_4096::INVROOTS_64_4096,
+            params_64_4096::NINV_64_4096
+        ),
+        _ => panic!(
+            "The size chosen is not valid ({}). Check ALLOWED_POLY_SIZE.",
+            poly_size.0
+        ),
+    }
+}
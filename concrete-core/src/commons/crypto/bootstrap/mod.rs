@@ -3,9 +3,11 @@
 //! The bootstrapping operation allows to reduce the level of noise in an LWE ciphertext, while
 //! evaluating an univariate function.
 
+pub(crate) mod multi_bit_standard;
 mod seeded_standard;
 mod standard;
 
+pub use multi_bit_standard::StandardMultiBitBootstrapKey;
 pub use seeded_standard::StandardSeededBootstrapKey;
 pub use standard::StandardBootstrapKey;
 
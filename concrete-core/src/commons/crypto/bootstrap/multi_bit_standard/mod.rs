@@ -0,0 +1,533 @@
+use crate::commons::crypto::encoding::Plaintext;
+use crate::commons::crypto::ggsw::StandardGgswCiphertext;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::commons::math::random::ByteRandomGenerator;
+#[cfg(feature = "__commons_parallel")]
+use crate::commons::math::random::ParallelByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
+use crate::commons::utils::{zip, zip_args};
+use crate::prelude::{
+    BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweSize,
+    LweBskGroupingFactor, LweDimension, PolynomialSize,
+};
+#[cfg(feature = "__commons_parallel")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A multi-bit bootstrapping key represented in the standard domain.
+///
+/// The input key coefficients are split into groups of `grouping_factor` consecutive
+/// coefficients. For every group, the key stores `2^grouping_factor - 1` GGSW ciphertexts, one
+/// for each non-zero bit pattern `b` of the group. The GGSW ciphertext at index `b - 1` encrypts
+/// one if the key coefficients of the group are equal to `b`, and zero otherwise, the first
+/// coefficient of the group being matched against the most significant bit of `b`.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandardMultiBitBootstrapKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    rlwe_size: GlweSize,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+    grouping_factor: LweBskGroupingFactor,
+}
+
+tensor_traits!(StandardMultiBitBootstrapKey);
+
+/// Returns the number of GGSW ciphertexts stored for each group of key coefficients.
+///
+/// # Panics
+///
+/// Panics if `2^grouping_factor` does not fit in a `usize`.
+pub(crate) fn ggsw_per_multi_bit_group(grouping_factor: LweBskGroupingFactor) -> usize {
+    u32::try_from(grouping_factor.0)
+        .ok()
+        .and_then(|shift| 1_usize.checked_shl(shift))
+        .expect("The grouping factor is too large.")
+        - 1
+}
+
+impl<Scalar> StandardMultiBitBootstrapKey<Vec<Scalar>> {
+    /// Allocates a new multi-bit bootstrapping key in the standard domain whose polynomials
+    /// coefficients are all `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key size is not a multiple of the grouping factor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::bootstrap::StandardMultiBitBootstrapKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// let bsk = StandardMultiBitBootstrapKey::allocate(
+    ///     9u32,
+    ///     GlweSize(7),
+    ///     PolynomialSize(9),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    ///     LweBskGroupingFactor(2),
+    /// );
+    /// assert_eq!(bsk.polynomial_size(), PolynomialSize(9));
+    /// assert_eq!(bsk.glwe_size(), GlweSize(7));
+    /// assert_eq!(bsk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(bsk.base_log(), DecompositionBaseLog(5));
+    /// assert_eq!(bsk.key_size(), LweDimension(4));
+    /// assert_eq!(bsk.grouping_factor(), LweBskGroupingFactor(2));
+    /// assert_eq!(bsk.ggsw_iter().count(), 6);
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        rlwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        key_size: LweDimension,
+        grouping_factor: LweBskGroupingFactor,
+    ) -> StandardMultiBitBootstrapKey<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_div!(key_size.0 => grouping_factor.0);
+        let ggsw_count = key_size.0 / grouping_factor.0 * ggsw_per_multi_bit_group(grouping_factor);
+        StandardMultiBitBootstrapKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                ggsw_count
+                    * decomp_level.0
+                    * rlwe_size.0
+                    * rlwe_size.0
+                    * poly_size.0
+            ]),
+            decomp_level,
+            decomp_base_log,
+            rlwe_size,
+            poly_size,
+            grouping_factor,
+        }
+    }
+}
+
+impl<Cont> StandardMultiBitBootstrapKey<Cont> {
+    /// Creates a multi-bit bootstrapping key from an existing container of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::bootstrap::StandardMultiBitBootstrapKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// let vector = vec![0u32; 10 * 5 * 4 * 4 * 5 * 7];
+    /// let bsk = StandardMultiBitBootstrapKey::from_container(
+    ///     vector.as_slice(),
+    ///     GlweSize(4),
+    ///     PolynomialSize(10),
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(4),
+    ///     LweBskGroupingFactor(3),
+    /// );
+    /// assert_eq!(bsk.key_size(), LweDimension(15));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        grouping_factor: LweBskGroupingFactor,
+    ) -> StandardMultiBitBootstrapKey<Cont>
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() =>
+            decomp_level.0,
+            glwe_size.0 * glwe_size.0,
+            poly_size.0,
+            ggsw_per_multi_bit_group(grouping_factor)
+        );
+        StandardMultiBitBootstrapKey {
+            tensor,
+            rlwe_size: glwe_size,
+            poly_size,
+            decomp_level,
+            decomp_base_log,
+            grouping_factor,
+        }
+    }
+
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
+    pub fn as_view(&self) -> StandardMultiBitBootstrapKey<&'_ [Cont::Element]>
+    where
+        Cont: Container,
+    {
+        StandardMultiBitBootstrapKey {
+            tensor: Tensor::from_container(self.tensor.as_container().as_ref()),
+            rlwe_size: self.rlwe_size,
+            poly_size: self.poly_size,
+            decomp_level: self.decomp_level,
+            decomp_base_log: self.decomp_base_log,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+
+    /// Generate a new multi-bit bootstrap key from the input parameters, and fills the current
+    /// container with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::bootstrap::StandardMultiBitBootstrapKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     LweBskGroupingFactor, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    ///
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(9));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let mut bsk = StandardMultiBitBootstrapKey::allocate(
+    ///     9u32,
+    ///     glwe_dim.to_glwe_size(),
+    ///     poly_size,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     lwe_dim,
+    ///     LweBskGroupingFactor(2),
+    /// );
+    /// let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+    /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// bsk.fill_with_new_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// ```
+    pub fn fill_with_new_key<LweCont, RlweCont, Scalar, Gen>(
+        &mut self,
+        lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
+        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.key_size().0 => lwe_secret_key.key_size().0);
+        self.as_mut_tensor()
+            .fill_with_element(<Scalar as Numeric>::ZERO);
+
+        let plaintexts = self.group_plaintexts(lwe_secret_key);
+        let gen_iter = generator
+            .fork_bsk_to_ggsw::<Scalar>(
+                LweDimension(plaintexts.len()),
+                self.decomp_level,
+                glwe_secret_key.key_size().to_glwe_size(),
+                self.poly_size,
+            )
+            .unwrap();
+
+        for zip_args!(mut rgsw, encoded, mut generator) in
+            zip!(self.ggsw_iter_mut(), plaintexts.iter(), gen_iter)
+        {
+            glwe_secret_key.encrypt_constant_ggsw(
+                &mut rgsw,
+                encoded,
+                noise_parameters,
+                &mut generator,
+            );
+        }
+    }
+
+    /// Generate a new multi-bit bootstrap key from the input parameters, and fills the current
+    /// container with it, using all the available threads.
+    ///
+    /// # Note
+    ///
+    /// This method uses _rayon_ internally, and is hidden behind the "__commons_parallel" feature
+    /// gate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::bootstrap::StandardMultiBitBootstrapKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     LweBskGroupingFactor, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(6), GlweDimension(6), PolynomialSize(9));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let mut bsk = StandardMultiBitBootstrapKey::allocate(
+    ///     9u32,
+    ///     glwe_dim.to_glwe_size(),
+    ///     poly_size,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     lwe_dim,
+    ///     LweBskGroupingFactor(3),
+    /// );
+    /// let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+    /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// bsk.par_fill_with_new_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_fill_with_new_key<LweCont, RlweCont, Scalar, Gen>(
+        &mut self,
+        lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
+        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        noise_parameters: impl DispersionParameter + Sync + Send,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Sync + Send,
+        RlweCont: Sync,
+        Gen: ParallelByteRandomGenerator,
+    {
+        ck_dim_eq!(self.key_size().0 => lwe_secret_key.key_size().0);
+        self.as_mut_tensor()
+            .fill_with_element(<Scalar as Numeric>::ZERO);
+
+        let plaintexts = self.group_plaintexts(lwe_secret_key);
+        let gen_iter = generator
+            .par_fork_bsk_to_ggsw::<Scalar>(
+                LweDimension(plaintexts.len()),
+                self.decomp_level,
+                glwe_secret_key.key_size().to_glwe_size(),
+                self.poly_size,
+            )
+            .unwrap();
+        self.par_ggsw_iter_mut()
+            .zip(plaintexts.par_iter())
+            .zip(gen_iter)
+            .for_each(|((mut rgsw, encoded), mut generator)| {
+                glwe_secret_key.par_encrypt_constant_ggsw(
+                    &mut rgsw,
+                    encoded,
+                    noise_parameters,
+                    &mut generator,
+                );
+            });
+    }
+
+    // Computes the plaintexts encrypted in the key: for each group of key coefficients and each
+    // non-zero bit pattern, the product of the key coefficients (or of their complement) selected
+    // by the pattern.
+    fn group_plaintexts<LweCont, Scalar>(
+        &self,
+        lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
+    ) -> Vec<Plaintext<Scalar>>
+    where
+        LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let grouping_factor = self.grouping_factor.0;
+        lwe_secret_key
+            .as_tensor()
+            .as_slice()
+            .chunks_exact(grouping_factor)
+            .flat_map(|group| {
+                (1..=ggsw_per_multi_bit_group(self.grouping_factor)).map(move |pattern| {
+                    let value = group.iter().enumerate().fold(
+                        <Scalar as Numeric>::ONE,
+                        |acc, (i, &key_bit)| {
+                            let pattern_bit = (pattern >> (grouping_factor - 1 - i)) & 1;
+                            if pattern_bit == 1 {
+                                acc.wrapping_mul(key_bit)
+                            } else {
+                                acc.wrapping_mul(<Scalar as Numeric>::ONE.wrapping_sub(key_bit))
+                            }
+                        },
+                    );
+                    Plaintext(value)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the size of the polynomials used in the bootstrapping key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the size of the GLWE ciphertexts used in the bootstrapping key.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.rlwe_size
+    }
+
+    /// Returns the number of levels used to decompose the key bits.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used to decompose the key bits.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of input key coefficients encrypted together.
+    pub fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.grouping_factor
+    }
+
+    /// Returns the size of the LWE encrypted key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::bootstrap::StandardMultiBitBootstrapKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweBskGroupingFactor,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// let bsk = StandardMultiBitBootstrapKey::allocate(
+    ///     9u32,
+    ///     GlweSize(7),
+    ///     PolynomialSize(9),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(6),
+    ///     LweBskGroupingFactor(3),
+    /// );
+    /// assert_eq!(bsk.key_size(), LweDimension(6));
+    /// ```
+    pub fn key_size(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        let ggsw_size =
+            self.rlwe_size.0 * self.rlwe_size.0 * self.poly_size.0 * self.decomp_level.0;
+        ck_dim_div!(self.as_tensor().len() =>
+            ggsw_size,
+            ggsw_per_multi_bit_group(self.grouping_factor)
+        );
+        LweDimension(
+            self.as_tensor().len() / (ggsw_size * ggsw_per_multi_bit_group(self.grouping_factor))
+                * self.grouping_factor.0,
+        )
+    }
+
+    /// Returns an iterator over the borrowed GGSW ciphertext composing the key.
+    ///
+    /// The ciphertexts of a group are contiguous, and sorted by increasing bit pattern.
+    pub fn ggsw_iter(
+        &self,
+    ) -> impl Iterator<Item = StandardGgswCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let chunks_size =
+            self.rlwe_size.0 * self.rlwe_size.0 * self.poly_size.0 * self.decomp_level.0;
+        let rlwe_size = self.rlwe_size;
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        self.as_tensor()
+            .subtensor_iter(chunks_size)
+            .map(move |tensor| {
+                StandardGgswCiphertext::from_container(
+                    tensor.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                )
+            })
+    }
+
+    /// Returns an iterator over the mutably borrowed GGSW ciphertext composing the key.
+    pub fn ggsw_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = StandardGgswCiphertext<&mut [<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let chunks_size =
+            self.rlwe_size.0 * self.rlwe_size.0 * self.poly_size.0 * self.decomp_level.0;
+        let rlwe_size = self.rlwe_size;
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunks_size)
+            .map(move |tensor| {
+                StandardGgswCiphertext::from_container(
+                    tensor.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                )
+            })
+    }
+
+    /// Returns a parallel iterator over the mutably borrowed GGSW ciphertext composing the
+    /// key.
+    ///
+    /// # Notes
+    ///
+    /// This iterator is hidden behind the "__commons_parallel" feature gate.
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_ggsw_iter_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = StandardGgswCiphertext<&mut [<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+        <Self as AsRefTensor>::Element: Sync + Send,
+    {
+        let chunks_size =
+            self.rlwe_size.0 * self.rlwe_size.0 * self.poly_size.0 * self.decomp_level.0;
+        let rlwe_size = self.rlwe_size;
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        self.as_mut_tensor()
+            .par_subtensor_iter_mut(chunks_size)
+            .map(move |tensor| {
+                StandardGgswCiphertext::from_container(
+                    tensor.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                )
+            })
+    }
+}
@@ -0,0 +1,269 @@
+use super::StandardGgswCiphertext;
+use crate::commons::math::tensor::{
+    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize, PolynomialSize,
+};
+#[cfg(feature = "__commons_parallel")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A list of GGSW ciphertexts sharing the same parameters.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandardGgswCiphertextList<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    rlwe_size: GlweSize,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(StandardGgswCiphertextList);
+
+impl<Scalar> StandardGgswCiphertextList<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a list of GGSW ciphertexts whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::ggsw::StandardGgswCiphertextList;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    ///
+    /// let list = StandardGgswCiphertextList::allocate(
+    ///     9 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     GgswCiphertextCount(5),
+    /// );
+    /// assert_eq!(list.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(list.glwe_size(), GlweSize(7));
+    /// assert_eq!(list.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(list.decomposition_base_log(), DecompositionBaseLog(4));
+    /// assert_eq!(list.ciphertext_count(), GgswCiphertextCount(5));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        rlwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        ciphertext_count: GgswCiphertextCount,
+    ) -> Self {
+        StandardGgswCiphertextList {
+            tensor: Tensor::from_container(vec![
+                value;
+                ciphertext_count.0
+                    * decomp_level.0
+                    * rlwe_size.0
+                    * rlwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            rlwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> StandardGgswCiphertextList<Cont> {
+    /// Creates a list of GGSW ciphertexts from an existing container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::ggsw::StandardGgswCiphertextList;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    ///
+    /// let list = StandardGgswCiphertextList::from_container(
+    ///     vec![9 as u8; 5 * 7 * 7 * 10 * 3],
+    ///     GlweSize(7),
+    ///     PolynomialSize(10),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(list.ciphertext_count(), GgswCiphertextCount(5));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        rlwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => rlwe_size.0 * rlwe_size.0, poly_size.0, decomp_level.0);
+        StandardGgswCiphertextList {
+            tensor,
+            poly_size,
+            rlwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+
+    /// Returns the number of ciphertexts in the list.
+    pub fn ciphertext_count(&self) -> GgswCiphertextCount
+    where
+        Self: AsRefTensor,
+    {
+        GgswCiphertextCount(self.as_tensor().len() / self.ciphertext_size())
+    }
+
+    /// Returns the size of the GLWE ciphertexts used in the GGSW ciphertexts.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.rlwe_size
+    }
+
+    /// Returns the size of the polynomials used in the ciphertexts.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of decomposition levels used in the ciphertexts.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used in the ciphertexts.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns an iterator over borrowed GGSW ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::ggsw::StandardGgswCiphertextList;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    ///
+    /// let list = StandardGgswCiphertextList::allocate(
+    ///     9 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     GgswCiphertextCount(5),
+    /// );
+    /// for ggsw in list.ciphertext_iter() {
+    ///     assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// }
+    /// assert_eq!(list.ciphertext_iter().count(), 5);
+    /// ```
+    pub fn ciphertext_iter(
+        &self,
+    ) -> impl Iterator<Item = StandardGgswCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let rlwe_size = self.rlwe_size;
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        self.as_tensor()
+            .subtensor_iter(self.ciphertext_size())
+            .map(move |sub| {
+                StandardGgswCiphertext::from_container(
+                    sub.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                )
+            })
+    }
+
+    /// Returns a parallel iterator over borrowed GGSW ciphertexts.
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_ciphertext_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = StandardGgswCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Sync,
+    {
+        let rlwe_size = self.rlwe_size;
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        self.as_tensor()
+            .par_subtensor_iter(self.ciphertext_size())
+            .map(move |sub| {
+                StandardGgswCiphertext::from_container(
+                    sub.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                )
+            })
+    }
+
+    /// Returns an iterator over mutably borrowed GGSW ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::ggsw::StandardGgswCiphertextList;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    ///
+    /// let mut list = StandardGgswCiphertextList::allocate(
+    ///     9 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     GgswCiphertextCount(5),
+    /// );
+    /// for mut ggsw in list.ciphertext_iter_mut() {
+    ///     ggsw.as_mut_tensor().fill_with_element(0);
+    /// }
+    /// assert!(list.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn ciphertext_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = StandardGgswCiphertext<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let rlwe_size = self.rlwe_size;
+        let poly_size = self.poly_size;
+        let base_log = self.decomp_base_log;
+        let chunks_size = self.ciphertext_size();
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunks_size)
+            .map(move |sub| {
+                StandardGgswCiphertext::from_container(
+                    sub.into_container(),
+                    rlwe_size,
+                    poly_size,
+                    base_log,
+                )
+            })
+    }
+
+    fn ciphertext_size(&self) -> usize {
+        self.decomp_level.0 * self.rlwe_size.0 * self.rlwe_size.0 * self.poly_size.0
+    }
+}
@@ -1,10 +1,10 @@
-use crate::commons::crypto::encoding::Plaintext;
+use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
 use crate::commons::math::tensor::Container;
 
 use crate::commons::crypto::glwe::GlweList;
 use crate::commons::math::decomposition::DecompositionLevel;
 use crate::commons::math::tensor::{
-    ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
 
@@ -97,6 +97,26 @@ where
         ciphertext.fill_with_trivial_encryption(plaintext);
         ciphertext
     }
+
+    pub fn new_trivial_polynomial_encryption<InputCont>(
+        glwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        plaintext_list: &PlaintextList<InputCont>,
+    ) -> Self
+    where
+        PlaintextList<InputCont>: AsRefTensor<Element = Scalar>,
+    {
+        let mut ciphertext = Self::allocate(
+            Scalar::ZERO,
+            PolynomialSize(plaintext_list.count().0),
+            glwe_size,
+            decomp_level,
+            decomp_base_log,
+        );
+        ciphertext.fill_with_trivial_polynomial_encryption(plaintext_list);
+        ciphertext
+    }
 }
 
 impl<Cont> StandardGgswCiphertext<Cont> {
@@ -532,4 +552,32 @@ impl<Cont> StandardGgswCiphertext<Cont> {
             }
         }
     }
+
+    /// Fills the GGSW ciphertext with the trivial encryption of a polynomial.
+    ///
+    /// The masks are set to zero, and the body of the $i$-th row of every level matrix contains
+    /// the decomposition summand of the polynomial in its $i$-th polynomial.
+    pub fn fill_with_trivial_polynomial_encryption<Scalar, InputCont>(
+        &mut self,
+        plaintext_list: &PlaintextList<InputCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        PlaintextList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => plaintext_list.count().0);
+        self.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        let base_log = self.decomposition_base_log();
+        for mut matrix in self.level_matrix_iter_mut() {
+            let factor = Scalar::ONE
+                << (<Scalar as Numeric>::BITS - (base_log.0 * (matrix.decomposition_level().0)));
+            for (index, row) in matrix.row_iter_mut().enumerate() {
+                let mut polynomial_list = row.into_glwe().into_polynomial_list();
+                polynomial_list
+                    .get_mut_polynomial(index)
+                    .as_mut_tensor()
+                    .fill_with_one(plaintext_list.as_tensor(), |coef| coef.wrapping_mul(factor));
+            }
+        }
+    }
 }
@@ -2,9 +2,12 @@ use crate::commons::crypto::encoding::PlaintextList;
 use crate::commons::crypto::secret::generators::{DeterministicSeeder, EncryptionRandomGenerator};
 use crate::commons::crypto::secret::GlweSecretKey;
 use crate::commons::math::random::{CompressionSeed, Seeder};
+use crate::commons::math::tensor::AsMutTensor;
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::test_tools;
-use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, LogStandardDev};
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, LogStandardDev, PlaintextCount,
+};
 use concrete_csprng::generators::SoftwareRandomGenerator;
 
 use super::{StandardGgswCiphertext, StandardGgswSeededCiphertext};
@@ -97,6 +100,102 @@ fn test_seeded_ggsw_u64() {
     test_seeded_ggsw::<u64>()
 }
 
+fn test_polynomial_ggsw_matches_constant_ggsw<T: UnsignedTorus>() {
+    // random settings
+    let nb_ct = test_tools::random_ciphertext_count(10);
+    let dimension = test_tools::random_glwe_dimension(5);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let noise_parameters = LogStandardDev::from_log_standard_dev(-50.);
+    let decomp_level = DecompositionLevelCount(3);
+    let decomp_base_log = DecompositionBaseLog(7);
+    let mut secret_generator = test_tools::new_secret_random_generator();
+
+    // generates a secret key
+    let sk = GlweSecretKey::generate_binary(dimension, polynomial_size, &mut secret_generator);
+
+    // generates random plaintexts
+    let plaintext_vector =
+        PlaintextList::from_tensor(secret_generator.random_uniform_tensor(nb_ct.0));
+
+    for plaintext in plaintext_vector.plaintext_iter() {
+        let main_seed = test_tools::random_seed();
+        let mut deterministic_seeder =
+            DeterministicSeeder::<SoftwareRandomGenerator>::new(main_seed);
+        let noise_seed = deterministic_seeder.seed();
+        let mask_seed = deterministic_seeder.seed();
+
+        // the constant polynomial holding the plaintext
+        let mut polynomial = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        *polynomial.as_mut_tensor().first_mut() = plaintext.0;
+
+        let mut constant_ggsw = StandardGgswCiphertext::allocate(
+            T::ZERO,
+            polynomial_size,
+            dimension.to_glwe_size(),
+            decomp_level,
+            decomp_base_log,
+        );
+        let mut generator = EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+            mask_seed,
+            &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(main_seed),
+        );
+        generator.seed_noise_generator(noise_seed);
+        sk.encrypt_constant_ggsw(
+            &mut constant_ggsw,
+            plaintext,
+            noise_parameters,
+            &mut generator,
+        );
+
+        let mut polynomial_ggsw = StandardGgswCiphertext::allocate(
+            T::ZERO,
+            polynomial_size,
+            dimension.to_glwe_size(),
+            decomp_level,
+            decomp_base_log,
+        );
+        let mut generator = EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+            mask_seed,
+            &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(main_seed),
+        );
+        generator.seed_noise_generator(noise_seed);
+        sk.encrypt_polynomial_ggsw(
+            &mut polynomial_ggsw,
+            &polynomial,
+            noise_parameters,
+            &mut generator,
+        );
+
+        assert_eq!(constant_ggsw, polynomial_ggsw);
+
+        // the trivial encryptions must match as well
+        let trivial_constant_ggsw = StandardGgswCiphertext::new_trivial_encryption(
+            polynomial_size,
+            dimension.to_glwe_size(),
+            decomp_level,
+            decomp_base_log,
+            plaintext,
+        );
+        let trivial_polynomial_ggsw = StandardGgswCiphertext::new_trivial_polynomial_encryption(
+            dimension.to_glwe_size(),
+            decomp_level,
+            decomp_base_log,
+            &polynomial,
+        );
+        assert_eq!(trivial_constant_ggsw, trivial_polynomial_ggsw);
+    }
+}
+
+#[test]
+fn test_polynomial_ggsw_matches_constant_ggsw_u32() {
+    test_polynomial_ggsw_matches_constant_ggsw::<u32>()
+}
+
+#[test]
+fn test_polynomial_ggsw_matches_constant_ggsw_u64() {
+    test_polynomial_ggsw_matches_constant_ggsw::<u64>()
+}
+
 #[cfg(feature = "__commons_parallel")]
 mod parallel {
     use crate::commons::crypto::encoding::PlaintextList;
@@ -0,0 +1,433 @@
+use super::{GlweCiphertext, GlweList};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension, GlweSize,
+    KeyKind, PlaintextCount, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE keyswitching key.
+///
+/// A GLWE keyswitching key allows to switch a GLWE ciphertext encrypted under a GLWE secret key
+/// $\vec{S}\_{\mathsf{in}}$ to a GLWE ciphertext encrypted under an other GLWE secret key
+/// $\vec{S}\_{\mathsf{out}}$, with the same polynomial size. For every polynomial
+/// $S\_{\mathsf{in},i}$ of the input key, and every level $l$ of the decomposition, it contains a
+/// GLWE encryption of $S\_{\mathsf{in},i}\cdot 2^{w-l\beta}$ under $\vec{S}\_{\mathsf{out}}$,
+/// with $w$ the number of bits of the integers and $\beta$ the decomposition base log.
+///
+/// The ciphertexts are stored input polynomial after input polynomial, and for a given input
+/// polynomial, level after level, starting with the first level.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+}
+
+tensor_traits!(GlweKeyswitchKey);
+
+impl<Scalar> GlweKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a GLWE keyswitching key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a keyswitch key, but merely allocates a container of the
+    /// right size. See [`GlweKeyswitchKey::fill_with_keyswitch_key`] to fill the container with a
+    /// proper keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(3),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.decomposition_level_count(), DecompositionLevelCount(10));
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: GlweDimension,
+        output_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self {
+        GlweKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                input_dimension.0
+                    * decomp_size.0
+                    * output_dimension.to_glwe_size().0
+                    * poly_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            poly_size,
+        }
+    }
+}
+
+impl<Cont> GlweKeyswitchKey<Cont> {
+    /// Creates a GLWE keyswitching key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a keyswitch key, but merely wraps the container in the proper
+    /// type. It assumes that either the container already contains a proper keyswitching key, or
+    /// that [`GlweKeyswitchKey::fill_with_keyswitch_key`] will be called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::from_container(
+    ///     vec![0 as u8; 3 * 4 * 3 * 256],
+    ///     DecompositionBaseLog(7),
+    ///     DecompositionLevelCount(4),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        output_glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> GlweKeyswitchKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() =>
+            output_glwe_dimension.to_glwe_size().0,
+            poly_size.0,
+            decomp_size.0
+        );
+        GlweKeyswitchKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            poly_size,
+        }
+    }
+
+    /// Returns the dimension of the input GLWE key.
+    pub fn input_glwe_dimension(&self) -> GlweDimension
+    where
+        Self: AsRefTensor,
+    {
+        GlweDimension(
+            self.as_tensor().len()
+                / (self.output_glwe_size.0 * self.poly_size.0 * self.decomp_level_count.0),
+        )
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the input and output keys.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns a borrowed list of the GLWE ciphertexts contained in the key.
+    pub fn as_glwe_list(&self) -> GlweList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        GlweList::from_container(
+            self.as_tensor().as_slice(),
+            self.output_glwe_dimension(),
+            self.poly_size,
+        )
+    }
+
+    /// Returns a mutably borrowed list of the GLWE ciphertexts contained in the key.
+    pub fn as_mut_glwe_list(&mut self) -> GlweList<&mut [<Self as AsMutTensor>::Element]>
+    where
+        Self: AsMutTensor,
+    {
+        let output_glwe_dimension = self.output_glwe_dimension();
+        let poly_size = self.poly_size;
+        GlweList::from_container(
+            self.as_mut_tensor().as_mut_slice(),
+            output_glwe_dimension,
+            poly_size,
+        )
+    }
+
+    /// Fills the current keyswitching key container with an actual keyswitching key constructed
+    /// from an input and an output GLWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key: GlweSecretKey<_, Vec<u32>> = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     GlweDimension(2),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    /// );
+    /// ksk.fill_with_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-25.),
+    ///     &mut encryption_generator,
+    /// );
+    /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_keyswitch_key<InKind, OutKind, InKeyCont, OutKeyCont, Scalar, Gen>(
+        &mut self,
+        input_key: &GlweSecretKey<InKind, InKeyCont>,
+        output_key: &GlweSecretKey<OutKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(input_key.key_size().0 => self.input_glwe_dimension().0);
+        ck_dim_eq!(output_key.key_size().0 => self.output_glwe_dimension().0);
+        ck_dim_eq!(self.poly_size.0 =>
+            input_key.polynomial_size().0,
+            output_key.polynomial_size().0
+        );
+
+        let messages =
+            encode_keyswitch_key_messages(input_key, self.decomp_base_log, self.decomp_level_count);
+        output_key.encrypt_glwe_list(
+            &mut self.as_mut_glwe_list(),
+            &messages,
+            noise_parameters,
+            generator,
+        );
+    }
+
+    /// Keyswitches a GLWE ciphertext, and writes the result in `after`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweKeyswitchKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let poly_size = PolynomialSize(256);
+    /// let input_dimension = GlweDimension(2);
+    /// let output_dimension = GlweDimension(1);
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key =
+    ///     GlweSecretKey::generate_binary(input_dimension, poly_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_dimension, poly_size, &mut secret_generator);
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     DecompositionLevelCount(4),
+    ///     DecompositionBaseLog(10),
+    ///     input_dimension,
+    ///     output_dimension,
+    ///     poly_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// let mut messages = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// *messages.as_mut_tensor().first_mut() = 3 << 60;
+    /// let mut before = GlweCiphertext::allocate(0u64, poly_size, input_dimension.to_glwe_size());
+    /// input_key.encrypt_glwe(&mut before, &messages, noise, &mut encryption_generator);
+    /// let mut after = GlweCiphertext::allocate(0u64, poly_size, output_dimension.to_glwe_size());
+    /// ksk.keyswitch_ciphertext(&mut after, &before);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// output_key.decrypt_glwe(&mut decrypted, &after);
+    /// let rounded = decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .map(|a| (a.wrapping_add(1 << 59) >> 60))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(rounded[0], 3);
+    /// assert!(rounded[1..].iter().all(|a| *a == 0));
+    /// ```
+    pub fn keyswitch_ciphertext<Scalar, OutCont, InCont>(
+        &self,
+        after: &mut GlweCiphertext<OutCont>,
+        before: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => after.polynomial_size().0, before.polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_size.0 => after.size().0);
+        ck_dim_eq!(self.input_glwe_dimension().0 => before.mask_size().0);
+
+        // We reset the output, and copy the input body.
+        after.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        after
+            .get_mut_body()
+            .as_mut_tensor()
+            .fill_with_copy(before.get_body().as_tensor());
+
+        // We subtract the products of every decomposed polynomial of the input mask, with the
+        // encryptions of the corresponding input key polynomial.
+        let level_count = self.decomp_level_count.0;
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let glwe_list = self.as_glwe_list();
+        let ciphertexts = glwe_list.ciphertext_iter().collect::<Vec<_>>();
+        for (input_index, mask_polynomial) in before
+            .get_mask()
+            .as_polynomial_list()
+            .polynomial_iter()
+            .enumerate()
+        {
+            let mut decomposition = decomposer.decompose_tensor(&mask_polynomial);
+            while let Some(term) = decomposition.next_term() {
+                let level_index = term.level().0 - 1;
+                let ciphertext = &ciphertexts[input_index * level_count + level_index];
+                let term_polynomial = Polynomial::from_container(term.as_tensor().as_slice());
+                for (mut output_polynomial, key_polynomial) in after
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(ciphertext.as_polynomial_list().polynomial_iter())
+                {
+                    output_polynomial
+                        .update_with_wrapping_sub_mul(&term_polynomial, &key_polynomial);
+                }
+            }
+        }
+    }
+}
+
+/// Encodes the input key polynomials scaled by $2^{w-l\beta}$, for every level $l$ of the
+/// decomposition, in the order in which they are stored in a GLWE keyswitching key.
+pub(super) fn encode_keyswitch_key_messages<Kind, KeyCont, Scalar>(
+    input_key: &GlweSecretKey<Kind, KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+) -> PlaintextList<Vec<Scalar>>
+where
+    GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+    Kind: KeyKind,
+    Scalar: UnsignedTorus,
+{
+    let poly_size = input_key.polynomial_size().0;
+    let level_count = decomp_level_count.0;
+    let mut messages = PlaintextList::allocate(
+        Scalar::ZERO,
+        PlaintextCount(input_key.key_size().0 * level_count * poly_size),
+    );
+    for (key_polynomial, mut block) in input_key
+        .as_polynomial_list()
+        .polynomial_iter()
+        .zip(messages.sublist_iter_mut(PlaintextCount(level_count * poly_size)))
+    {
+        for (level, mut level_messages) in (1..=level_count)
+            .map(DecompositionLevel)
+            .zip(block.sublist_iter_mut(PlaintextCount(poly_size)))
+        {
+            for (message, key_coefficient) in level_messages
+                .as_mut_tensor()
+                .iter_mut()
+                .zip(key_polynomial.coefficient_iter())
+            {
+                *message = DecompositionTerm::new(level, decomp_base_log, *key_coefficient)
+                    .to_recomposition_summand();
+            }
+        }
+    }
+    messages
+}
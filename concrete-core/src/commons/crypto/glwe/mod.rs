@@ -2,16 +2,26 @@
 
 mod body;
 mod ciphertext;
+mod glwe_keyswitch;
 mod keyswitch;
 mod list;
 mod mask;
+mod relinearization;
 mod seeded_ciphertext;
+mod seeded_glwe_keyswitch;
 mod seeded_list;
+mod seeded_packing_keyswitch;
+mod tensor_product;
 
 pub use body::*;
 pub use ciphertext::*;
+pub use glwe_keyswitch::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use mask::*;
+pub use relinearization::*;
 pub use seeded_ciphertext::*;
+pub use seeded_glwe_keyswitch::*;
 pub use seeded_list::*;
+pub use seeded_packing_keyswitch::*;
+pub use tensor_product::*;
@@ -0,0 +1,404 @@
+use super::{tensor_product_quadratic_count, GlweCiphertext, GlweList, GlweTensorProduct};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor,
+    Container, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension, GlweSize,
+    KeyKind, PlaintextCount, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE relinearization key.
+///
+/// A relinearization key allows to turn the tensor product of two GLWE ciphertexts (see
+/// [`GlweTensorProduct`]) back into a GLWE ciphertext encrypted under the original key $\vec{S}$.
+/// For every product $S\_iS\_j$ with $i\leq j$, and every level $l$ of the decomposition, it
+/// contains a GLWE encryption of $S\_iS\_j\cdot 2^{w-l\beta}$ under $\vec{S}$, with $w$ the
+/// number of bits of the integers and $\beta$ the decomposition base log.
+///
+/// The ciphertexts are stored level after level, starting with the first level, and within a level
+/// in the order of the quadratic part of the tensor product.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandardGlweRelinearizationKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) poly_size: PolynomialSize,
+    pub(crate) glwe_size: GlweSize,
+    pub(crate) decomp_base_log: DecompositionBaseLog,
+    pub(crate) decomp_level_count: DecompositionLevelCount,
+}
+
+tensor_traits!(StandardGlweRelinearizationKey);
+
+impl<Scalar> StandardGlweRelinearizationKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a new relinearization key, whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::StandardGlweRelinearizationKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let rlk = StandardGlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(rlk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(rlk.decomposition_base_log(), DecompositionBaseLog(5));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        let ciphertext_count =
+            decomp_level_count.0 * tensor_product_quadratic_count(glwe_dimension).0;
+        StandardGlweRelinearizationKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                ciphertext_count * glwe_size.0 * poly_size.0
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+}
+
+impl<Cont> StandardGlweRelinearizationKey<Cont> {
+    /// Creates a relinearization key from an existing container.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::StandardGlweRelinearizationKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let rlk = StandardGlweRelinearizationKey::from_container(
+    ///     vec![0 as u32; 3 * 3 * 3 * 256],
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        let glwe_size = glwe_dimension.to_glwe_size();
+        ck_dim_div!(tensor.len() =>
+            decomp_level_count.0,
+            tensor_product_quadratic_count(glwe_dimension).0,
+            glwe_size.0,
+            poly_size.0
+        );
+        StandardGlweRelinearizationKey {
+            tensor,
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+
+    /// Returns the size of the polynomials of the key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the GLWE ciphertexts the key can relinearize.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
+    pub fn as_view(&self) -> StandardGlweRelinearizationKey<&'_ [Cont::Element]>
+    where
+        Cont: Container,
+    {
+        StandardGlweRelinearizationKey {
+            tensor: Tensor::from_container(self.tensor.as_container().as_ref()),
+            poly_size: self.poly_size,
+            glwe_size: self.glwe_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+        }
+    }
+
+    /// Returns a borrowed list of the GLWE ciphertexts contained in the key.
+    pub fn as_glwe_list(&self) -> GlweList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        GlweList::from_container(
+            self.as_tensor().as_slice(),
+            self.glwe_dimension(),
+            self.poly_size,
+        )
+    }
+
+    /// Returns a mutably borrowed list of the GLWE ciphertexts contained in the key.
+    pub fn as_mut_glwe_list(&mut self) -> GlweList<&mut [<Self as AsMutTensor>::Element]>
+    where
+        Self: AsMutTensor,
+    {
+        let glwe_dimension = self.glwe_dimension();
+        let poly_size = self.poly_size;
+        GlweList::from_container(
+            self.as_mut_tensor().as_mut_slice(),
+            glwe_dimension,
+            poly_size,
+        )
+    }
+
+    /// Fills the current key with encryptions of the products of the polynomials of `secret_key`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::StandardGlweRelinearizationKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut rlk = StandardGlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    /// );
+    /// rlk.fill_with_relinearization_key(
+    ///     &secret_key,
+    ///     LogStandardDev::from_log_standard_dev(-25.),
+    ///     &mut encryption_generator,
+    /// );
+    /// assert!(!rlk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_relinearization_key<Kind, KeyCont, Scalar, Gen>(
+        &mut self,
+        secret_key: &GlweSecretKey<Kind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Kind: KeyKind,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(secret_key.key_size().0 => self.glwe_dimension().0);
+        ck_dim_eq!(secret_key.polynomial_size().0 => self.poly_size.0);
+
+        // We compute the products of the polynomials of the secret key once.
+        let key_polynomials = secret_key.as_polynomial_list();
+        let glwe_dimension = self.glwe_dimension().0;
+        let mut products =
+            Vec::with_capacity(tensor_product_quadratic_count(self.glwe_dimension()).0);
+        for i in 0..glwe_dimension {
+            for j in i..glwe_dimension {
+                let mut product = Polynomial::allocate(Scalar::ZERO, self.poly_size);
+                product.fill_with_wrapping_mul(
+                    &key_polynomials.get_polynomial(i),
+                    &key_polynomials.get_polynomial(j),
+                );
+                products.push(product);
+            }
+        }
+
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count.0;
+        let mut encoded = PlaintextList::allocate(Scalar::ZERO, PlaintextCount(self.poly_size.0));
+        let mut glwe_list = self.as_mut_glwe_list();
+        let mut ciphertexts = glwe_list.ciphertext_iter_mut();
+        for level in (1..=decomp_level_count).map(DecompositionLevel) {
+            for product in products.iter() {
+                for (plaintext, coefficient) in encoded
+                    .as_mut_tensor()
+                    .iter_mut()
+                    .zip(product.coefficient_iter())
+                {
+                    *plaintext = DecompositionTerm::new(level, decomp_base_log, *coefficient)
+                        .to_recomposition_summand();
+                }
+                secret_key.encrypt_glwe(
+                    &mut ciphertexts.next().unwrap(),
+                    &encoded,
+                    noise_parameters,
+                    generator,
+                );
+            }
+        }
+    }
+
+    /// Relinearizes a tensor product, and writes the resulting GLWE ciphertext in `output`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{
+    ///     GlweCiphertext, GlweTensorProduct, StandardGlweRelinearizationKey,
+    /// };
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, LogStandardDev,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_dimension = GlweDimension(1);
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(glwe_dimension, poly_size, &mut secret_generator);
+    /// let mut rlk = StandardGlweRelinearizationKey::allocate(
+    ///     0 as u64,
+    ///     poly_size,
+    ///     glwe_dimension,
+    ///     DecompositionLevelCount(4),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// rlk.fill_with_relinearization_key(&secret_key, noise, &mut encryption_generator);
+    ///
+    /// // We encrypt the constant polynomials 3 and 2, with a scaling of 2^60.
+    /// let delta_log = DeltaLog(60);
+    /// let mut messages = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// let mut lhs = GlweCiphertext::allocate(0u64, poly_size, glwe_dimension.to_glwe_size());
+    /// let mut rhs = GlweCiphertext::allocate(0u64, poly_size, glwe_dimension.to_glwe_size());
+    /// *messages.as_mut_tensor().first_mut() = 3 << delta_log.0;
+    /// secret_key.encrypt_glwe(&mut lhs, &messages, noise, &mut encryption_generator);
+    /// *messages.as_mut_tensor().first_mut() = 2 << delta_log.0;
+    /// secret_key.encrypt_glwe(&mut rhs, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut tensor_product = GlweTensorProduct::allocate(0u64, poly_size, glwe_dimension);
+    /// tensor_product.fill_with_tensor_product(&lhs, &rhs, delta_log);
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, glwe_dimension.to_glwe_size());
+    /// rlk.relinearize(&mut output, &tensor_product);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// secret_key.decrypt_glwe(&mut decrypted, &output);
+    /// let rounded = decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .map(|a| (a.wrapping_add(1 << (delta_log.0 - 1)) >> delta_log.0) % 16)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(rounded[0], 6);
+    /// assert!(rounded[1..].iter().all(|a| *a == 0));
+    /// ```
+    pub fn relinearize<Scalar, OutCont, InCont>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        tensor_product: &GlweTensorProduct<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweTensorProduct<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => output.polynomial_size().0, tensor_product.polynomial_size().0);
+        ck_dim_eq!(self.glwe_size.0 => output.size().0);
+        ck_dim_eq!(self.glwe_dimension().0 => tensor_product.glwe_dimension().0);
+
+        // The linear part of the tensor product is already a valid GLWE ciphertext.
+        output
+            .as_mut_tensor()
+            .fill_with_copy(tensor_product.as_linear_part().as_tensor());
+
+        // We add the external products of every decomposed polynomial of the quadratic part, with
+        // the encryptions of the corresponding product of secret polynomials.
+        let pair_count = tensor_product_quadratic_count(self.glwe_dimension()).0;
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let glwe_list = self.as_glwe_list();
+        let ciphertexts = glwe_list.ciphertext_iter().collect::<Vec<_>>();
+        for (pair_index, quadratic_polynomial) in tensor_product
+            .as_quadratic_part()
+            .polynomial_iter()
+            .enumerate()
+        {
+            let mut decomposition = decomposer.decompose_tensor(&quadratic_polynomial);
+            while let Some(term) = decomposition.next_term() {
+                let level_index = term.level().0 - 1;
+                let ciphertext = &ciphertexts[level_index * pair_count + pair_index];
+                let term_polynomial = Polynomial::from_container(term.as_tensor().as_slice());
+                for (mut output_polynomial, key_polynomial) in output
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(ciphertext.as_polynomial_list().polynomial_iter())
+                {
+                    output_polynomial
+                        .update_with_wrapping_add_mul(&term_polynomial, &key_polynomial);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use super::glwe_keyswitch::encode_keyswitch_key_messages;
+use super::{GlweKeyswitchKey, GlweSeededList};
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::random::{
+    ByteRandomGenerator, CompressionSeed, RandomGenerable, Seeder, Uniform,
+};
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension, GlweSize,
+    KeyKind, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A seeded GLWE keyswitching key.
+///
+/// This is the seeded counterpart of [`GlweKeyswitchKey`]: only the bodies of the GLWE
+/// ciphertexts are stored, and the masks are regenerated from the compression seed when the key is
+/// expanded.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSeededKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(GlweSeededKeyswitchKey);
+
+impl<Scalar> GlweSeededKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates a seeded GLWE keyswitching key whose bodies are all zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweSeededKeyswitchKey;
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::seeders::Seed;
+    /// let ksk = GlweSeededKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(3),
+    ///     GlweDimension(3),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// assert_eq!(ksk.decomposition_level_count(), DecompositionLevelCount(10));
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(3));
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: GlweDimension,
+        output_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        GlweSeededKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                input_dimension.0 * decomp_size.0 * poly_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            poly_size,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> GlweSeededKeyswitchKey<Cont> {
+    /// Returns the dimension of the input GLWE key.
+    pub fn input_glwe_dimension(&self) -> GlweDimension
+    where
+        Self: AsRefTensor,
+    {
+        GlweDimension(self.as_tensor().len() / (self.poly_size.0 * self.decomp_level_count.0))
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the input and output keys.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the compression seed used to generate the masks of the key.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    fn as_seeded_glwe_list(&self) -> GlweSeededList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.poly_size.0);
+        GlweSeededList::from_container(
+            self.as_tensor().as_slice(),
+            self.output_glwe_dimension(),
+            self.poly_size,
+            self.compression_seed,
+        )
+    }
+
+    fn as_mut_seeded_glwe_list(&mut self) -> GlweSeededList<&mut [<Self as AsMutTensor>::Element]>
+    where
+        Self: AsMutTensor,
+    {
+        let output_glwe_dimension = self.output_glwe_dimension();
+        let poly_size = self.poly_size;
+        let compression_seed = self.compression_seed;
+        GlweSeededList::from_container(
+            self.as_mut_tensor().as_mut_slice(),
+            output_glwe_dimension,
+            poly_size,
+            compression_seed,
+        )
+    }
+
+    /// Fills the current seeded keyswitching key container with an actual seeded keyswitching key
+    /// constructed from an input and an output GLWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::GlweSeededKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let input_key: GlweSecretKey<_, Vec<u32>> = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut ksk = GlweSeededKeyswitchKey::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     GlweDimension(2),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-25.),
+    ///     &mut UnixSeeder::new(0),
+    /// );
+    /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_seeded_keyswitch_key<
+        InKind,
+        OutKind,
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_key: &GlweSecretKey<InKind, InKeyCont>,
+        output_key: &GlweSecretKey<OutKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(input_key.key_size().0 => self.input_glwe_dimension().0);
+        ck_dim_eq!(output_key.key_size().0 => self.output_glwe_dimension().0);
+        ck_dim_eq!(self.poly_size.0 =>
+            input_key.polynomial_size().0,
+            output_key.polynomial_size().0
+        );
+
+        let messages =
+            encode_keyswitch_key_messages(input_key, self.decomp_base_log, self.decomp_level_count);
+
+        output_key.encrypt_seeded_glwe_list::<_, _, _, _, _, Gen>(
+            &mut self.as_mut_seeded_glwe_list(),
+            &messages,
+            noise_parameters,
+            noise_seeder,
+        );
+    }
+
+    /// Expands the seeded keyswitching key into a standard keyswitching key, by regenerating the
+    /// masks from the compression seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::{GlweKeyswitchKey, GlweSeededKeyswitchKey};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let input_key: GlweSecretKey<_, Vec<u32>> = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut seeded_ksk = GlweSeededKeyswitchKey::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     GlweDimension(2),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// seeded_ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-25.),
+    ///     &mut UnixSeeder::new(0),
+    /// );
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     GlweDimension(2),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    /// );
+    /// seeded_ksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut ksk);
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(self, output: &mut GlweKeyswitchKey<OutCont>)
+    where
+        GlweKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_glwe_dimension().0 => output.input_glwe_dimension().0);
+        ck_dim_eq!(self.output_glwe_dimension().0 => output.output_glwe_dimension().0);
+        ck_dim_eq!(self.poly_size.0 => output.polynomial_size().0);
+
+        self.as_seeded_glwe_list()
+            .expand_into::<_, _, Gen>(&mut output.as_mut_glwe_list());
+    }
+}
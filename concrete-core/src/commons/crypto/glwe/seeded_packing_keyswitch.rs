@@ -0,0 +1,1331 @@
+use super::{
+    GlweList, GlweSeededList, LwePackingKeyswitchKey, LwePrivateFunctionalPackingKeyswitchKey,
+    LwePrivateFunctionalPackingKeyswitchKeyList,
+};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::commons::math::polynomial::{Polynomial, PolynomialList};
+use crate::commons::math::random::{
+    ByteRandomGenerator, CompressionSeed, RandomGenerable, Seeder, Uniform,
+};
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::Numeric;
+use crate::prelude::{
+    BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount, DispersionParameter,
+    FunctionalPackingKeyswitchKeyCount, GlweDimension, GlweSize, LweDimension, LweSize,
+    MonomialDegree, PlaintextCount, PolynomialCount, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A seeded packing keyswitching key.
+///
+/// This is the seeded counterpart of [`LwePackingKeyswitchKey`]: only the bodies of the GLWE
+/// ciphertexts are stored, and the masks are regenerated from the compression seed when the key is
+/// expanded.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPackingKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(LweSeededPackingKeyswitchKey);
+
+impl<Scalar> LweSeededPackingKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates a seeded packing keyswitching key whose bodies are all zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::LweSeededPackingKeyswitchKey;
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::seeders::Seed;
+    /// let pksk = LweSeededPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(3),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// assert_eq!(
+    ///     pksk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(pksk.decomposition_base_log(), DecompositionBaseLog(3));
+    /// assert_eq!(pksk.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(pksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(pksk.output_polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        LweSeededPackingKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                input_dimension.0
+                    * decomp_size.0
+                    * output_polynomial_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> LweSeededPackingKeyswitchKey<Cont> {
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(
+            self.as_tensor().len() / (self.output_polynomial_size.0 * self.decomp_level_count.0),
+        )
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials composing the GLWE ciphertexts.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the compression seed used to generate the masks of the key.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Fills the current seeded packing keyswitching key container with an actual seeded packing
+    /// keyswitching key constructed from an input LWE secret key and an output GLWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::LweSeededPackingKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let input_key: LweSecretKey<_, Vec<u32>> =
+    ///     LweSecretKey::generate_binary(LweDimension(10), &mut secret_generator);
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut pksk = LweSeededPackingKeyswitchKey::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pksk.fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-25.),
+    ///     &mut UnixSeeder::new(0),
+    /// );
+    /// assert!(!pksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_seeded_packing_keyswitch_key<
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(input_lwe_key.key_size().0 => self.input_lwe_key_dimension().0);
+        ck_dim_eq!(output_glwe_key.key_size().0 => self.output_glwe_key_dimension().0);
+        ck_dim_eq!(output_glwe_key.polynomial_size().0 => self.output_polynomial_size.0);
+
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let polynomial_size = self.output_polynomial_size;
+
+        let mut messages =
+            PlaintextList::allocate(Scalar::ZERO, PlaintextCount(self.as_tensor().len()));
+        for (input_key_bit, mut block) in input_lwe_key.as_tensor().iter().zip(
+            messages.sublist_iter_mut(PlaintextCount(decomp_level_count.0 * polynomial_size.0)),
+        ) {
+            for (level, mut message) in (1..=decomp_level_count.0)
+                .map(DecompositionLevel)
+                .zip(block.sublist_iter_mut(PlaintextCount(polynomial_size.0)))
+            {
+                *message.as_mut_tensor().first_mut() =
+                    DecompositionTerm::new(level, decomp_base_log, *input_key_bit)
+                        .to_recomposition_summand();
+            }
+        }
+
+        let output_glwe_size = self.output_glwe_size;
+        let compression_seed = self.compression_seed;
+        output_glwe_key.encrypt_seeded_glwe_list::<_, _, _, _, _, Gen>(
+            &mut as_mut_seeded_glwe_list(
+                self.as_mut_tensor().as_mut_slice(),
+                output_glwe_size,
+                polynomial_size,
+                compression_seed,
+            ),
+            &messages,
+            noise_parameters,
+            noise_seeder,
+        );
+    }
+
+    /// Expands the seeded packing keyswitching key into a standard packing keyswitching key, by
+    /// regenerating the masks from the compression seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::{
+    ///     LwePackingKeyswitchKey, LweSeededPackingKeyswitchKey,
+    /// };
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let input_key: LweSecretKey<_, Vec<u32>> =
+    ///     LweSecretKey::generate_binary(LweDimension(10), &mut secret_generator);
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut seeded_pksk = LweSeededPackingKeyswitchKey::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// seeded_pksk.fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-25.),
+    ///     &mut UnixSeeder::new(0),
+    /// );
+    /// let mut pksk = LwePackingKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// seeded_pksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut pksk);
+    /// assert_eq!(pksk.input_lwe_key_dimension(), LweDimension(10));
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(self, output: &mut LwePackingKeyswitchKey<OutCont>)
+    where
+        LwePackingKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => output.input_lwe_key_dimension().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output.output_glwe_key_dimension().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output.output_polynomial_size().0);
+
+        expand_seeded_glwe_tensor::<_, Gen>(
+            self.as_tensor().as_slice(),
+            output.as_mut_tensor().as_mut_slice(),
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.compression_seed,
+        );
+    }
+}
+
+/// A seeded private functional packing keyswitching key.
+///
+/// This is the seeded counterpart of [`LwePrivateFunctionalPackingKeyswitchKey`]: only the bodies
+/// of the GLWE ciphertexts are stored, and the masks are regenerated from the compression seed
+/// when the key is expanded.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(LweSeededPrivateFunctionalPackingKeyswitchKey);
+
+impl<Scalar> LweSeededPrivateFunctionalPackingKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates a seeded private functional packing keyswitching key whose bodies are all zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKey;
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::seeders::Seed;
+    /// let pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(3),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// assert_eq!(
+    ///     pfpksk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(pfpksk.decomposition_base_log(), DecompositionBaseLog(3));
+    /// assert_eq!(pfpksk.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(pfpksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// ```
+    pub fn allocate(
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        LweSeededPrivateFunctionalPackingKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                input_dimension.to_lwe_size().0
+                    * decomp_size.0
+                    * output_polynomial_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> LweSeededPrivateFunctionalPackingKeyswitchKey<Cont> {
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweSize(
+            self.as_tensor().len() / (self.output_polynomial_size.0 * self.decomp_level_count.0),
+        )
+        .to_lwe_dimension()
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials composing the GLWE ciphertexts.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the compression seed used to generate the masks of the key.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Fills the current seeded private functional packing keyswitching key container with an
+    /// actual seeded key, applying the function `f` and multiplying by `polynomial` the same way
+    /// as [`LwePrivateFunctionalPackingKeyswitchKey::fill_with_private_functional_packing_keyswitch_key`]
+    /// does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::polynomial::Polynomial;
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let input_key: LweSecretKey<_, Vec<u32>> =
+    ///     LweSecretKey::generate_binary(LweDimension(10), &mut secret_generator);
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// let polynomial = Polynomial::allocate(1_u32, PolynomialSize(256));
+    /// pfpksk
+    ///     .fill_with_seeded_private_functional_packing_keyswitch_key::<
+    ///         _,
+    ///         _,
+    ///         _,
+    ///         _,
+    ///         _,
+    ///         _,
+    ///         _,
+    ///         SoftwareRandomGenerator,
+    ///     >(
+    ///         &input_key,
+    ///         &output_key,
+    ///         LogStandardDev::from_log_standard_dev(-25.),
+    ///         &mut UnixSeeder::new(0),
+    ///         &|x| x,
+    ///         &polynomial,
+    ///     );
+    /// assert!(!pfpksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_with_seeded_private_functional_packing_keyswitch_key<
+        InKeyCont,
+        OutKeyCont,
+        PolyCont,
+        Scalar,
+        ScalarFunc,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+        f: &ScalarFunc,
+        polynomial: &Polynomial<PolyCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        ScalarFunc: Fn(Scalar) -> Scalar + ?Sized,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(input_lwe_key.key_size().0 => self.input_lwe_key_dimension().0);
+        ck_dim_eq!(output_glwe_key.key_size().0 => self.output_glwe_key_dimension().0);
+        ck_dim_eq!(output_glwe_key.polynomial_size().0 => self.output_polynomial_size.0);
+        ck_dim_eq!(polynomial.polynomial_size().0 => self.output_polynomial_size.0);
+
+        let mut messages =
+            PlaintextList::allocate(Scalar::ZERO, PlaintextCount(self.as_tensor().len()));
+        encode_private_functional_packing_keyswitch_key_messages(
+            &mut messages,
+            input_lwe_key,
+            self.decomp_base_log,
+            self.decomp_level_count,
+            f,
+            polynomial,
+        );
+
+        let output_glwe_size = self.output_glwe_size;
+        let output_polynomial_size = self.output_polynomial_size;
+        let compression_seed = self.compression_seed;
+        output_glwe_key.encrypt_seeded_glwe_list::<_, _, _, _, _, Gen>(
+            &mut as_mut_seeded_glwe_list(
+                self.as_mut_tensor().as_mut_slice(),
+                output_glwe_size,
+                output_polynomial_size,
+                compression_seed,
+            ),
+            &messages,
+            noise_parameters,
+            noise_seeder,
+        );
+    }
+
+    /// Expands the seeded private functional packing keyswitching key into a standard private
+    /// functional packing keyswitching key, by regenerating the masks from the compression seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::{
+    ///     LwePrivateFunctionalPackingKeyswitchKey, LweSeededPrivateFunctionalPackingKeyswitchKey,
+    /// };
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let seeded_pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// let mut pfpksk = LwePrivateFunctionalPackingKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// seeded_pfpksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut pfpksk);
+    /// assert_eq!(pfpksk.input_lwe_key_dimension(), LweDimension(10));
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(
+        self,
+        output: &mut LwePrivateFunctionalPackingKeyswitchKey<OutCont>,
+    ) where
+        LwePrivateFunctionalPackingKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => output.input_lwe_key_dimension().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output.output_glwe_key_dimension().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output.output_polynomial_size().0);
+
+        expand_seeded_glwe_tensor::<_, Gen>(
+            self.as_tensor().as_slice(),
+            output.as_mut_tensor().as_mut_slice(),
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.compression_seed,
+        );
+    }
+}
+
+/// A seeded private functional packing keyswitching key list.
+///
+/// This is the seeded counterpart of [`LwePrivateFunctionalPackingKeyswitchKeyList`]. A single
+/// compression seed is used for the whole list, the masks of all the keys being generated one
+/// after the other.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKeyList<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_lwe_size: LweSize,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(LweSeededPrivateFunctionalPackingKeyswitchKeyList);
+
+impl<Scalar> LweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates a seeded list of private functional packing keyswitching keys whose bodies are
+    /// all zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::seeders::Seed;
+    /// let pfpksk_list = LweSeededPrivateFunctionalPackingKeyswitchKeyList::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(3),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// assert_eq!(
+    ///     pfpksk_list.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(
+    ///     pfpksk_list.decomposition_base_log(),
+    ///     DecompositionBaseLog(3)
+    /// );
+    /// assert_eq!(pfpksk_list.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(pfpksk_list.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(
+    ///     pfpksk_list.fpksk_count(),
+    ///     FunctionalPackingKeyswitchKeyCount(3)
+    /// );
+    /// ```
+    pub fn allocate(
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        fpksk_count: FunctionalPackingKeyswitchKeyCount,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        LweSeededPrivateFunctionalPackingKeyswitchKeyList {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                input_dimension.to_lwe_size().0
+                    * decomp_size.0
+                    * output_polynomial_size.0
+                    * fpksk_count.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            input_lwe_size: input_dimension.to_lwe_size(),
+            output_glwe_size: output_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> LweSeededPrivateFunctionalPackingKeyswitchKeyList<Cont> {
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension {
+        self.input_lwe_size.to_lwe_dimension()
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials composing the GLWE ciphertexts.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the compression seed used to generate the masks of the keys.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Returns the number of private functional packing keyswitch keys in the list.
+    pub fn fpksk_count(&self) -> FunctionalPackingKeyswitchKeyCount
+    where
+        Self: AsRefTensor,
+    {
+        let single_ksk_size =
+            self.output_polynomial_size.0 * self.decomp_level_count.0 * self.input_lwe_size.0;
+        ck_dim_div!(self.as_tensor().len() => single_ksk_size);
+        FunctionalPackingKeyswitchKeyCount(self.as_tensor().len() / single_ksk_size)
+    }
+
+    /// Fills the current list with the seeded private functional packing keyswitching keys
+    /// needed by the circuit bootstrap, in the same way as
+    /// [`LwePrivateFunctionalPackingKeyswitchKeyList::fill_with_fpksk_for_circuit_bootstrap`]
+    /// does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LogStandardDev, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let input_key: LweSecretKey<_, Vec<u32>> =
+    ///     LweSecretKey::generate_binary(LweDimension(10), &mut secret_generator);
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut pfpksk_list = LweSeededPrivateFunctionalPackingKeyswitchKeyList::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pfpksk_list
+    ///     .fill_with_seeded_fpksk_for_circuit_bootstrap::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///         &input_key,
+    ///         &output_key,
+    ///         LogStandardDev::from_log_standard_dev(-25.),
+    ///         &mut UnixSeeder::new(0),
+    ///     );
+    /// assert!(!pfpksk_list.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_seeded_fpksk_for_circuit_bootstrap<
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(input_lwe_key.key_size().0 => self.input_lwe_key_dimension().0);
+        ck_dim_eq!(output_glwe_key.key_size().0 => self.output_glwe_key_dimension().0);
+        ck_dim_eq!(output_glwe_key.polynomial_size().0 => self.output_polynomial_size.0);
+        ck_dim_eq!(self.fpksk_count().0 => output_glwe_key.key_size().to_glwe_size().0);
+
+        let mut last_polynomial_as_list = PolynomialList::allocate(
+            Scalar::ZERO,
+            PolynomialCount(1),
+            output_glwe_key.polynomial_size(),
+        );
+        // As in the standard key list, we use Scalar::MAX == -Scalar::ONE for the last key so that
+        // the same x -> -x function can be used for every key.
+        *last_polynomial_as_list
+            .get_mut_polynomial(0)
+            .get_mut_monomial(MonomialDegree(0))
+            .get_mut_coefficient() = Scalar::MAX;
+
+        let mut messages =
+            PlaintextList::allocate(Scalar::ZERO, PlaintextCount(self.as_tensor().len()));
+        let single_ksk_size =
+            self.output_polynomial_size.0 * self.decomp_level_count.0 * self.input_lwe_size.0;
+        for (mut fpksk_messages, polynomial_to_encrypt) in messages
+            .sublist_iter_mut(PlaintextCount(single_ksk_size))
+            .zip(
+                output_glwe_key
+                    .as_polynomial_list()
+                    .polynomial_iter()
+                    .chain(last_polynomial_as_list.polynomial_iter()),
+            )
+        {
+            encode_private_functional_packing_keyswitch_key_messages(
+                &mut fpksk_messages,
+                input_lwe_key,
+                self.decomp_base_log,
+                self.decomp_level_count,
+                &|x| Scalar::ZERO.wrapping_sub(x),
+                &polynomial_to_encrypt,
+            );
+        }
+
+        let output_glwe_size = self.output_glwe_size;
+        let output_polynomial_size = self.output_polynomial_size;
+        let compression_seed = self.compression_seed;
+        output_glwe_key.encrypt_seeded_glwe_list::<_, _, _, _, _, Gen>(
+            &mut as_mut_seeded_glwe_list(
+                self.as_mut_tensor().as_mut_slice(),
+                output_glwe_size,
+                output_polynomial_size,
+                compression_seed,
+            ),
+            &messages,
+            noise_parameters,
+            noise_seeder,
+        );
+    }
+
+    /// Expands the seeded list into a standard list of private functional packing keyswitching
+    /// keys, by regenerating the masks from the compression seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::crypto::glwe::{
+    ///     LwePrivateFunctionalPackingKeyswitchKeyList,
+    ///     LweSeededPrivateFunctionalPackingKeyswitchKeyList,
+    /// };
+    /// use concrete_core::commons::math::random::CompressionSeed;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let seeded_list = LweSeededPrivateFunctionalPackingKeyswitchKeyList::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// let mut list = LwePrivateFunctionalPackingKeyswitchKeyList::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    /// );
+    /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
+    /// assert_eq!(list.fpksk_count(), FunctionalPackingKeyswitchKeyCount(3));
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(
+        self,
+        output: &mut LwePrivateFunctionalPackingKeyswitchKeyList<OutCont>,
+    ) where
+        LwePrivateFunctionalPackingKeyswitchKeyList<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => output.input_lwe_key_dimension().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output.output_glwe_key_dimension().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output.output_polynomial_size().0);
+        ck_dim_eq!(self.fpksk_count().0 => output.fpksk_count().0);
+
+        expand_seeded_glwe_tensor::<_, Gen>(
+            self.as_tensor().as_slice(),
+            output.as_mut_tensor().as_mut_slice(),
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.compression_seed,
+        );
+    }
+}
+
+// Writes in `messages` the plaintexts encrypted by a private functional packing keyswitch key: for
+// every bit of the input key (followed by a -1 for the body), and every level, the polynomial
+// multiplied by the decomposition term of f(1) times the bit.
+fn encode_private_functional_packing_keyswitch_key_messages<
+    MsgCont,
+    KeyCont,
+    PolyCont,
+    Scalar,
+    ScalarFunc,
+>(
+    messages: &mut PlaintextList<MsgCont>,
+    input_lwe_key: &LweSecretKey<BinaryKeyKind, KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    f: &ScalarFunc,
+    polynomial: &Polynomial<PolyCont>,
+) where
+    PlaintextList<MsgCont>: AsMutTensor<Element = Scalar>,
+    LweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+    Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+    ScalarFunc: Fn(Scalar) -> Scalar + ?Sized,
+{
+    let polynomial_size = polynomial.polynomial_size().0;
+    let last_key_iter_bit = [Scalar::MAX];
+    let input_key_bit_iter = input_lwe_key
+        .as_tensor()
+        .iter()
+        .chain(last_key_iter_bit.iter());
+    for (&input_key_bit, mut block) in input_key_bit_iter
+        .zip(messages.sublist_iter_mut(PlaintextCount(decomp_level_count.0 * polynomial_size)))
+    {
+        for (level, mut message) in (1..=decomp_level_count.0)
+            .map(DecompositionLevel)
+            .zip(block.sublist_iter_mut(PlaintextCount(polynomial_size)))
+        {
+            message
+                .as_mut_tensor()
+                .update_with_wrapping_add_element_mul(
+                    polynomial.as_tensor(),
+                    DecompositionTerm::new(
+                        level,
+                        decomp_base_log,
+                        f(Scalar::ONE).wrapping_mul(input_key_bit),
+                    )
+                    .to_recomposition_summand(),
+                );
+        }
+    }
+}
+
+fn as_mut_seeded_glwe_list<Scalar>(
+    bodies: &mut [Scalar],
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+) -> GlweSeededList<&mut [Scalar]> {
+    GlweSeededList::from_container(
+        bodies,
+        glwe_size.to_glwe_dimension(),
+        polynomial_size,
+        compression_seed,
+    )
+}
+
+// Regenerates the masks of a tensor of GLWE ciphertexts stored one after the other, from the
+// bodies and the compression seed.
+fn expand_seeded_glwe_tensor<Scalar, Gen>(
+    bodies: &[Scalar],
+    output: &mut [Scalar],
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+) where
+    Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    ck_dim_eq!(bodies.len() * glwe_size.0 => output.len());
+    GlweSeededList::from_container(
+        bodies,
+        glwe_size.to_glwe_dimension(),
+        polynomial_size,
+        compression_seed,
+    )
+    .expand_into::<_, _, Gen>(&mut GlweList::from_container(
+        output,
+        glwe_size.to_glwe_dimension(),
+        polynomial_size,
+    ));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::commons::crypto::encoding::PlaintextList;
+    use crate::commons::crypto::glwe::{
+        GlweList, LwePackingKeyswitchKey, LwePrivateFunctionalPackingKeyswitchKey,
+        LwePrivateFunctionalPackingKeyswitchKeyList, LweSeededPackingKeyswitchKey,
+        LweSeededPrivateFunctionalPackingKeyswitchKey,
+        LweSeededPrivateFunctionalPackingKeyswitchKeyList,
+    };
+    use crate::commons::crypto::secret::generators::DeterministicSeeder;
+    use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    use crate::commons::math::decomposition::SignedDecomposer;
+    use crate::commons::math::polynomial::Polynomial;
+    use crate::commons::math::random::CompressionSeed;
+    use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    use crate::commons::math::torus::UnsignedTorus;
+    use crate::commons::test_tools::{
+        any_uint, any_usize, new_encryption_random_generator, new_secret_random_generator,
+        random_usize_between,
+    };
+    use crate::prelude::{
+        BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount,
+        FunctionalPackingKeyswitchKeyCount, GlweDimension, LogStandardDev, LweDimension,
+        PlaintextCount, PolynomialSize,
+    };
+    use concrete_csprng::generators::SoftwareRandomGenerator;
+    use concrete_csprng::seeders::Seed;
+
+    struct Parameters {
+        input_lwe_dimension: LweDimension,
+        output_glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        noise: LogStandardDev,
+    }
+
+    fn random_parameters() -> Parameters {
+        Parameters {
+            input_lwe_dimension: LweDimension(random_usize_between(5..10)),
+            output_glwe_dimension: GlweDimension(random_usize_between(1..3)),
+            polynomial_size: PolynomialSize(256),
+            decomp_base_log: DecompositionBaseLog(random_usize_between(2..5)),
+            decomp_level_count: DecompositionLevelCount(random_usize_between(2..5)),
+            noise: LogStandardDev::from_log_standard_dev(-25.),
+        }
+    }
+
+    // Decrypts the GLWE ciphertexts of an expanded seeded key and of a key generated with the
+    // standard path, and checks that they encrypt the same messages, up to the noise.
+    fn assert_encrypt_same_messages<T: UnsignedTorus>(
+        expanded: &[T],
+        reference: &[T],
+        output_key: &GlweSecretKey<BinaryKeyKind, Vec<T>>,
+        parameters: &Parameters,
+    ) {
+        assert_eq!(expanded.len(), reference.len());
+        let decrypt = |ciphertexts: &[T]| {
+            let list = GlweList::from_container(
+                ciphertexts,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+            );
+            let mut messages = PlaintextList::allocate(
+                T::ZERO,
+                PlaintextCount(list.ciphertext_count().0 * parameters.polynomial_size.0),
+            );
+            output_key.decrypt_glwe_list(&mut messages, &list);
+            messages
+        };
+        let decomposer =
+            SignedDecomposer::<T>::new(parameters.decomp_base_log, parameters.decomp_level_count);
+        let expanded_messages = decrypt(expanded);
+        let reference_messages = decrypt(reference);
+        for (expanded, reference) in expanded_messages
+            .as_tensor()
+            .iter()
+            .zip(reference_messages.as_tensor().iter())
+        {
+            assert_eq!(
+                decomposer.closest_representable(*expanded),
+                decomposer.closest_representable(*reference)
+            );
+        }
+    }
+
+    fn test_seeded_pksk_matches_pksk<T: UnsignedTorus>() {
+        for _ in 0..10 {
+            let parameters = random_parameters();
+            let mut secret_generator = new_secret_random_generator();
+            let input_key: LweSecretKey<_, Vec<T>> = LweSecretKey::generate_binary(
+                parameters.input_lwe_dimension,
+                &mut secret_generator,
+            );
+            let output_key = GlweSecretKey::generate_binary(
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+                &mut secret_generator,
+            );
+
+            let mut pksk = LwePackingKeyswitchKey::allocate(
+                T::ZERO,
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.input_lwe_dimension,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+            );
+            pksk.fill_with_packing_keyswitch_key(
+                &input_key,
+                &output_key,
+                parameters.noise,
+                &mut new_encryption_random_generator(),
+            );
+
+            let mut seeded_pksk = LweSeededPackingKeyswitchKey::allocate(
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.input_lwe_dimension,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+                CompressionSeed {
+                    seed: Seed(any_usize() as u128),
+                },
+            );
+            seeded_pksk
+                .fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+                    &input_key,
+                    &output_key,
+                    parameters.noise,
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(
+                        any_usize() as u128
+                    )),
+                );
+            let mut expanded_pksk = LwePackingKeyswitchKey::allocate(
+                T::ZERO,
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.input_lwe_dimension,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+            );
+            seeded_pksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut expanded_pksk);
+
+            assert_encrypt_same_messages(
+                expanded_pksk.as_tensor().as_slice(),
+                pksk.as_tensor().as_slice(),
+                &output_key,
+                &parameters,
+            );
+        }
+    }
+
+    fn test_seeded_pfpksk_matches_pfpksk<T: UnsignedTorus>() {
+        for _ in 0..10 {
+            let parameters = random_parameters();
+            let mut secret_generator = new_secret_random_generator();
+            let input_key: LweSecretKey<_, Vec<T>> = LweSecretKey::generate_binary(
+                parameters.input_lwe_dimension,
+                &mut secret_generator,
+            );
+            let output_key = GlweSecretKey::generate_binary(
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+                &mut secret_generator,
+            );
+            let polynomial = Polynomial::from_container(
+                (0..parameters.polynomial_size.0)
+                    .map(|_| any_uint::<T>())
+                    .collect::<Vec<_>>(),
+            );
+
+            let mut pfpksk = LwePrivateFunctionalPackingKeyswitchKey::allocate(
+                T::ZERO,
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.input_lwe_dimension,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+            );
+            pfpksk.fill_with_private_functional_packing_keyswitch_key(
+                &input_key,
+                &output_key,
+                parameters.noise,
+                &mut new_encryption_random_generator(),
+                &|x| x,
+                &polynomial,
+            );
+
+            let mut seeded_pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::allocate(
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.input_lwe_dimension,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+                CompressionSeed {
+                    seed: Seed(any_usize() as u128),
+                },
+            );
+            seeded_pfpksk
+                .fill_with_seeded_private_functional_packing_keyswitch_key::<_, _, _, _, _, _, _, SoftwareRandomGenerator>(
+                    &input_key,
+                    &output_key,
+                    parameters.noise,
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(
+                        any_usize() as u128,
+                    )),
+                    &|x| x,
+                    &polynomial,
+                );
+            let mut expanded_pfpksk = LwePrivateFunctionalPackingKeyswitchKey::allocate(
+                T::ZERO,
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.input_lwe_dimension,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+            );
+            seeded_pfpksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut expanded_pfpksk);
+
+            assert_encrypt_same_messages(
+                expanded_pfpksk.as_tensor().as_slice(),
+                pfpksk.as_tensor().as_slice(),
+                &output_key,
+                &parameters,
+            );
+        }
+    }
+
+    fn test_seeded_cbs_pfpksk_list_matches_cbs_pfpksk_list<T: UnsignedTorus>() {
+        for _ in 0..10 {
+            let parameters = random_parameters();
+            let fpksk_count = FunctionalPackingKeyswitchKeyCount(
+                parameters.output_glwe_dimension.to_glwe_size().0,
+            );
+            let mut secret_generator = new_secret_random_generator();
+            let input_key: LweSecretKey<_, Vec<T>> = LweSecretKey::generate_binary(
+                parameters.input_lwe_dimension,
+                &mut secret_generator,
+            );
+            let output_key = GlweSecretKey::generate_binary(
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+                &mut secret_generator,
+            );
+
+            let mut pfpksk_list = LwePrivateFunctionalPackingKeyswitchKeyList::allocate(
+                T::ZERO,
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.input_lwe_dimension,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+                fpksk_count,
+            );
+            pfpksk_list.fill_with_fpksk_for_circuit_bootstrap(
+                &input_key,
+                &output_key,
+                parameters.noise,
+                &mut new_encryption_random_generator(),
+            );
+
+            let mut seeded_pfpksk_list =
+                LweSeededPrivateFunctionalPackingKeyswitchKeyList::allocate(
+                    parameters.decomp_level_count,
+                    parameters.decomp_base_log,
+                    parameters.input_lwe_dimension,
+                    parameters.output_glwe_dimension,
+                    parameters.polynomial_size,
+                    fpksk_count,
+                    CompressionSeed {
+                        seed: Seed(any_usize() as u128),
+                    },
+                );
+            seeded_pfpksk_list
+                .fill_with_seeded_fpksk_for_circuit_bootstrap::<_, _, _, _, _, SoftwareRandomGenerator>(
+                    &input_key,
+                    &output_key,
+                    parameters.noise,
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(
+                        any_usize() as u128,
+                    )),
+                );
+            let mut expanded_pfpksk_list = LwePrivateFunctionalPackingKeyswitchKeyList::allocate(
+                T::ZERO,
+                parameters.decomp_level_count,
+                parameters.decomp_base_log,
+                parameters.input_lwe_dimension,
+                parameters.output_glwe_dimension,
+                parameters.polynomial_size,
+                fpksk_count,
+            );
+            seeded_pfpksk_list
+                .expand_into::<_, _, SoftwareRandomGenerator>(&mut expanded_pfpksk_list);
+
+            assert_encrypt_same_messages(
+                expanded_pfpksk_list.as_tensor().as_slice(),
+                pfpksk_list.as_tensor().as_slice(),
+                &output_key,
+                &parameters,
+            );
+        }
+    }
+
+    #[test]
+    fn test_seeded_pksk_matches_pksk_u32() {
+        test_seeded_pksk_matches_pksk::<u32>()
+    }
+
+    #[test]
+    fn test_seeded_pksk_matches_pksk_u64() {
+        test_seeded_pksk_matches_pksk::<u64>()
+    }
+
+    #[test]
+    fn test_seeded_pfpksk_matches_pfpksk_u32() {
+        test_seeded_pfpksk_matches_pfpksk::<u32>()
+    }
+
+    #[test]
+    fn test_seeded_pfpksk_matches_pfpksk_u64() {
+        test_seeded_pfpksk_matches_pfpksk::<u64>()
+    }
+
+    #[test]
+    fn test_seeded_cbs_pfpksk_list_matches_cbs_pfpksk_list_u32() {
+        test_seeded_cbs_pfpksk_list_matches_cbs_pfpksk_list::<u32>()
+    }
+
+    #[test]
+    fn test_seeded_cbs_pfpksk_list_matches_cbs_pfpksk_list_u64() {
+        test_seeded_cbs_pfpksk_list_matches_cbs_pfpksk_list::<u64>()
+    }
+}
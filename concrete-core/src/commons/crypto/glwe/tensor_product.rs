@@ -0,0 +1,331 @@
+use super::GlweCiphertext;
+use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::numeric::{CastFrom, CastInto};
+use crate::prelude::{DeltaLog, GlweDimension, GlweSize, PolynomialCount, PolynomialSize};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// The rescaled tensor product of two GLWE ciphertexts.
+///
+/// Given two GLWE ciphertexts $(\vec{A}, B)$ and $(\vec{A'}, B')$ encrypted under the same secret
+/// key $\vec{S}$ of dimension $k$, their tensor product is made of:
+///
+/// + A linear part, which is a GLWE ciphertext of the same size:
+///   $$\left(A\_0B' + A'\_0B, \cdots, A\_{k-1}B' + A'\_{k-1}B, BB'\right)$$
+/// + A quadratic part, made of the $k(k+1)/2$ polynomials $R\_{i,j}$ for $0\leq i\leq j<k$,
+///   stored in lexicographic order, with $R\_{i,i} = A\_iA'\_i$ and
+///   $R\_{i,j} = A\_iA'\_j + A\_jA'\_i$ otherwise.
+///
+/// Every coefficient is computed on the centered representatives of the inputs, and divided by
+/// $\Delta=2^{\mathsf{delta\\_log}}$ with a rounding, such that the phase:
+/// $$BB' - \sum\_{i}(A\_iB' + A'\_iB)S\_i + \sum\_{i\leq j}R\_{i,j}S\_iS\_j$$
+/// approximates the product of the phases of the inputs divided by $\Delta$. The quadratic part
+/// can then be removed with a
+/// [`StandardGlweRelinearizationKey`](`super::StandardGlweRelinearizationKey`).
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweTensorProduct<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) poly_size: PolynomialSize,
+    pub(crate) glwe_size: GlweSize,
+}
+
+tensor_traits!(GlweTensorProduct);
+
+/// Returns the number of polynomials in the quadratic part of the tensor product of two GLWE
+/// ciphertexts of dimension `glwe_dimension`.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::commons::crypto::glwe::tensor_product_quadratic_count;
+/// use concrete_core::prelude::{GlweDimension, PolynomialCount};
+/// assert_eq!(
+///     tensor_product_quadratic_count(GlweDimension(3)),
+///     PolynomialCount(6)
+/// );
+/// ```
+pub fn tensor_product_quadratic_count(glwe_dimension: GlweDimension) -> PolynomialCount {
+    PolynomialCount(glwe_dimension.0 * (glwe_dimension.0 + 1) / 2)
+}
+
+/// Returns, for every polynomial of the tensor product of two GLWE ciphertexts of dimension
+/// `glwe_dimension`, the pairs of indices of the input polynomials whose products are summed to
+/// compute it.
+pub(crate) fn tensor_product_terms(glwe_dimension: GlweDimension) -> Vec<Vec<(usize, usize)>> {
+    let k = glwe_dimension.0;
+    let linear = (0..k)
+        .map(|i| vec![(i, k), (k, i)])
+        .chain(std::iter::once(vec![(k, k)]));
+    let quadratic = (0..k).flat_map(move |i| {
+        (i..k).map(move |j| {
+            if i == j {
+                vec![(i, i)]
+            } else {
+                vec![(i, j), (j, i)]
+            }
+        })
+    });
+    linear.chain(quadratic).collect()
+}
+
+/// Divides a product computed modulo $2^{128}$ by $2^{\mathsf{delta\\_log}}$ with a rounding, and
+/// reduces it modulo the size of the output integer.
+pub(crate) fn rescale_tensor_product_coefficient<Scalar>(value: u128, delta_log: DeltaLog) -> Scalar
+where
+    Scalar: CastFrom<u128>,
+{
+    if delta_log.0 == 0 {
+        value.cast_into()
+    } else {
+        (value.wrapping_add(1 << (delta_log.0 - 1)) >> delta_log.0).cast_into()
+    }
+}
+
+impl<Scalar> GlweTensorProduct<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a new tensor product, whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::GlweTensorProduct;
+    /// use concrete_core::prelude::{GlweDimension, PolynomialCount, PolynomialSize};
+    /// let tensor_product = GlweTensorProduct::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(tensor_product.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(tensor_product.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(tensor_product.polynomial_count(), PolynomialCount(6));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+    ) -> Self {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        let polynomial_count = glwe_size.0 + tensor_product_quadratic_count(glwe_dimension).0;
+        GlweTensorProduct {
+            tensor: Tensor::from_container(vec![value; polynomial_count * poly_size.0]),
+            poly_size,
+            glwe_size,
+        }
+    }
+}
+
+impl<Cont> GlweTensorProduct<Cont> {
+    /// Returns the size of the polynomials of the tensor product.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::GlweTensorProduct;
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize};
+    /// let tensor_product = GlweTensorProduct::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(tensor_product.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the ciphertexts the tensor product was computed from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::GlweTensorProduct;
+    /// use concrete_core::prelude::{GlweDimension, PolynomialSize};
+    /// let tensor_product = GlweTensorProduct::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(tensor_product.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the total number of polynomials of the tensor product.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::GlweTensorProduct;
+    /// use concrete_core::prelude::{GlweDimension, PolynomialCount, PolynomialSize};
+    /// let tensor_product = GlweTensorProduct::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// assert_eq!(tensor_product.polynomial_count(), PolynomialCount(6));
+    /// ```
+    pub fn polynomial_count(&self) -> PolynomialCount {
+        PolynomialCount(self.glwe_size.0 + tensor_product_quadratic_count(self.glwe_dimension()).0)
+    }
+
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
+    pub fn as_view(&self) -> GlweTensorProduct<&'_ [Cont::Element]>
+    where
+        Cont: Container,
+    {
+        GlweTensorProduct {
+            tensor: Tensor::from_container(self.tensor.as_container().as_ref()),
+            poly_size: self.poly_size,
+            glwe_size: self.glwe_size,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> GlweTensorProduct<&'_ mut [Cont::Element]>
+    where
+        Cont: Container,
+        Cont: AsMut<[Cont::Element]>,
+    {
+        GlweTensorProduct {
+            tensor: Tensor::from_container(self.tensor.as_mut_container().as_mut()),
+            poly_size: self.poly_size,
+            glwe_size: self.glwe_size,
+        }
+    }
+
+    /// Returns a borrowed list containing every polynomial of the tensor product, the linear part
+    /// first.
+    pub fn as_polynomial_list(&self) -> PolynomialList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        PolynomialList::from_container(self.as_tensor().as_slice(), self.poly_size)
+    }
+
+    /// Returns a mutably borrowed list containing every polynomial of the tensor product, the
+    /// linear part first.
+    pub fn as_mut_polynomial_list(
+        &mut self,
+    ) -> PolynomialList<&mut [<Self as AsMutTensor>::Element]>
+    where
+        Self: AsMutTensor,
+    {
+        let poly_size = self.poly_size;
+        PolynomialList::from_container(self.as_mut_tensor().as_mut_slice(), poly_size)
+    }
+
+    /// Returns a borrowed view of the linear part of the tensor product, as a GLWE ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::GlweTensorProduct;
+    /// use concrete_core::prelude::{GlweDimension, GlweSize, PolynomialSize};
+    /// let tensor_product = GlweTensorProduct::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// let linear = tensor_product.as_linear_part();
+    /// assert_eq!(linear.size(), GlweSize(3));
+    /// assert_eq!(linear.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn as_linear_part(&self) -> GlweCiphertext<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        let linear_len = self.glwe_size.0 * self.poly_size.0;
+        GlweCiphertext::from_container(&self.as_tensor().as_slice()[..linear_len], self.poly_size)
+    }
+
+    /// Returns a borrowed list of the polynomials of the quadratic part of the tensor product.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::GlweTensorProduct;
+    /// use concrete_core::prelude::{GlweDimension, PolynomialCount, PolynomialSize};
+    /// let tensor_product = GlweTensorProduct::allocate(0 as u8, PolynomialSize(10), GlweDimension(2));
+    /// let quadratic = tensor_product.as_quadratic_part();
+    /// assert_eq!(quadratic.polynomial_count(), PolynomialCount(3));
+    /// ```
+    pub fn as_quadratic_part(&self) -> PolynomialList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        let linear_len = self.glwe_size.0 * self.poly_size.0;
+        PolynomialList::from_container(&self.as_tensor().as_slice()[linear_len..], self.poly_size)
+    }
+
+    /// Fills the current tensor product with the tensor product of two GLWE ciphertexts, divided
+    /// by $2^{\mathsf{delta\\_log}}$.
+    ///
+    /// The products are computed exactly, and the rounding is only performed at the end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweTensorProduct};
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_core::prelude::{DeltaLog, GlweDimension, GlweSize, PolynomialSize};
+    /// // Two trivial encryptions of constant polynomials.
+    /// let mut lhs = GlweCiphertext::allocate(0 as u32, PolynomialSize(4), GlweSize(2));
+    /// let mut rhs = GlweCiphertext::allocate(0 as u32, PolynomialSize(4), GlweSize(2));
+    /// *lhs.get_mut_body().as_mut_tensor().first_mut() = 3 << 20;
+    /// *rhs.get_mut_body().as_mut_tensor().first_mut() = 5 << 20;
+    /// let mut tensor_product =
+    ///     GlweTensorProduct::allocate(0 as u32, PolynomialSize(4), GlweDimension(1));
+    /// tensor_product.fill_with_tensor_product(&lhs, &rhs, DeltaLog(20));
+    /// let linear = tensor_product.as_linear_part();
+    /// assert_eq!(*linear.get_body().as_tensor().first(), 15 << 20);
+    /// ```
+    pub fn fill_with_tensor_product<Scalar, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &GlweCiphertext<LhsCont>,
+        rhs: &GlweCiphertext<RhsCont>,
+        delta_log: DeltaLog,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<LhsCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<RhsCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + CastFrom<u128>,
+        u128: CastFrom<Scalar::Signed>,
+    {
+        ck_dim_eq!(self.poly_size.0 => lhs.polynomial_size().0, rhs.polynomial_size().0);
+        ck_dim_eq!(self.glwe_size.0 => lhs.size().0, rhs.size().0);
+        debug_assert!(
+            delta_log.0 <= Scalar::BITS,
+            "The rescaling can not be larger than the size of the integers."
+        );
+        let poly_size = self.poly_size.0;
+        // The coefficients are lifted to their centered representatives, which can be multiplied
+        // without overflow in 128 bits integers.
+        let lhs_lifted: Vec<u128> = lhs
+            .as_tensor()
+            .iter()
+            .map(|c| u128::cast_from(c.into_signed()))
+            .collect();
+        let rhs_lifted: Vec<u128> = rhs
+            .as_tensor()
+            .iter()
+            .map(|c| u128::cast_from(c.into_signed()))
+            .collect();
+        let terms = tensor_product_terms(self.glwe_dimension());
+        let mut accumulator = vec![0u128; poly_size];
+        for (mut output, term) in self
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(terms)
+        {
+            accumulator.iter_mut().for_each(|a| *a = 0);
+            for (lhs_index, rhs_index) in term {
+                let lhs_poly = &lhs_lifted[lhs_index * poly_size..(lhs_index + 1) * poly_size];
+                let rhs_poly = &rhs_lifted[rhs_index * poly_size..(rhs_index + 1) * poly_size];
+                for (i, lhs_coef) in lhs_poly.iter().enumerate() {
+                    for (j, rhs_coef) in rhs_poly.iter().enumerate() {
+                        let product = lhs_coef.wrapping_mul(*rhs_coef);
+                        if i + j < poly_size {
+                            accumulator[i + j] = accumulator[i + j].wrapping_add(product);
+                        } else {
+                            let index = i + j - poly_size;
+                            accumulator[index] = accumulator[index].wrapping_sub(product);
+                        }
+                    }
+                }
+            }
+            for (out, acc) in output.coefficient_iter_mut().zip(accumulator.iter()) {
+                *out = rescale_tensor_product_coefficient(*acc, delta_log);
+            }
+        }
+    }
+}
@@ -808,7 +808,7 @@ impl<Cont> LweMask<Cont> {
         self.as_tensor().fold_with_one(
             key.as_tensor(),
             <Scalar as Numeric>::ZERO,
-            |ac, s_i, o_i| ac.wrapping_add(*s_i * *o_i),
+            |ac, s_i, o_i| ac.wrapping_add(s_i.wrapping_mul(*o_i)),
         )
     }
 }
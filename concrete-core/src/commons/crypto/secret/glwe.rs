@@ -7,7 +7,7 @@ use crate::commons::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
 use crate::commons::crypto::secret::LweSecretKey;
-use crate::commons::math::polynomial::PolynomialList;
+use crate::commons::math::polynomial::{Polynomial, PolynomialList};
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
 use crate::commons::math::random::{ByteRandomGenerator, Gaussian, RandomGenerable, Seeder};
@@ -1136,6 +1136,110 @@ where
         }
     }
 
+    /// This function encrypts a polynomial message as a GGSW ciphertext.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::ggsw::StandardGgswCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(10), &mut generator);
+    /// let mut ciphertext = StandardGgswCiphertext::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(10),
+    ///     GlweSize(3),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// // The monomial X
+    /// let encoded = PlaintextList::from_container(vec![0 as u32, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let mut secret_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// secret_key.encrypt_polynomial_ggsw(&mut ciphertext, &encoded, noise, &mut secret_generator);
+    /// ```
+    pub fn encrypt_polynomial_ggsw<OutputCont, InputCont, Scalar, Gen>(
+        &self,
+        encrypted: &mut StandardGgswCiphertext<OutputCont>,
+        encoded: &PlaintextList<InputCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        StandardGgswCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        InputCont: AsRefSlice<Element = Scalar>,
+        OutputCont: AsMutSlice<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.polynomial_size() => encrypted.polynomial_size());
+        ck_dim_eq!(self.key_size() => encrypted.glwe_size().to_glwe_dimension());
+        ck_dim_eq!(self.poly_size.0 => encoded.count().0);
+
+        // The rows of the level matrices encrypt multiples of -S_i * M, and of M for the last one.
+        // We compute those products once.
+        let message = Polynomial::from_container(encoded.as_tensor().as_slice());
+        let mut products: Vec<_> = self
+            .as_polynomial_list()
+            .polynomial_iter()
+            .map(|sk_poly| {
+                let mut product = Polynomial::allocate(Scalar::ZERO, self.poly_size);
+                product.fill_with_wrapping_mul(&sk_poly, &message);
+                product.as_mut_tensor().update_with_wrapping_neg();
+                product
+            })
+            .collect();
+        products.push(Polynomial::from_container(
+            encoded.as_tensor().as_slice().to_vec(),
+        ));
+
+        let gen_iter = generator
+            .fork_ggsw_to_ggsw_levels::<Scalar>(
+                encrypted.decomposition_level_count(),
+                self.key_size().to_glwe_size(),
+                self.poly_size,
+            )
+            .expect("Failed to split generator into ggsw levels");
+
+        let base_log = encrypted.decomposition_base_log();
+        let mut row_encoded =
+            PlaintextList::allocate(Scalar::ZERO, PlaintextCount(self.poly_size.0));
+        for (mut matrix, mut generator) in encrypted.level_matrix_iter_mut().zip(gen_iter) {
+            let factor =
+                Scalar::ONE << (Scalar::BITS - (base_log.0 * (matrix.decomposition_level().0)));
+
+            let gen_iter = generator
+                .fork_ggsw_level_to_glwe::<Scalar>(self.key_size().to_glwe_size(), self.poly_size)
+                .expect("Failed to split generator into rlwe");
+
+            for ((row, product), mut generator) in
+                matrix.row_iter_mut().zip(products.iter()).zip(gen_iter)
+            {
+                row_encoded
+                    .as_mut_tensor()
+                    .fill_with_one(product.as_tensor(), |coef| coef.wrapping_mul(factor));
+                self.encrypt_glwe(
+                    &mut row.into_glwe(),
+                    &row_encoded,
+                    noise_parameters,
+                    &mut generator,
+                );
+            }
+        }
+    }
+
     fn encrypt_constant_seeded_ggsw_row<Scalar, InputCont, OutputCont, Gen>(
         &self,
         (row_index, last_row_index): (usize, usize),
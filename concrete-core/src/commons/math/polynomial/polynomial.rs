@@ -552,13 +552,13 @@ impl<Cont> Polynomial<Cont> {
                     let update = self
                         .as_tensor()
                         .get_element(target_degree)
-                        .wrapping_add(*lhsi.get_coefficient() * *rhsi.get_coefficient());
+                        .wrapping_add(lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient()));
                     *self.as_mut_tensor().get_element_mut(target_degree) = update;
                 } else {
                     let update = self
                         .as_tensor()
                         .get_element(target_degree % (degree + 1))
-                        .wrapping_sub(*lhsi.get_coefficient() * *rhsi.get_coefficient());
+                        .wrapping_sub(lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient()));
                     *self
                         .as_mut_tensor()
                         .get_element_mut(target_degree % (degree + 1)) = update;
@@ -605,13 +605,13 @@ impl<Cont> Polynomial<Cont> {
                     let update = self
                         .as_tensor()
                         .get_element(target_degree)
-                        .wrapping_sub(*lhsi.get_coefficient() * *rhsi.get_coefficient());
+                        .wrapping_sub(lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient()));
                     *self.as_mut_tensor().get_element_mut(target_degree) = update;
                 } else {
                     let update = self
                         .as_tensor()
                         .get_element(target_degree % (degree + 1))
-                        .wrapping_add(*lhsi.get_coefficient() * *rhsi.get_coefficient());
+                        .wrapping_add(lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient()));
                     *self
                         .as_mut_tensor()
                         .as_mut_slice()
@@ -12,6 +12,11 @@ engine_error! {
 ///
 /// This [pure](super#operation-semantics) operation generates an entity containing the
 /// deserialization of the `serialized` type.
+///
+/// The [`SerializationHeader`](crate::specification::serialization::SerializationHeader) at the
+/// start of the serialized value is checked against the expected entity, and the checksums of the
+/// header and of the payload are verified before the entity is decoded. Values serialized before
+/// the introduction of the header are still accepted, without those checks.
 pub trait EntityDeserializationEngine<Serialized, Entity>: AbstractEngine
 where
     Entity: AbstractEntity,
@@ -12,6 +12,11 @@ engine_error! {
 ///
 /// This [pure](super#operation-semantics) operation generates a value containing the serialization
 /// of `entity`.
+///
+/// The serialized value starts with a
+/// [`SerializationHeader`](crate::specification::serialization::SerializationHeader) describing
+/// the entity, which can be read with a
+/// [`SerializationHeaderInspectionEngine`](super::SerializationHeaderInspectionEngine).
 pub trait EntitySerializationEngine<Entity, Serialized>: AbstractEngine
 where
     Entity: AbstractEntity,
@@ -0,0 +1,45 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use std::io::Read;
+
+engine_error! {
+    EntityStreamDeserializationError for EntityStreamDeserializationEngine @
+}
+
+/// A trait for engines deserializing entities from a reader.
+///
+/// # Semantics
+///
+/// This operation generates an entity by reading its serialization from `reader`. Contrary to
+/// [`EntityDeserializationEngine`](super::EntityDeserializationEngine), the serialized value does
+/// not need to be loaded in memory beforehand: the container of the entity is filled as the bytes
+/// are read. Only the bytes of one entity are consumed, which means that several entities can be
+/// read one after the other from the same reader.
+///
+/// As for the in-memory deserialization, the serialization header, its checksum and the version
+/// the entity was serialized with are checked before the payload is read. The checksum of the
+/// payload is computed while it gets decoded, and verified before the entity is returned, so that
+/// corrupted values are rejected without holding the serialized value in memory.
+///
+/// Readers performing a system call on each read (such as [`std::fs::File`]) should be wrapped in
+/// a [`std::io::BufReader`].
+pub trait EntityStreamDeserializationEngine<Reader, Entity>: AbstractEngine
+where
+    Reader: Read,
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity from a reader.
+    fn deserialize_from(
+        &mut self,
+        reader: &mut Reader,
+    ) -> Result<Entity, EntityStreamDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity from a reader.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityStreamDeserializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn deserialize_from_unchecked(&mut self, reader: &mut Reader) -> Entity;
+}
@@ -0,0 +1,42 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use std::io::Write;
+
+engine_error! {
+    EntityStreamSerializationError for EntityStreamSerializationEngine @
+}
+
+/// A trait for engines serializing entities into a writer.
+///
+/// # Semantics
+///
+/// This operation writes the serialization of `entity` into `writer`. Contrary to
+/// [`EntitySerializationEngine`](super::EntitySerializationEngine), the serialized value is never
+/// held in memory as a whole: the container of the entity is written to `writer` as it is
+/// traversed, so that large keys can be sent to a file or a socket without being duplicated in
+/// memory. The bytes written are the same as the ones returned by
+/// [`EntitySerializationEngine`](super::EntitySerializationEngine) for the same entity.
+///
+/// Writers performing a system call on each write (such as [`std::fs::File`]) should be wrapped
+/// in a [`std::io::BufWriter`].
+pub trait EntityStreamSerializationEngine<Entity, Writer>: AbstractEngine
+where
+    Entity: AbstractEntity,
+    Writer: Write,
+{
+    /// Serializes an entity into a writer.
+    fn serialize_into(
+        &mut self,
+        entity: &Entity,
+        writer: &mut Writer,
+    ) -> Result<(), EntityStreamSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity into a writer.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityStreamSerializationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn serialize_into_unchecked(&mut self, entity: &Entity, writer: &mut Writer);
+}
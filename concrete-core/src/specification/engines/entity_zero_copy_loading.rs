@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityZeroCopyLoadingError for EntityZeroCopyLoadingEngine @
+}
+
+/// A trait for engines loading views over entities from a region of memory, without copy.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a view over an entity, whose data
+/// is borrowed from `region`. The region is expected to contain an entity written by an
+/// [`EntityZeroCopySerializationEngine`](super::EntityZeroCopySerializationEngine), for instance
+/// in a read-only memory map of a file. The data of the entity is neither copied nor allocated,
+/// which allows several processes to share the same physical memory for the entity.
+///
+/// The layout, the size and the alignment of the region are checked before the view is returned.
+/// Refer to the implementer documentation for the details of the layout.
+pub trait EntityZeroCopyLoadingEngine<Region, EntityView>: AbstractEngine
+where
+    EntityView: AbstractEntity,
+{
+    /// Loads a view over an entity from a region of memory.
+    fn load_zero_copy(
+        &mut self,
+        region: Region,
+    ) -> Result<EntityView, EntityZeroCopyLoadingError<Self::EngineError>>;
+
+    /// Unsafely loads a view over an entity from a region of memory.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityZeroCopyLoadingError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn load_zero_copy_unchecked(&mut self, region: Region) -> EntityView;
+}
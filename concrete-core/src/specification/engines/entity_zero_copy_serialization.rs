@@ -0,0 +1,42 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+use std::io::Write;
+
+engine_error! {
+    EntityZeroCopySerializationError for EntityZeroCopySerializationEngine @
+}
+
+/// A trait for engines serializing entities in a layout which can be loaded without copy.
+///
+/// # Semantics
+///
+/// This operation writes `entity` into `writer`, in a layout matching the memory representation
+/// of the entity. The bytes written this way can be turned back into a view over the entity with
+/// an [`EntityZeroCopyLoadingEngine`](super::EntityZeroCopyLoadingEngine), without copying nor
+/// allocating the data of the entity. This makes it possible to store an entity in a file, and to
+/// share a read-only memory map of this file between several processes.
+///
+/// Contrary to the output of an [`EntitySerializationEngine`](super::EntitySerializationEngine),
+/// the layout is engine-specific, and may depend on the platform the entity was serialized on.
+/// Refer to the implementer documentation for the details of the layout.
+pub trait EntityZeroCopySerializationEngine<Entity, Writer>: AbstractEngine
+where
+    Entity: AbstractEntity,
+    Writer: Write,
+{
+    /// Serializes an entity into a writer, in a layout which can be loaded without copy.
+    fn serialize_zero_copy_into(
+        &mut self,
+        entity: &Entity,
+        writer: &mut Writer,
+    ) -> Result<(), EntityZeroCopySerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity into a writer, in a layout which can be loaded without copy.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityZeroCopySerializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn serialize_zero_copy_into_unchecked(&mut self, entity: &Entity, writer: &mut Writer);
+}
@@ -0,0 +1,84 @@
+use super::engine_error;
+use crate::prelude::Variance;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GgswCiphertextPolynomialDiscardingEncryptionError for
+    GgswCiphertextPolynomialDiscardingEncryptionEngine @
+    GlweDimensionMismatch => "The GLWE dimension of the key and ciphertext must be the same.",
+    PolynomialSizeMismatch => "The polynomial size of the key and ciphertext must be the same.",
+    PlaintextCountMismatch => "The plaintext count of the input vector and the key polynomial size \
+                               must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    GgswCiphertextPolynomialDiscardingEncryptionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, PlaintextVector, Ciphertext>(
+        key: &SecretKey,
+        output: &Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        Ciphertext: GgswCiphertextEntity,
+    {
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting (discarding) GGSW ciphertexts containing a polynomial.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GGSW ciphertext with
+/// the encryption of the polynomial whose coefficients are the `input` plaintexts, under the `key`
+/// secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::GgswCiphertextPolynomialEncryptionEngine`)
+pub trait GgswCiphertextPolynomialDiscardingEncryptionEngine<SecretKey, PlaintextVector, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: GgswCiphertextEntity,
+{
+    /// Encrypts a polynomial into a GGSW ciphertext.
+    fn discard_encrypt_polynomial_ggsw_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<(), GgswCiphertextPolynomialDiscardingEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a polynomial into a GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextPolynomialDiscardingEncryptionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn discard_encrypt_polynomial_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+        noise: Variance,
+    );
+}
@@ -0,0 +1,77 @@
+use super::engine_error;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GgswCiphertextPolynomialEncryptionError for GgswCiphertextPolynomialEncryptionEngine @
+    PlaintextCountMismatch => "The plaintext count of the input vector and the key polynomial size \
+                               must be the same."
+}
+
+impl<EngineError: std::error::Error> GgswCiphertextPolynomialEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, PlaintextVector>(
+        key: &SecretKey,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting GGSW ciphertexts containing a polynomial.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext containing the
+/// encryption of the polynomial whose coefficients are the `input` plaintexts, under the `key`
+/// secret key.
+///
+/// # Formal Definition
+///
+/// Let $\mathsf{M}$ be the polynomial whose coefficients are the `input` plaintexts, and
+/// $\vec{S} = (S\_0, \ldots, S\_{k-1})$ the GLWE secret key. For every level $j$ of the
+/// decomposition, the $i$-th row of the level matrix is a GLWE encryption of
+/// $-S\_i \cdot \mathsf{M} \cdot \frac{q}{\beta^j}$ for $i < k$, and the last row is a GLWE
+/// encryption of $\mathsf{M} \cdot \frac{q}{\beta^j}$.
+pub trait GgswCiphertextPolynomialEncryptionEngine<SecretKey, PlaintextVector, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: GgswCiphertextEntity,
+{
+    /// Encrypts a polynomial into a GGSW ciphertext.
+    fn encrypt_polynomial_ggsw_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<Ciphertext, GgswCiphertextPolynomialEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a polynomial into a GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextPolynomialEncryptionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn encrypt_polynomial_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Ciphertext;
+}
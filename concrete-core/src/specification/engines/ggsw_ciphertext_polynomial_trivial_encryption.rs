@@ -0,0 +1,54 @@
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, GlweSize};
+
+use crate::specification::engines::{engine_error, AbstractEngine};
+use crate::specification::entities::{GgswCiphertextEntity, PlaintextVectorEntity};
+
+engine_error! {
+    GgswCiphertextPolynomialTrivialEncryptionError for
+    GgswCiphertextPolynomialTrivialEncryptionEngine @
+}
+
+/// A trait for engines trivially encrypting GGSW ciphertexts containing a polynomial.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext containing the
+/// trivial encryption of the polynomial whose coefficients are the `input` plaintexts, with the
+/// requested `glwe_size`. The polynomial size of the output is the plaintext count of `input`.
+///
+/// # Formal Definition
+///
+/// A trivial encryption uses a zero mask and no noise.
+/// It is absolutely not secure, as the body contains a direct copy of the plaintext.
+/// However, it is useful for some FHE algorithms taking public information as input. For
+/// example, a trivial GGSW encryption of a public monomial can be used to rotate a GLWE ciphertext
+/// with an external product.
+pub trait GgswCiphertextPolynomialTrivialEncryptionEngine<PlaintextVector, Ciphertext>:
+    AbstractEngine
+where
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: GgswCiphertextEntity,
+{
+    /// Trivially encrypts a polynomial into a GGSW ciphertext.
+    fn trivially_encrypt_polynomial_ggsw_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &PlaintextVector,
+    ) -> Result<Ciphertext, GgswCiphertextPolynomialTrivialEncryptionError<Self::EngineError>>;
+
+    /// Unsafely creates the trivial GGSW encryption of a polynomial.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextPolynomialTrivialEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn trivially_encrypt_polynomial_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &PlaintextVector,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,73 @@
+use super::engine_error;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GgswCiphertextVectorEntity;
+
+engine_error! {
+    GgswCiphertextVectorCreationError for GgswCiphertextVectorCreationEngine @
+    EmptyContainer => "The container used to create the GGSW ciphertext vector is of length 0!",
+    InvalidContainerSize => "The length of the container used to create the GGSW ciphertext \
+    vector needs to be a multiple of `decomposition_level_count * glwe_size * glwe_size * \
+    polynomial_size`."
+}
+
+impl<EngineError: std::error::Error> GgswCiphertextVectorCreationError<EngineError> {
+    /// Validates the inputs, the container is expected to have a length which is a multiple of
+    /// the size of a single GGSW ciphertext, the number of ciphertexts in the vector is deduced
+    /// from it.
+    pub fn perform_generic_checks(
+        container_length: usize,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<(), Self> {
+        if container_length == 0 {
+            return Err(Self::EmptyContainer);
+        }
+        if container_length
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(Self::InvalidContainerSize);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines creating a GGSW ciphertext vector from an arbitrary container.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a GGSW ciphertext vector from the
+/// arbitrary `container`. By arbitrary here, we mean that `Container` can be any type that
+/// allows to instantiate a `GgswCiphertextVectorEntity`.
+pub trait GgswCiphertextVectorCreationEngine<Container, CiphertextVector>: AbstractEngine
+where
+    CiphertextVector: GgswCiphertextVectorEntity,
+{
+    /// Creates a GGSW ciphertext vector from an arbitrary container.
+    fn create_ggsw_ciphertext_vector_from(
+        &mut self,
+        container: Container,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<CiphertextVector, GgswCiphertextVectorCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a GGSW ciphertext vector from an arbitrary container.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextVectorCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_ggsw_ciphertext_vector_from_unchecked(
+        &mut self,
+        container: Container,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> CiphertextVector;
+}
@@ -0,0 +1,95 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextVectorEntity, GlweCiphertextEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GlweCiphertextDiscardingCmuxTreeError for GlweCiphertextDiscardingCmuxTreeEngine @
+    PolynomialSizeMismatch => "The output GLWE ciphertext and the GGSW ciphertexts polynomial \
+                               sizes must be the same.",
+    GlweDimensionMismatch => "The output GLWE ciphertext and the GGSW ciphertexts GLWE dimensions \
+                              must be the same.",
+    LutSizeMismatch => "The lookup table must contain `2^ggsw_ciphertext_count` polynomials of \
+                        the output polynomial size."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingCmuxTreeError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Lut, Selectors, Output>(
+        output: &Output,
+        lut: &Lut,
+        selectors: &Selectors,
+    ) -> Result<(), Self>
+    where
+        Lut: PlaintextVectorEntity,
+        Selectors: GgswCiphertextVectorEntity,
+        Output: GlweCiphertextEntity,
+    {
+        if output.polynomial_size() != selectors.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if output.glwe_dimension() != selectors.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        let lut_polynomial_count = 1_usize
+            .checked_shl(selectors.ggsw_ciphertext_count().0 as u32)
+            .ok_or(Self::LutSizeMismatch)?;
+        if lut.plaintext_count().0 != lut_polynomial_count * output.polynomial_size().0 {
+            return Err(Self::LutSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines evaluating (discarding) a tree of controlled multiplexers (cmux) on a
+/// lookup table.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the polynomial of the `lut` lookup table selected by the bits encrypted in the `selectors` GGSW
+/// ciphertext vector.
+///
+/// # Formal Definition
+///
+/// The `lut` plaintext vector is seen as a list of $2^{\ell}$ polynomials
+/// $\mathsf{P}\_0, \ldots, \mathsf{P}\_{2^{\ell}-1}$, where $\ell$ is the number of
+/// [`GGSW ciphertexts`](`crate::specification::entities::GgswCiphertextVectorEntity`)
+/// $\mathsf{CT}\_0, \ldots, \mathsf{CT}\_{\ell-1}$ in `selectors`, each encrypting a bit $b\_i$.
+/// The first selector holds the most significant bit of the index, so that the output is a
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{ct}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}}(\mathsf{P}\_j)$ with
+/// $j = \sum\_{i=0}^{\ell-1} b\_i \cdot 2^{\ell-1-i}$.
+///
+/// The result is computed with a binary tree of
+/// [`cmux`](`crate::specification::engines::GlweCiphertextsGgswCiphertextFusingCmuxEngine`)
+/// operations, starting from trivial encryptions of the lookup table polynomials: each layer of
+/// the tree halves the number of candidates, the first layer using the last selector.
+pub trait GlweCiphertextDiscardingCmuxTreeEngine<Lut, Selectors, Output>: AbstractEngine
+where
+    Lut: PlaintextVectorEntity,
+    Selectors: GgswCiphertextVectorEntity,
+    Output: GlweCiphertextEntity,
+{
+    /// Evaluates a cmux tree on a lookup table.
+    fn discard_cmux_tree_glwe_ciphertext(
+        &mut self,
+        output: &mut Output,
+        lut: &Lut,
+        selectors: &Selectors,
+    ) -> Result<(), GlweCiphertextDiscardingCmuxTreeError<Self::EngineError>>;
+
+    /// Unsafely evaluates a cmux tree on a lookup table.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingCmuxTreeError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn discard_cmux_tree_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut Output,
+        lut: &Lut,
+        selectors: &Selectors,
+    );
+}
@@ -0,0 +1,87 @@
+use super::engine_error;
+#[allow(deprecated)]
+use crate::prelude::MonomialDegree;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextDiscardingMonomialMultiplicationError for
+    GlweCiphertextDiscardingMonomialMultiplicationEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial sizes must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextDiscardingMonomialMultiplicationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+#[allow(deprecated)]
+/// A trait for engines multiplying (discarding) GLWE ciphertexts by monomials.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the `input` GLWE ciphertext, whose polynomials are all multiplied by the monomial
+/// $X^{\mathsf{degree}}$. Since polynomials are taken modulo $X^N+1$, this is a negacyclic
+/// rotation of the coefficients, and a degree $2N - d$ amounts to a division by $X^d$.
+///
+/// # Formal Definition
+///
+/// ## GLWE monomial multiplication
+///
+/// Let $\mathsf{CT} = \left( \vec{A}, B\right) \in \mathsf{GLWE}\_{\vec{S}} \left( \mathsf{PT}
+/// \right) \subseteq \mathcal{R}\_q^{k+1}$ be a GLWE ciphertext, and $d$ a monomial degree.
+///
+/// ###### outputs:
+/// - $\mathsf{CT}' = \left( \vec{A}' , B' \right) \in \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT} \cdot
+///   X^d )\subseteq \mathcal{R}\_q^{k+1}$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. Compute $\vec{A}' = \vec{A} \cdot X^d \in\mathcal{R}^k\_q$
+/// 2. Compute $B' = B \cdot X^d \in\mathcal{R}\_q$
+/// 3. Output $\left( \vec{A}' , B' \right)$
+pub trait GlweCiphertextDiscardingMonomialMultiplicationEngine<InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Multiplies a GLWE ciphertext by a monomial.
+    fn discard_mul_glwe_ciphertext_monomial(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        degree: MonomialDegree,
+    ) -> Result<(), GlweCiphertextDiscardingMonomialMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies a GLWE ciphertext by a monomial.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingMonomialMultiplicationError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn discard_mul_glwe_ciphertext_monomial_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        degree: MonomialDegree,
+    );
+}
@@ -0,0 +1,134 @@
+use super::engine_error;
+use crate::prelude::DeltaLog;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingMultiplicationError for GlweCiphertextDiscardingMultiplicationEngine @
+    PolynomialSizeMismatch => "The polynomial sizes of the ciphertexts and of the relinearization \
+                               key must be the same.",
+    GlweDimensionMismatch => "The GLWE dimensions of the ciphertexts and of the relinearization \
+                              key must be the same.",
+    DeltaLogTooLarge => "The scaling factor log must be smaller than the precision of the \
+                         ciphertexts."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingMultiplicationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, RelinearizationKey, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        let polynomial_size = relinearization_key.polynomial_size();
+        if output.polynomial_size() != polynomial_size
+            || input_1.polynomial_size() != polynomial_size
+            || input_2.polynomial_size() != polynomial_size
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        let glwe_dimension = relinearization_key.glwe_dimension();
+        if output.glwe_dimension() != glwe_dimension
+            || input_1.glwe_dimension() != glwe_dimension
+            || input_2.glwe_dimension() != glwe_dimension
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if delta_log.0 >= ciphertext_modulus_log {
+            return Err(Self::DeltaLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the product of the `input_1` GLWE ciphertext and the `input_2` GLWE ciphertext, divided by
+/// $\Delta = 2^{\mathsf{delta\\_log}}$.
+///
+/// # Formal Definition
+///
+/// ## GLWE homomorphic multiplication
+///
+/// Let two GLWE ciphertexts
+/// $$
+/// \begin{cases}
+/// \mathsf{CT}\_1 = \left( \vec{A}\_1, B\_1\right) \in \mathsf{GLWE}\_{\vec{S}} \left(
+/// \Delta\cdot\mathsf{M}\_1 \right) \subseteq \mathcal{R}\_q^{k+1} \\ \mathsf{CT}\_2 = \left(
+/// \vec{A}\_2, B\_2\right) \in \mathsf{GLWE}\_{\vec{S}} \left( \Delta\cdot\mathsf{M}\_2 \right)
+/// \subseteq \mathcal{R}\_q^{k+1} \end{cases} $$
+/// encrypted under the same
+/// [`GLWE secret key`](`crate::specification::entities::GlweSecretKeyEntity`) $\vec{S} \in
+/// \mathcal{R}\_q^k$. We can multiply these ciphertexts homomorphically and obtain as a result a
+/// new GLWE ciphertext encrypting $\Delta\cdot\mathsf{M}\_1\cdot\mathsf{M}\_2$.
+///
+/// ###### inputs:
+/// - $\mathsf{CT}\_1 = \left( \vec{A}\_1, B\_1\right) \in \mathsf{GLWE}\_{\vec{S}} \left(
+///   \Delta\cdot\mathsf{M}\_1 \right) \subseteq \mathcal{R}\_q^{k+1}$: a GLWE ciphertext
+/// - $\mathsf{CT}\_2 = \left( \vec{A}\_2, B\_2\right) \in \mathsf{GLWE}\_{\vec{S}} \left(
+///   \Delta\cdot\mathsf{M}\_2 \right) \subseteq \mathcal{R}\_q^{k+1}$: a GLWE ciphertext
+/// - $\mathsf{RLK}\_{\vec{S}}$: a [`GLWE relinearization
+///   key`](`crate::specification::entities::GlweRelinearizationKeyEntity`)
+///
+/// ###### outputs:
+/// - $\mathsf{CT} = \left( \vec{A}, B\right) \in \mathsf{GLWE}\_{\vec{S}} \left(
+///   \Delta\cdot\mathsf{M}\_1\cdot\mathsf{M}\_2 \right) \subseteq \mathcal{R}\_q^{k+1}$: the GLWE
+///   ciphertext of the product
+///
+/// ###### algorithm:
+/// 1. Compute the tensor product of the two ciphertexts, on the centered representatives of
+///    their coefficients and divided by $\Delta$ with a rounding:
+///     - $T\_i = \left\lfloor\frac{A\_{1,i}\cdot B\_2 + A\_{2,i}\cdot B\_1}{\Delta}\right\rceil$
+///       for $0\le i<k$
+///     - $T\_k = \left\lfloor\frac{B\_1\cdot B\_2}{\Delta}\right\rceil$
+///     - $R\_{i,j} = \left\lfloor\frac{A\_{1,i}\cdot A\_{2,j} + A\_{1,j}\cdot A\_{2,i}}{\Delta}
+///       \right\rceil$ for $0\le i<j<k$, and $R\_{i,i} = \left\lfloor\frac{A\_{1,i}\cdot
+///       A\_{2,i}}{\Delta}\right\rceil$
+/// 2. Relinearize, by adding to $\left(T\_0, \cdots, T\_k\right)$ the external products
+///    between the decompositions of the $R\_{i,j}$ and the GLev encryptions of $S\_i\cdot S\_j$
+///    contained in $\mathsf{RLK}\_{\vec{S}}$.
+pub trait GlweCiphertextDiscardingMultiplicationEngine<
+    InputCiphertext,
+    RelinearizationKey,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: GlweCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Multiplies two GLWE ciphertexts.
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingMultiplicationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    );
+}
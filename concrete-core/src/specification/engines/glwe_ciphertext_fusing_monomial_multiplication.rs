@@ -0,0 +1,45 @@
+use super::engine_error;
+#[allow(deprecated)]
+use crate::prelude::MonomialDegree;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextFusingMonomialMultiplicationError for
+    GlweCiphertextFusingMonomialMultiplicationEngine @
+}
+
+#[allow(deprecated)]
+/// A trait for engines multiplying (fusing) GLWE ciphertexts by monomials.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation multiplies every polynomial of the
+/// `ciphertext` GLWE ciphertext by the monomial $X^{\mathsf{degree}}$, in place.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::GlweCiphertextDiscardingMonomialMultiplicationEngine`)
+pub trait GlweCiphertextFusingMonomialMultiplicationEngine<Ciphertext>: AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Multiplies a GLWE ciphertext by a monomial.
+    fn fuse_mul_glwe_ciphertext_monomial(
+        &mut self,
+        ciphertext: &mut Ciphertext,
+        degree: MonomialDegree,
+    ) -> Result<(), GlweCiphertextFusingMonomialMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies a GLWE ciphertext by a monomial.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextFusingMonomialMultiplicationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn fuse_mul_glwe_ciphertext_monomial_unchecked(
+        &mut self,
+        ciphertext: &mut Ciphertext,
+        degree: MonomialDegree,
+    );
+}
@@ -0,0 +1,34 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextFusingOppositeError for GlweCiphertextFusingOppositeEngine @
+}
+
+/// A trait for engines computing the opposite (fusing) of GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation computes the opposite of the `input`
+/// GLWE ciphertext.
+///
+/// # Formal Definition
+pub trait GlweCiphertextFusingOppositeEngine<Ciphertext>: AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Computes the opposite of a GLWE ciphertext.
+    fn fuse_opp_glwe_ciphertext(
+        &mut self,
+        input: &mut Ciphertext,
+    ) -> Result<(), GlweCiphertextFusingOppositeError<Self::EngineError>>;
+
+    /// Unsafely computes the opposite of a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextFusingOppositeError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn fuse_opp_glwe_ciphertext_unchecked(&mut self, input: &mut Ciphertext);
+}
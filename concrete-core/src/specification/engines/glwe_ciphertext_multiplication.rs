@@ -0,0 +1,87 @@
+use super::engine_error;
+use crate::prelude::DeltaLog;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+
+engine_error! {
+    GlweCiphertextMultiplicationError for GlweCiphertextMultiplicationEngine @
+    PolynomialSizeMismatch => "The polynomial sizes of the ciphertexts and of the relinearization \
+                               key must be the same.",
+    GlweDimensionMismatch => "The GLWE dimensions of the ciphertexts and of the relinearization \
+                              key must be the same.",
+    DeltaLogTooLarge => "The scaling factor log must be smaller than the precision of the \
+                         ciphertexts."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextMultiplicationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, RelinearizationKey>(
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+    {
+        let polynomial_size = relinearization_key.polynomial_size();
+        if input_1.polynomial_size() != polynomial_size
+            || input_2.polynomial_size() != polynomial_size
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        let glwe_dimension = relinearization_key.glwe_dimension();
+        if input_1.glwe_dimension() != glwe_dimension || input_2.glwe_dimension() != glwe_dimension
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if delta_log.0 >= ciphertext_modulus_log {
+            return Err(Self::DeltaLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext with the product
+/// of the `input_1` GLWE ciphertext and the `input_2` GLWE ciphertext, divided by
+/// $\Delta = 2^{\mathsf{delta\\_log}}$.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::GlweCiphertextDiscardingMultiplicationEngine`)
+pub trait GlweCiphertextMultiplicationEngine<InputCiphertext, RelinearizationKey, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext: GlweCiphertextEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Multiplies two GLWE ciphertexts.
+    fn mul_glwe_ciphertext(
+        &mut self,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    ) -> Result<OutputCiphertext, GlweCiphertextMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextMultiplicationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn mul_glwe_ciphertext_unchecked(
+        &mut self,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    ) -> OutputCiphertext;
+}
@@ -0,0 +1,60 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, PlaintextVectorEntity};
+
+engine_error! {
+    GlweCiphertextPlaintextVectorFusingAdditionError for
+    GlweCiphertextPlaintextVectorFusingAdditionEngine @
+    PlaintextCountMismatch => "The plaintext count must be equal to the polynomial size."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextPlaintextVectorFusingAdditionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<Ciphertext, PlaintextVector>(
+        output: &Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        Ciphertext: GlweCiphertextEntity,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if input.plaintext_count().0 != output.polynomial_size().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines adding (fusing) GLWE ciphertexts with plaintext vectors.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation adds the `input` plaintext vector,
+/// interpreted as a polynomial, to the body of the `output` GLWE ciphertext.
+///
+/// # Formal Definition
+pub trait GlweCiphertextPlaintextVectorFusingAdditionEngine<Ciphertext, PlaintextVector>:
+    AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Adds a plaintext vector to a GLWE ciphertext.
+    fn fuse_add_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), GlweCiphertextPlaintextVectorFusingAdditionError<Self::EngineError>>;
+
+    /// Unsafely adds a plaintext vector to a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextPlaintextVectorFusingAdditionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn fuse_add_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+    );
+}
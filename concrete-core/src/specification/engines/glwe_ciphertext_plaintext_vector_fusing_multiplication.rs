@@ -0,0 +1,62 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, PlaintextVectorEntity};
+
+engine_error! {
+    GlweCiphertextPlaintextVectorFusingMultiplicationError for
+    GlweCiphertextPlaintextVectorFusingMultiplicationEngine @
+    PlaintextCountMismatch => "The plaintext count must be equal to the polynomial size."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextPlaintextVectorFusingMultiplicationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<Ciphertext, PlaintextVector>(
+        output: &Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        Ciphertext: GlweCiphertextEntity,
+        PlaintextVector: PlaintextVectorEntity,
+    {
+        if input.plaintext_count().0 != output.polynomial_size().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (fusing) GLWE ciphertexts by plaintext vectors.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation multiplies the `output` GLWE ciphertext
+/// by the `input` plaintext vector, interpreted as a polynomial.
+///
+/// # Formal Definition
+pub trait GlweCiphertextPlaintextVectorFusingMultiplicationEngine<Ciphertext, PlaintextVector>:
+    AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Multiplies a GLWE ciphertext by a plaintext vector.
+    fn fuse_mul_glwe_ciphertext_plaintext_vector(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), GlweCiphertextPlaintextVectorFusingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies a GLWE ciphertext by a plaintext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextPlaintextVectorFusingMultiplicationError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn fuse_mul_glwe_ciphertext_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+    );
+}
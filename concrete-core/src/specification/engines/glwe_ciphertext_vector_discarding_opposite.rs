@@ -0,0 +1,71 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextVectorEntity;
+
+engine_error! {
+    GlweCiphertextVectorDiscardingOppositeError for GlweCiphertextVectorDiscardingOppositeEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial sizes must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextVectorDiscardingOppositeError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertextVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: GlweCiphertextVectorEntity,
+        OutputCiphertextVector: GlweCiphertextVectorEntity,
+    {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if output.glwe_ciphertext_count() != input.glwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the opposite (discarding) of GLWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext vector
+/// with the opposite of the `input` GLWE ciphertext vector.
+///
+/// # Formal Definition
+///
+/// The opposite is computed for each GLWE ciphertext of the input, as described in
+/// [`super::GlweCiphertextDiscardingOppositeEngine`].
+pub trait GlweCiphertextVectorDiscardingOppositeEngine<
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    InputCiphertextVector: GlweCiphertextVectorEntity,
+    OutputCiphertextVector: GlweCiphertextVectorEntity,
+{
+    /// Computes the opposite of a GLWE ciphertext vector.
+    fn discard_opp_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+    ) -> Result<(), GlweCiphertextVectorDiscardingOppositeError<Self::EngineError>>;
+
+    /// Unsafely computes the opposite of a GLWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorDiscardingOppositeError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_opp_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+    );
+}
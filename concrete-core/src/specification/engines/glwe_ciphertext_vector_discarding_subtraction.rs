@@ -0,0 +1,82 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextVectorEntity;
+
+engine_error! {
+    GlweCiphertextVectorDiscardingSubtractionError for
+    GlweCiphertextVectorDiscardingSubtractionEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial sizes must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextVectorDiscardingSubtractionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertextVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &InputCiphertextVector,
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: GlweCiphertextVectorEntity,
+        OutputCiphertextVector: GlweCiphertextVectorEntity,
+    {
+        if output.glwe_dimension() != input_1.glwe_dimension()
+            || output.glwe_dimension() != input_2.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input_1.polynomial_size()
+            || output.polynomial_size() != input_2.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if output.glwe_ciphertext_count() != input_1.glwe_ciphertext_count()
+            || output.glwe_ciphertext_count() != input_2.glwe_ciphertext_count()
+        {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines subtracting (discarding) GLWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext vector
+/// with the subtraction of the `input_1` GLWE ciphertext vector and the `input_2` GLWE ciphertext
+/// vector.
+///
+/// # Formal Definition
+///
+/// The subtraction is applied to each pair of GLWE ciphertexts of the inputs, as described in
+/// [`super::GlweCiphertextDiscardingSubtractionEngine`].
+pub trait GlweCiphertextVectorDiscardingSubtractionEngine<
+    InputCiphertextVector,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    InputCiphertextVector: GlweCiphertextVectorEntity,
+    OutputCiphertextVector: GlweCiphertextVectorEntity,
+{
+    /// Subtracts two GLWE ciphertext vectors.
+    fn discard_sub_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &InputCiphertextVector,
+    ) -> Result<(), GlweCiphertextVectorDiscardingSubtractionError<Self::EngineError>>;
+
+    /// Unsafely subtracts two GLWE ciphertext vectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorDiscardingSubtractionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_sub_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &InputCiphertextVector,
+    );
+}
@@ -0,0 +1,67 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextVectorEntity;
+
+engine_error! {
+    GlweCiphertextVectorFusingAdditionError for GlweCiphertextVectorFusingAdditionEngine @
+    GlweDimensionMismatch => "The input and output GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output polynomial sizes must be the same.",
+    CiphertextCountMismatch => "The input and output ciphertext count must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextVectorFusingAdditionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertextVector, OutputCiphertextVector>(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertextVector,
+    ) -> Result<(), Self>
+    where
+        InputCiphertextVector: GlweCiphertextVectorEntity,
+        OutputCiphertextVector: GlweCiphertextVectorEntity,
+    {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if output.glwe_ciphertext_count() != input.glwe_ciphertext_count() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines adding (fusing) GLWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation adds the `input` GLWE ciphertext vector to
+/// the `output` GLWE ciphertext vector.
+///
+/// # Formal Definition
+pub trait GlweCiphertextVectorFusingAdditionEngine<InputCiphertextVector, OutputCiphertextVector>:
+    AbstractEngine
+where
+    InputCiphertextVector: GlweCiphertextVectorEntity,
+    OutputCiphertextVector: GlweCiphertextVectorEntity,
+{
+    /// Adds a GLWE ciphertext vector to another.
+    fn fuse_add_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+    ) -> Result<(), GlweCiphertextVectorFusingAdditionError<Self::EngineError>>;
+
+    /// Unsafely adds a GLWE ciphertext vector to another.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorFusingAdditionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn fuse_add_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+    );
+}
@@ -0,0 +1,34 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextVectorEntity;
+
+engine_error! {
+    GlweCiphertextVectorFusingOppositeError for GlweCiphertextVectorFusingOppositeEngine @
+}
+
+/// A trait for engines computing the opposite (fusing) of GLWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation computes the opposite of the `input`
+/// GLWE ciphertext vector.
+///
+/// # Formal Definition
+pub trait GlweCiphertextVectorFusingOppositeEngine<CiphertextVector>: AbstractEngine
+where
+    CiphertextVector: GlweCiphertextVectorEntity,
+{
+    /// Computes the opposite of a GLWE ciphertext vector.
+    fn fuse_opp_glwe_ciphertext_vector(
+        &mut self,
+        input: &mut CiphertextVector,
+    ) -> Result<(), GlweCiphertextVectorFusingOppositeError<Self::EngineError>>;
+
+    /// Unsafely computes the opposite of a GLWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextVectorFusingOppositeError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn fuse_opp_glwe_ciphertext_vector_unchecked(&mut self, input: &mut CiphertextVector);
+}
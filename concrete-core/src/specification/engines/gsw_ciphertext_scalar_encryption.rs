@@ -0,0 +1,54 @@
+use super::engine_error;
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, Variance};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GswCiphertextEntity, LweSecretKeyEntity, PlaintextEntity};
+
+engine_error! {
+    GswCiphertextScalarEncryptionError for GswCiphertextScalarEncryptionEngine @
+}
+
+/// A trait for engines encrypting GSW ciphertexts containing a single plaintext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GSW ciphertext containing the
+/// encryption of the `input` plaintext, under the `key` LWE secret key.
+///
+/// # Formal Definition
+///
+/// A GSW ciphertext is the LWE analogue of a GGSW ciphertext (cf
+/// [`here`](`crate::specification::entities::GswCiphertextEntity`)): it is made of
+/// $\ell \cdot (n+1)$ LWE ciphertexts, encrypting $-s\_i \cdot \mathsf{pt}$ for each mask row and
+/// $\mathsf{pt}$ for the body row, scaled by $\frac{q}{\beta^j}$ for each level $j$.
+pub trait GswCiphertextScalarEncryptionEngine<SecretKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: GswCiphertextEntity,
+{
+    /// Encrypts a plaintext into a GSW ciphertext.
+    fn encrypt_scalar_gsw_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<Ciphertext, GswCiphertextScalarEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a plaintext into a GSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GswCiphertextScalarEncryptionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn encrypt_scalar_gsw_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,123 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingBlindRotationError for LweCiphertextDiscardingBlindRotationEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and key polynomial sizes must be the \
+                                     same.",
+    OutputGlweDimensionMismatch => "The output ciphertext and key GLWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingBlindRotationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<BootstrapKey, Accumulator, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+        if output.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines blind rotating (discarding) GLWE accumulators with LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the `acc` accumulator, blindly rotated by the (modulus switched) plaintext encrypted in the
+/// `input` LWE ciphertext, using the `bsk` bootstrap key. This is the programmable bootstrap of
+/// [`LweCiphertextDiscardingBootstrapEngine`](`super::LweCiphertextDiscardingBootstrapEngine`),
+/// without the final sample extraction.
+///
+/// # Formal Definition
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $\mathsf{ct}\_{\mathsf{in}} = \left( \vec{a}\_{\mathsf{in}} , b\_{\mathsf{in}}\right) \in
+///   \mathsf{LWE}^{n\_{\mathsf{in}}}\_{\vec{s}\_{\mathsf{in}}}( \mathsf{pt} )$: an [`LWE
+///   ciphertext`](`LweCiphertextEntity`) with $\vec{a}\_{\mathsf{in}}=\left(a\_0, \cdots
+///   a\_{n\_{\mathsf{in}}-1}\right)$
+/// - $\mathsf{BSK}\_{\vec{s}\_{\mathsf{in}}\rightarrow \vec{S}\_{\mathsf{out}}}$: a bootstrapping
+///   key
+/// - $\mathsf{ACC} \in \mathsf{GLWE}\_{\vec{S}\_{\mathsf{out}}}(\mathsf{LUT})$: a [`GLWE
+///   ciphertext`](`GlweCiphertextEntity`) encrypting a LUT polynomial
+///
+/// ###### outputs:
+/// - $\mathsf{ct}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}\_{\mathsf{out}}} \left( \mathsf{LUT}
+///   \cdot X^{-\tilde{\mathsf{pt}}} \right)$: a [`GLWE ciphertext`](`GlweCiphertextEntity`), where
+///   $\tilde{\mathsf{pt}}$ is the plaintext switched to $\mathbb{Z}\_{2N\_{\mathsf{out}}}$
+///
+/// ###### algorithm:
+/// 1. Compute $\tilde{a}\_i \in \mathbb{Z}\_{2N\_{\mathsf{out}}} \leftarrow \lfloor \frac{2
+///    N\_{\mathsf{out}} \cdot a\_i}{q} \rceil$, for $i= 0, 1, \ldots, n\_{\mathsf{in}-1}$
+/// 2. Compute $\tilde{b}\_\mathsf{in} \in \mathbb{Z}\_{2N\_{\mathsf{out}}} \leftarrow \lfloor
+///    \frac{2 N\_{\mathsf{out}} \cdot b\_\mathsf{in}}{q} \rceil$
+/// 3. Set $\mathsf{ct}\_{\mathsf{out}} = \mathsf{ACC} \cdot X^{-\tilde{b}\_\mathsf{in}}$
+/// 4. Compute $\mathsf{ct}\_{\mathsf{out}} = \mathsf{CMux}(\overline{\overline{\mathsf{CT}\_i}},
+///    \mathsf{ct}\_{\mathsf{out}} \cdot X^{\tilde{a}\_i}, \mathsf{ct}\_{\mathsf{out}})$, for $i= 0,
+///    1, \ldots, n\_{\mathsf{in}-1}$
+/// 5. Output $\mathsf{ct}\_{\mathsf{out}}$
+pub trait LweCiphertextDiscardingBlindRotationEngine<
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Blind rotate an accumulator with an LWE ciphertext.
+    fn discard_blind_rotate_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingBlindRotationError<Self::EngineError>>;
+
+    /// Unsafely blind rotate an accumulator with an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingBlindRotationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_blind_rotate_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    );
+}
@@ -0,0 +1,125 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingManyLutBootstrapError for LweCiphertextDiscardingManyLutBootstrapEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext vector dimension and key size (dimension \
+                                   * polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same.",
+    LutCountNotPowerOfTwo => "The output ciphertext vector count (the number of lookup tables) \
+                              must be a power of two.",
+    LutCountTooLarge => "The output ciphertext vector count (the number of lookup tables) must \
+                         not exceed the polynomial size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingManyLutBootstrapError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if !output.lwe_ciphertext_count().0.is_power_of_two() {
+            return Err(Self::LutCountNotPowerOfTwo);
+        }
+        if output.lwe_ciphertext_count().0 > bsk.polynomial_size().0 {
+            return Err(Self::LutCountTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines bootstrapping (discarding) an LWE ciphertext through several lookup tables
+/// at once.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the evaluations of the lookup tables packed in the `acc` accumulator on the `input` LWE
+/// ciphertext, using a single blind rotation with the `bsk` bootstrap key. The $i$-th ciphertext
+/// of `output` contains the evaluation of the $i$-th lookup table.
+///
+/// # Formal Definition
+///
+/// Let $\vartheta$ be the number of ciphertexts in `output`, which must be a power of two. The
+/// accumulator packs $\vartheta$ lookup tables $\mathsf{LUT}\_0, \ldots,
+/// \mathsf{LUT}\_{\vartheta-1}$ in an interleaved way: for every $j$ multiple of $\vartheta$, the
+/// coefficient $j + i$ of the accumulator polynomial holds the value of $\mathsf{LUT}\_i$ for the
+/// input range starting at $j$.
+///
+/// The algorithm is the one of the
+/// [`programmable bootstrap`](`super::LweCiphertextDiscardingBootstrapEngine`), except that the
+/// modulus switched mask and body $\tilde{a}\_i$ and $\tilde{b}\_\mathsf{in}$ are rounded to
+/// multiples of $\vartheta$ in $\mathbb{Z}\_{2N\_{\mathsf{out}}}$. After the blind rotation, the
+/// coefficient $i$ of the accumulator thus encrypts $\mathsf{LUT}\_i(\mathsf{pt})$, and the $i$-th
+/// output ciphertext is obtained with
+/// $\mathsf{ct}\_{\mathsf{out}, i} \leftarrow \mathsf{SampleExtract}(\mathsf{ACC}, i)$, for
+/// $i = 0, 1, \ldots, \vartheta - 1$.
+///
+/// The extra rounding reduces the precision available for each lookup table by
+/// $\log\_2(\vartheta)$ bits, when compared to a regular programmable bootstrap.
+pub trait LweCiphertextDiscardingManyLutBootstrapEngine<
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Bootstrap an LWE ciphertext through several lookup tables.
+    fn discard_many_lut_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingManyLutBootstrapError<Self::EngineError>>;
+
+    /// Unsafely bootstrap an LWE ciphertext through several lookup tables.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingManyLutBootstrapError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_many_lut_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    );
+}
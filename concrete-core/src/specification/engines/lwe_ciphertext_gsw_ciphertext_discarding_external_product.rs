@@ -0,0 +1,81 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GswCiphertextEntity, LweCiphertextEntity};
+
+use super::engine_error;
+
+engine_error! {
+    LweCiphertextGswCiphertextDiscardingExternalProductError for
+    LweCiphertextGswCiphertextDiscardingExternalProductEngine @
+    LweDimensionMismatch => "All the GSW and LWE ciphertexts LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextGswCiphertextDiscardingExternalProductError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<LweCiphertext, GswCiphertext>(
+        lwe_input: &LweCiphertext,
+        gsw_input: &GswCiphertext,
+        output: &LweCiphertext,
+    ) -> Result<(), Self>
+    where
+        LweCiphertext: LweCiphertextEntity,
+        GswCiphertext: GswCiphertextEntity,
+    {
+        if lwe_input.lwe_dimension() != gsw_input.lwe_dimension()
+            || lwe_input.lwe_dimension() != output.lwe_dimension()
+        {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the external product between an LWE ciphertext and a GSW
+/// ciphertext.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the result of the external product between a `lwe_input` LWE ciphertext and a `gsw_input` GSW
+/// ciphertext.
+///
+/// # Formal Definition
+///
+/// This is the LWE counterpart of the
+/// [`GLWE external product`](`super::GlweCiphertextGgswCiphertextDiscardingExternalProductEngine`).
+/// It takes an [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`)
+/// $\mathsf{ct} \in \mathsf{LWE}\_{\vec{s}}( \mathsf{pt} )$ and a
+/// [`GSW ciphertext`](`crate::specification::entities::GswCiphertextEntity`)
+/// $\mathsf{CT} \in \mathsf{GSW}\_{\vec{s}}( m )$, for a small integer $m$, and returns
+/// $\mathsf{ct}\_{\mathsf{out}} = \mathsf{CT} \boxdot \mathsf{ct} \in \mathsf{LWE}\_{\vec{s}}( m
+/// \cdot \mathsf{pt} )$, computed as the vector-matrix product between the signed decomposition
+/// of $\mathsf{ct}$ and $\mathsf{CT}$.
+pub trait LweCiphertextGswCiphertextDiscardingExternalProductEngine<LweInput, GswInput, Output>:
+    AbstractEngine
+where
+    LweInput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity,
+    Output: LweCiphertextEntity,
+{
+    /// Computes the discarding external product between an LWE and a GSW ciphertext.
+    fn discard_compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+        output: &mut Output,
+    ) -> Result<(), LweCiphertextGswCiphertextDiscardingExternalProductError<Self::EngineError>>;
+
+    /// Unsafely computes the discarding external product between an LWE and a GSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextGswCiphertextDiscardingExternalProductError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+        output: &mut Output,
+    );
+}
@@ -0,0 +1,119 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextVectorEntity, LweCiphertextVectorEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDiscardingVerticalPackingError for
+    LweCiphertextVectorDiscardingVerticalPackingEngine @
+    EmptyOutput => "The output LWE ciphertext vector must contain at least one ciphertext.",
+    OutputLweDimensionMismatch => "The output ciphertexts LWE dimension must be the same as the \
+                                   selectors GLWE dimension times their polynomial size.",
+    MalformedLookUpTables => "The input `luts` must have a size divisible by the selectors \
+                              polynomial size times the number of output ciphertexts. This is \
+                              required to get small look-up tables of polynomials of the same \
+                              size for each output ciphertext.",
+    InvalidSmallLookUpTableSize => "The number of polynomials in a small look-up table must be a \
+                                    power of two, not greater than 2 to the power the number of \
+                                    selectors."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorDiscardingVerticalPackingError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<Output, LUTs, Selectors>(
+        output: &Output,
+        luts: &LUTs,
+        selectors: &Selectors,
+    ) -> Result<(), Self>
+    where
+        Output: LweCiphertextVectorEntity,
+        LUTs: PlaintextVectorEntity,
+        Selectors: GgswCiphertextVectorEntity,
+    {
+        if output.lwe_ciphertext_count().0 == 0 {
+            return Err(Self::EmptyOutput);
+        }
+        let polynomial_size = selectors.polynomial_size().0;
+        if output.lwe_dimension().0 != selectors.glwe_dimension().0 * polynomial_size {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if luts.plaintext_count().0 % (polynomial_size * output.lwe_ciphertext_count().0) != 0 {
+            return Err(Self::MalformedLookUpTables);
+        }
+        let small_lut_polynomial_count =
+            luts.plaintext_count().0 / (polynomial_size * output.lwe_ciphertext_count().0);
+        if small_lut_polynomial_count == 0
+            || !small_lut_polynomial_count.is_power_of_two()
+            || small_lut_polynomial_count.trailing_zeros() as usize
+                > selectors.ggsw_ciphertext_count().0
+        {
+            return Err(Self::InvalidSmallLookUpTableSize);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines performing a (discarding) vertical packing on a vector of GGSW selectors.
+///
+/// The provided "big" `luts` look-up table is divided into as many chunks of polynomials as there
+/// are ciphertexts in the `output` LWE ciphertext vector, following the same layout as the one
+/// described for the [circuit bootstrap + vertical packing][cbs_vp] operation. Each chunk is used
+/// as a look-up table to fill an output ciphertext.
+///
+/// This operation is useful when the GGSW selectors are obtained by other means than a circuit
+/// bootstrap, for example when they are encrypted directly by the client.
+///
+/// [cbs_vp]: super::LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills each ciphertext of the `output`
+/// LWE ciphertext vector with the value of its look-up table selected by the bits encrypted in
+/// the `selectors` GGSW ciphertext vector.
+///
+/// # Formal Definition
+///
+/// Let $\mathsf{CT}\_0, \ldots, \mathsf{CT}\_{\ell-1}$ be the
+/// [`GGSW ciphertexts`](`crate::specification::entities::GgswCiphertextVectorEntity`) of
+/// `selectors`, each encrypting a bit $b\_i$, the first selector holding the most significant bit
+/// of the index $j = \sum\_{i=0}^{\ell-1} b\_i \cdot 2^{\ell-1-i}$. Each small look-up table is
+/// seen as a table of values $\mathsf{T}\_0, \ldots$ stored in its polynomials coefficients.
+///
+/// The most significant selectors are used to evaluate a
+/// [`cmux tree`](`crate::specification::engines::GlweCiphertextDiscardingCmuxTreeEngine`)
+/// selecting one polynomial of the small look-up table. The remaining selectors are then used to
+/// blindly rotate that polynomial, and the constant coefficient of the result is sample extracted
+/// to give an [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`)
+/// $\mathsf{ct}\_{\mathsf{out}} \in \mathsf{LWE}\_{\vec{s}}(\mathsf{T}\_j)$, where $\vec{s}$ is
+/// the flattened GLWE secret key used to encrypt the selectors.
+pub trait LweCiphertextVectorDiscardingVerticalPackingEngine<LUTs, Selectors, Output>:
+    AbstractEngine
+where
+    LUTs: PlaintextVectorEntity,
+    Selectors: GgswCiphertextVectorEntity,
+    Output: LweCiphertextVectorEntity,
+{
+    /// Performs the vertical packing of the look-up tables with the provided selectors.
+    fn discard_vertical_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut Output,
+        luts: &LUTs,
+        selectors: &Selectors,
+    ) -> Result<(), LweCiphertextVectorDiscardingVerticalPackingError<Self::EngineError>>;
+
+    /// Unsafely performs the vertical packing of the look-up tables with the provided selectors.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingVerticalPackingError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn discard_vertical_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut Output,
+        luts: &LUTs,
+        selectors: &Selectors,
+    );
+}
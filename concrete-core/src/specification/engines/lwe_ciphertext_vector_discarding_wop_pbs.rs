@@ -0,0 +1,212 @@
+use super::engine_error;
+use crate::prelude::{
+    CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount, ExtractedBitsCount,
+};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweBootstrapKeyEntity, LweCiphertextVectorEntity,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, LweKeyswitchKeyEntity,
+    PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDiscardingWopPbsError for LweCiphertextVectorDiscardingWopPbsEngine @
+    InputLweDimensionMismatch => "The input ciphertexts and bootstrap key output LWE dimension \
+                                  must be the same.",
+    InputKeyswitchKeyLweDimensionMismatch => "The input ciphertexts LWE dimension must be the same \
+                                              as the keyswitch key input LWE dimension.",
+    KeyDimensionMismatch => "The keyswitch key output LWE dimension must be the same as the \
+                             bootstrap key input LWE dimension.",
+    KeysLweDimensionMismatch => "The bootstrap key output LWE dimension must be the same as the \
+                                 input LWE dimension of the circuit bootstrap private functional \
+                                 packing keyswitch keys.",
+    OutputLweDimensionMismatch => "The output ciphertexts LWE dimension must be the same as the \
+                                   `cbs_pfpksk` output GLWE dimension times its output polynomial \
+                                   size.",
+    ExtractedBitsCountMismatch => "There must be exactly one number of bits to extract per input \
+                                   ciphertext.",
+    NotEnoughBitsToExtract => "The number of bits to extract from each input ciphertext must be \
+                               greater than zero and must not exceed the precision of the \
+                               ciphertext.",
+    MalformedLookUpTables => "The input `luts` must have a size divisible by the circuit bootstrap \
+                              private functional packing keyswitch keys output polynomial size \
+                              times the number of output ciphertexts.",
+    InvalidSmallLookUpTableSize => "The size of a small look-up table must be equal to 2 to the \
+                                    power the total number of extracted bits, and must not be \
+                                    smaller than the `cbs_pfpksk` output polynomial size.",
+    NullDecompositionBaseLog => "The circuit bootstrap decomposition base log must be greater \
+                                 than zero.",
+    NullDecompositionLevelCount => "The circuit bootstrap decomposition level count must be \
+                                    greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorDiscardingWopPbsError<EngineError> {
+    /// Validates the inputs
+    #[allow(clippy::too_many_arguments)]
+    pub fn perform_generic_checks<Input, Output, BootstrapKey, KeyswitchKey, LUTs, CBSPFPKSK>(
+        output: &Output,
+        input: &Input,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+        luts: &LUTs,
+        extracted_bits_counts: &[ExtractedBitsCount],
+        cbs_decomposition_level_count: DecompositionLevelCount,
+        cbs_decomposition_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &CBSPFPKSK,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<(), Self>
+    where
+        Input: LweCiphertextVectorEntity,
+        Output: LweCiphertextVectorEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        LUTs: PlaintextVectorEntity,
+        CBSPFPKSK: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    {
+        if input.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(Self::InputKeyswitchKeyLweDimensionMismatch);
+        }
+        if ksk.output_lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::KeyDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != cbs_pfpksk.input_lwe_dimension() {
+            return Err(Self::KeysLweDimensionMismatch);
+        }
+        if output.lwe_dimension().0
+            != cbs_pfpksk.output_glwe_dimension().0 * cbs_pfpksk.output_polynomial_size().0
+        {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if extracted_bits_counts.len() != input.lwe_ciphertext_count().0 {
+            return Err(Self::ExtractedBitsCountMismatch);
+        }
+        if extracted_bits_counts
+            .iter()
+            .any(|bits| bits.0 == 0 || bits.0 > ciphertext_modulus_log.0)
+        {
+            return Err(Self::NotEnoughBitsToExtract);
+        }
+
+        let lut_polynomial_size = cbs_pfpksk.output_polynomial_size().0;
+        if output.lwe_ciphertext_count().0 == 0
+            || luts.plaintext_count().0 % (lut_polynomial_size * output.lwe_ciphertext_count().0)
+                != 0
+        {
+            return Err(Self::MalformedLookUpTables);
+        }
+        let total_bits: usize = extracted_bits_counts.iter().map(|bits| bits.0).sum();
+        let small_lut_size = luts.plaintext_count().0 / output.lwe_ciphertext_count().0;
+        if 1_usize.checked_shl(total_bits as u32) != Some(small_lut_size)
+            || small_lut_size < lut_polynomial_size
+        {
+            return Err(Self::InvalidSmallLookUpTableSize);
+        }
+
+        if cbs_decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if cbs_decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if cbs_decomposition_base_log.0 * cbs_decomposition_level_count.0 > ciphertext_modulus_log.0
+        {
+            return Err(Self::DecompositionTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines performing a (discarding) programmable bootstrapping without padding
+/// (WoP-PBS) on a vector of LWE ciphertexts.
+///
+/// The `input` LWE ciphertext vector holds the blocks of a large integer, for example its residues
+/// in a CRT representation or its digits in a radix representation. Each block encrypts a message
+/// without padding bit, stored in the `extracted_bits_counts[i]` most significant bits of the
+/// plaintext.
+///
+/// The bits of all the blocks are extracted and concatenated, the bits of the first block being
+/// the most significant ones, to form the index at which the look-up tables are evaluated. The
+/// "big" `luts` look-up table is divided into as many small look-up tables as there are
+/// ciphertexts in the `output` vector, each one containing $2^{n}$ values, where $n$ is the total
+/// number of extracted bits. The [WoP-PBS look-up table generation][lut_generation] engine can be
+/// used to build such a look-up table from a function.
+///
+/// [lut_generation]: super::PlaintextVectorWopPbsLutGenerationEngine
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills each ciphertext of the `output`
+/// LWE ciphertext vector with the encryption of the value of its small look-up table at the index
+/// given by the bits of the `input` blocks.
+///
+/// # Formal Definition
+///
+/// Let $\mathsf{ct}\_0, \ldots, \mathsf{ct}\_{k-1}$ be the `input` ciphertexts, each
+/// $\mathsf{ct}\_i$ encrypting a message $m\_i$ of $n\_i$ bits.
+///
+/// The operation first applies the
+/// [`bit extraction`](`crate::specification::engines::LweCiphertextDiscardingBitExtractEngine`)
+/// to each input ciphertext, using the keyswitch key `ksk` and the bootstrap key `bsk`, which gives
+/// the encryptions of the $n = \sum\_i n\_i$ bits of the index
+/// $j = \sum\_{i=0}^{k-1} m\_i \cdot 2^{\sum\_{l > i} n\_l}$. It then performs a
+/// [boolean circuit bootstrap followed by a vertical packing][cbs_vp] of the extracted bits, so
+/// that the $o$-th output ciphertext is an encryption of $\mathsf{T}\_{o,j}$, the $j$-th value of
+/// the $o$-th small look-up table.
+///
+/// [cbs_vp]: super::LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine
+pub trait LweCiphertextVectorDiscardingWopPbsEngine<
+    Input,
+    Output,
+    BootstrapKey,
+    KeyswitchKey,
+    LUTs,
+    CircuitBootstrapFunctionalPackingKeyswitchKeys,
+>: AbstractEngine where
+    Input: LweCiphertextVectorEntity,
+    Output: LweCiphertextVectorEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    LUTs: PlaintextVectorEntity,
+    CircuitBootstrapFunctionalPackingKeyswitchKeys:
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    /// Performs a programmable bootstrapping without padding on the input blocks.
+    #[allow(clippy::too_many_arguments)]
+    fn discard_wop_pbs_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+        luts: &LUTs,
+        extracted_bits_counts: &[ExtractedBitsCount],
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    ) -> Result<(), LweCiphertextVectorDiscardingWopPbsError<Self::EngineError>>;
+
+    /// Unsafely performs a programmable bootstrapping without padding on the input blocks.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingWopPbsError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn discard_wop_pbs_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+        bsk: &BootstrapKey,
+        ksk: &KeyswitchKey,
+        luts: &LUTs,
+        extracted_bits_counts: &[ExtractedBitsCount],
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    );
+}
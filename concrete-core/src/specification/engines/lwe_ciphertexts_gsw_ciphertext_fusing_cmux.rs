@@ -0,0 +1,84 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GswCiphertextEntity, LweCiphertextEntity};
+
+use super::engine_error;
+
+engine_error! {
+    LweCiphertextsGswCiphertextFusingCmuxError for
+    LweCiphertextsGswCiphertextFusingCmuxEngine @
+    LweDimensionMismatch => "The GSW ciphertext and LWE ciphertexts LWE dimensions must be the \
+    same."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextsGswCiphertextFusingCmuxError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputLweCiphertext, OutputLweCiphertext, GswCiphertext>(
+        lwe_output: &OutputLweCiphertext,
+        lwe_input: &InputLweCiphertext,
+        gsw: &GswCiphertext,
+    ) -> Result<(), Self>
+    where
+        InputLweCiphertext: LweCiphertextEntity,
+        OutputLweCiphertext: LweCiphertextEntity,
+        GswCiphertext: GswCiphertextEntity,
+    {
+        if (lwe_input.lwe_dimension() != lwe_output.lwe_dimension())
+            | (lwe_output.lwe_dimension() != gsw.lwe_dimension())
+        {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the controlled multiplexer (cmux) of two LWE ciphertexts and a
+/// GSW ciphertext.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation computes the result of the cmux between
+/// `lwe_output` and `lwe_input` LWE ciphertexts and a `gsw_input` GSW ciphertext, and stores it in
+/// `lwe_output`.
+///
+/// # Formal Definition
+///
+/// This is the LWE counterpart of the
+/// [`GLWE cmux`](`super::GlweCiphertextsGgswCiphertextFusingCmuxEngine`). It takes two
+/// [`LWE ciphertexts`](`crate::specification::entities::LweCiphertextEntity`)
+/// $\mathsf{ct}\_0 \in \mathsf{LWE}\_{\vec{s}}( \mathsf{pt}\_0 )$ (the `lwe_output`) and
+/// $\mathsf{ct}\_1 \in \mathsf{LWE}\_{\vec{s}}( \mathsf{pt}\_1 )$ (the `lwe_input`) and a
+/// [`GSW ciphertext`](`crate::specification::entities::GswCiphertextEntity`)
+/// $\mathsf{CT} \in \mathsf{GSW}\_{\vec{s}}( b )$, for a bit $b$, and sets `lwe_output` to
+/// $\mathsf{ct}\_{\mathsf{out}} \in \mathsf{LWE}\_{\vec{s}}( \mathsf{pt}\_b )$.
+///
+/// This is done by homomorphically computing $(\mathsf{pt}\_1-\mathsf{pt}\_0)*b + \mathsf{pt}\_0$
+/// using the external product: $\mathsf{CT} \boxdot (\mathsf{ct}\_1 - \mathsf{ct}\_0) +
+/// \mathsf{ct}\_0$.
+pub trait LweCiphertextsGswCiphertextFusingCmuxEngine<LweInput, LweOutput, GswInput>:
+    AbstractEngine
+where
+    LweInput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity,
+    LweOutput: LweCiphertextEntity,
+{
+    /// Computes the cmux between two LWE ciphertexts and a GSW ciphertext.
+    fn fuse_cmux_lwe_ciphertexts_gsw_ciphertext(
+        &mut self,
+        lwe_output: &mut LweOutput,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+    ) -> Result<(), LweCiphertextsGswCiphertextFusingCmuxError<Self::EngineError>>;
+
+    /// Unsafely computes the cmux between two LWE ciphertexts and a GSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different
+    /// variants of [`LweCiphertextsGswCiphertextFusingCmuxError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn fuse_cmux_lwe_ciphertexts_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_output: &mut LweOutput,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+    );
+}
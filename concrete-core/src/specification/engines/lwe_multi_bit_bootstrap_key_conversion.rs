@@ -0,0 +1,37 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweMultiBitBootstrapKeyEntity;
+
+engine_error! {
+    LweMultiBitBootstrapKeyConversionError for LweMultiBitBootstrapKeyConversionEngine @
+}
+
+/// A trait for engines converting multi-bit LWE bootstrap keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a multi-bit LWE bootstrap key
+/// containing the conversion of the `input` bootstrap key to a type with a different
+/// representation (for instance from the standard to the Fourier domain).
+///
+/// # Formal Definition
+pub trait LweMultiBitBootstrapKeyConversionEngine<InputKey, OutputKey>: AbstractEngine
+where
+    InputKey: LweMultiBitBootstrapKeyEntity,
+    OutputKey: LweMultiBitBootstrapKeyEntity,
+{
+    /// Converts a multi-bit LWE bootstrap key.
+    fn convert_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input: &InputKey,
+    ) -> Result<OutputKey, LweMultiBitBootstrapKeyConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a multi-bit LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweMultiBitBootstrapKeyConversionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn convert_lwe_multi_bit_bootstrap_key_unchecked(&mut self, input: &InputKey)
+        -> OutputKey;
+}
@@ -0,0 +1,102 @@
+use super::engine_error;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, LweBskGroupingFactor, Variance,
+};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweSecretKeyEntity, LweMultiBitBootstrapKeyEntity, LweSecretKeyEntity,
+};
+
+engine_error! {
+    LweMultiBitBootstrapKeyGenerationError for LweMultiBitBootstrapKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    NullGroupingFactor => "The grouping factor must be greater than zero.",
+    GroupingFactorTooLarge => "The grouping factor must not exceed 3.",
+    InputLweDimensionNotDivisible => "The input LWE dimension must be a multiple of the grouping \
+                                      factor."
+}
+
+impl<EngineError: std::error::Error> LweMultiBitBootstrapKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<LweSecretKey>(
+        input_key: &LweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self>
+    where
+        LweSecretKey: LweSecretKeyEntity,
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > ciphertext_modulus_log {
+            return Err(Self::DecompositionTooLarge);
+        }
+        if grouping_factor.0 == 0 {
+            return Err(Self::NullGroupingFactor);
+        }
+        // The key stores 2^grouping_factor - 1 GGSW ciphertexts per group, which only pays off in
+        // latency for small groups.
+        if grouping_factor.0 > 3 {
+            return Err(Self::GroupingFactorTooLarge);
+        }
+        if input_key.lwe_dimension().0 % grouping_factor.0 != 0 {
+            return Err(Self::InputLweDimensionNotDivisible);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new multi-bit LWE bootstrap keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new multi-bit LWE bootstrap key
+/// from the `input_key` LWE secret key, and the `output_key` GLWE secret key. The coefficients of
+/// the input key are encrypted by groups of `grouping_factor`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::LweMultiBitBootstrapKeyEntity`)
+pub trait LweMultiBitBootstrapKeyGenerationEngine<LweSecretKey, GlweSecretKey, BootstrapKey>:
+    AbstractEngine
+where
+    BootstrapKey: LweMultiBitBootstrapKeyEntity,
+    LweSecretKey: LweSecretKeyEntity,
+    GlweSecretKey: GlweSecretKeyEntity,
+{
+    /// Generates a new multi-bit LWE bootstrap key.
+    fn generate_new_lwe_multi_bit_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey,
+        output_key: &GlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> Result<BootstrapKey, LweMultiBitBootstrapKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new multi-bit LWE bootstrap key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweMultiBitBootstrapKeyGenerationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn generate_new_lwe_multi_bit_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey,
+        output_key: &GlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        noise: Variance,
+    ) -> BootstrapKey;
+}
@@ -0,0 +1,91 @@
+use super::engine_error;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSecretKeyEntity, LweSecretKeyEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, Variance,
+};
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationError for
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error>
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        ciphertext_modulus_log: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > ciphertext_modulus_log {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new seeded LWE functional packing keyswitch keys used in a
+/// circuit bootstrapping.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new seeded set of LWE private
+/// functional packing keyswitch keys required to perform a circuit bootstrapping.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity`)
+pub trait LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine<
+    InputLweSecretKey,
+    OutputGlweSecretKey,
+    SeededCBSFPKSK,
+>: AbstractEngine where
+    InputLweSecretKey: LweSecretKeyEntity,
+    OutputGlweSecretKey: GlweSecretKeyEntity,
+    SeededCBSFPKSK: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    /// Generate a new seeded LWE CBSFPKSK.
+    fn generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_lwe_key: &InputLweSecretKey,
+        output_glwe_key: &OutputGlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<
+        SeededCBSFPKSK,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationError<
+            Self::EngineError,
+        >,
+    >;
+
+    /// Unsafely generate a new seeded LWE CBSFPKSK.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationError`]. For
+    /// safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        input_lwe_key: &InputLweSecretKey,
+        output_glwe_key: &OutputGlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> SeededCBSFPKSK;
+}
@@ -0,0 +1,55 @@
+use super::engine_error;
+use crate::prelude::AbstractEngine;
+
+use crate::specification::entities::{
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+};
+
+engine_error! {
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError
+    for
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine @
+}
+
+/// A trait for engines transforming seeded circuit bootstrap private functional packing keyswitch
+/// keys into circuit bootstrap private functional packing keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing seeded keys into standard
+/// circuit bootstrap private functional packing keyswitch keys.
+///
+/// # Formal Definition
+///
+/// ## Seeded CBSFPKSK to CBSFPKSK transformation
+///
+/// The masks of the GLWE ciphertexts of all the keys are regenerated from the compression seed,
+/// and the bodies are moved to the output keys.
+pub trait LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine<
+    InputKeys,
+    OutputKeys,
+>: AbstractEngine where
+    InputKeys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    OutputKeys: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    /// Does the transformation of the seeded CBSFPKSK into a CBSFPKSK
+    fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys: InputKeys,
+    ) -> Result<
+        OutputKeys,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a seeded CBSFPKSK into a CBSFPKSK
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys: InputKeys,
+    ) -> OutputKeys;
+}
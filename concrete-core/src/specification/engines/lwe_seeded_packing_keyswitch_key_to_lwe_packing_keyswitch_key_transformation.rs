@@ -0,0 +1,52 @@
+use super::engine_error;
+use crate::prelude::AbstractEngine;
+
+use crate::specification::entities::{
+    LwePackingKeyswitchKeyEntity, LweSeededPackingKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationError for
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine @
+}
+
+/// A trait for engines transforming seeded packing keyswitch keys into packing keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing seeded packing keyswitch
+/// key into a packing keyswitch key.
+///
+/// # Formal Definition
+///
+/// ## Seeded packing keyswitch key to packing keyswitch key transformation
+///
+/// The masks of the GLWE ciphertexts of the key are regenerated from the compression seed, and the
+/// bodies are moved to the output key.
+pub trait LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine<
+    InputKey,
+    OutputKey,
+>: AbstractEngine where
+    InputKey: LweSeededPackingKeyswitchKeyEntity,
+    OutputKey: LwePackingKeyswitchKeyEntity,
+{
+    /// Does the transformation of the seeded packing keyswitch key into a packing keyswitch key
+    fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+        &mut self,
+        lwe_seeded_packing_keyswitch_key: InputKey,
+    ) -> Result<
+        OutputKey,
+        LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a seeded packing keyswitch key into a packing keyswitch key
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_unchecked(
+        &mut self,
+        lwe_seeded_packing_keyswitch_key: InputKey,
+    ) -> OutputKey;
+}
@@ -0,0 +1,109 @@
+use super::engine_error;
+use crate::prelude::{
+    CleartextVectorEntity, GlweSecretKeyEntity, LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+};
+use crate::specification::engines::AbstractEngine;
+
+use crate::prelude::{DecompositionBaseLog, DecompositionLevelCount, PolynomialSize, StandardDev};
+use crate::specification::entities::LweSecretKeyEntity;
+
+engine_error! {
+    LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationError for
+    LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    DifferentPolynomialSizes => "The polynomial size of the output GLWE key is different from \
+                                 that of the polynomial scalar defining the function."
+}
+
+impl<EngineError: std::error::Error>
+    LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        ciphertext_modulus_log: usize,
+        output_key_polynomial_size: PolynomialSize,
+        polynomial_scalar_polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > ciphertext_modulus_log {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if output_key_polynomial_size != polynomial_scalar_polynomial_size {
+            return Err(Self::DifferentPolynomialSizes);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new seeded LWE private functional packing keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new seeded LWE private functional
+/// packing keyswitch key allowing to switch from the `input_key` LWE secret key to the
+/// `output_key` GLWE secret key while applying the private function.
+///
+/// # Formal Definition
+///
+/// The key is generated exactly as in
+/// [`LwePrivateFunctionalLwePackingKeyswitchKeyGenerationEngine`](`super::LwePrivateFunctionalLwePackingKeyswitchKeyGenerationEngine`),
+/// except that only the bodies of the GLWE ciphertexts are stored along with the compression seed.
+pub trait LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine<
+    InputSecretKey,
+    OutputSecretKey,
+    SeededPrivateFunctionalPackingKeyswitchKey,
+    CleartextVector,
+    FunctionScalarType,
+>: AbstractEngine where
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    CleartextVector: CleartextVectorEntity,
+    SeededPrivateFunctionalPackingKeyswitchKey: LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+{
+    /// Generates a new seeded private functional packing keyswitch key.
+    #[allow(clippy::too_many_arguments)]
+    fn generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: StandardDev,
+        f: &dyn Fn(FunctionScalarType) -> FunctionScalarType,
+        polynomial: &CleartextVector,
+    ) -> Result<
+        SeededPrivateFunctionalPackingKeyswitchKey,
+        LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationError<Self::EngineError>,
+    >;
+
+    /// Unsafely generates a new seeded private functional packing keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn generate_new_lwe_seeded_private_functional_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: StandardDev,
+        f: &dyn Fn(FunctionScalarType) -> FunctionScalarType,
+        polynomial: &CleartextVector,
+    ) -> SeededPrivateFunctionalPackingKeyswitchKey;
+}
@@ -0,0 +1,58 @@
+use super::engine_error;
+use crate::prelude::{LwePublicKeyZeroEncryptionCount, Variance};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweSecretKeyEntity, LweSeededPublicKeyEntity};
+
+engine_error! {
+    LweSeededPublicKeyGenerationError for LweSeededPublicKeyGenerationEngine @
+    NullPublicKeyZeroEncryptionCount => "The number of LWE encryptions of zero in the seeded LWE \
+                                        public key must be greater than zero."
+}
+
+impl<EngineError: std::error::Error> LweSeededPublicKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<(), Self> {
+        if lwe_public_key_zero_encryption_count.0 == 0 {
+            return Err(Self::NullPublicKeyZeroEncryptionCount);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new seeded LWE public keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new seeded LWE public key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::LweSeededPublicKeyEntity`)
+pub trait LweSeededPublicKeyGenerationEngine<SecretKey, SeededPublicKey>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    SeededPublicKey: LweSeededPublicKeyEntity,
+{
+    /// Generates a new seeded LWE public key.
+    fn generate_new_lwe_seeded_public_key(
+        &mut self,
+        lwe_secret_key: &SecretKey,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<SeededPublicKey, LweSeededPublicKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new seeded LWE public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededPublicKeyGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_lwe_seeded_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &SecretKey,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> SeededPublicKey;
+}
@@ -164,37 +164,72 @@ mod encoder_creation;
 mod encoder_vector_creation;
 mod entity_deserialization;
 mod entity_serialization;
+mod entity_stream_deserialization;
+mod entity_stream_serialization;
+mod entity_zero_copy_loading;
+mod entity_zero_copy_serialization;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
+mod ggsw_ciphertext_polynomial_discarding_encryption;
+mod ggsw_ciphertext_polynomial_encryption;
+mod ggsw_ciphertext_polynomial_trivial_encryption;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_vector_conversion;
+mod ggsw_ciphertext_vector_creation;
+mod ggsw_ciphertext_vector_scalar_encryption;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_addition;
+mod glwe_ciphertext_discarding_cmux_tree;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_keyswitch;
+mod glwe_ciphertext_discarding_monomial_multiplication;
+mod glwe_ciphertext_discarding_multiplication;
+mod glwe_ciphertext_discarding_opposite;
+mod glwe_ciphertext_discarding_subtraction;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_addition;
+mod glwe_ciphertext_fusing_monomial_multiplication;
+mod glwe_ciphertext_fusing_opposite;
+mod glwe_ciphertext_fusing_subtraction;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_multiplication;
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+mod glwe_ciphertext_plaintext_vector_discarding_multiplication;
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
+mod glwe_ciphertext_plaintext_vector_fusing_multiplication;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_consuming_retrieval;
 mod glwe_ciphertext_vector_conversion;
 mod glwe_ciphertext_vector_creation;
 mod glwe_ciphertext_vector_decryption;
+mod glwe_ciphertext_vector_discarding_addition;
 mod glwe_ciphertext_vector_discarding_conversion;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
+mod glwe_ciphertext_vector_discarding_opposite;
+mod glwe_ciphertext_vector_discarding_subtraction;
 mod glwe_ciphertext_vector_encryption;
+mod glwe_ciphertext_vector_fusing_addition;
+mod glwe_ciphertext_vector_fusing_opposite;
+mod glwe_ciphertext_vector_fusing_subtraction;
 mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_keyswitch_key_generation;
+mod glwe_relinearization_key_conversion;
+mod glwe_relinearization_key_generation;
 mod glwe_secret_key_conversion;
 mod glwe_secret_key_discarding_conversion;
 mod glwe_secret_key_generation;
@@ -202,7 +237,10 @@ mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
 mod glwe_seeded_ciphertext_vector_encryption;
 mod glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation;
+mod glwe_seeded_keyswitch_key_generation;
+mod glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation;
 mod glwe_to_lwe_secret_key_transformation;
+mod gsw_ciphertext_scalar_encryption;
 mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
@@ -216,6 +254,7 @@ mod lwe_ciphertext_creation;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bit_extraction;
+mod lwe_ciphertext_discarding_blind_rotation;
 mod lwe_ciphertext_discarding_bootstrap;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
@@ -223,6 +262,8 @@ mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
 mod lwe_ciphertext_discarding_loading;
+mod lwe_ciphertext_discarding_many_lut_bootstrap;
+mod lwe_ciphertext_discarding_multi_bit_bootstrap;
 mod lwe_ciphertext_discarding_opposite;
 mod lwe_ciphertext_discarding_public_key_encryption;
 mod lwe_ciphertext_discarding_storing;
@@ -231,6 +272,7 @@ mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gsw_ciphertext_discarding_external_product;
 mod lwe_ciphertext_loading;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
@@ -255,6 +297,8 @@ mod lwe_ciphertext_vector_discarding_keyswitch;
 mod lwe_ciphertext_vector_discarding_loading;
 mod lwe_ciphertext_vector_discarding_opposite;
 mod lwe_ciphertext_vector_discarding_subtraction;
+mod lwe_ciphertext_vector_discarding_vertical_packing;
+mod lwe_ciphertext_vector_discarding_wop_pbs;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_opposite;
@@ -267,6 +311,7 @@ mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_conversion;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
 mod lwe_keyswitch_key_consuming_retrieval;
@@ -274,6 +319,8 @@ mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_creation;
 mod lwe_keyswitch_key_discarding_conversion;
 mod lwe_keyswitch_key_generation;
+mod lwe_multi_bit_bootstrap_key_conversion;
+mod lwe_multi_bit_bootstrap_key_generation;
 mod lwe_packing_keyswitch_key_generation;
 mod lwe_private_functional_packing_keyswitch_key_conversion;
 mod lwe_private_functional_packing_keyswitch_key_generation;
@@ -287,8 +334,16 @@ mod lwe_seeded_ciphertext_encryption;
 mod lwe_seeded_ciphertext_to_lwe_ciphertext_transformation;
 mod lwe_seeded_ciphertext_vector_encryption;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation;
 mod lwe_seeded_keyswitch_key_generation;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
+mod lwe_seeded_packing_keyswitch_key_generation;
+mod lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_generation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation;
+mod lwe_seeded_public_key_generation;
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
 mod lwe_to_glwe_secret_key_transformation;
 mod plaintext_conversion;
 mod plaintext_creation;
@@ -302,6 +357,8 @@ mod plaintext_vector_decoding;
 mod plaintext_vector_discarding_conversion;
 mod plaintext_vector_discarding_retrieval;
 mod plaintext_vector_retrieval;
+mod plaintext_vector_wop_pbs_lut_generation;
+mod serialization_header_inspection;
 
 pub use cleartext_conversion::*;
 pub use cleartext_creation::*;
@@ -319,37 +376,72 @@ pub use encoder_creation::*;
 pub use encoder_vector_creation::*;
 pub use entity_deserialization::*;
 pub use entity_serialization::*;
+pub use entity_stream_deserialization::*;
+pub use entity_stream_serialization::*;
+pub use entity_zero_copy_loading::*;
+pub use entity_zero_copy_serialization::*;
 pub use ggsw_ciphertext_conversion::*;
 pub use ggsw_ciphertext_discarding_conversion::*;
+pub use ggsw_ciphertext_polynomial_discarding_encryption::*;
+pub use ggsw_ciphertext_polynomial_encryption::*;
+pub use ggsw_ciphertext_polynomial_trivial_encryption::*;
 pub use ggsw_ciphertext_scalar_discarding_encryption::*;
 pub use ggsw_ciphertext_scalar_encryption::*;
 pub use ggsw_ciphertext_scalar_trivial_encryption::*;
+pub use ggsw_ciphertext_vector_conversion::*;
+pub use ggsw_ciphertext_vector_creation::*;
+pub use ggsw_ciphertext_vector_scalar_encryption::*;
 pub use glwe_ciphertext_consuming_retrieval::*;
 pub use glwe_ciphertext_conversion::*;
 pub use glwe_ciphertext_creation::*;
 pub use glwe_ciphertext_decryption::*;
+pub use glwe_ciphertext_discarding_addition::*;
+pub use glwe_ciphertext_discarding_cmux_tree::*;
 pub use glwe_ciphertext_discarding_conversion::*;
 pub use glwe_ciphertext_discarding_decryption::*;
 pub use glwe_ciphertext_discarding_encryption::*;
+pub use glwe_ciphertext_discarding_keyswitch::*;
+pub use glwe_ciphertext_discarding_monomial_multiplication::*;
+pub use glwe_ciphertext_discarding_multiplication::*;
+pub use glwe_ciphertext_discarding_opposite::*;
+pub use glwe_ciphertext_discarding_subtraction::*;
 pub use glwe_ciphertext_discarding_trivial_encryption::*;
 pub use glwe_ciphertext_encryption::*;
+pub use glwe_ciphertext_fusing_addition::*;
+pub use glwe_ciphertext_fusing_monomial_multiplication::*;
+pub use glwe_ciphertext_fusing_opposite::*;
+pub use glwe_ciphertext_fusing_subtraction::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+pub use glwe_ciphertext_multiplication::*;
+pub use glwe_ciphertext_plaintext_vector_discarding_addition::*;
+pub use glwe_ciphertext_plaintext_vector_discarding_multiplication::*;
+pub use glwe_ciphertext_plaintext_vector_fusing_addition::*;
+pub use glwe_ciphertext_plaintext_vector_fusing_multiplication::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
 pub use glwe_ciphertext_vector_consuming_retrieval::*;
 pub use glwe_ciphertext_vector_conversion::*;
 pub use glwe_ciphertext_vector_creation::*;
 pub use glwe_ciphertext_vector_decryption::*;
+pub use glwe_ciphertext_vector_discarding_addition::*;
 pub use glwe_ciphertext_vector_discarding_conversion::*;
 pub use glwe_ciphertext_vector_discarding_decryption::*;
 pub use glwe_ciphertext_vector_discarding_encryption::*;
+pub use glwe_ciphertext_vector_discarding_opposite::*;
+pub use glwe_ciphertext_vector_discarding_subtraction::*;
 pub use glwe_ciphertext_vector_encryption::*;
+pub use glwe_ciphertext_vector_fusing_addition::*;
+pub use glwe_ciphertext_vector_fusing_opposite::*;
+pub use glwe_ciphertext_vector_fusing_subtraction::*;
 pub use glwe_ciphertext_vector_trivial_decryption::*;
 pub use glwe_ciphertext_vector_trivial_encryption::*;
 pub use glwe_ciphertext_vector_zero_encryption::*;
 pub use glwe_ciphertext_zero_encryption::*;
 pub use glwe_ciphertexts_ggsw_ciphertext_fusing_cmux::*;
+pub use glwe_keyswitch_key_generation::*;
+pub use glwe_relinearization_key_conversion::*;
+pub use glwe_relinearization_key_generation::*;
 pub use glwe_secret_key_conversion::*;
 pub use glwe_secret_key_discarding_conversion::*;
 pub use glwe_secret_key_generation::*;
@@ -357,7 +449,10 @@ pub use glwe_seeded_ciphertext_encryption::*;
 pub use glwe_seeded_ciphertext_to_glwe_ciphertext_transformation::*;
 pub use glwe_seeded_ciphertext_vector_encryption::*;
 pub use glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation::*;
+pub use glwe_seeded_keyswitch_key_generation::*;
+pub use glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation::*;
 pub use glwe_to_lwe_secret_key_transformation::*;
+pub use gsw_ciphertext_scalar_encryption::*;
 pub use lwe_bootstrap_key_consuming_retrieval::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_creation::*;
@@ -371,6 +466,7 @@ pub use lwe_ciphertext_creation::*;
 pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
 pub use lwe_ciphertext_discarding_bit_extraction::*;
+pub use lwe_ciphertext_discarding_blind_rotation::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
 pub use lwe_ciphertext_discarding_conversion::*;
 pub use lwe_ciphertext_discarding_decryption::*;
@@ -378,6 +474,8 @@ pub use lwe_ciphertext_discarding_encryption::*;
 pub use lwe_ciphertext_discarding_extraction::*;
 pub use lwe_ciphertext_discarding_keyswitch::*;
 pub use lwe_ciphertext_discarding_loading::*;
+pub use lwe_ciphertext_discarding_many_lut_bootstrap::*;
+pub use lwe_ciphertext_discarding_multi_bit_bootstrap::*;
 pub use lwe_ciphertext_discarding_opposite::*;
 pub use lwe_ciphertext_discarding_public_key_encryption::*;
 pub use lwe_ciphertext_discarding_storing::*;
@@ -386,6 +484,7 @@ pub use lwe_ciphertext_encryption::*;
 pub use lwe_ciphertext_fusing_addition::*;
 pub use lwe_ciphertext_fusing_opposite::*;
 pub use lwe_ciphertext_fusing_subtraction::*;
+pub use lwe_ciphertext_gsw_ciphertext_discarding_external_product::*;
 pub use lwe_ciphertext_loading::*;
 pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
@@ -410,6 +509,8 @@ pub use lwe_ciphertext_vector_discarding_keyswitch::*;
 pub use lwe_ciphertext_vector_discarding_loading::*;
 pub use lwe_ciphertext_vector_discarding_opposite::*;
 pub use lwe_ciphertext_vector_discarding_subtraction::*;
+pub use lwe_ciphertext_vector_discarding_vertical_packing::*;
+pub use lwe_ciphertext_vector_discarding_wop_pbs::*;
 pub use lwe_ciphertext_vector_encryption::*;
 pub use lwe_ciphertext_vector_fusing_addition::*;
 pub use lwe_ciphertext_vector_fusing_opposite::*;
@@ -422,6 +523,7 @@ pub use lwe_ciphertext_vector_trivial_decryption::*;
 pub use lwe_ciphertext_vector_trivial_encryption::*;
 pub use lwe_ciphertext_vector_zero_encryption::*;
 pub use lwe_ciphertext_zero_encryption::*;
+pub use lwe_ciphertexts_gsw_ciphertext_fusing_cmux::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_conversion::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation::*;
 pub use lwe_keyswitch_key_consuming_retrieval::*;
@@ -429,6 +531,8 @@ pub use lwe_keyswitch_key_conversion::*;
 pub use lwe_keyswitch_key_creation::*;
 pub use lwe_keyswitch_key_discarding_conversion::*;
 pub use lwe_keyswitch_key_generation::*;
+pub use lwe_multi_bit_bootstrap_key_conversion::*;
+pub use lwe_multi_bit_bootstrap_key_generation::*;
 pub use lwe_packing_keyswitch_key_generation::*;
 pub use lwe_private_functional_packing_keyswitch_key_conversion::*;
 pub use lwe_private_functional_packing_keyswitch_key_generation::*;
@@ -442,8 +546,16 @@ pub use lwe_seeded_ciphertext_encryption::*;
 pub use lwe_seeded_ciphertext_to_lwe_ciphertext_transformation::*;
 pub use lwe_seeded_ciphertext_vector_encryption::*;
 pub use lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation::*;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation::*;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation::*;
 pub use lwe_seeded_keyswitch_key_generation::*;
 pub use lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation::*;
+pub use lwe_seeded_packing_keyswitch_key_generation::*;
+pub use lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation::*;
+pub use lwe_seeded_private_functional_packing_keyswitch_key_generation::*;
+pub use lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation::*;
+pub use lwe_seeded_public_key_generation::*;
+pub use lwe_seeded_public_key_to_lwe_public_key_transformation::*;
 pub use lwe_to_glwe_secret_key_transformation::*;
 pub use plaintext_conversion::*;
 pub use plaintext_creation::*;
@@ -457,3 +569,5 @@ pub use plaintext_vector_decoding::*;
 pub use plaintext_vector_discarding_conversion::*;
 pub use plaintext_vector_discarding_retrieval::*;
 pub use plaintext_vector_retrieval::*;
+pub use plaintext_vector_wop_pbs_lut_generation::*;
+pub use serialization_header_inspection::*;
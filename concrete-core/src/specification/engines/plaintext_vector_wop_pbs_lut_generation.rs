@@ -0,0 +1,105 @@
+use super::engine_error;
+use crate::prelude::{CiphertextModulusLog, ExtractedBitsCount};
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::PlaintextVectorEntity;
+
+engine_error! {
+    PlaintextVectorWopPbsLutGenerationError for PlaintextVectorWopPbsLutGenerationEngine @
+    EmptyInputBlocks => "There must be at least one input block.",
+    EmptyOutputBlocks => "There must be at least one output block.",
+    NullBitsCount => "Each block must contain at least one bit.",
+    TooManyInputBits => "The total number of input bits must be smaller than the number of bits \
+                         of a `usize`.",
+    OutputBitsTooLarge => "The number of bits of an output block must not exceed the precision of \
+                           the ciphertext.",
+    FunctionOutputCountMismatch => "The function must return exactly one value per output block."
+}
+
+impl<EngineError: std::error::Error> PlaintextVectorWopPbsLutGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        input_bits_counts: &[ExtractedBitsCount],
+        output_bits_counts: &[ExtractedBitsCount],
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<(), Self> {
+        if input_bits_counts.is_empty() {
+            return Err(Self::EmptyInputBlocks);
+        }
+        if output_bits_counts.is_empty() {
+            return Err(Self::EmptyOutputBlocks);
+        }
+        if input_bits_counts
+            .iter()
+            .chain(output_bits_counts.iter())
+            .any(|bits| bits.0 == 0)
+        {
+            return Err(Self::NullBitsCount);
+        }
+        let total_input_bits: usize = input_bits_counts.iter().map(|bits| bits.0).sum();
+        if total_input_bits >= usize::BITS as usize {
+            return Err(Self::TooManyInputBits);
+        }
+        if output_bits_counts
+            .iter()
+            .any(|bits| bits.0 > ciphertext_modulus_log.0)
+        {
+            return Err(Self::OutputBitsTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating the look-up tables evaluated by a
+/// [`WoP-PBS`](`crate::specification::engines::LweCiphertextVectorDiscardingWopPbsEngine`) from a
+/// function.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a plaintext vector containing one
+/// small look-up table per output block, each one encoding the values of the function `f` for all
+/// the possible values of the input blocks.
+///
+/// # Formal Definition
+///
+/// Let $n\_0, \ldots, n\_{k-1}$ be the `input_bits_counts` and $p\_0, \ldots, p\_{r-1}$ be the
+/// `output_bits_counts`. For each index $j$ of $n = \sum\_i n\_i$ bits, the input block values
+/// $m\_0, \ldots, m\_{k-1}$ are obtained by splitting the bits of $j$, the bits of the first block
+/// being the most significant ones. The function `f` is then called on these values and must
+/// return $r$ values $v\_0, \ldots, v\_{r-1}$.
+///
+/// The $j$-th value of the $o$-th small look-up table is set to
+/// $(v\_o \bmod 2^{p\_o}) \cdot 2^{q - p\_o}$, where $q$ is the number of bits of the ciphertext
+/// modulus. This encodes the output value in the most significant bits, without padding bit, as
+/// expected by a subsequent WoP-PBS.
+///
+/// This can be used to evaluate a function on an integer represented in CRT or in radix form,
+/// `f` then being responsible for the decomposition and recomposition of the blocks.
+pub trait PlaintextVectorWopPbsLutGenerationEngine<PlaintextVector>: AbstractEngine
+where
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Generates the look-up tables of a WoP-PBS from a function.
+    fn generate_wop_pbs_lut_plaintext_vector<F>(
+        &mut self,
+        input_bits_counts: &[ExtractedBitsCount],
+        output_bits_counts: &[ExtractedBitsCount],
+        f: F,
+    ) -> Result<PlaintextVector, PlaintextVectorWopPbsLutGenerationError<Self::EngineError>>
+    where
+        F: Fn(&[u64]) -> Vec<u64>;
+
+    /// Unsafely generates the look-up tables of a WoP-PBS from a function.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`PlaintextVectorWopPbsLutGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_wop_pbs_lut_plaintext_vector_unchecked<F>(
+        &mut self,
+        input_bits_counts: &[ExtractedBitsCount],
+        output_bits_counts: &[ExtractedBitsCount],
+        f: F,
+    ) -> PlaintextVector
+    where
+        F: Fn(&[u64]) -> Vec<u64>;
+}
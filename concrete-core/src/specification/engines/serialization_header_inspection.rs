@@ -0,0 +1,39 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::serialization::SerializationHeader;
+
+engine_error! {
+    SerializationHeaderInspectionError for SerializationHeaderInspectionEngine @
+}
+
+/// A trait for engines reading the header of a serialized entity.
+///
+/// # Semantics
+///
+/// This operation reads the [`SerializationHeader`] at the start of the `serialized` value, and
+/// returns it without deserializing the entity. The header describes the kind, the precision and
+/// the parameters of the serialized entity, which allows routing a serialized value before
+/// picking the type to deserialize it into.
+///
+/// Only the header is read: when `serialized` is a reader, exactly the bytes of the header are
+/// consumed. The checksum of the header is verified, but the checksum of the payload is not, as
+/// it would require reading the whole value. When the whole value is available in memory, it can
+/// be verified with [`SerializationHeader::validate`].
+pub trait SerializationHeaderInspectionEngine<Serialized>: AbstractEngine {
+    /// Reads the header of a serialized entity.
+    fn inspect_serialization_header(
+        &mut self,
+        serialized: Serialized,
+    ) -> Result<SerializationHeader, SerializationHeaderInspectionError<Self::EngineError>>;
+
+    /// Unsafely reads the header of a serialized entity.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`SerializationHeaderInspectionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn inspect_serialization_header_unchecked(
+        &mut self,
+        serialized: Serialized,
+    ) -> SerializationHeader;
+}
@@ -0,0 +1,39 @@
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweRelinearizationKeyKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a GLWE relinearization key.
+///
+/// # Formal Definition
+///
+/// ## Relinearization Key
+///
+/// A relinearization key is a vector of GLev ciphertexts (described on the bottom of
+/// [`this page`](`crate::specification::entities::GgswCiphertextEntity`)). It encrypts the
+/// products of the polynomials of the
+/// [`GLWE secret key`](`crate::specification::entities::GlweSecretKeyEntity`)
+/// $\vec{S} = \left( S\_0, \cdots, S\_{k-1}\right)$ under the same key:
+///
+/// $$\mathsf{RLK}\_{\vec{S}} = \left( \overline{\overline{\mathsf{CT}}}\_{i,j}\right)\_{0\le i\le
+/// j<k}$$
+///
+/// where for all $0\le i\le j<k$ we have $\overline{\overline{\mathsf{CT}}}\_{i,j} \in
+/// \mathsf{GLev}\_{\vec{S}}^{\beta, \ell}\left(S\_i\cdot S\_j\right)$.
+///
+/// It is used to bring the tensor product of two GLWE ciphertexts, whose phase involves the
+/// products $S\_i\cdot S\_j$, back to a GLWE ciphertext encrypted under $\vec{S}$.
+pub trait GlweRelinearizationKeyEntity: AbstractEntity<Kind = GlweRelinearizationKeyKind> {
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+}
@@ -0,0 +1,37 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::GlweSeededKeyswitchKeyKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a seeded GLWE keyswitch key.
+///
+/// # Formal Definition
+///
+/// ## Seeded GLWE Key Switching Key
+///
+/// A seeded GLWE key switching key is a
+/// [`GLWE key switching key`](`crate::specification::entities::GlweKeyswitchKeyEntity`) in which
+/// only the bodies of the GLWE ciphertexts are stored. The masks are regenerated from the
+/// compression seed when the key is transformed into a standard GLWE keyswitch key.
+pub trait GlweSeededKeyswitchKeyEntity: AbstractEntity<Kind = GlweSeededKeyswitchKeyKind> {
+    /// Returns the input GLWE dimension of the key.
+    fn input_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the compression seed used to generate the masks of the GLWE ciphertexts during
+    /// encryption.
+    fn compression_seed(&self) -> CompressionSeed;
+}
@@ -0,0 +1,56 @@
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    LweDimension, PolynomialSize,
+};
+use crate::specification::entities::markers::LweMultiBitBootstrapKeyKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a multi-bit LWE bootstrap key.
+///
+/// # Formal Definition
+///
+/// ## Multi-Bit Bootstrapping Key
+///
+/// A multi-bit bootstrapping key is a vector of
+/// [`GGSW ciphertexts`](`crate::specification::entities::GgswCiphertextEntity`). The coefficients
+/// of the [`LWE secret key`](`crate::specification::entities::LweSecretKeyEntity`)
+/// $\vec{s}\_{\mathsf{in}}$ are split into $n\_{\mathsf{in}} / g$ groups of $g$ consecutive
+/// coefficients, where $g$ is the grouping factor. For every group
+/// $\left(s\_{gj}, \cdots, s\_{gj+g-1}\right)$ and every non-zero bit pattern
+/// $\vec{b} = \left(b\_0, \cdots, b\_{g-1}\right) \in \\{0,1\\}^g$ the key contains a GGSW
+/// ciphertext under the [GLWE secret key](`crate::specification::entities::GlweSecretKeyEntity`)
+/// $\vec{S}\_{\mathsf{out}}$ of
+///
+/// $$f\_{\vec{b}}\left(s\_{gj}, \cdots, s\_{gj+g-1}\right) = \prod\_{i=0}^{g-1}
+/// s\_{gj+i}^{b\_i}\cdot\left(1-s\_{gj+i}\right)^{1-b\_i}$$
+///
+/// that is, an encryption of one if the group of key coefficients is equal to $\vec{b}$, and of
+/// zero otherwise. The key thus contains $\frac{n\_{\mathsf{in}}}{g}\cdot\left(2^g-1\right)$ GGSW
+/// ciphertexts, and the patterns of a group are ordered by increasing value of
+/// $\sum\_{i=0}^{g-1} b\_i \cdot 2^{g-1-i}$.
+pub trait LweMultiBitBootstrapKeyEntity:
+    AbstractEntity<Kind = LweMultiBitBootstrapKeyKind>
+{
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the input LWE dimension of the key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output LWE dimension of the key.
+    fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.glwe_dimension().0 * self.polynomial_size().0)
+    }
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the number of input key coefficients encrypted together in the key.
+    fn grouping_factor(&self) -> LweBskGroupingFactor;
+}
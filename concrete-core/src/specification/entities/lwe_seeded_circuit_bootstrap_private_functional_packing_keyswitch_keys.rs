@@ -0,0 +1,45 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::markers::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, LweDimension, PolynomialSize,
+};
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a seeded private functional packing keyswitch key
+/// vector used for circuit bootstrapping.
+///
+/// # Formal Definition
+///
+/// ## Seeded Circuit Bootstrap Private Functional Packing Keyswitch Keys
+///
+/// This is the seeded counterpart of the
+/// [`circuit bootstrap
+/// keys`](`crate::specification::entities::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity`):
+/// only the bodies of the GLWE ciphertexts of all the keys are stored. The masks are regenerated
+/// from a single compression seed when the keys are transformed into standard keys.
+pub trait LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity:
+    AbstractEntity<Kind = LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind>
+{
+    /// Returns the input LWE dimension of the keys.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output GLWE dimension of the keys.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output polynomial degree of the keys.
+    fn output_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the keys.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the keys.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the number of keys contained in the vector.
+    fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount;
+
+    /// Returns the compression seed used to generate the masks of the GLWE ciphertexts during
+    /// encryption.
+    fn compression_seed(&self) -> CompressionSeed;
+}
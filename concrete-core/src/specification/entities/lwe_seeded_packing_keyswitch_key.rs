@@ -0,0 +1,39 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::markers::LweSeededPackingKeyswitchKeyKind;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a seeded packing keyswitch key.
+///
+/// # Formal Definition
+///
+/// ## Seeded Packing Keyswitch Key
+///
+/// A seeded packing keyswitch key is a
+/// [`packing keyswitch key`](`crate::specification::entities::LwePackingKeyswitchKeyEntity`) in
+/// which only the bodies of the GLWE ciphertexts are stored. The masks are regenerated from the
+/// compression seed when the key is transformed into a standard packing keyswitch key.
+pub trait LweSeededPackingKeyswitchKeyEntity:
+    AbstractEntity<Kind = LweSeededPackingKeyswitchKeyKind>
+{
+    /// Returns the input LWE dimension of the key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output polynomial degree of the key.
+    fn output_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the compression seed used to generate the masks of the GLWE ciphertexts during
+    /// encryption.
+    fn compression_seed(&self) -> CompressionSeed;
+}
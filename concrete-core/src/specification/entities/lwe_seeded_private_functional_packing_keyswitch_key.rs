@@ -0,0 +1,41 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::markers::LweSeededPrivateFunctionalPackingKeyswitchKeyKind;
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a seeded private functional packing keyswitch key.
+///
+/// # Formal Definition
+///
+/// ## Seeded Private Functional Packing Keyswitch Key
+///
+/// A seeded private functional packing keyswitch key is a
+/// [`private functional packing keyswitch
+/// key`](`crate::specification::entities::LwePrivateFunctionalPackingKeyswitchKeyEntity`)
+/// in which only the bodies of the GLWE ciphertexts are stored. The masks are regenerated from the
+/// compression seed when the key is transformed into a standard private functional packing
+/// keyswitch key.
+pub trait LweSeededPrivateFunctionalPackingKeyswitchKeyEntity:
+    AbstractEntity<Kind = LweSeededPrivateFunctionalPackingKeyswitchKeyKind>
+{
+    /// Returns the input LWE dimension of the key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output polynomial degree of the key.
+    fn output_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the compression seed used to generate the masks of the GLWE ciphertexts during
+    /// encryption.
+    fn compression_seed(&self) -> CompressionSeed;
+}
@@ -0,0 +1,26 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount};
+use crate::specification::entities::markers::LweSeededPublicKeyKind;
+use crate::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a seeded LWE public key.
+///
+/// # Formal Definition
+///
+/// ## Seeded LWE Public Key
+///
+/// A seeded LWE public key is an [`LWE public
+/// key`](`crate::specification::entities::LwePublicKeyEntity`) in which only the bodies of the LWE
+/// encryptions of 0 are stored. The masks are regenerated from the compression seed when the key is
+/// transformed into a standard LWE public key.
+pub trait LweSeededPublicKeyEntity: AbstractEntity<Kind = LweSeededPublicKeyKind> {
+    /// Returns the LWE dimension of the key.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of LWE encryption of 0 in the key.
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount;
+
+    /// Returns the compression seed used to generate the masks of the LWE ciphertexts during
+    /// encryption.
+    fn compression_seed(&self) -> CompressionSeed;
+}
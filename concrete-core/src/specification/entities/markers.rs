@@ -62,6 +62,8 @@ entity_kind_marker! {
             => "An empty type representing the GSW ciphertext vector kind in the type system.",
         LwePublicKeyKind
             => "An empty type representing the LWE public key kind in the type system.",
+        LweSeededPublicKeyKind
+            => "An empty type representing the seeded LWE public key kind in the type system.",
         LweSecretKeyKind
             => "An empty type representing the LWE secret key kind in the type system.",
         GlweSecretKeyKind
@@ -72,16 +74,34 @@ entity_kind_marker! {
             => "An empty type representing the seeded LWE keyswitch key kind in the type system.",
         LwePackingKeyswitchKeyKind
             => "An empty type representing the packing keyswitch key kind in the type system.",
+        LweSeededPackingKeyswitchKeyKind
+            => "An empty type representing the seeded packing keyswitch key kind in the type \
+            system.",
         LwePrivateFunctionalPackingKeyswitchKeyKind
             => "An empty type representing the private functional packing keyswitch key in the \
             type system.",
+        LweSeededPrivateFunctionalPackingKeyswitchKeyKind
+            => "An empty type representing the seeded private functional packing keyswitch key in \
+            the type system.",
         LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind
             => "An empty type representing the private functional packing keyswitch key vector \
             used for a circuit bootstrap in the type system.",
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind
+            => "An empty type representing the seeded private functional packing keyswitch key \
+            vector used for a circuit bootstrap in the type system.",
         LweBootstrapKeyKind
             => "An empty type representing the LWE bootstrap key kind in the type system.",
         LweSeededBootstrapKeyKind
             => "An empty type representing the seeded LWE bootstrap key kind in the type system.",
+        LweMultiBitBootstrapKeyKind
+            => "An empty type representing the multi-bit LWE bootstrap key kind in the type \
+            system.",
+        GlweRelinearizationKeyKind
+            => "An empty type representing the GLWE relinearization key kind in the type system.",
+        GlweKeyswitchKeyKind
+            => "An empty type representing the GLWE keyswitch key kind in the type system.",
+        GlweSeededKeyswitchKeyKind
+            => "An empty type representing the seeded GLWE keyswitch key kind in the type system.",
         EncoderKind
             => "An empty type representing the encoder kind in the type system.",
         EncoderVectorKind
@@ -20,6 +20,9 @@
 //! # Parameters
 //! This module contains structures that wrap unsigned integer parameters of
 //! concrete, like the ciphertext dimension or the polynomial degree.
+//!
+//! # Serialization
+//! This module contains the header shared by the serialized forms of the entities.
 
 pub mod engines;
 pub mod entities;
@@ -27,3 +30,4 @@ pub mod entities;
 pub mod dispersion;
 pub mod key_kinds;
 pub mod parameters;
+pub mod serialization;
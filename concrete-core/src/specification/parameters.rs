@@ -180,6 +180,14 @@ pub struct DecompositionLevelCount(pub usize);
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 pub struct LutCountLog(pub usize);
 
+/// The number of LWE secret key coefficients jointly encrypted in a multi-bit bootstrap key.
+///
+/// A multi-bit bootstrap key encrypts, for each group of this many consecutive input key
+/// coefficients, every combination the group can take.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+pub struct LweBskGroupingFactor(pub usize);
+
 /// The number of MSB shifted in a Modulus Switch.
 ///
 /// When performing a Modulus Switch, this type represents the number of MSB that will be
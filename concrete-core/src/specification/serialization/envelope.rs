@@ -0,0 +1,438 @@
+//! Helpers wrapping the payload written by the serialization engines into a header and a trailer.
+use super::{
+    Crc32, SerializationHeader, SerializationHeaderError, SerializedEntityKind,
+    SerializedEntityParameters, SerializedEntityPrecision, CHECKSUM_OFFSET,
+    LEGACY_SERIALIZATION_PREFIX, SERIALIZATION_FORMAT_VERSION, SERIALIZATION_HEADER_SIZE,
+    SERIALIZATION_MAGIC, SERIALIZATION_TRAILER_SIZE,
+};
+use serde::Serialize;
+use std::io::{Read, Write};
+
+/// A trait implemented by the entities which can be serialized, to describe them in the header.
+pub(crate) trait SerializedEntity {
+    const KIND: SerializedEntityKind;
+    const PRECISION: SerializedEntityPrecision;
+
+    fn serialized_parameters(&self) -> SerializedEntityParameters;
+}
+
+/// Implements [`SerializedEntity`] for a list of entity types sharing the same kind, precision and
+/// parameters. The parameters are computed by an expression of the entity.
+macro_rules! impl_serialized_entity {
+    (
+        $kind: ident,
+        $precision: ident,
+        |$entity: ident| $parameters: expr,
+        $($entity_type: ty),+ $(,)?
+    ) => {
+        $(
+            impl $crate::specification::serialization::SerializedEntity for $entity_type {
+                const KIND: $crate::specification::serialization::SerializedEntityKind =
+                    $crate::specification::serialization::SerializedEntityKind::$kind;
+                const PRECISION: $crate::specification::serialization::SerializedEntityPrecision =
+                    $crate::specification::serialization::SerializedEntityPrecision::$precision;
+
+                fn serialized_parameters(
+                    &self,
+                ) -> $crate::specification::serialization::SerializedEntityParameters {
+                    let $entity = self;
+                    $parameters
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_serialized_entity;
+
+/// The error returned by the helpers, separating the errors of the header from the errors of the
+/// payload.
+pub(crate) enum EnvelopeError<E> {
+    Header(SerializationHeaderError),
+    Payload(E),
+}
+
+fn build_header<Entity: SerializedEntity>(
+    entity: &Entity,
+    payload_size: u64,
+) -> SerializationHeader {
+    let mut header = SerializationHeader {
+        format_version: SERIALIZATION_FORMAT_VERSION,
+        kind: Entity::KIND,
+        precision: Entity::PRECISION,
+        parameters: entity.serialized_parameters(),
+        payload_size,
+        checksum: 0,
+    };
+    let mut checksum = Crc32::new();
+    checksum.update(&header.to_bytes()[..CHECKSUM_OFFSET]);
+    header.checksum = checksum.finalize();
+    header
+}
+
+fn check_entity<Entity: SerializedEntity>(
+    header: &SerializationHeader,
+) -> Result<(), SerializationHeaderError> {
+    if header.kind != Entity::KIND || header.precision != Entity::PRECISION {
+        return Err(SerializationHeaderError::UnexpectedEntity {
+            expected: (Entity::KIND, Entity::PRECISION),
+            found: (header.kind, header.precision),
+        });
+    }
+    Ok(())
+}
+
+/// Serializes an entity in memory, by surrounding the payload written by `write_payload` with the
+/// header and the trailer of the entity.
+pub(crate) fn serialize_with_header<Entity, E>(
+    entity: &Entity,
+    write_payload: impl FnOnce(&mut Vec<u8>) -> Result<(), E>,
+) -> Result<Vec<u8>, EnvelopeError<E>>
+where
+    Entity: SerializedEntity,
+{
+    let mut serialized = vec![0u8; SERIALIZATION_HEADER_SIZE];
+    write_payload(&mut serialized).map_err(EnvelopeError::Payload)?;
+    let payload = &serialized[SERIALIZATION_HEADER_SIZE..];
+    let payload_size = payload.len() as u64;
+    let mut checksum = Crc32::new();
+    checksum.update(payload);
+    serialized.extend_from_slice(&checksum.finalize().to_le_bytes());
+    let header = build_header(entity, payload_size);
+    serialized[..SERIALIZATION_HEADER_SIZE].copy_from_slice(&header.to_bytes());
+    Ok(serialized)
+}
+
+/// Serializes an entity into a writer, by surrounding the bincode serialization of `payload` with
+/// the header and the trailer of the entity.
+///
+/// The size of the payload is computed beforehand with [`bincode::serialized_size`], and its
+/// checksum while it gets written, so that the payload is encoded only once.
+pub(crate) fn serialize_into_with_header<Entity, W, P>(
+    entity: &Entity,
+    writer: &mut W,
+    payload: &P,
+) -> Result<(), EnvelopeError<bincode::Error>>
+where
+    Entity: SerializedEntity,
+    W: Write,
+    P: Serialize + ?Sized,
+{
+    let payload_size = bincode::serialized_size(payload).map_err(EnvelopeError::Payload)?;
+    let header = build_header(entity, payload_size);
+    writer
+        .write_all(&header.to_bytes())
+        .map_err(|error| EnvelopeError::Header(error.into()))?;
+    let mut payload_writer = ChecksumWriter {
+        inner: &mut *writer,
+        size: 0,
+        checksum: Crc32::new(),
+    };
+    bincode::serialize_into(&mut payload_writer, payload).map_err(EnvelopeError::Payload)?;
+    if payload_writer.size != payload_size {
+        return Err(EnvelopeError::Header(
+            SerializationHeaderError::InvalidSize {
+                expected: header.serialized_size(),
+                found: header.serialized_size() - payload_size + payload_writer.size,
+            },
+        ));
+    }
+    let checksum = payload_writer.checksum.finalize();
+    writer
+        .write_all(&checksum.to_le_bytes())
+        .map_err(|error| EnvelopeError::Header(error.into()))
+}
+
+/// Deserializes an entity from memory, by checking its header and its checksums before handing
+/// the payload to `read_payload`.
+///
+/// Entities serialized before the header was introduced start with
+/// [`LEGACY_SERIALIZATION_PREFIX`]. They are handed to `read_payload` as a whole, without any
+/// check.
+pub(crate) fn deserialize_with_header<'a, Entity, T, E>(
+    serialized: &'a [u8],
+    read_payload: impl FnOnce(&'a [u8]) -> Result<T, E>,
+) -> Result<T, EnvelopeError<E>>
+where
+    Entity: SerializedEntity,
+{
+    if serialized.starts_with(&LEGACY_SERIALIZATION_PREFIX) {
+        return read_payload(serialized).map_err(EnvelopeError::Payload);
+    }
+    let header = SerializationHeader::from_bytes(serialized).map_err(EnvelopeError::Header)?;
+    check_entity::<Entity>(&header).map_err(EnvelopeError::Header)?;
+    header.validate(serialized).map_err(EnvelopeError::Header)?;
+    let payload_end = serialized.len() - SERIALIZATION_TRAILER_SIZE;
+    read_payload(&serialized[SERIALIZATION_HEADER_SIZE..payload_end])
+        .map_err(EnvelopeError::Payload)
+}
+
+/// Deserializes an entity from a reader, by checking its header before handing the payload to
+/// `read_payload`, and checking that the payload was entirely consumed and matches the checksum
+/// of the trailer.
+///
+/// The payload is decoded as it is read, and its checksum is computed on the way, so that the
+/// serialized value is never held in memory. A corrupted payload is only detected once decoded,
+/// but it is rejected before the entity is returned. Exactly the bytes of the entity are read
+/// from `reader`.
+///
+/// Entities serialized before the header was introduced start with
+/// [`LEGACY_SERIALIZATION_PREFIX`]. They are handed to `read_payload` directly from `reader`,
+/// without any check.
+pub(crate) fn deserialize_from_with_header<Entity, R, T, E>(
+    reader: &mut R,
+    read_payload: impl FnOnce(&mut dyn Read) -> Result<T, E>,
+) -> Result<T, EnvelopeError<E>>
+where
+    Entity: SerializedEntity,
+    R: Read,
+{
+    let magic_size = SERIALIZATION_MAGIC.len();
+    let mut header_bytes = [0u8; SERIALIZATION_HEADER_SIZE];
+    reader
+        .read_exact(&mut header_bytes[..magic_size])
+        .map_err(|error| EnvelopeError::Header(error.into()))?;
+    if header_bytes[..magic_size] == LEGACY_SERIALIZATION_PREFIX {
+        let mut legacy_reader = Read::chain(&header_bytes[..magic_size], reader);
+        return read_payload(&mut legacy_reader).map_err(EnvelopeError::Payload);
+    }
+    if header_bytes[..magic_size] != SERIALIZATION_MAGIC {
+        return Err(EnvelopeError::Header(
+            SerializationHeaderError::InvalidMagic,
+        ));
+    }
+    reader
+        .read_exact(&mut header_bytes[magic_size..])
+        .map_err(|error| EnvelopeError::Header(error.into()))?;
+    let header = decode_header(&header_bytes).map_err(EnvelopeError::Header)?;
+    check_entity::<Entity>(&header).map_err(EnvelopeError::Header)?;
+
+    let mut payload_reader = ChecksumReader {
+        inner: Read::take(&mut *reader, header.payload_size),
+        checksum: Crc32::new(),
+    };
+    let output = read_payload(&mut payload_reader).map_err(EnvelopeError::Payload)?;
+    let remaining = payload_reader.inner.limit();
+    if remaining != 0 {
+        return Err(EnvelopeError::Header(
+            SerializationHeaderError::InvalidSize {
+                expected: header.serialized_size(),
+                found: header.serialized_size() - remaining,
+            },
+        ));
+    }
+    let checksum = payload_reader.checksum.finalize();
+    let mut trailer = [0u8; SERIALIZATION_TRAILER_SIZE];
+    reader
+        .read_exact(&mut trailer)
+        .map_err(|error| EnvelopeError::Header(error.into()))?;
+    if checksum.to_le_bytes() != trailer {
+        return Err(EnvelopeError::Header(
+            SerializationHeaderError::ChecksumMismatch,
+        ));
+    }
+    Ok(output)
+}
+
+/// Decodes the header of a serialized entity, and verifies its checksum.
+pub(crate) fn decode_header(bytes: &[u8]) -> Result<SerializationHeader, SerializationHeaderError> {
+    let header = SerializationHeader::from_bytes(bytes)?;
+    header.check_checksum(bytes)?;
+    Ok(header)
+}
+
+/// Reads the header of a serialized entity from a reader, consuming only the header bytes.
+pub(crate) fn read_header<R: Read>(
+    reader: &mut R,
+) -> Result<SerializationHeader, SerializationHeaderError> {
+    let mut header_bytes = [0u8; SERIALIZATION_HEADER_SIZE];
+    reader.read_exact(&mut header_bytes)?;
+    decode_header(&header_bytes)
+}
+
+// A writer forwarding its input, while keeping track of its size and checksum.
+struct ChecksumWriter<W> {
+    inner: W,
+    size: u64,
+    checksum: Crc32,
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.size += written as u64;
+        self.checksum.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// A reader forwarding the bytes it reads, while keeping track of their checksum.
+struct ChecksumReader<R> {
+    inner: R,
+    checksum: Crc32,
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.checksum.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::LweDimension;
+    use std::io::{Error, ErrorKind};
+
+    // Starts with the legacy prefix, to be decoded as a legacy entity when stored without header.
+    const PAYLOAD: [u8; 16] = *b"\x00\x00\x00\x00456789abcdef";
+
+    struct TestEntity;
+
+    impl SerializedEntity for TestEntity {
+        const KIND: SerializedEntityKind = SerializedEntityKind::LweCiphertext;
+        const PRECISION: SerializedEntityPrecision = SerializedEntityPrecision::Unsigned64;
+
+        fn serialized_parameters(&self) -> SerializedEntityParameters {
+            SerializedEntityParameters {
+                lwe_dimension: Some(LweDimension(15)),
+                ..Default::default()
+            }
+        }
+    }
+
+    fn serialize_test_entity() -> Vec<u8> {
+        let mut serialized = Vec::new();
+        serialize_into_with_header(&TestEntity, &mut serialized, &PAYLOAD)
+            .ok()
+            .unwrap();
+        serialized
+    }
+
+    fn read_test_payload(reader: &mut dyn Read) -> Result<Vec<u8>, Error> {
+        let mut payload = vec![0u8; PAYLOAD.len()];
+        reader.read_exact(&mut payload)?;
+        if payload == PAYLOAD {
+            Ok(payload)
+        } else {
+            Err(Error::from(ErrorKind::InvalidData))
+        }
+    }
+
+    fn read_no_payload(_reader: &mut dyn Read) -> Result<Vec<u8>, Error> {
+        panic!("A corrupted payload was handed to the decoder.")
+    }
+
+    #[test]
+    fn test_stream_and_memory_serializations_match() {
+        let serialized = serialize_with_header(&TestEntity, |writer| {
+            writer.extend_from_slice(&PAYLOAD);
+            Ok::<_, Error>(())
+        })
+        .ok()
+        .unwrap();
+        assert_eq!(serialized, serialize_test_entity());
+    }
+
+    #[test]
+    fn test_stream_round_trip_reads_exactly_the_entity() {
+        let mut serialized = serialize_test_entity();
+        serialized.extend_from_slice(b"trailing");
+        let mut reader = serialized.as_slice();
+        let payload =
+            deserialize_from_with_header::<TestEntity, _, _, _>(&mut reader, read_test_payload)
+                .ok()
+                .unwrap();
+        assert_eq!(payload, PAYLOAD);
+        assert_eq!(reader, b"trailing");
+    }
+
+    #[test]
+    fn test_bit_flips_are_rejected() {
+        let serialized = serialize_test_entity();
+        for position in 0..serialized.len() * 8 {
+            let mut altered = serialized.clone();
+            altered[position / 8] ^= 1 << (position % 8);
+            // From a stream, a corrupted header is rejected before the payload is decoded, and a
+            // corrupted payload or trailer once it is decoded.
+            let read_payload = if position < SERIALIZATION_HEADER_SIZE * 8 {
+                read_no_payload
+            } else {
+                read_test_payload
+            };
+            assert!(deserialize_from_with_header::<TestEntity, _, _, _>(
+                &mut altered.as_slice(),
+                read_payload,
+            )
+            .is_err());
+            assert!(
+                deserialize_with_header::<TestEntity, _, _>(&altered, |payload| {
+                    read_no_payload(&mut &*payload)
+                })
+                .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_corrupted_magic_is_rejected() {
+        let mut serialized = serialize_test_entity();
+        serialized[0] ^= 1;
+        assert!(matches!(
+            deserialize_from_with_header::<TestEntity, _, _, _>(
+                &mut serialized.as_slice(),
+                read_no_payload,
+            ),
+            Err(EnvelopeError::Header(
+                SerializationHeaderError::InvalidMagic
+            ))
+        ));
+        assert!(matches!(
+            deserialize_with_header::<TestEntity, _, _>(&serialized, |payload| {
+                read_no_payload(&mut &*payload)
+            }),
+            Err(EnvelopeError::Header(
+                SerializationHeaderError::InvalidMagic
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_stream_rejects_truncations() {
+        let serialized = serialize_test_entity();
+        for length in 0..serialized.len() {
+            let read_payload = if length < SERIALIZATION_HEADER_SIZE {
+                read_no_payload
+            } else {
+                read_test_payload
+            };
+            assert!(deserialize_from_with_header::<TestEntity, _, _, _>(
+                &mut &serialized[..length],
+                read_payload,
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_legacy_entities_are_decoded_without_header() {
+        let payload = deserialize_from_with_header::<TestEntity, _, _, _>(
+            &mut PAYLOAD.as_slice(),
+            read_test_payload,
+        )
+        .ok()
+        .unwrap();
+        assert_eq!(payload, PAYLOAD);
+        let payload = deserialize_with_header::<TestEntity, _, _>(&PAYLOAD, |payload| {
+            read_test_payload(&mut &*payload)
+        })
+        .ok()
+        .unwrap();
+        assert_eq!(payload, PAYLOAD);
+    }
+}
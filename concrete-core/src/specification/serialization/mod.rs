@@ -0,0 +1,629 @@
+//! This module contains the header shared by the serialized forms of the entities.
+//!
+//! Every entity serialized by a serialization engine (be it in memory or into a writer) is
+//! prefixed with a fixed-size [`SerializationHeader`]. This header describes the serialized
+//! entity (its kind, its precision and its parameters), and carries a checksum of its own bytes.
+//! It can be read without deserializing the entity, which allows storage services to route and
+//! validate serialized entities cheaply (see the
+//! [`SerializationHeaderInspectionEngine`](super::engines::SerializationHeaderInspectionEngine)
+//! trait).
+//!
+//! # Layout
+//!
+//! The header is [`SERIALIZATION_HEADER_SIZE`] bytes long, and all its integers are encoded in
+//! little-endian order:
+//!
+//! | Offset | Size | Content                                                              |
+//! |--------|------|----------------------------------------------------------------------|
+//! | 0      | 4    | The magic bytes [`SERIALIZATION_MAGIC`]                              |
+//! | 4      | 2    | The format version of the header, [`SERIALIZATION_FORMAT_VERSION`]   |
+//! | 6      | 2    | The code of the [`SerializedEntityKind`]                             |
+//! | 8      | 1    | The code of the [`SerializedEntityPrecision`]                        |
+//! | 9      | 1    | Reserved, always zero                                                |
+//! | 10     | 2    | A bit mask of the parameters present in the header                   |
+//! | 12     | 56   | The seven parameters of [`SerializedEntityParameters`], as `u64`     |
+//! | 68     | 8    | The size in bytes of the payload following the header                |
+//! | 76     | 4    | The CRC-32 (IEEE) of the bytes `0..76` of the header                 |
+//!
+//! The parameters are stored in the order of the fields of [`SerializedEntityParameters`], and
+//! the bit `i` of the mask is set if the `i`-th parameter is present. Absent parameters, the
+//! unused bits of the mask and the reserved byte are stored as zeros, and headers where they are
+//! not are rejected.
+//!
+//! The payload is followed by a trailer of [`SERIALIZATION_TRAILER_SIZE`] bytes, holding the CRC-32
+//! of the payload in little-endian order. Placing this checksum after the payload allows the
+//! entities to be written to, and read from, a stream in a single pass.
+//!
+//! # Compatibility
+//!
+//! Entities serialized before the introduction of the header start with the version of their
+//! payload instead of [`SERIALIZATION_MAGIC`], which is always their first version, encoded as
+//! the four bytes of [`LEGACY_SERIALIZATION_PREFIX`]. The deserialization engines still accept
+//! them, and decode them without any check, but the
+//! [`SerializationHeaderInspectionEngine`](super::engines::SerializationHeaderInspectionEngine)
+//! reports them as [`SerializationHeaderError::InvalidMagic`]. Entities serialized with the
+//! header cannot be deserialized by the versions of `concrete-core` which predate it. Serialized
+//! values starting with neither prefix are rejected with
+//! [`SerializationHeaderError::InvalidMagic`].
+//!
+//! The payload following the header is the backend-specific serialization of the entity.
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+
+#[cfg(feature = "__commons_serialization")]
+mod envelope;
+#[cfg(feature = "__commons_serialization")]
+pub(crate) use envelope::*;
+
+/// The magic bytes starting every serialized entity.
+pub const SERIALIZATION_MAGIC: [u8; 4] = *b"CCSE";
+
+/// The current version of the serialization header format.
+pub const SERIALIZATION_FORMAT_VERSION: u16 = 1;
+
+/// The size in bytes of the serialization header.
+pub const SERIALIZATION_HEADER_SIZE: usize = 80;
+
+/// The size in bytes of the trailer following the payload of a serialized entity.
+pub const SERIALIZATION_TRAILER_SIZE: usize = 4;
+
+/// The first bytes of the entities serialized before the introduction of the header, which encode
+/// the first version of their payload.
+pub const LEGACY_SERIALIZATION_PREFIX: [u8; 4] = [0; 4];
+
+// The offset of the checksum in the header. The checksum covers the bytes before it.
+const CHECKSUM_OFFSET: usize = 76;
+
+/// The kind of a serialized entity.
+///
+/// Each kind is encoded in the header by a stable code, which never changes once assigned. The
+/// views over an entity are serialized with the same kind as the owned entity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerializedEntityKind {
+    Cleartext,
+    CleartextVector,
+    Plaintext,
+    PlaintextVector,
+    FloatEncoder,
+    FloatEncoderVector,
+    LweCiphertext,
+    LweCiphertextVector,
+    LweSeededCiphertext,
+    LweSeededCiphertextVector,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    GlweSeededCiphertext,
+    GlweSeededCiphertextVector,
+    GgswCiphertext,
+    GgswCiphertextVector,
+    GgswSeededCiphertext,
+    LweSecretKey,
+    GlweSecretKey,
+    LwePublicKey,
+    LweSeededPublicKey,
+    LweKeyswitchKey,
+    LweSeededKeyswitchKey,
+    LwePackingKeyswitchKey,
+    LweSeededPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKey,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    LweBootstrapKey,
+    LweSeededBootstrapKey,
+    FourierGgswCiphertext,
+    FourierGgswCiphertextVector,
+    FourierLweBootstrapKey,
+}
+
+impl SerializedEntityKind {
+    /// Returns the code encoding the kind in the header.
+    pub fn code(&self) -> u16 {
+        use SerializedEntityKind::*;
+        match self {
+            Cleartext => 0x0001,
+            CleartextVector => 0x0002,
+            Plaintext => 0x0003,
+            PlaintextVector => 0x0004,
+            FloatEncoder => 0x0005,
+            FloatEncoderVector => 0x0006,
+            LweCiphertext => 0x0101,
+            LweCiphertextVector => 0x0102,
+            LweSeededCiphertext => 0x0103,
+            LweSeededCiphertextVector => 0x0104,
+            GlweCiphertext => 0x0201,
+            GlweCiphertextVector => 0x0202,
+            GlweSeededCiphertext => 0x0203,
+            GlweSeededCiphertextVector => 0x0204,
+            GgswCiphertext => 0x0301,
+            GgswCiphertextVector => 0x0302,
+            GgswSeededCiphertext => 0x0303,
+            LweSecretKey => 0x0401,
+            GlweSecretKey => 0x0402,
+            LwePublicKey => 0x0403,
+            LweSeededPublicKey => 0x0404,
+            LweKeyswitchKey => 0x0501,
+            LweSeededKeyswitchKey => 0x0502,
+            LwePackingKeyswitchKey => 0x0503,
+            LweSeededPackingKeyswitchKey => 0x0504,
+            LweSeededPrivateFunctionalPackingKeyswitchKey => 0x0505,
+            LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys => 0x0506,
+            LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys => 0x0507,
+            LweBootstrapKey => 0x0601,
+            LweSeededBootstrapKey => 0x0602,
+            FourierGgswCiphertext => 0x0701,
+            FourierGgswCiphertextVector => 0x0702,
+            FourierLweBootstrapKey => 0x0703,
+        }
+    }
+
+    /// Returns the kind encoded by a code, if any.
+    pub fn from_code(code: u16) -> Option<Self> {
+        use SerializedEntityKind::*;
+        let kind = match code {
+            0x0001 => Cleartext,
+            0x0002 => CleartextVector,
+            0x0003 => Plaintext,
+            0x0004 => PlaintextVector,
+            0x0005 => FloatEncoder,
+            0x0006 => FloatEncoderVector,
+            0x0101 => LweCiphertext,
+            0x0102 => LweCiphertextVector,
+            0x0103 => LweSeededCiphertext,
+            0x0104 => LweSeededCiphertextVector,
+            0x0201 => GlweCiphertext,
+            0x0202 => GlweCiphertextVector,
+            0x0203 => GlweSeededCiphertext,
+            0x0204 => GlweSeededCiphertextVector,
+            0x0301 => GgswCiphertext,
+            0x0302 => GgswCiphertextVector,
+            0x0303 => GgswSeededCiphertext,
+            0x0401 => LweSecretKey,
+            0x0402 => GlweSecretKey,
+            0x0403 => LwePublicKey,
+            0x0404 => LweSeededPublicKey,
+            0x0501 => LweKeyswitchKey,
+            0x0502 => LweSeededKeyswitchKey,
+            0x0503 => LwePackingKeyswitchKey,
+            0x0504 => LweSeededPackingKeyswitchKey,
+            0x0505 => LweSeededPrivateFunctionalPackingKeyswitchKey,
+            0x0506 => LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+            0x0507 => LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+            0x0601 => LweBootstrapKey,
+            0x0602 => LweSeededBootstrapKey,
+            0x0701 => FourierGgswCiphertext,
+            0x0702 => FourierGgswCiphertextVector,
+            0x0703 => FourierLweBootstrapKey,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// The precision of the scalars of a serialized entity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SerializedEntityPrecision {
+    Unsigned32,
+    Unsigned64,
+    Float64,
+}
+
+impl SerializedEntityPrecision {
+    /// Returns the code encoding the precision in the header.
+    pub fn code(&self) -> u8 {
+        match self {
+            SerializedEntityPrecision::Unsigned32 => 1,
+            SerializedEntityPrecision::Unsigned64 => 2,
+            SerializedEntityPrecision::Float64 => 3,
+        }
+    }
+
+    /// Returns the precision encoded by a code, if any.
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(SerializedEntityPrecision::Unsigned32),
+            2 => Some(SerializedEntityPrecision::Unsigned64),
+            3 => Some(SerializedEntityPrecision::Float64),
+            _ => None,
+        }
+    }
+}
+
+/// The parameters of a serialized entity.
+///
+/// Only the parameters relevant to the kind of the entity are set. For keys mapping an input
+/// dimension to an output dimension, `lwe_dimension` is the input LWE dimension, and the output
+/// is described by either `output_lwe_dimension`, or `glwe_dimension` and `polynomial_size`.
+/// The `count` parameter is the number of elements of vectors, lists of keys and public keys.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct SerializedEntityParameters {
+    pub lwe_dimension: Option<LweDimension>,
+    pub output_lwe_dimension: Option<LweDimension>,
+    pub glwe_dimension: Option<GlweDimension>,
+    pub polynomial_size: Option<PolynomialSize>,
+    pub decomposition_level_count: Option<DecompositionLevelCount>,
+    pub decomposition_base_log: Option<DecompositionBaseLog>,
+    pub count: Option<usize>,
+}
+
+impl SerializedEntityParameters {
+    fn to_raw(self) -> [Option<usize>; 7] {
+        [
+            self.lwe_dimension.map(|p| p.0),
+            self.output_lwe_dimension.map(|p| p.0),
+            self.glwe_dimension.map(|p| p.0),
+            self.polynomial_size.map(|p| p.0),
+            self.decomposition_level_count.map(|p| p.0),
+            self.decomposition_base_log.map(|p| p.0),
+            self.count,
+        ]
+    }
+
+    fn from_raw(raw: [Option<usize>; 7]) -> Self {
+        SerializedEntityParameters {
+            lwe_dimension: raw[0].map(LweDimension),
+            output_lwe_dimension: raw[1].map(LweDimension),
+            glwe_dimension: raw[2].map(GlweDimension),
+            polynomial_size: raw[3].map(PolynomialSize),
+            decomposition_level_count: raw[4].map(DecompositionLevelCount),
+            decomposition_base_log: raw[5].map(DecompositionBaseLog),
+            count: raw[6],
+        }
+    }
+}
+
+/// The header prefixing every serialized entity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SerializationHeader {
+    /// The version of the header format.
+    pub format_version: u16,
+    /// The kind of the serialized entity.
+    pub kind: SerializedEntityKind,
+    /// The precision of the serialized entity.
+    pub precision: SerializedEntityPrecision,
+    /// The parameters of the serialized entity.
+    pub parameters: SerializedEntityParameters,
+    /// The size in bytes of the payload following the header.
+    pub payload_size: u64,
+    /// The CRC-32 of the rest of the header.
+    pub checksum: u32,
+}
+
+impl SerializationHeader {
+    /// Reads a header from the first [`SERIALIZATION_HEADER_SIZE`] bytes of a serialized entity.
+    ///
+    /// Only the header is decoded: neither the payload nor the checksums are checked. Use
+    /// [`SerializationHeader::validate`] to check them.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationHeaderError> {
+        if bytes.len() < SERIALIZATION_HEADER_SIZE {
+            return Err(SerializationHeaderError::Truncated);
+        }
+        if bytes[0..4] != SERIALIZATION_MAGIC {
+            return Err(SerializationHeaderError::InvalidMagic);
+        }
+        let format_version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if format_version != SERIALIZATION_FORMAT_VERSION {
+            return Err(SerializationHeaderError::UnsupportedFormatVersion(
+                format_version,
+            ));
+        }
+        let kind_code = u16::from_le_bytes([bytes[6], bytes[7]]);
+        let kind = SerializedEntityKind::from_code(kind_code)
+            .ok_or(SerializationHeaderError::UnknownEntityKind(kind_code))?;
+        let precision = SerializedEntityPrecision::from_code(bytes[8])
+            .ok_or(SerializationHeaderError::UnknownPrecision(bytes[8]))?;
+        let mask = u16::from_le_bytes([bytes[10], bytes[11]]);
+        if bytes[9] != 0 || mask >> 7 != 0 {
+            return Err(SerializationHeaderError::NonZeroReservedBytes);
+        }
+        let mut raw = [None; 7];
+        for (i, parameter) in raw.iter_mut().enumerate() {
+            let offset = 12 + 8 * i;
+            let value = read_u64(&bytes[offset..offset + 8]);
+            if mask & (1 << i) != 0 {
+                *parameter = Some(value as usize);
+            } else if value != 0 {
+                return Err(SerializationHeaderError::NonZeroReservedBytes);
+            }
+        }
+        Ok(SerializationHeader {
+            format_version,
+            kind,
+            precision,
+            parameters: SerializedEntityParameters::from_raw(raw),
+            payload_size: read_u64(&bytes[68..76]),
+            checksum: u32::from_le_bytes([bytes[76], bytes[77], bytes[78], bytes[79]]),
+        })
+    }
+
+    /// Encodes the header.
+    pub fn to_bytes(&self) -> [u8; SERIALIZATION_HEADER_SIZE] {
+        let mut bytes = [0u8; SERIALIZATION_HEADER_SIZE];
+        bytes[0..4].copy_from_slice(&SERIALIZATION_MAGIC);
+        bytes[4..6].copy_from_slice(&self.format_version.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.kind.code().to_le_bytes());
+        bytes[8] = self.precision.code();
+        let mut mask = 0u16;
+        for (i, parameter) in self.parameters.to_raw().iter().enumerate() {
+            if let Some(value) = parameter {
+                mask |= 1 << i;
+                let offset = 12 + 8 * i;
+                bytes[offset..offset + 8].copy_from_slice(&(*value as u64).to_le_bytes());
+            }
+        }
+        bytes[10..12].copy_from_slice(&mask.to_le_bytes());
+        bytes[68..76].copy_from_slice(&self.payload_size.to_le_bytes());
+        bytes[76..80].copy_from_slice(&self.checksum.to_le_bytes());
+        bytes
+    }
+
+    /// Returns the total size in bytes of the serialized entity, header and trailer included.
+    pub fn serialized_size(&self) -> u64 {
+        (SERIALIZATION_HEADER_SIZE + SERIALIZATION_TRAILER_SIZE) as u64 + self.payload_size
+    }
+
+    /// Checks that `serialized` is the complete serialized entity described by this header, by
+    /// verifying its size, the checksum of its header and the checksum of its payload.
+    ///
+    /// This does not deserialize the entity.
+    pub fn validate(&self, serialized: &[u8]) -> Result<(), SerializationHeaderError> {
+        let found = serialized.len() as u64;
+        if found != self.serialized_size() {
+            return Err(SerializationHeaderError::InvalidSize {
+                expected: self.serialized_size(),
+                found,
+            });
+        }
+        self.check_checksum(&serialized[..SERIALIZATION_HEADER_SIZE])?;
+        let (payload, trailer) =
+            serialized[SERIALIZATION_HEADER_SIZE..].split_at(self.payload_size as usize);
+        let mut checksum = Crc32::new();
+        checksum.update(payload);
+        if checksum.finalize().to_le_bytes() != trailer {
+            return Err(SerializationHeaderError::ChecksumMismatch);
+        }
+        Ok(())
+    }
+
+    // Checks the checksum of the header against the bytes the header was read from.
+    pub(crate) fn check_checksum(
+        &self,
+        header_bytes: &[u8],
+    ) -> Result<(), SerializationHeaderError> {
+        // The checksum is computed over the bytes actually stored, so that alterations of the
+        // reserved bytes or of the absent parameters are detected as well.
+        let mut checksum = Crc32::new();
+        checksum.update(&header_bytes[..CHECKSUM_OFFSET]);
+        if checksum.finalize() != self.checksum {
+            return Err(SerializationHeaderError::ChecksumMismatch);
+        }
+        Ok(())
+    }
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut array = [0u8; 8];
+    array.copy_from_slice(bytes);
+    u64::from_le_bytes(array)
+}
+
+/// The error which can occur when reading or checking a serialization header.
+#[derive(Debug)]
+pub enum SerializationHeaderError {
+    /// The serialized value is shorter than its header.
+    Truncated,
+    /// The serialized value does not start with [`SERIALIZATION_MAGIC`].
+    InvalidMagic,
+    /// The header format version is not supported.
+    UnsupportedFormatVersion(u16),
+    /// The header contains an unknown entity kind code.
+    UnknownEntityKind(u16),
+    /// The header contains an unknown precision code.
+    UnknownPrecision(u8),
+    /// The reserved bytes of the header, or its absent parameters, are not zero.
+    NonZeroReservedBytes,
+    /// The header describes another entity than the one being deserialized.
+    UnexpectedEntity {
+        expected: (SerializedEntityKind, SerializedEntityPrecision),
+        found: (SerializedEntityKind, SerializedEntityPrecision),
+    },
+    /// The size of the serialized value does not match the one described by the header.
+    InvalidSize { expected: u64, found: u64 },
+    /// The checksum of the serialized value does not match the one of the header.
+    ChecksumMismatch,
+    /// An error occurred while reading or writing the serialized value.
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for SerializationHeaderError {
+    fn from(error: std::io::Error) -> Self {
+        if error.kind() == ErrorKind::UnexpectedEof {
+            SerializationHeaderError::Truncated
+        } else {
+            SerializationHeaderError::Io(error)
+        }
+    }
+}
+
+impl Display for SerializationHeaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SerializationHeaderError::Truncated => {
+                write!(f, "The serialized value is truncated.")
+            }
+            SerializationHeaderError::InvalidMagic => {
+                write!(
+                    f,
+                    "The serialized value does not start with the magic bytes."
+                )
+            }
+            SerializationHeaderError::UnsupportedFormatVersion(version) => {
+                write!(f, "The header format version {} is not supported.", version)
+            }
+            SerializationHeaderError::UnknownEntityKind(code) => {
+                write!(f, "The entity kind code {:#06x} is unknown.", code)
+            }
+            SerializationHeaderError::UnknownPrecision(code) => {
+                write!(f, "The precision code {} is unknown.", code)
+            }
+            SerializationHeaderError::NonZeroReservedBytes => {
+                write!(f, "The reserved bytes of the header are not zero.")
+            }
+            SerializationHeaderError::UnexpectedEntity { expected, found } => {
+                write!(
+                    f,
+                    "Expected a serialized {:?} of precision {:?}, found a {:?} of precision {:?}.",
+                    expected.0, expected.1, found.0, found.1
+                )
+            }
+            SerializationHeaderError::InvalidSize { expected, found } => {
+                write!(
+                    f,
+                    "The serialized value is {} bytes long, while its header describes {} bytes.",
+                    found, expected
+                )
+            }
+            SerializationHeaderError::ChecksumMismatch => {
+                write!(f, "The checksum of the serialized value does not match.")
+            }
+            SerializationHeaderError::Io(error) => {
+                write!(f, "Failed to access the serialized value: {}", error)
+            }
+        }
+    }
+}
+
+impl Error for SerializationHeaderError {}
+
+// The lookup table of the CRC-32 (IEEE 802.3, reflected polynomial 0xEDB88320).
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// An incremental CRC-32 computation.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Crc32(u32);
+
+impl Crc32 {
+    pub(crate) fn new() -> Self {
+        Crc32(0xFFFF_FFFF)
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = CRC32_TABLE[((self.0 ^ *byte as u32) & 0xFF) as usize] ^ (self.0 >> 8);
+        }
+    }
+
+    pub(crate) fn finalize(&self) -> u32 {
+        !self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_check_value() {
+        let mut checksum = Crc32::new();
+        checksum.update(b"123456789");
+        assert_eq!(checksum.finalize(), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_header_round_trip() {
+        let header = SerializationHeader {
+            format_version: SERIALIZATION_FORMAT_VERSION,
+            kind: SerializedEntityKind::LweKeyswitchKey,
+            precision: SerializedEntityPrecision::Unsigned64,
+            parameters: SerializedEntityParameters {
+                lwe_dimension: Some(LweDimension(1024)),
+                output_lwe_dimension: Some(LweDimension(630)),
+                decomposition_level_count: Some(DecompositionLevelCount(3)),
+                decomposition_base_log: Some(DecompositionBaseLog(4)),
+                ..Default::default()
+            },
+            payload_size: 42,
+            checksum: 0xDEAD_BEEF,
+        };
+        let bytes = header.to_bytes();
+        assert_eq!(SerializationHeader::from_bytes(&bytes).unwrap(), header);
+    }
+
+    #[test]
+    fn test_header_rejects_non_zero_reserved_bytes() {
+        let header = SerializationHeader {
+            format_version: SERIALIZATION_FORMAT_VERSION,
+            kind: SerializedEntityKind::LweCiphertext,
+            precision: SerializedEntityPrecision::Unsigned32,
+            parameters: SerializedEntityParameters {
+                lwe_dimension: Some(LweDimension(1024)),
+                ..Default::default()
+            },
+            payload_size: 42,
+            checksum: 0xDEAD_BEEF,
+        };
+        // The reserved byte, an unused bit of the mask, and an absent parameter.
+        for (index, value) in [(9, 1), (11, 0x80), (20, 1)] {
+            let mut bytes = header.to_bytes();
+            bytes[index] = value;
+            assert!(matches!(
+                SerializationHeader::from_bytes(&bytes),
+                Err(SerializationHeaderError::NonZeroReservedBytes)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_checks_the_stored_header_bytes() {
+        let payload = [1u8, 2, 3, 4];
+        let mut header = SerializationHeader {
+            format_version: SERIALIZATION_FORMAT_VERSION,
+            kind: SerializedEntityKind::Plaintext,
+            precision: SerializedEntityPrecision::Unsigned32,
+            parameters: SerializedEntityParameters::default(),
+            payload_size: payload.len() as u64,
+            checksum: 0,
+        };
+        let mut checksum = Crc32::new();
+        checksum.update(&header.to_bytes()[..CHECKSUM_OFFSET]);
+        header.checksum = checksum.finalize();
+        let mut serialized = header.to_bytes().to_vec();
+        serialized.extend_from_slice(&payload);
+        let mut checksum = Crc32::new();
+        checksum.update(&payload);
+        serialized.extend_from_slice(&checksum.finalize().to_le_bytes());
+        assert!(header.validate(&serialized).is_ok());
+        // The checksum field itself is skipped, as the header is not read again.
+        let positions =
+            (0..CHECKSUM_OFFSET * 8).chain(SERIALIZATION_HEADER_SIZE * 8..serialized.len() * 8);
+        for position in positions {
+            let mut altered = serialized.clone();
+            altered[position / 8] ^= 1 << (position % 8);
+            assert!(matches!(
+                header.validate(&altered),
+                Err(SerializationHeaderError::ChecksumMismatch)
+            ));
+        }
+    }
+}
@@ -13,6 +13,32 @@
 #include <stdlib.h>
 
 
+enum CsprngStatus
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  CsprngValid = 0,
+  CsprngExhausted = 1,
+};
+#ifndef __cplusplus
+typedef uint32_t CsprngStatus;
+#endif // __cplusplus
+
+enum ForkStatus
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  ForkValid = 0,
+  ForkTooLarge = 1,
+  ForkZeroChildrenCount = 2,
+  ForkZeroBytesPerChild = 3,
+};
+#ifndef __cplusplus
+typedef uint32_t ForkStatus;
+#endif // __cplusplus
+
 enum Parallelism
 #ifdef __cplusplus
   : uint32_t
@@ -41,6 +67,10 @@ typedef struct Csprng Csprng;
 
 typedef struct Fft Fft;
 
+typedef struct Uint128 {
+  uint8_t little_endian_bytes[16];
+} Uint128;
+
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
@@ -149,17 +179,21 @@ ScratchStatus concrete_cpu_circuit_bootstrap_boolean_vertical_packing_lwe_cipher
                                                                                                  Parallelism parallelism,
                                                                                                  const struct Fft *fft);
 
+struct Uint128 concrete_cpu_csprng_remaining_bytes(const struct Csprng *csprng);
+
 void concrete_cpu_decrypt_lwe_ciphertext_u64(const uint64_t *lwe_sk,
                                              const uint64_t *lwe_ct_in,
                                              size_t lwe_dimension,
                                              uint64_t *plaintext);
 
-void concrete_cpu_encrypt_lwe_ciphertext_u64(const uint64_t *lwe_sk,
-                                             uint64_t *lwe_out,
-                                             uint64_t input,
-                                             size_t lwe_dimension,
-                                             double variance,
-                                             struct Csprng *csprng);
+void concrete_cpu_destroy_csprng(struct Csprng *csprng);
+
+CsprngStatus concrete_cpu_encrypt_lwe_ciphertext_u64(const uint64_t *lwe_sk,
+                                                     uint64_t *lwe_out,
+                                                     uint64_t input,
+                                                     size_t lwe_dimension,
+                                                     double variance,
+                                                     struct Csprng *csprng);
 
 void concrete_cpu_extract_bit_lwe_ciphertext_u64(uint64_t *ct_vec_out,
                                                  const uint64_t *ct_in,
@@ -199,28 +233,12 @@ ScratchStatus concrete_cpu_extract_bit_lwe_ciphertext_u64_scratch(size_t *stack_
                                                                   Parallelism parallelism,
                                                                   const struct Fft *fft);
 
-void concrete_cpu_init_lwe_bootstrap_key_u64(uint64_t *lwe_bsk,
-                                             uint64_t *input_lwe_sk,
-                                             uint64_t *output_glwe_sk,
-                                             size_t input_lwe_dimension,
-                                             size_t output_poly_size,
-                                             size_t output_glwe_dimension,
-                                             size_t level,
-                                             size_t base_log,
-                                             double variance,
-                                             struct Csprng *csprng);
-
-void concrete_cpu_init_lwe_keyswitch_key_u64(uint64_t *lwe_ksk,
-                                             uint64_t *input_lwe_sk,
-                                             uint64_t *output_lwe_sk,
-                                             size_t input_lwe_dimension,
-                                             size_t output_lwe_dimension,
-                                             size_t level,
-                                             size_t base_log,
-                                             double variance,
-                                             struct Csprng *csprng);
-
-void concrete_cpu_init_lwe_packing_keyswitch_key_u64(uint64_t *lwe_pksk,
+ForkStatus concrete_cpu_fork_csprng(struct Csprng *csprng,
+                                    size_t children_count,
+                                    size_t bytes_per_child,
+                                    struct Csprng **children);
+
+CsprngStatus concrete_cpu_init_lwe_bootstrap_key_u64(uint64_t *lwe_bsk,
                                                      uint64_t *input_lwe_sk,
                                                      uint64_t *output_glwe_sk,
                                                      size_t input_lwe_dimension,
@@ -231,9 +249,30 @@ void concrete_cpu_init_lwe_packing_keyswitch_key_u64(uint64_t *lwe_pksk,
                                                      double variance,
                                                      struct Csprng *csprng);
 
-void concrete_cpu_init_lwe_secret_key_u64(uint64_t *lwe_sk,
-                                          size_t lwe_dimension,
-                                          struct Csprng *csprng);
+CsprngStatus concrete_cpu_init_lwe_keyswitch_key_u64(uint64_t *lwe_ksk,
+                                                     uint64_t *input_lwe_sk,
+                                                     uint64_t *output_lwe_sk,
+                                                     size_t input_lwe_dimension,
+                                                     size_t output_lwe_dimension,
+                                                     size_t level,
+                                                     size_t base_log,
+                                                     double variance,
+                                                     struct Csprng *csprng);
+
+CsprngStatus concrete_cpu_init_lwe_packing_keyswitch_key_u64(uint64_t *lwe_pksk,
+                                                             uint64_t *input_lwe_sk,
+                                                             uint64_t *output_glwe_sk,
+                                                             size_t input_lwe_dimension,
+                                                             size_t output_poly_size,
+                                                             size_t output_glwe_dimension,
+                                                             size_t level,
+                                                             size_t base_log,
+                                                             double variance,
+                                                             struct Csprng *csprng);
+
+CsprngStatus concrete_cpu_init_lwe_secret_key_u64(uint64_t *lwe_sk,
+                                                  size_t lwe_dimension,
+                                                  struct Csprng *csprng);
 
 void concrete_cpu_keyswitch_lwe_ciphertext_u64(uint64_t *ct_out,
                                                const uint64_t *ct_in,
@@ -263,6 +302,10 @@ void concrete_cpu_negate_lwe_ciphertext_u64(uint64_t *ct_out,
                                             const uint64_t *ct_in,
                                             size_t lwe_dimension);
 
+struct Csprng *concrete_cpu_new_csprng(struct Uint128 seed);
+
+struct Csprng *concrete_cpu_new_csprng_from_os_seeder(struct Uint128 secret);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif // __cplusplus
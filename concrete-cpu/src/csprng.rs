@@ -0,0 +1,100 @@
+use crate::types::{Csprng, ForkStatus, Uint128};
+use concrete_csprng::generators::{
+    BytesPerChild, ChildrenCount, ForkError, RandomGenerator, SoftwareRandomGenerator,
+};
+use concrete_csprng::seeders::{Seed, Seeder, UnixSeeder};
+use core::slice;
+
+/// Creates a new csprng seeded with `seed`.
+///
+/// Two csprngs created from the same seed output the same bytes.
+///
+/// # Safety
+///
+/// The returned pointer must be released with [`concrete_cpu_destroy_csprng`].
+#[no_mangle]
+#[must_use]
+pub unsafe extern "C" fn concrete_cpu_new_csprng(seed: Uint128) -> *mut Csprng {
+    Box::into_raw(Box::new(Csprng {
+        inner: SoftwareRandomGenerator::new(Seed(u128::from_le_bytes(seed.little_endian_bytes))),
+    }))
+}
+
+/// Creates a new csprng seeded by the operating system.
+///
+/// The seed is read from `/dev/random`, and mixed with `secret` so that the seed stays
+/// unpredictable on systems where the outputs of `/dev/random` could be predicted.
+///
+/// # Safety
+///
+/// The returned pointer must be released with [`concrete_cpu_destroy_csprng`].
+#[no_mangle]
+#[must_use]
+pub unsafe extern "C" fn concrete_cpu_new_csprng_from_os_seeder(secret: Uint128) -> *mut Csprng {
+    let mut seeder = UnixSeeder::new(u128::from_le_bytes(secret.little_endian_bytes));
+    Box::into_raw(Box::new(Csprng {
+        inner: SoftwareRandomGenerator::new(seeder.seed()),
+    }))
+}
+
+/// # Safety
+///
+/// `csprng` must have been returned by one of the `concrete_cpu_new_csprng*` functions, or by
+/// [`concrete_cpu_fork_csprng`], and must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn concrete_cpu_destroy_csprng(csprng: *mut Csprng) {
+    drop(Box::from_raw(csprng));
+}
+
+/// Returns the number of bytes the csprng can still output before reaching its bound.
+///
+/// A fresh csprng can output more bytes than an unsigned 128 bits integer can represent, in which
+/// case $2^{128}-1$ is returned.
+///
+/// # Safety
+///
+/// `csprng` must point to a valid csprng.
+#[no_mangle]
+#[must_use]
+pub unsafe extern "C" fn concrete_cpu_csprng_remaining_bytes(csprng: *const Csprng) -> Uint128 {
+    Uint128 {
+        little_endian_bytes: (*csprng).inner.remaining_bytes().0.to_le_bytes(),
+    }
+}
+
+/// Forks `csprng` into `children_count` new csprngs, each able to output `bytes_per_child` bytes.
+///
+/// The children output disjoint parts of the stream of the parent, which is advanced past all of
+/// them. Forking two csprngs in the same state yields the same children.
+///
+/// On success, the children are written to `children`, and must each be released with
+/// [`concrete_cpu_destroy_csprng`]. On failure, `children` and `csprng` are left untouched.
+///
+/// # Safety
+///
+/// `csprng` must point to a valid csprng, and `children` must be valid for `children_count`
+/// writes.
+#[no_mangle]
+#[must_use]
+pub unsafe extern "C" fn concrete_cpu_fork_csprng(
+    csprng: *mut Csprng,
+    children_count: usize,
+    bytes_per_child: usize,
+    children: *mut *mut Csprng,
+) -> ForkStatus {
+    match (*csprng)
+        .inner
+        .try_fork(ChildrenCount(children_count), BytesPerChild(bytes_per_child))
+    {
+        Ok(forked) => {
+            let children = slice::from_raw_parts_mut(children, children_count);
+            for (child, inner) in children.iter_mut().zip(forked) {
+                *child = Box::into_raw(Box::new(Csprng { inner }));
+            }
+            ForkStatus::ForkValid
+        }
+        Err(ForkError::ForkTooLarge) => ForkStatus::ForkTooLarge,
+        Err(ForkError::ZeroChildrenCount) => ForkStatus::ForkZeroChildrenCount,
+        Err(ForkError::ZeroBytesPerChild) => ForkStatus::ForkZeroBytesPerChild,
+    }
+}
@@ -14,28 +14,54 @@ macro_rules! unused {
 }
 
 mod bootstrap;
+mod csprng;
 mod keyswitch;
 mod linear_op;
 mod types;
 mod wop_pbs;
 
 pub use bootstrap::*;
+pub use csprng::*;
 pub use keyswitch::*;
 pub use linear_op::*;
 pub use types::*;
 pub use wop_pbs::*;
 
+use concrete_core::commons::crypto::bootstrap::StandardBootstrapKey;
+use concrete_core::commons::crypto::encoding::Plaintext;
+use concrete_core::commons::crypto::glwe::LwePrivateFunctionalPackingKeyswitchKeyList;
+use concrete_core::commons::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+use concrete_core::commons::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use concrete_core::prelude::*;
+use concrete_csprng::generators::SoftwareRandomGenerator;
+use concrete_csprng::seeders::Seeder;
+use core::slice;
+
 #[no_mangle]
+#[must_use]
 pub unsafe extern "C" fn concrete_cpu_init_lwe_secret_key_u64(
     lwe_sk: *mut u64,
     lwe_dimension: usize,
     csprng: *mut Csprng,
-) {
-    unused!(lwe_sk, lwe_dimension, csprng);
-    todo!()
+) -> CsprngStatus {
+    if !(*csprng).can_seed(1) {
+        return CsprngStatus::CsprngExhausted;
+    }
+
+    let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new((*csprng).seed());
+    let key = LweSecretKey::generate_binary(LweDimension(lwe_dimension), &mut generator);
+
+    slice::from_raw_parts_mut(lwe_sk, lwe_dimension).copy_from_slice(key.as_tensor().as_slice());
+
+    CsprngStatus::CsprngValid
 }
 
 #[no_mangle]
+#[must_use]
 pub unsafe extern "C" fn concrete_cpu_init_lwe_bootstrap_key_u64(
     // bootstrap key
     lwe_bsk: *mut u64,
@@ -51,23 +77,42 @@ pub unsafe extern "C" fn concrete_cpu_init_lwe_bootstrap_key_u64(
     base_log: usize,
     variance: f64,
     csprng: *mut Csprng,
-) {
-    unused!(
-        lwe_bsk,
+) -> CsprngStatus {
+    if !(*csprng).can_seed(2) {
+        return CsprngStatus::CsprngExhausted;
+    }
+
+    let glwe_size = GlweDimension(output_glwe_dimension).to_glwe_size();
+
+    let input_key = LweSecretKey::binary_from_container(slice::from_raw_parts(
         input_lwe_sk,
-        output_glwe_sk,
         input_lwe_dimension,
-        output_poly_size,
-        output_glwe_dimension,
-        level,
-        base_log,
-        variance,
-        csprng,
+    ));
+    let output_key = GlweSecretKey::binary_from_container(
+        slice::from_raw_parts(output_glwe_sk, output_glwe_dimension * output_poly_size),
+        PolynomialSize(output_poly_size),
     );
-    todo!()
+
+    let mut bsk = StandardBootstrapKey::from_container(
+        slice::from_raw_parts_mut(
+            lwe_bsk,
+            input_lwe_dimension * level * glwe_size.0 * glwe_size.0 * output_poly_size,
+        ),
+        glwe_size,
+        PolynomialSize(output_poly_size),
+        DecompositionLevelCount(level),
+        DecompositionBaseLog(base_log),
+    );
+
+    let mut generator =
+        EncryptionRandomGenerator::<SoftwareRandomGenerator>::new((*csprng).seed(), &mut *csprng);
+    bsk.fill_with_new_key(&input_key, &output_key, Variance(variance), &mut generator);
+
+    CsprngStatus::CsprngValid
 }
 
 #[no_mangle]
+#[must_use]
 pub unsafe extern "C" fn concrete_cpu_init_lwe_keyswitch_key_u64(
     // keyswitch key
     lwe_ksk: *mut u64,
@@ -82,22 +127,39 @@ pub unsafe extern "C" fn concrete_cpu_init_lwe_keyswitch_key_u64(
     base_log: usize,
     variance: f64,
     csprng: *mut Csprng,
-) {
-    unused!(
-        lwe_ksk,
+) -> CsprngStatus {
+    if !(*csprng).can_seed(2) {
+        return CsprngStatus::CsprngExhausted;
+    }
+
+    let input_key = LweSecretKey::binary_from_container(slice::from_raw_parts(
         input_lwe_sk,
-        output_lwe_sk,
         input_lwe_dimension,
+    ));
+    let output_key = LweSecretKey::binary_from_container(slice::from_raw_parts(
+        output_lwe_sk,
         output_lwe_dimension,
-        level,
-        base_log,
-        variance,
-        csprng,
+    ));
+
+    let mut ksk = LweKeyswitchKey::from_container(
+        slice::from_raw_parts_mut(
+            lwe_ksk,
+            level * LweDimension(output_lwe_dimension).to_lwe_size().0 * input_lwe_dimension,
+        ),
+        DecompositionBaseLog(base_log),
+        DecompositionLevelCount(level),
+        LweDimension(output_lwe_dimension),
     );
-    todo!()
+
+    let mut generator =
+        EncryptionRandomGenerator::<SoftwareRandomGenerator>::new((*csprng).seed(), &mut *csprng);
+    ksk.fill_with_keyswitch_key(&input_key, &output_key, Variance(variance), &mut generator);
+
+    CsprngStatus::CsprngValid
 }
 
 #[no_mangle]
+#[must_use]
 pub unsafe extern "C" fn concrete_cpu_init_lwe_packing_keyswitch_key_u64(
     // packing keyswitch key
     lwe_pksk: *mut u64,
@@ -113,23 +175,55 @@ pub unsafe extern "C" fn concrete_cpu_init_lwe_packing_keyswitch_key_u64(
     base_log: usize,
     variance: f64,
     csprng: *mut Csprng,
-) {
-    unused!(
-        lwe_pksk,
+) -> CsprngStatus {
+    if !(*csprng).can_seed(2) {
+        return CsprngStatus::CsprngExhausted;
+    }
+
+    // The circuit bootstrap uses one private functional packing keyswitch key per polynomial of
+    // the output GLWE key, plus one for the body.
+    let glwe_size = GlweDimension(output_glwe_dimension).to_glwe_size();
+
+    let input_key = LweSecretKey::binary_from_container(slice::from_raw_parts(
         input_lwe_sk,
-        output_glwe_sk,
         input_lwe_dimension,
-        output_poly_size,
-        output_glwe_dimension,
-        level,
-        base_log,
-        variance,
-        csprng,
+    ));
+    let output_key = GlweSecretKey::binary_from_container(
+        slice::from_raw_parts(output_glwe_sk, output_glwe_dimension * output_poly_size),
+        PolynomialSize(output_poly_size),
+    );
+
+    let mut pksk = LwePrivateFunctionalPackingKeyswitchKeyList::from_container(
+        slice::from_raw_parts_mut(
+            lwe_pksk,
+            level
+                * glwe_size.0
+                * output_poly_size
+                * LweDimension(input_lwe_dimension).to_lwe_size().0
+                * glwe_size.0,
+        ),
+        DecompositionBaseLog(base_log),
+        DecompositionLevelCount(level),
+        LweDimension(input_lwe_dimension),
+        GlweDimension(output_glwe_dimension),
+        PolynomialSize(output_poly_size),
+        FunctionalPackingKeyswitchKeyCount(glwe_size.0),
     );
-    todo!()
+
+    let mut generator =
+        EncryptionRandomGenerator::<SoftwareRandomGenerator>::new((*csprng).seed(), &mut *csprng);
+    pksk.fill_with_fpksk_for_circuit_bootstrap(
+        &input_key,
+        &output_key,
+        Variance(variance),
+        &mut generator,
+    );
+
+    CsprngStatus::CsprngValid
 }
 
 #[no_mangle]
+#[must_use]
 pub unsafe extern "C" fn concrete_cpu_encrypt_lwe_ciphertext_u64(
     // secret key
     lwe_sk: *const u64,
@@ -142,9 +236,27 @@ pub unsafe extern "C" fn concrete_cpu_encrypt_lwe_ciphertext_u64(
     // encryption parameters
     variance: f64,
     csprng: *mut Csprng,
-) {
-    unused!(lwe_sk, lwe_out, input, lwe_dimension, variance, csprng);
-    todo!()
+) -> CsprngStatus {
+    if !(*csprng).can_seed(2) {
+        return CsprngStatus::CsprngExhausted;
+    }
+
+    let key = LweSecretKey::binary_from_container(slice::from_raw_parts(lwe_sk, lwe_dimension));
+    let mut ct = LweCiphertext::from_container(slice::from_raw_parts_mut(
+        lwe_out,
+        LweDimension(lwe_dimension).to_lwe_size().0,
+    ));
+
+    let mut generator =
+        EncryptionRandomGenerator::<SoftwareRandomGenerator>::new((*csprng).seed(), &mut *csprng);
+    key.encrypt_lwe(
+        &mut ct,
+        &Plaintext(input),
+        Variance(variance),
+        &mut generator,
+    );
+
+    CsprngStatus::CsprngValid
 }
 
 #[no_mangle]
@@ -158,6 +270,13 @@ pub unsafe extern "C" fn concrete_cpu_decrypt_lwe_ciphertext_u64(
     // plaintext
     plaintext: *mut u64,
 ) {
-    unused!(lwe_sk, lwe_ct_in, lwe_dimension, plaintext);
-    todo!()
+    let key = LweSecretKey::binary_from_container(slice::from_raw_parts(lwe_sk, lwe_dimension));
+    let ct = LweCiphertext::from_container(slice::from_raw_parts(
+        lwe_ct_in,
+        LweDimension(lwe_dimension).to_lwe_size().0,
+    ));
+
+    let mut output = Plaintext(0_u64);
+    key.decrypt_lwe(&mut output, &ct);
+    *plaintext = output.0;
 }
@@ -1,19 +1,72 @@
 use concrete_core::backends::fft::private::math::fft::Fft as FftImpl;
+use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
+use concrete_csprng::seeders::{Seed, Seeder};
 
 pub struct Csprng {
-    __private: (),
+    pub(crate) inner: SoftwareRandomGenerator,
+}
+
+/// The number of bytes drawn from a [`Csprng`] to produce a seed.
+pub(crate) const SEED_BYTES: u128 = core::mem::size_of::<u128>() as u128;
+
+impl Csprng {
+    /// Returns whether the csprng can still output `seed_count` seeds.
+    pub(crate) fn can_seed(&self, seed_count: u128) -> bool {
+        self.inner.remaining_bytes().0 >= seed_count * SEED_BYTES
+    }
+}
+
+// The key generation and encryption functions draw the seeds of the `concrete-core` generators from
+// the csprng handed by the caller, so that their outputs only depend on the seed of the csprng.
+// Those functions check that the csprng can output the seeds with `Csprng::can_seed` beforehand,
+// and report an exhausted csprng through `CsprngStatus`.
+impl Seeder for Csprng {
+    fn seed(&mut self) -> Seed {
+        let mut bytes = [0_u8; SEED_BYTES as usize];
+        for byte in bytes.iter_mut() {
+            *byte = self
+                .inner
+                .next_byte()
+                .expect("The csprng reached its bound.");
+        }
+        Seed(u128::from_le_bytes(bytes))
+    }
+
+    fn is_available() -> bool {
+        true
+    }
 }
 
 pub struct Fft {
     pub(crate) inner: FftImpl,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Uint128 {
+    pub little_endian_bytes: [u8; 16],
+}
+
 #[repr(u32)]
 pub enum ScratchStatus {
     Valid = 0,
     SizeOverflow = 1,
 }
 
+#[repr(u32)]
+pub enum ForkStatus {
+    ForkValid = 0,
+    ForkTooLarge = 1,
+    ForkZeroChildrenCount = 2,
+    ForkZeroBytesPerChild = 3,
+}
+
+#[repr(u32)]
+pub enum CsprngStatus {
+    CsprngValid = 0,
+    CsprngExhausted = 1,
+}
+
 #[repr(u32)]
 pub enum Parallelism {
     No = 0,
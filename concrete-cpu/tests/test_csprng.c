@@ -0,0 +1,142 @@
+#include "concrete-cpu.h"
+#include <assert.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "utils.h"
+
+uint64_t remaining_bytes_low(const Csprng *csprng) {
+  Uint128 remaining = concrete_cpu_csprng_remaining_bytes(csprng);
+  uint64_t output = 0;
+  for (int i = 0; i < 8; ++i) {
+    output |= ((uint64_t)remaining.little_endian_bytes[i]) << (8 * i);
+  }
+  return output;
+}
+
+int remaining_bytes_is_max(const Csprng *csprng) {
+  Uint128 remaining = concrete_cpu_csprng_remaining_bytes(csprng);
+  for (int i = 0; i < 16; ++i) {
+    if (remaining.little_endian_bytes[i] != 0xff) {
+      return 0;
+    }
+  }
+  return 1;
+}
+
+void os_seeder_test(void) {
+  Uint128 secret = {{0}};
+  Csprng *csprng_1 = concrete_cpu_new_csprng_from_os_seeder(secret);
+  Csprng *csprng_2 = concrete_cpu_new_csprng_from_os_seeder(secret);
+  assert(csprng_1 != NULL);
+  assert(csprng_2 != NULL);
+  assert(remaining_bytes_is_max(csprng_1));
+
+  // Two csprngs seeded by the os generate different keys
+  size_t lwe_dimension = 630;
+  uint64_t *sk_1 = malloc(sizeof(uint64_t) * lwe_dimension);
+  uint64_t *sk_2 = malloc(sizeof(uint64_t) * lwe_dimension);
+  concrete_cpu_init_lwe_secret_key_u64(sk_1, lwe_dimension, csprng_1);
+  concrete_cpu_init_lwe_secret_key_u64(sk_2, lwe_dimension, csprng_2);
+  assert(memcmp(sk_1, sk_2, sizeof(uint64_t) * lwe_dimension) != 0);
+
+  concrete_cpu_destroy_csprng(csprng_1);
+  concrete_cpu_destroy_csprng(csprng_2);
+  free(sk_1);
+  free(sk_2);
+}
+
+void fork_test(void) {
+  size_t children_count = 4;
+  size_t bytes_per_child = 1 << 20;
+  Csprng *children_1[4];
+  Csprng *children_2[4];
+
+  // Forking two csprngs in the same state yields the same children
+  Csprng *parent_1 = new_csprng_from_u64(3);
+  Csprng *parent_2 = new_csprng_from_u64(3);
+  ForkStatus status_1 =
+      concrete_cpu_fork_csprng(parent_1, children_count, bytes_per_child, children_1);
+  ForkStatus status_2 =
+      concrete_cpu_fork_csprng(parent_2, children_count, bytes_per_child, children_2);
+  assert(status_1 == ForkValid);
+  assert(status_2 == ForkValid);
+
+  size_t lwe_dimension = 630;
+  uint64_t *sk_1 = malloc(sizeof(uint64_t) * lwe_dimension);
+  uint64_t *sk_2 = malloc(sizeof(uint64_t) * lwe_dimension);
+  uint64_t *sk_other = malloc(sizeof(uint64_t) * lwe_dimension);
+  for (size_t i = 0; i < children_count; ++i) {
+    assert(remaining_bytes_low(children_1[i]) == bytes_per_child);
+
+    concrete_cpu_init_lwe_secret_key_u64(sk_1, lwe_dimension, children_1[i]);
+    concrete_cpu_init_lwe_secret_key_u64(sk_2, lwe_dimension, children_2[i]);
+    assert(memcmp(sk_1, sk_2, sizeof(uint64_t) * lwe_dimension) == 0);
+
+    // The bytes drawn from the child are accounted for
+    assert(remaining_bytes_low(children_1[i]) < bytes_per_child);
+  }
+
+  // The children output different streams, which differ from the one of the parent
+  concrete_cpu_init_lwe_secret_key_u64(sk_other, lwe_dimension, parent_1);
+  assert(memcmp(sk_1, sk_other, sizeof(uint64_t) * lwe_dimension) != 0);
+  concrete_cpu_init_lwe_secret_key_u64(sk_other, lwe_dimension, children_1[0]);
+  assert(memcmp(sk_1, sk_other, sizeof(uint64_t) * lwe_dimension) != 0);
+
+  // Invalid forks are reported and leave the parent usable
+  Csprng *child = NULL;
+  assert(concrete_cpu_fork_csprng(parent_1, 0, bytes_per_child, &child) == ForkZeroChildrenCount);
+  assert(concrete_cpu_fork_csprng(parent_1, 1, 0, &child) == ForkZeroBytesPerChild);
+  assert(concrete_cpu_fork_csprng(children_1[0], 2, bytes_per_child, &child) == ForkTooLarge);
+  assert(child == NULL);
+  concrete_cpu_init_lwe_secret_key_u64(sk_other, lwe_dimension, children_1[0]);
+
+  for (size_t i = 0; i < children_count; ++i) {
+    concrete_cpu_destroy_csprng(children_1[i]);
+    concrete_cpu_destroy_csprng(children_2[i]);
+  }
+  concrete_cpu_destroy_csprng(parent_1);
+  concrete_cpu_destroy_csprng(parent_2);
+  free(sk_1);
+  free(sk_2);
+  free(sk_other);
+}
+
+void exhaustion_test(void) {
+  // The children can each output the 16 bytes of a single seed
+  Csprng *parent = new_csprng_from_u64(5);
+  Csprng *children[2];
+  assert(concrete_cpu_fork_csprng(parent, 2, 16, children) == ForkValid);
+
+  size_t lwe_dimension = 630;
+  uint64_t *sk = malloc(sizeof(uint64_t) * lwe_dimension);
+  uint64_t *ct = malloc(sizeof(uint64_t) * (lwe_dimension + 1));
+
+  // A secret key draws one seed, after which the csprng is reported as exhausted
+  assert(concrete_cpu_init_lwe_secret_key_u64(sk, lwe_dimension, children[0]) == CsprngValid);
+  assert(remaining_bytes_low(children[0]) == 0);
+  assert(concrete_cpu_init_lwe_secret_key_u64(sk, lwe_dimension, children[0]) == CsprngExhausted);
+
+  // An encryption draws two seeds, and leaves the ciphertext and the csprng untouched on failure
+  memset(ct, 0, sizeof(uint64_t) * (lwe_dimension + 1));
+  assert(concrete_cpu_encrypt_lwe_ciphertext_u64(sk, ct, 0, lwe_dimension, 0.000000001,
+                                                 children[1]) == CsprngExhausted);
+  assert(remaining_bytes_low(children[1]) == 16);
+  for (size_t i = 0; i < lwe_dimension + 1; ++i) {
+    assert(ct[i] == 0);
+  }
+
+  concrete_cpu_destroy_csprng(children[0]);
+  concrete_cpu_destroy_csprng(children[1]);
+  concrete_cpu_destroy_csprng(parent);
+  free(sk);
+  free(ct);
+}
+
+int main(void) {
+  os_seeder_test();
+  fork_test();
+  exhaustion_test();
+  return EXIT_SUCCESS;
+}
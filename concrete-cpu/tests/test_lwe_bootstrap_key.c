@@ -0,0 +1,91 @@
+#include "concrete-cpu.h"
+#include <assert.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <tgmath.h>
+
+#include "utils.h"
+
+// Computes the coefficients of `body - sum_i mask_i * key_i` modulo X^N + 1, that is the raw
+// decryption of a GLWE ciphertext.
+void decrypt_glwe(uint64_t *output, const uint64_t *glwe, const uint64_t *glwe_sk,
+                  size_t glwe_dimension, size_t polynomial_size) {
+  const uint64_t *body = glwe + glwe_dimension * polynomial_size;
+  for (size_t i = 0; i < polynomial_size; ++i) {
+    output[i] = body[i];
+  }
+  for (size_t k = 0; k < glwe_dimension; ++k) {
+    const uint64_t *mask = glwe + k * polynomial_size;
+    const uint64_t *key = glwe_sk + k * polynomial_size;
+    for (size_t i = 0; i < polynomial_size; ++i) {
+      for (size_t j = 0; j < polynomial_size; ++j) {
+        uint64_t product = mask[i] * key[j];
+        if (i + j < polynomial_size) {
+          output[i + j] -= product;
+        } else {
+          output[i + j - polynomial_size] += product;
+        }
+      }
+    }
+  }
+}
+
+int64_t torus_distance(uint64_t a, uint64_t b) { return llabs((int64_t)(a - b)); }
+
+void bootstrap_key_test(void) {
+  // We generate the random sources
+  Csprng *csprng = new_csprng_from_u64(0);
+  double variance = 0.00000000000000000001;
+
+  // We generate the secret keys
+  size_t input_lwe_dimension = 8;
+  size_t glwe_dimension = 1;
+  size_t polynomial_size = 256;
+  uint64_t *input_sk = malloc(sizeof(uint64_t) * input_lwe_dimension);
+  uint64_t *output_sk = malloc(sizeof(uint64_t) * glwe_dimension * polynomial_size);
+  concrete_cpu_init_lwe_secret_key_u64(input_sk, input_lwe_dimension, csprng);
+  concrete_cpu_init_lwe_secret_key_u64(output_sk, glwe_dimension * polynomial_size, csprng);
+
+  // We generate the bootstrap key
+  size_t level = 2;
+  size_t base_log = 10;
+  size_t glwe_size = glwe_dimension + 1;
+  size_t glwe_len = glwe_size * polynomial_size;
+  size_t level_matrix_len = glwe_size * glwe_len;
+  size_t ggsw_len = level * level_matrix_len;
+  uint64_t *bsk = malloc(sizeof(uint64_t) * input_lwe_dimension * ggsw_len);
+  concrete_cpu_init_lwe_bootstrap_key_u64(bsk, input_sk, output_sk, input_lwe_dimension,
+                                          polynomial_size, glwe_dimension, level, base_log,
+                                          variance, csprng);
+
+  // Each GGSW ciphertext encrypts a bit of the input key: the last row of the level matrix j
+  // decrypts to the constant polynomial bit * 2^(64 - j * base_log).
+  uint64_t *decrypted = malloc(sizeof(uint64_t) * polynomial_size);
+  uint64_t tolerance = ((uint64_t)1) << 40;
+  for (size_t i = 0; i < input_lwe_dimension; ++i) {
+    for (size_t j = 1; j <= level; ++j) {
+      const uint64_t *last_row =
+          bsk + i * ggsw_len + (j - 1) * level_matrix_len + glwe_dimension * glwe_len;
+      decrypt_glwe(decrypted, last_row, output_sk, glwe_dimension, polynomial_size);
+
+      uint64_t expected = input_sk[i] << (64 - j * base_log);
+      assert(torus_distance(decrypted[0], expected) < tolerance);
+      for (size_t k = 1; k < polynomial_size; ++k) {
+        assert(torus_distance(decrypted[k], 0) < tolerance);
+      }
+    }
+  }
+  printf("Decrypted the %zu GGSW ciphertexts of the bootstrap key.\n", input_lwe_dimension);
+
+  // We deallocate the objects
+  concrete_cpu_destroy_csprng(csprng);
+  free(input_sk);
+  free(output_sk);
+  free(bsk);
+  free(decrypted);
+}
+
+int main(void) {
+  bootstrap_key_test();
+  return EXIT_SUCCESS;
+}
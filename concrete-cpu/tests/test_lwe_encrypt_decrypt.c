@@ -0,0 +1,78 @@
+#include "concrete-cpu.h"
+#include <assert.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <tgmath.h>
+
+#include "utils.h"
+
+void encrypt_decrypt_test(void) {
+  // We generate the random sources
+  Csprng *csprng = new_csprng_from_u64(0);
+  double variance = 0.000000001;
+
+  // We generate the secret key
+  size_t lwe_dimension = 10;
+  uint64_t *sk = malloc(sizeof(uint64_t) * lwe_dimension);
+  CsprngStatus sk_status = concrete_cpu_init_lwe_secret_key_u64(sk, lwe_dimension, csprng);
+  assert(sk_status == CsprngValid);
+
+  for (size_t i = 0; i < lwe_dimension; ++i) {
+    assert(sk[i] == 0 || sk[i] == 1);
+  }
+
+  // We encrypt the plaintext
+  uint64_t *ct = malloc(sizeof(uint64_t) * (lwe_dimension + 1));
+  uint64_t plaintext = ((uint64_t)2) << SHIFT;
+  CsprngStatus encrypt_status =
+      concrete_cpu_encrypt_lwe_ciphertext_u64(sk, ct, plaintext, lwe_dimension, variance, csprng);
+  assert(encrypt_status == CsprngValid);
+
+  // We decrypt the plaintext
+  uint64_t output = -1;
+  concrete_cpu_decrypt_lwe_ciphertext_u64(sk, ct, lwe_dimension, &output);
+
+  // We check that the output are the same
+  double expected = (double)plaintext / pow(2, SHIFT);
+  double obtained = (double)output / pow(2, SHIFT);
+  printf("Comparing output. Expected %f, Obtained %f\n", expected, obtained);
+  double abs_diff = fabs(obtained - expected);
+  double rel_error = abs_diff / fmax(expected, obtained);
+  assert(rel_error < 0.002);
+
+  // We deallocate the objects
+  concrete_cpu_destroy_csprng(csprng);
+  free(sk);
+  free(ct);
+}
+
+void secret_key_determinism_test(void) {
+  size_t lwe_dimension = 630;
+  uint64_t *sk_1 = malloc(sizeof(uint64_t) * lwe_dimension);
+  uint64_t *sk_2 = malloc(sizeof(uint64_t) * lwe_dimension);
+  uint64_t *sk_3 = malloc(sizeof(uint64_t) * lwe_dimension);
+
+  // Two csprngs built from the same seed generate the same key
+  Csprng *csprng_1 = new_csprng_from_u64(42);
+  Csprng *csprng_2 = new_csprng_from_u64(42);
+  concrete_cpu_init_lwe_secret_key_u64(sk_1, lwe_dimension, csprng_1);
+  concrete_cpu_init_lwe_secret_key_u64(sk_2, lwe_dimension, csprng_2);
+  assert(memcmp(sk_1, sk_2, sizeof(uint64_t) * lwe_dimension) == 0);
+
+  // A csprng is advanced by the generation, so the next key is different
+  concrete_cpu_init_lwe_secret_key_u64(sk_3, lwe_dimension, csprng_1);
+  assert(memcmp(sk_1, sk_3, sizeof(uint64_t) * lwe_dimension) != 0);
+
+  concrete_cpu_destroy_csprng(csprng_1);
+  concrete_cpu_destroy_csprng(csprng_2);
+  free(sk_1);
+  free(sk_2);
+  free(sk_3);
+}
+
+int main(void) {
+  encrypt_decrypt_test();
+  secret_key_determinism_test();
+  return EXIT_SUCCESS;
+}
@@ -0,0 +1,71 @@
+#include "concrete-cpu.h"
+#include <assert.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <tgmath.h>
+
+#include "utils.h"
+
+void keyswitch_test(void) {
+  // We generate the random sources
+  Csprng *csprng = new_csprng_from_u64(0);
+  double variance = 0.00000000000000000001;
+
+  // We generate the secret keys
+  size_t input_lwe_dimension = 1024;
+  size_t output_lwe_dimension = 600;
+  uint64_t *input_sk = malloc(sizeof(uint64_t) * input_lwe_dimension);
+  uint64_t *output_sk = malloc(sizeof(uint64_t) * output_lwe_dimension);
+  concrete_cpu_init_lwe_secret_key_u64(input_sk, input_lwe_dimension, csprng);
+  concrete_cpu_init_lwe_secret_key_u64(output_sk, output_lwe_dimension, csprng);
+
+  // We generate the keyswitch key
+  size_t level = 3;
+  size_t base_log = 5;
+  uint64_t *ksk = malloc(sizeof(uint64_t) * level * (output_lwe_dimension + 1) * input_lwe_dimension);
+  concrete_cpu_init_lwe_keyswitch_key_u64(ksk, input_sk, output_sk, input_lwe_dimension,
+                                          output_lwe_dimension, level, base_log, variance, csprng);
+
+  // We encrypt the plaintext
+  uint64_t *input_ct = malloc(sizeof(uint64_t) * (input_lwe_dimension + 1));
+  uint64_t *output_ct = malloc(sizeof(uint64_t) * (output_lwe_dimension + 1));
+  uint64_t plaintext = ((uint64_t)3) << SHIFT;
+  concrete_cpu_encrypt_lwe_ciphertext_u64(input_sk, input_ct, plaintext, input_lwe_dimension,
+                                          variance, csprng);
+
+  // We keyswitch the ciphertext
+  size_t stack_size = 0;
+  size_t stack_align = 0;
+  ScratchStatus scratch_ok = concrete_cpu_keyswitch_lwe_ciphertext_u64_scratch(
+      &stack_size, &stack_align, level, base_log, input_lwe_dimension, output_lwe_dimension, No);
+  assert(scratch_ok == Valid);
+  assert(stack_size == 0);
+  concrete_cpu_keyswitch_lwe_ciphertext_u64(output_ct, input_ct, ksk, level, base_log,
+                                            input_lwe_dimension, output_lwe_dimension, No, NULL,
+                                            stack_size);
+
+  // We decrypt the plaintext
+  uint64_t output = -1;
+  concrete_cpu_decrypt_lwe_ciphertext_u64(output_sk, output_ct, output_lwe_dimension, &output);
+
+  // We check that the output are the same
+  double expected = (double)plaintext / pow(2, SHIFT);
+  double obtained = (double)output / pow(2, SHIFT);
+  printf("Comparing output. Expected %f, Obtained %f\n", expected, obtained);
+  double abs_diff = fabs(obtained - expected);
+  double rel_error = abs_diff / fmax(expected, obtained);
+  assert(rel_error < 0.002);
+
+  // We deallocate the objects
+  concrete_cpu_destroy_csprng(csprng);
+  free(input_sk);
+  free(output_sk);
+  free(ksk);
+  free(input_ct);
+  free(output_ct);
+}
+
+int main(void) {
+  keyswitch_test();
+  return EXIT_SUCCESS;
+}
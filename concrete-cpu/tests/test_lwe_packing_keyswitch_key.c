@@ -0,0 +1,63 @@
+#include "concrete-cpu.h"
+#include <assert.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "utils.h"
+
+void packing_keyswitch_key_test(void) {
+  double variance = 0.00000000000000000001;
+
+  size_t input_lwe_dimension = 64;
+  size_t glwe_dimension = 1;
+  size_t polynomial_size = 256;
+  size_t level = 2;
+  size_t base_log = 15;
+
+  // The circuit bootstrap uses one private functional packing keyswitch key per output GLWE
+  // polynomial, the body included.
+  size_t glwe_size = glwe_dimension + 1;
+  size_t pksk_len =
+      level * glwe_size * polynomial_size * (input_lwe_dimension + 1) * glwe_size;
+
+  // We generate the same keys with two csprngs seeded identically
+  uint64_t *keys[2];
+  for (int i = 0; i < 2; ++i) {
+    Csprng *csprng = new_csprng_from_u64(7);
+
+    uint64_t *input_sk = malloc(sizeof(uint64_t) * input_lwe_dimension);
+    uint64_t *output_sk = malloc(sizeof(uint64_t) * glwe_dimension * polynomial_size);
+    concrete_cpu_init_lwe_secret_key_u64(input_sk, input_lwe_dimension, csprng);
+    concrete_cpu_init_lwe_secret_key_u64(output_sk, glwe_dimension * polynomial_size, csprng);
+
+    keys[i] = calloc(pksk_len, sizeof(uint64_t));
+    concrete_cpu_init_lwe_packing_keyswitch_key_u64(keys[i], input_sk, output_sk,
+                                                    input_lwe_dimension, polynomial_size,
+                                                    glwe_dimension, level, base_log, variance,
+                                                    csprng);
+
+    concrete_cpu_destroy_csprng(csprng);
+    free(input_sk);
+    free(output_sk);
+  }
+
+  // The whole key was filled
+  size_t zeros = 0;
+  for (size_t i = 0; i < pksk_len; ++i) {
+    zeros += keys[0][i] == 0;
+  }
+  printf("Found %zu zero coefficients out of %zu.\n", zeros, pksk_len);
+  assert(zeros < pksk_len / 1000);
+
+  // The generation only depends on the seed of the csprng
+  assert(memcmp(keys[0], keys[1], sizeof(uint64_t) * pksk_len) == 0);
+
+  free(keys[0]);
+  free(keys[1]);
+}
+
+int main(void) {
+  packing_keyswitch_key_test();
+  return EXIT_SUCCESS;
+}
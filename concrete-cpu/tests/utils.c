@@ -0,0 +1,12 @@
+#include "concrete-cpu.h"
+#include <assert.h>
+
+Csprng *new_csprng_from_u64(uint64_t seed) {
+  Uint128 seed_128 = {{0}};
+  for (int i = 0; i < 8; ++i) {
+    seed_128.little_endian_bytes[i] = (uint8_t)(seed >> (8 * i));
+  }
+  Csprng *csprng = concrete_cpu_new_csprng(seed_128);
+  assert(csprng != NULL);
+  return csprng;
+}
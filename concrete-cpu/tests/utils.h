@@ -0,0 +1,11 @@
+#ifndef CPU_TEST_UTILS
+#define CPU_TEST_UTILS
+
+#include "concrete-cpu.h"
+
+const int MESSAGE_BITS = 4;
+const int SHIFT = 64 - (MESSAGE_BITS + 1);
+
+Csprng *new_csprng_from_u64(uint64_t seed);
+
+#endif // CPU_TEST_UTILS
@@ -56,6 +56,14 @@ pub fn ffi() -> Result<(), Error> {
     ))
 }
 
+pub fn cpu() -> Result<(), Error> {
+    cmd!(<ENV_TARGET_NATIVE>
+        &format!(
+        "./concrete-cpu/build-and-run-c-tests.sh --rust-toolchain '{}'",
+        get_build_toolchain()?,
+    ))
+}
+
 pub fn cuda_test() -> Result<(), Error> {
     cmd!(<ENV_TARGET_NATIVE>
         &format!("cargo test --profile release-debug-asserts --no-fail-fast \
@@ -77,7 +85,8 @@ pub fn crates() -> Result<(), Error> {
     core_test()?;
     csprng()?;
     npe()?;
-    ffi()
+    ffi()?;
+    cpu()
 }
 
 pub fn cuda() -> Result<(), Error> {